@@ -5,7 +5,7 @@
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 use tokio::sync::{mpsc, RwLock};
 use tokio::time::{Duration, Instant};
@@ -63,42 +63,129 @@ pub enum StrategyType {
 // HIGH-PERFORMANCE DATA PROCESSING
 // ============================================================================
 
+/// Field names tried in order; providers rename fields more often than they drop them
+const SYMBOL_FIELDS: &[&str] = &["symbol", "s", "pair", "market"];
+const PRICE_FIELDS: &[&str] = &["price", "p", "last_price", "lastPrice", "last"];
+const VOLUME_FIELDS: &[&str] = &["volume", "v", "vol", "volume_24h"];
+const TIMESTAMP_FIELDS: &[&str] = &["timestamp", "ts", "time", "t"];
+const BID_FIELDS: &[&str] = &["bid", "b", "best_bid", "bestBid"];
+const ASK_FIELDS: &[&str] = &["ask", "a", "best_ask", "bestAsk"];
+const SPREAD_FIELDS: &[&str] = &["spread"];
+/// Envelopes some providers wrap the payload in
+const ENVELOPE_FIELDS: &[&str] = &["data", "result", "payload"];
+
+#[derive(Debug, Clone)]
+pub struct TolerantParsingConfig {
+    /// Recover what can be extracted from unexpected shapes instead of rejecting them
+    pub best_effort: bool,
+    /// Consecutive malformed messages that raise a provider schema alert
+    pub alert_after_consecutive: u64,
+}
+
+impl Default for TolerantParsingConfig {
+    fn default() -> Self {
+        Self {
+            best_effort: true,
+            alert_after_consecutive: 100,
+        }
+    }
+}
+
+/// Parse health counters, exported as metrics
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct ParseMetrics {
+    pub parsed: u64,
+    /// Messages in an unexpected shape that still yielded market data
+    pub recovered: u64,
+    /// Messages no market data could be extracted from
+    pub malformed: u64,
+    pub consecutive_malformed: u64,
+    pub schema_alerts: u64,
+}
+
 pub struct FastMarketDataProcessor {
     buffer: Vec<u8>,
     parser_cache: HashMap<String, Value>,
+    config: TolerantParsingConfig,
+    metrics: ParseMetrics,
+    /// Shapes already logged, so each new schema is reported once
+    seen_shapes: HashSet<String>,
 }
 
 impl FastMarketDataProcessor {
     pub fn new() -> Self {
+        Self::with_config(TolerantParsingConfig::default())
+    }
+
+    pub fn with_config(config: TolerantParsingConfig) -> Self {
         Self {
             buffer: Vec::with_capacity(4096),
             parser_cache: HashMap::with_capacity(1000),
+            config,
+            metrics: ParseMetrics::default(),
+            seen_shapes: HashSet::new(),
         }
     }
 
+    pub fn metrics(&self) -> &ParseMetrics {
+        &self.metrics
+    }
+
     #[instrument(skip(self, json_data))]
     pub fn parse_market_data(&mut self, json_data: &str) -> Result<MarketData> {
         // Fast path: check cache first
         if let Some(cached) = self.parser_cache.get(json_data) {
-            return self.extract_market_data_from_value(cached);
+            let market_data = Self::extract_market_data_from_value(cached)?;
+            self.metrics.parsed += 1;
+            return Ok(market_data);
         }
 
-        // Parse and cache
-        let value: Value = serde_json::from_str(json_data)?;
-        let market_data = self.extract_market_data_from_value(&value)?;
-        
+        let value: Value = match serde_json::from_str(json_data) {
+            Ok(value) => value,
+            Err(e) => {
+                self.note_shape("invalid-json", json_data);
+                return Err(self.malformed(anyhow::anyhow!("Invalid market data JSON: {}", e)));
+            }
+        };
+
+        let market_data = match Self::extract_market_data_from_value(&value) {
+            Ok(market_data) => market_data,
+            Err(e) => {
+                self.note_shape(&Self::shape_of(&value), json_data);
+                if !self.config.best_effort {
+                    return Err(self.malformed(e));
+                }
+                match Self::extract_best_effort(&value) {
+                    Some(market_data) => {
+                        self.metrics.recovered += 1;
+                        market_data
+                    }
+                    None => return Err(self.malformed(e)),
+                }
+            }
+        };
+        self.metrics.parsed += 1;
+        self.metrics.consecutive_malformed = 0;
+
         // Cache for future use (with size limit)
         if self.parser_cache.len() < 1000 {
             self.parser_cache.insert(json_data.to_string(), value);
         }
-        
+
         Ok(market_data)
     }
 
-    fn extract_market_data_from_value(&self, value: &Value) -> Result<MarketData> {
+    /// The expected schema; symbol and price are required, the rest default to zero
+    fn extract_market_data_from_value(value: &Value) -> Result<MarketData> {
+        let symbol = value["symbol"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Market data without symbol"))?;
+        let price = value["price"]
+            .as_f64()
+            .ok_or_else(|| anyhow::anyhow!("Market data without numeric price"))?;
         Ok(MarketData {
-            symbol: value["symbol"].as_str().unwrap_or("UNKNOWN").to_string(),
-            price: value["price"].as_f64().unwrap_or(0.0),
+            symbol: symbol.to_string(),
+            price,
             volume: value["volume"].as_f64().unwrap_or(0.0),
             timestamp: value["timestamp"].as_i64().unwrap_or(0),
             bid: value["bid"].as_f64().unwrap_or(0.0),
@@ -107,6 +194,72 @@ impl FastMarketDataProcessor {
         })
     }
 
+    /// Look through envelopes and renamed fields, accepting numbers sent as strings
+    fn extract_best_effort(value: &Value) -> Option<MarketData> {
+        let body = ENVELOPE_FIELDS
+            .iter()
+            .find_map(|field| value.get(*field).filter(|v| v.is_object()))
+            .unwrap_or(value);
+
+        let symbol = SYMBOL_FIELDS
+            .iter()
+            .find_map(|field| body.get(*field)?.as_str())?;
+        let price = first_number(body, PRICE_FIELDS).filter(|p| p.is_finite() && *p > 0.0)?;
+        let bid = first_number(body, BID_FIELDS).unwrap_or(0.0);
+        let ask = first_number(body, ASK_FIELDS).unwrap_or(0.0);
+        Some(MarketData {
+            symbol: symbol.to_string(),
+            price,
+            volume: first_number(body, VOLUME_FIELDS).unwrap_or(0.0),
+            timestamp: first_number(body, TIMESTAMP_FIELDS).map_or(0, |t| t as i64),
+            bid,
+            ask,
+            spread: first_number(body, SPREAD_FIELDS).unwrap_or(if bid > 0.0 && ask > 0.0 {
+                ask - bid
+            } else {
+                0.0
+            }),
+        })
+    }
+
+    /// Sorted top-level keys, e.g. "{data,type}"; identifies a schema without its values
+    fn shape_of(value: &Value) -> String {
+        match value {
+            Value::Object(map) => {
+                let mut keys: Vec<&str> = map.keys().map(String::as_str).collect();
+                keys.sort_unstable();
+                format!("{{{}}}", keys.join(","))
+            }
+            Value::Array(_) => "array".to_string(),
+            Value::String(_) => "string".to_string(),
+            Value::Number(_) => "number".to_string(),
+            Value::Bool(_) => "bool".to_string(),
+            Value::Null => "null".to_string(),
+        }
+    }
+
+    fn note_shape(&mut self, shape: &str, sample: &str) {
+        if self.seen_shapes.insert(shape.to_string()) {
+            let sample: String = sample.chars().take(200).collect();
+            warn!("Unexpected market data shape {}: {}", shape, sample);
+        }
+    }
+
+    /// Count a message no market data could be extracted from
+    fn malformed(&mut self, error: anyhow::Error) -> anyhow::Error {
+        self.metrics.malformed += 1;
+        self.metrics.consecutive_malformed += 1;
+        if self.metrics.consecutive_malformed == self.config.alert_after_consecutive.max(1) {
+            self.metrics.schema_alerts += 1;
+            error!(
+                "ALERT: last {} market data messages were malformed, the provider schema may have changed ({} unexpected shapes seen)",
+                self.metrics.consecutive_malformed,
+                self.seen_shapes.len()
+            );
+        }
+        error
+    }
+
     pub fn create_websocket_subscription(&self, symbol: &str) -> String {
         json!({
             "type": "subscribe",
@@ -117,6 +270,15 @@ impl FastMarketDataProcessor {
     }
 }
 
+/// First of `fields` holding a number, or a string that parses as one
+fn first_number(body: &Value, fields: &[&str]) -> Option<f64> {
+    fields.iter().find_map(|field| match body.get(*field)? {
+        Value::Number(n) => n.as_f64(),
+        Value::String(s) => s.parse().ok(),
+        _ => None,
+    })
+}
+
 // ============================================================================
 // ASYNC STRATEGY EXECUTION
 // ============================================================================
@@ -445,4 +607,48 @@ mod tests {
         assert_eq!(result.symbol, "SOL/USDC");
         assert_eq!(result.price, 100.0);
     }
+
+    #[test]
+    fn test_market_data_parsing_recovers_renamed_fields() {
+        let mut processor = FastMarketDataProcessor::new();
+
+        let json_data = r#"{"data": {"s": "SOL/USDC", "p": "101.5", "b": 101.4, "a": 101.6}}"#;
+
+        let result = processor.parse_market_data(json_data).unwrap();
+        assert_eq!(result.symbol, "SOL/USDC");
+        assert_eq!(result.price, 101.5);
+        assert!((result.spread - 0.2).abs() < 1e-9);
+        assert_eq!(processor.metrics().recovered, 1);
+        assert_eq!(processor.metrics().malformed, 0);
+    }
+
+    #[test]
+    fn test_market_data_parsing_strict_rejects_unexpected_shape() {
+        let mut processor = FastMarketDataProcessor::with_config(TolerantParsingConfig {
+            best_effort: false,
+            ..Default::default()
+        });
+
+        assert!(processor.parse_market_data(r#"{"s": "SOL/USDC", "p": 101.5}"#).is_err());
+        assert_eq!(processor.metrics().malformed, 1);
+    }
+
+    #[test]
+    fn test_consecutive_malformed_messages_raise_schema_alert() {
+        let mut processor = FastMarketDataProcessor::with_config(TolerantParsingConfig {
+            best_effort: true,
+            alert_after_consecutive: 3,
+        });
+
+        for _ in 0..3 {
+            assert!(processor.parse_market_data(r#"{"unexpected": true}"#).is_err());
+        }
+        assert!(processor.parse_market_data("not json").is_err());
+        assert_eq!(processor.metrics().malformed, 4);
+        assert_eq!(processor.metrics().schema_alerts, 1);
+
+        let ok = r#"{"symbol": "SOL/USDC", "price": 100.0}"#;
+        processor.parse_market_data(ok).unwrap();
+        assert_eq!(processor.metrics().consecutive_malformed, 0);
+    }
 }
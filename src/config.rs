@@ -15,6 +15,7 @@ pub struct Config {
     pub logging: LoggingConfig,
     // THE OVERMIND PROTOCOL - HFT Engine Configuration
     pub overmind: OvermindConfig,
+    pub message_bus: MessageBusConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,6 +23,159 @@ pub struct TradingConfig {
     pub mode: TradingMode,
     pub max_position_size: f64,
     pub max_daily_loss: f64,
+    /// SOL that may be deployed in open positions across all wallets (0 disables)
+    pub max_portfolio_exposure_sol: f64,
+    pub max_consecutive_losses: u32,
+    pub losing_streak_cooldown_secs: u64,
+    /// Window after a fill during which the same strategy may not trade the same symbol (0 disables)
+    pub post_execution_cooldown_secs: u64,
+    /// Periodically shift position-size allocation toward better-performing strategies
+    pub allocation_rebalancer_enabled: bool,
+    pub allocation_rebalance_interval_secs: u64,
+    /// Commitment live trades must reach before being reported Confirmed (processed/confirmed/finalized)
+    pub confirmation_commitment: String,
+    /// Per-strategy overrides, e.g. "TokenSniping=processed,Arbitrage=finalized"
+    pub strategy_confirmation_commitments: Option<String>,
+    /// Slots a live submission may stay unconfirmed before its bundle is rebuilt (0 disables)
+    pub max_bundle_age_slots: u64,
+    /// Delay paper trades by the confirmation latency of their commitment level instead of a flat 50ms
+    pub paper_confirmation_simulated: bool,
+    /// Median:p90 confirmation latency overrides in ms, e.g. "processed=400:900,finalized=13000:16000"
+    pub paper_confirmation_latencies_ms: Option<String>,
+    /// Reject sniping buys of tokens whose mint flags block or tax sells
+    pub honeypot_check_enabled: bool,
+    pub honeypot_cache_ttl_secs: u64,
+    /// Per-strategy sane target price bands, e.g. "MeteoraDAMM=0.000000001:0.01"
+    pub target_price_bands: Option<String>,
+    /// Per-strategy allowed slippage by order size as a fraction of pool liquidity, e.g.
+    /// "TokenSniping=0.001:30|0.05:300,default=0.01:100"; unset leaves fills unbounded
+    pub slippage_curves: Option<String>,
+    /// Per-strategy symbols and sources ticks are routed for, e.g. "TokenSniping=BONK/USDC|@Helius"
+    pub strategy_market_filters: Option<String>,
+    /// Strategies the engine does not run, e.g. "TokenSniping"
+    pub disabled_strategies: Option<String>,
+    /// Strategies whose signals are logged and persisted but never sent to risk, e.g. "TokenSniping"
+    pub observe_only_strategies: Option<String>,
+    /// Fail readiness while the strategy engine has no active strategy
+    pub require_active_strategies: bool,
+    /// Share a TTL cache of mint decimals, symbol and authorities across components
+    pub token_metadata_cache_enabled: bool,
+    pub token_metadata_ttl_secs: u64,
+    /// Cache misses report metadata unavailable after this long instead of blocking
+    pub token_metadata_fetch_timeout_ms: u64,
+    /// Comma-separated mints fetched into the cache at startup
+    pub token_metadata_warm_mints: Option<String>,
+    /// Venue swap fee charged on trade notional, for fee accounting (basis points)
+    pub swap_fee_bps: f64,
+    /// Priority fee assumed on execution paths that do not choose one per trade
+    pub default_priority_fee_lamports: u64,
+    /// Largest acceptable difference between the local clock and cluster block time
+    pub max_clock_skew_ms: u64,
+    pub clock_skew_check_interval_secs: u64,
+    /// Calibrate the paper fill model's slippage from confirmed live fills
+    pub slippage_learning_enabled: bool,
+    /// Learned slippage parameters, loaded at startup and saved after each calibration
+    pub slippage_params_path: Option<String>,
+    /// Use the loaded slippage parameters without further calibration
+    pub slippage_params_frozen: bool,
+    pub slippage_calibration_interval_secs: u64,
+    /// Compare persisted positions with on-chain wallet balances before live trading starts
+    pub reconcile_on_startup: bool,
+    /// Replace the persisted positions with the reconciled on-chain view
+    pub reconcile_rebuild_positions: bool,
+    pub position_snapshot_path: String,
+    /// JSON-lines trade log used to attribute untracked holdings when rebuilding
+    pub trade_log_path: Option<String>,
+    /// Let the strategy engine, risk manager and wallet manager persist their own events
+    pub persist_module_events: bool,
+    /// Record every rejected signal with the risk parameters in force, for threshold tuning
+    pub record_rejected_signals: bool,
+    /// Catch strategy panics instead of letting them stop the strategy engine
+    pub strategy_sandbox_enabled: bool,
+    /// Caught panics before a strategy is disabled
+    pub strategy_max_panics: u32,
+    /// Pre-flight simulations the live trading halt judges the failure rate over
+    pub simulation_guard_window: usize,
+    pub simulation_guard_min_samples: usize,
+    /// Simulation failure rate (0.0 - 1.0) above which live trading is halted
+    pub simulation_guard_max_failure_rate: f64,
+    /// Consecutive successful simulations that lift a halt (0 requires POST /trading/live/enable)
+    pub simulation_guard_recovery_streak: u32,
+    /// Compared ticks the shadow-vs-live disagreement rate is judged over
+    pub shadow_divergence_window: usize,
+    pub shadow_divergence_min_samples: usize,
+    /// Shadow-vs-live disagreement rate (0.0 - 1.0) above which an alert is raised
+    pub shadow_divergence_max_rate: f64,
+    /// Entry price of a candidate token sniping config run in shadow; unset runs no shadow
+    pub shadow_sniping_entry_price: Option<f64>,
+    /// Wallet selection decisions kept for /admin/wallet-selection-trace (0 disables); only
+    /// multi-wallet routing selects wallets, so the trace stays empty on the single-wallet path
+    pub wallet_selection_audit_size: usize,
+    /// Run the developer tracker and trade the launches it detects
+    pub developer_tracker_enabled: bool,
+    /// Symbols whose full signal→execution pipeline is captured for forensics (comma-separated)
+    pub capture_symbols: Option<String>,
+    /// Signal IDs whose pipeline is captured regardless of symbol (comma-separated)
+    pub capture_signal_ids: Option<String>,
+    /// Captured traces kept in memory for `/debug/trace/:signal_id`
+    pub capture_max_traces: usize,
+    /// Optional Solana token-list JSON used to resolve symbols to mints
+    pub token_list_path: Option<String>,
+    /// Market ticks older than this when dequeued are skipped (milliseconds)
+    pub max_tick_age_ms: u64,
+    /// After a market data reconnect, exact tick replays are dropped for this long (0 disables)
+    pub replay_dedup_window_ms: u64,
+    /// How long forwarded ticks are remembered to recognize replays (milliseconds)
+    pub replay_dedup_ttl_ms: u64,
+    /// Persist and count strategy Hold decisions (they are never executed either way)
+    pub record_hold_decisions: bool,
+    /// DragonflyDB holding executed signal IDs across restarts and instances (unset disables)
+    pub idempotency_store_url: Option<String>,
+    /// How long an executed signal ID is remembered in the store (seconds)
+    pub idempotency_ttl_secs: u64,
+    /// Execute signals unchecked while the store is down instead of rejecting them
+    pub idempotency_fail_open: bool,
+    /// Refuse to start live trading while `Config::validate_live` reports issues
+    pub live_safety_checks_enabled: bool,
+    /// Lowest AI confidence threshold accepted for live trading
+    pub live_min_confidence_threshold: f64,
+    /// Highest execution latency budget accepted for live trading (milliseconds)
+    pub live_max_execution_latency_ms: u64,
+    /// Notional (quantity x target price) each strategy may open per minute (0 disables)
+    pub max_notional_per_minute: f64,
+    /// Per-strategy overrides of the notional rate, e.g. "TokenSniping=50,MeteoraDAMM=5"
+    pub strategy_notional_rate_limits: Option<String>,
+    /// Handling of signals whose symbol has no market price yet: "signal_price", "reject",
+    /// "wait_proceed:<ms>" or "wait_reject:<ms>"
+    pub missing_price_policy: String,
+    /// Per-strategy overrides such as "Arbitrage=wait_reject:500,TokenSniping=signal_price"
+    pub strategy_missing_price_policies: Option<String>,
+    /// Fees and tips in SOL each strategy may pay per UTC day (0 disables)
+    pub daily_fee_budget_sol: f64,
+    /// Per-strategy overrides of the daily fee budget, e.g. "MeteoraDAMM=0.05"
+    pub strategy_fee_budgets: Option<String>,
+}
+
+/// Position size and daily loss defaults of `from_env`, which only make sense for testing
+const TEST_DEFAULT_MAX_POSITION_SIZE: f64 = 1000.0;
+const TEST_DEFAULT_MAX_DAILY_LOSS: f64 = 500.0;
+
+/// Words of credentials that were never replaced with real values. Matched against whole
+/// words only, so base58 keys that merely contain one of them are not flagged.
+const PLACEHOLDER_MARKERS: [&str; 6] =
+    ["test", "your", "placeholder", "changeme", "example", "xxx"];
+
+/// The placeholder word a credential contains, splitting it on non-alphanumeric characters
+fn placeholder_marker(value: &str) -> Option<&'static str> {
+    let lowered = value.to_lowercase();
+    let words: Vec<&str> = lowered
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    PLACEHOLDER_MARKERS
+        .iter()
+        .find(|marker| words.contains(marker))
+        .copied()
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +191,12 @@ pub struct SolanaConfig {
     // Multi-wallet support
     pub multi_wallet_enabled: bool,
     pub default_wallet_id: Option<String>,
+    /// SOL the wallet may lose beyond what our trades explain before it is treated as compromised (0 disables)
+    pub compromise_delta_threshold_sol: f64,
+    /// Public key that a compromised wallet's remaining SOL is swept to; no sweep when unset
+    pub compromise_safe_wallet: Option<String>,
+    /// How often on-chain balances are compared with what our trades explain (seconds)
+    pub balance_probe_interval_secs: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -51,16 +211,37 @@ pub struct ApiConfig {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DatabaseConfig {
     pub url: String,
+    /// Buffered records that trigger a batch insert
+    pub persistence_batch_size: usize,
+    /// Longest a record stays buffered before a timed flush
+    pub persistence_flush_interval_ms: u64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ServerConfig {
     pub port: u16,
+    /// How often external dependencies are probed for `/health`
+    pub dependency_probe_interval_secs: u64,
+    /// How long a single dependency probe may take before it counts as down
+    pub dependency_probe_timeout_ms: u64,
+    /// Bearer token required by the mutating HTTP routes; they are disabled when unset
+    pub admin_token: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoggingConfig {
     pub level: String,
+    /// Write a run summary to the logs, `shutdown_report_path` and persistence on exit
+    pub shutdown_report_enabled: bool,
+    pub shutdown_report_path: String,
+}
+
+/// Execution event streaming; requires the `event-bus` feature
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MessageBusConfig {
+    /// NATS server to publish to; streaming is off when unset
+    pub nats_url: Option<String>,
+    pub subject_prefix: String,
 }
 
 // THE OVERMIND PROTOCOL - HFT Engine Configuration
@@ -68,9 +249,69 @@ pub struct LoggingConfig {
 pub struct OvermindConfig {
     pub enabled: bool,
     pub tensorzero_gateway_url: String,
+    /// DragonflyDB the AI brain exchanges decisions through
+    pub dragonfly_url: String,
     pub jito_endpoint: String,
     pub max_execution_latency_ms: u64,
     pub ai_confidence_threshold: f64,
+    pub max_priority_fee_lamports: u64,
+    pub skip_on_priority_fee_cap: bool,
+    /// Jito tip paid by the least convincing AI trades (lamports)
+    pub min_jito_tip_lamports: u64,
+    /// Jito tip paid by the highest-conviction, highest-edge AI trades (lamports)
+    pub max_jito_tip_lamports: u64,
+    /// Upper bound on a tip as a fraction of the trade's estimated profit (0.0 - 1.0)
+    pub max_tip_profit_fraction: f64,
+    /// TensorZero inferences allowed in flight at once
+    pub max_concurrent_inferences: usize,
+    /// Wait for an inference slot before skipping the AI call (milliseconds)
+    pub inference_queue_timeout_ms: u64,
+    /// Jito bundles allowed outstanding until confirmed
+    pub max_bundles_in_flight: usize,
+    /// How long an execution waits for a bundle slot before skipping (ms)
+    pub bundle_queue_timeout_ms: u64,
+    /// Half-life, in samples, of the reported average latencies
+    pub latency_ewma_half_life: u32,
+    /// Report realized P&L of AI decisions back to TensorZero as feedback
+    pub ai_feedback_enabled: bool,
+    /// TensorZero metric the realized P&L is reported under
+    pub ai_feedback_metric_name: String,
+    /// Settled AI trades per strategy the estimated-vs-realized accuracy is computed over
+    pub ai_accuracy_window: usize,
+    /// Export per-stage counts of AI decisions from receipt to confirmation
+    pub ai_funnel_metrics_enabled: bool,
+    /// Tip account bundles pay; must be one of Jito's published tip accounts
+    pub jito_tip_account: Option<String>,
+    /// Known tip accounts used instead of fetching the list (air-gapped setups)
+    pub jito_tip_accounts_override: Option<Vec<String>>,
+    /// Where the fetched tip-account list is cached for when Jito is unreachable
+    pub jito_tip_accounts_cache_path: Option<String>,
+    /// "fixed" tips `jito_tip_account`; "round_robin" or "random" spread tips across the tip-account list
+    pub jito_tip_rotation: String,
+    /// Seed for "random" tip rotation, for reproducible runs (unset draws from entropy)
+    pub jito_tip_rotation_seed: Option<u64>,
+    /// Signal actions that skip TensorZero and go straight to standard submission, e.g. "Sell"
+    pub standard_path_actions: Option<String>,
+    /// Skip AI decisions priced further than this from the latest market price (bps, 0 disables)
+    pub max_ai_price_deviation_bps: f64,
+    /// Rebuild and resubmit bundles that expire unconfirmed instead of failing the trade
+    pub rebuild_expired_bundles: bool,
+    /// Rebuilds allowed per bundle before the trade fails
+    pub bundle_retry_attempts: u32,
+    /// Tip multiplier applied on each rebuild (1.0 keeps the tip)
+    pub bundle_rebuild_tip_multiplier: f64,
+    /// Allow replacing a pending bundle with a fee-bumped rebuild on request
+    pub replace_pending_bundles: bool,
+    /// Priority fee and tip multiplier applied on each replacement, up to the fee ceilings
+    pub bundle_replace_fee_multiplier: f64,
+    /// "block_engine" sends bundles to Jito; "local" accepts them without sending,
+    /// for local clusters that have no block engine
+    pub jito_backend: String,
+    /// Divide the execution latency budget by observed AI and bundle leg timings
+    /// (false keeps the fixed 1/3 : 2/3 split)
+    pub adaptive_latency_budget: bool,
+    /// Smallest share of the latency budget either leg is given when adapting
+    pub min_latency_leg_fraction: f64,
 }
 
 #[allow(dead_code)]
@@ -99,6 +340,240 @@ impl Config {
                     .unwrap_or_else(|_| "500".to_string())
                     .parse()
                     .context("Invalid SNIPER_MAX_DAILY_LOSS")?,
+                max_portfolio_exposure_sol: env::var("SNIPER_MAX_PORTFOLIO_EXPOSURE_SOL")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_MAX_PORTFOLIO_EXPOSURE_SOL")?,
+                max_consecutive_losses: env::var("SNIPER_MAX_CONSECUTIVE_LOSSES")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_MAX_CONSECUTIVE_LOSSES")?,
+                losing_streak_cooldown_secs: env::var("SNIPER_LOSING_STREAK_COOLDOWN_SECS")
+                    .unwrap_or_else(|_| "1800".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_LOSING_STREAK_COOLDOWN_SECS")?,
+                post_execution_cooldown_secs: env::var("SNIPER_POST_EXECUTION_COOLDOWN_SECS")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_POST_EXECUTION_COOLDOWN_SECS")?,
+                allocation_rebalancer_enabled: env::var("SNIPER_ALLOCATION_REBALANCER_ENABLED")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_ALLOCATION_REBALANCER_ENABLED")?,
+                allocation_rebalance_interval_secs: env::var(
+                    "SNIPER_ALLOCATION_REBALANCE_INTERVAL_SECS",
+                )
+                .unwrap_or_else(|_| "300".to_string())
+                .parse()
+                .context("Invalid SNIPER_ALLOCATION_REBALANCE_INTERVAL_SECS")?,
+                confirmation_commitment: env::var("SNIPER_CONFIRMATION_COMMITMENT")
+                    .unwrap_or_else(|_| "confirmed".to_string()),
+                strategy_confirmation_commitments: env::var(
+                    "SNIPER_STRATEGY_CONFIRMATION_COMMITMENTS",
+                )
+                .ok(),
+                max_bundle_age_slots: env::var("SNIPER_MAX_BUNDLE_AGE_SLOTS")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_MAX_BUNDLE_AGE_SLOTS")?,
+                paper_confirmation_simulated: env::var("SNIPER_PAPER_CONFIRMATION_SIMULATED")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_PAPER_CONFIRMATION_SIMULATED")?,
+                paper_confirmation_latencies_ms: env::var("SNIPER_PAPER_CONFIRMATION_LATENCIES_MS")
+                    .ok(),
+                honeypot_check_enabled: env::var("SNIPER_HONEYPOT_CHECK_ENABLED")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_HONEYPOT_CHECK_ENABLED")?,
+                honeypot_cache_ttl_secs: env::var("SNIPER_HONEYPOT_CACHE_TTL_SECS")
+                    .unwrap_or_else(|_| "60".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_HONEYPOT_CACHE_TTL_SECS")?,
+                target_price_bands: env::var("SNIPER_TARGET_PRICE_BANDS").ok(),
+                slippage_curves: env::var("SNIPER_SLIPPAGE_CURVES").ok(),
+                strategy_market_filters: env::var("SNIPER_STRATEGY_MARKET_FILTERS").ok(),
+                disabled_strategies: env::var("SNIPER_DISABLED_STRATEGIES").ok(),
+                observe_only_strategies: env::var("SNIPER_OBSERVE_ONLY_STRATEGIES").ok(),
+                require_active_strategies: env::var("SNIPER_REQUIRE_ACTIVE_STRATEGIES")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_REQUIRE_ACTIVE_STRATEGIES")?,
+                token_metadata_cache_enabled: env::var("SNIPER_TOKEN_METADATA_CACHE_ENABLED")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_TOKEN_METADATA_CACHE_ENABLED")?,
+                token_metadata_ttl_secs: env::var("SNIPER_TOKEN_METADATA_TTL_SECS")
+                    .unwrap_or_else(|_| "300".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_TOKEN_METADATA_TTL_SECS")?,
+                token_metadata_fetch_timeout_ms: env::var("SNIPER_TOKEN_METADATA_FETCH_TIMEOUT_MS")
+                    .unwrap_or_else(|_| "250".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_TOKEN_METADATA_FETCH_TIMEOUT_MS")?,
+                token_metadata_warm_mints: env::var("SNIPER_TOKEN_METADATA_WARM_MINTS").ok(),
+                swap_fee_bps: env::var("SNIPER_SWAP_FEE_BPS")
+                    .unwrap_or_else(|_| "25".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_SWAP_FEE_BPS")?,
+                default_priority_fee_lamports: env::var("SNIPER_DEFAULT_PRIORITY_FEE_LAMPORTS")
+                    .unwrap_or_else(|_| "10000".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_DEFAULT_PRIORITY_FEE_LAMPORTS")?,
+                max_clock_skew_ms: env::var("SNIPER_MAX_CLOCK_SKEW_MS")
+                    .unwrap_or_else(|_| "5000".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_MAX_CLOCK_SKEW_MS")?,
+                clock_skew_check_interval_secs: env::var("SNIPER_CLOCK_SKEW_CHECK_INTERVAL_SECS")
+                    .unwrap_or_else(|_| "60".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_CLOCK_SKEW_CHECK_INTERVAL_SECS")?,
+                slippage_learning_enabled: env::var("SNIPER_SLIPPAGE_LEARNING_ENABLED")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_SLIPPAGE_LEARNING_ENABLED")?,
+                slippage_params_path: env::var("SNIPER_SLIPPAGE_PARAMS_PATH").ok(),
+                slippage_params_frozen: env::var("SNIPER_SLIPPAGE_PARAMS_FROZEN")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_SLIPPAGE_PARAMS_FROZEN")?,
+                slippage_calibration_interval_secs: env::var(
+                    "SNIPER_SLIPPAGE_CALIBRATION_INTERVAL_SECS",
+                )
+                .unwrap_or_else(|_| "3600".to_string())
+                .parse()
+                .context("Invalid SNIPER_SLIPPAGE_CALIBRATION_INTERVAL_SECS")?,
+                reconcile_on_startup: env::var("SNIPER_RECONCILE_ON_STARTUP")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_RECONCILE_ON_STARTUP")?,
+                reconcile_rebuild_positions: env::var("SNIPER_RECONCILE_REBUILD_POSITIONS")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_RECONCILE_REBUILD_POSITIONS")?,
+                position_snapshot_path: env::var("SNIPER_POSITION_SNAPSHOT_PATH")
+                    .unwrap_or_else(|_| "positions.json".to_string()),
+                trade_log_path: env::var("SNIPER_TRADE_LOG_PATH").ok(),
+                persist_module_events: env::var("SNIPER_PERSIST_MODULE_EVENTS")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_PERSIST_MODULE_EVENTS")?,
+                record_rejected_signals: env::var("SNIPER_RECORD_REJECTED_SIGNALS")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_RECORD_REJECTED_SIGNALS")?,
+                strategy_sandbox_enabled: env::var("SNIPER_STRATEGY_SANDBOX_ENABLED")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_STRATEGY_SANDBOX_ENABLED")?,
+                strategy_max_panics: env::var("SNIPER_STRATEGY_MAX_PANICS")
+                    .unwrap_or_else(|_| "1".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_STRATEGY_MAX_PANICS")?,
+                simulation_guard_window: env::var("SNIPER_SIMULATION_GUARD_WINDOW")
+                    .unwrap_or_else(|_| "50".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_SIMULATION_GUARD_WINDOW")?,
+                simulation_guard_min_samples: env::var("SNIPER_SIMULATION_GUARD_MIN_SAMPLES")
+                    .unwrap_or_else(|_| "10".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_SIMULATION_GUARD_MIN_SAMPLES")?,
+                simulation_guard_max_failure_rate: env::var(
+                    "SNIPER_SIMULATION_GUARD_MAX_FAILURE_RATE",
+                )
+                .unwrap_or_else(|_| "0.5".to_string())
+                .parse()
+                .context("Invalid SNIPER_SIMULATION_GUARD_MAX_FAILURE_RATE")?,
+                simulation_guard_recovery_streak: env::var(
+                    "SNIPER_SIMULATION_GUARD_RECOVERY_STREAK",
+                )
+                .unwrap_or_else(|_| "20".to_string())
+                .parse()
+                .context("Invalid SNIPER_SIMULATION_GUARD_RECOVERY_STREAK")?,
+                shadow_divergence_window: env::var("SNIPER_SHADOW_DIVERGENCE_WINDOW")
+                    .unwrap_or_else(|_| "200".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_SHADOW_DIVERGENCE_WINDOW")?,
+                shadow_divergence_min_samples: env::var("SNIPER_SHADOW_DIVERGENCE_MIN_SAMPLES")
+                    .unwrap_or_else(|_| "50".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_SHADOW_DIVERGENCE_MIN_SAMPLES")?,
+                shadow_divergence_max_rate: env::var("SNIPER_SHADOW_DIVERGENCE_MAX_RATE")
+                    .unwrap_or_else(|_| "0.2".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_SHADOW_DIVERGENCE_MAX_RATE")?,
+                shadow_sniping_entry_price: env::var("SNIPER_SHADOW_SNIPING_ENTRY_PRICE")
+                    .ok()
+                    .map(|price| price.parse())
+                    .transpose()
+                    .context("Invalid SNIPER_SHADOW_SNIPING_ENTRY_PRICE")?,
+                wallet_selection_audit_size: env::var("SNIPER_WALLET_SELECTION_AUDIT_SIZE")
+                    .unwrap_or_else(|_| "100".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_WALLET_SELECTION_AUDIT_SIZE")?,
+                developer_tracker_enabled: env::var("SNIPER_DEVELOPER_TRACKER_ENABLED")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_DEVELOPER_TRACKER_ENABLED")?,
+                capture_symbols: env::var("SNIPER_CAPTURE_SYMBOLS").ok(),
+                capture_signal_ids: env::var("SNIPER_CAPTURE_SIGNAL_IDS").ok(),
+                capture_max_traces: env::var("SNIPER_CAPTURE_MAX_TRACES")
+                    .unwrap_or_else(|_| "100".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_CAPTURE_MAX_TRACES")?,
+                token_list_path: env::var("SNIPER_TOKEN_LIST_PATH").ok(),
+                max_tick_age_ms: env::var("SNIPER_MAX_TICK_AGE_MS")
+                    .unwrap_or_else(|_| "500".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_MAX_TICK_AGE_MS")?,
+                replay_dedup_window_ms: env::var("SNIPER_REPLAY_DEDUP_WINDOW_MS")
+                    .unwrap_or_else(|_| "5000".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_REPLAY_DEDUP_WINDOW_MS")?,
+                replay_dedup_ttl_ms: env::var("SNIPER_REPLAY_DEDUP_TTL_MS")
+                    .unwrap_or_else(|_| "30000".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_REPLAY_DEDUP_TTL_MS")?,
+                record_hold_decisions: env::var("SNIPER_RECORD_HOLD_DECISIONS")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_RECORD_HOLD_DECISIONS")?,
+                idempotency_store_url: env::var("SNIPER_IDEMPOTENCY_STORE_URL").ok(),
+                idempotency_ttl_secs: env::var("SNIPER_IDEMPOTENCY_TTL_SECS")
+                    .unwrap_or_else(|_| "86400".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_IDEMPOTENCY_TTL_SECS")?,
+                idempotency_fail_open: env::var("SNIPER_IDEMPOTENCY_FAIL_OPEN")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_IDEMPOTENCY_FAIL_OPEN")?,
+                live_safety_checks_enabled: env::var("SNIPER_LIVE_SAFETY_CHECKS_ENABLED")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_LIVE_SAFETY_CHECKS_ENABLED")?,
+                live_min_confidence_threshold: env::var("SNIPER_LIVE_MIN_CONFIDENCE_THRESHOLD")
+                    .unwrap_or_else(|_| "0.6".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_LIVE_MIN_CONFIDENCE_THRESHOLD")?,
+                live_max_execution_latency_ms: env::var("SNIPER_LIVE_MAX_EXECUTION_LATENCY_MS")
+                    .unwrap_or_else(|_| "1000".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_LIVE_MAX_EXECUTION_LATENCY_MS")?,
+                max_notional_per_minute: env::var("SNIPER_MAX_NOTIONAL_PER_MINUTE")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_MAX_NOTIONAL_PER_MINUTE")?,
+                strategy_notional_rate_limits: env::var("SNIPER_STRATEGY_NOTIONAL_RATE_LIMITS")
+                    .ok(),
+                missing_price_policy: env::var("SNIPER_MISSING_PRICE_POLICY")
+                    .unwrap_or_else(|_| "signal_price".to_string()),
+                strategy_missing_price_policies: env::var("SNIPER_STRATEGY_MISSING_PRICE_POLICIES")
+                    .ok(),
+                daily_fee_budget_sol: env::var("SNIPER_DAILY_FEE_BUDGET_SOL")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_DAILY_FEE_BUDGET_SOL")?,
+                strategy_fee_budgets: env::var("SNIPER_STRATEGY_FEE_BUDGETS").ok(),
             },
             solana: SolanaConfig {
                 rpc_url: env::var("SNIPER_SOLANA_RPC_URL")
@@ -110,6 +585,15 @@ impl Config {
                     .parse()
                     .unwrap_or(false),
                 default_wallet_id: env::var("OVERMIND_DEFAULT_WALLET").ok(),
+                compromise_delta_threshold_sol: env::var("OVERMIND_COMPROMISE_DELTA_THRESHOLD_SOL")
+                    .unwrap_or_else(|_| "0".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_COMPROMISE_DELTA_THRESHOLD_SOL")?,
+                compromise_safe_wallet: env::var("OVERMIND_COMPROMISE_SAFE_WALLET").ok(),
+                balance_probe_interval_secs: env::var("OVERMIND_BALANCE_PROBE_INTERVAL_SECS")
+                    .unwrap_or_else(|_| "30".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_BALANCE_PROBE_INTERVAL_SECS")?,
             },
             api: ApiConfig {
                 helius_api_key: env::var("SNIPER_HELIUS_API_KEY")
@@ -125,15 +609,38 @@ impl Config {
             },
             database: DatabaseConfig {
                 url: env::var("SNIPER_DATABASE_URL").context("SNIPER_DATABASE_URL is required")?,
+                persistence_batch_size: env::var("SNIPER_PERSISTENCE_BATCH_SIZE")
+                    .unwrap_or_else(|_| "100".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_PERSISTENCE_BATCH_SIZE")?,
+                persistence_flush_interval_ms: env::var("SNIPER_PERSISTENCE_FLUSH_INTERVAL_MS")
+                    .unwrap_or_else(|_| "1000".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_PERSISTENCE_FLUSH_INTERVAL_MS")?,
             },
             server: ServerConfig {
                 port: env::var("SNIPER_SERVER_PORT")
                     .unwrap_or_else(|_| "8080".to_string())
                     .parse()
                     .context("Invalid SNIPER_SERVER_PORT")?,
+                dependency_probe_interval_secs: env::var("SNIPER_DEPENDENCY_PROBE_INTERVAL_SECS")
+                    .unwrap_or_else(|_| "15".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_DEPENDENCY_PROBE_INTERVAL_SECS")?,
+                dependency_probe_timeout_ms: env::var("SNIPER_DEPENDENCY_PROBE_TIMEOUT_MS")
+                    .unwrap_or_else(|_| "2000".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_DEPENDENCY_PROBE_TIMEOUT_MS")?,
+                admin_token: env::var("SNIPER_ADMIN_TOKEN").ok().filter(|t| !t.is_empty()),
             },
             logging: LoggingConfig {
                 level: env::var("SNIPER_LOG_LEVEL").unwrap_or_else(|_| "info".to_string()),
+                shutdown_report_enabled: env::var("SNIPER_SHUTDOWN_REPORT_ENABLED")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid SNIPER_SHUTDOWN_REPORT_ENABLED")?,
+                shutdown_report_path: env::var("SNIPER_SHUTDOWN_REPORT_PATH")
+                    .unwrap_or_else(|_| "shutdown_report.json".to_string()),
             },
             // THE OVERMIND PROTOCOL - HFT Engine Configuration
             overmind: OvermindConfig {
@@ -143,6 +650,8 @@ impl Config {
                     .unwrap_or(false),
                 tensorzero_gateway_url: env::var("OVERMIND_TENSORZERO_URL")
                     .unwrap_or_else(|_| "http://localhost:3000".to_string()),
+                dragonfly_url: env::var("OVERMIND_DRAGONFLY_URL")
+                    .unwrap_or_else(|_| "redis://localhost:6379".to_string()),
                 jito_endpoint: env::var("OVERMIND_JITO_ENDPOINT")
                     .unwrap_or_else(|_| "https://mainnet.block-engine.jito.wtf".to_string()),
                 max_execution_latency_ms: env::var("OVERMIND_MAX_LATENCY_MS")
@@ -153,6 +662,112 @@ impl Config {
                     .unwrap_or_else(|_| "0.7".to_string())
                     .parse()
                     .unwrap_or(0.7),
+                max_priority_fee_lamports: env::var("OVERMIND_MAX_PRIORITY_FEE_LAMPORTS")
+                    .unwrap_or_else(|_| "100000".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_MAX_PRIORITY_FEE_LAMPORTS")?,
+                skip_on_priority_fee_cap: env::var("OVERMIND_SKIP_ON_PRIORITY_FEE_CAP")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .unwrap_or(false),
+                min_jito_tip_lamports: env::var("OVERMIND_MIN_JITO_TIP_LAMPORTS")
+                    .unwrap_or_else(|_| "1000".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_MIN_JITO_TIP_LAMPORTS")?,
+                max_jito_tip_lamports: env::var("OVERMIND_MAX_JITO_TIP_LAMPORTS")
+                    .unwrap_or_else(|_| "100000".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_MAX_JITO_TIP_LAMPORTS")?,
+                max_tip_profit_fraction: env::var("OVERMIND_MAX_TIP_PROFIT_FRACTION")
+                    .unwrap_or_else(|_| "0.1".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_MAX_TIP_PROFIT_FRACTION")?,
+                max_concurrent_inferences: env::var("OVERMIND_MAX_CONCURRENT_INFERENCES")
+                    .unwrap_or_else(|_| "8".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_MAX_CONCURRENT_INFERENCES")?,
+                inference_queue_timeout_ms: env::var("OVERMIND_INFERENCE_QUEUE_TIMEOUT_MS")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_INFERENCE_QUEUE_TIMEOUT_MS")?,
+                max_bundles_in_flight: env::var("OVERMIND_MAX_BUNDLES_IN_FLIGHT")
+                    .unwrap_or_else(|_| "4".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_MAX_BUNDLES_IN_FLIGHT")?,
+                bundle_queue_timeout_ms: env::var("OVERMIND_BUNDLE_QUEUE_TIMEOUT_MS")
+                    .unwrap_or_else(|_| "5".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_BUNDLE_QUEUE_TIMEOUT_MS")?,
+                latency_ewma_half_life: env::var("OVERMIND_LATENCY_EWMA_HALF_LIFE")
+                    .unwrap_or_else(|_| "50".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_LATENCY_EWMA_HALF_LIFE")?,
+                ai_feedback_enabled: env::var("OVERMIND_AI_FEEDBACK_ENABLED")
+                    .unwrap_or_else(|_| "false".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_AI_FEEDBACK_ENABLED")?,
+                ai_feedback_metric_name: env::var("OVERMIND_AI_FEEDBACK_METRIC")
+                    .unwrap_or_else(|_| "trade_pnl".to_string()),
+                ai_accuracy_window: env::var("OVERMIND_AI_ACCURACY_WINDOW")
+                    .unwrap_or_else(|_| "500".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_AI_ACCURACY_WINDOW")?,
+                ai_funnel_metrics_enabled: env::var("OVERMIND_AI_FUNNEL_METRICS_ENABLED")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_AI_FUNNEL_METRICS_ENABLED")?,
+                jito_tip_account: env::var("OVERMIND_JITO_TIP_ACCOUNT").ok(),
+                jito_tip_accounts_override: env::var("OVERMIND_JITO_TIP_ACCOUNTS")
+                    .ok()
+                    .map(|accounts| accounts.split(',').map(|a| a.trim().to_string()).collect()),
+                jito_tip_accounts_cache_path: env::var("OVERMIND_JITO_TIP_ACCOUNTS_CACHE").ok(),
+                jito_tip_rotation: env::var("OVERMIND_JITO_TIP_ROTATION")
+                    .unwrap_or_else(|_| "fixed".to_string()),
+                jito_tip_rotation_seed: env::var("OVERMIND_JITO_TIP_ROTATION_SEED")
+                    .ok()
+                    .map(|seed| seed.parse())
+                    .transpose()
+                    .context("Invalid OVERMIND_JITO_TIP_ROTATION_SEED")?,
+                standard_path_actions: env::var("OVERMIND_STANDARD_PATH_ACTIONS").ok(),
+                max_ai_price_deviation_bps: env::var("OVERMIND_MAX_AI_PRICE_DEVIATION_BPS")
+                    .unwrap_or_else(|_| "500".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_MAX_AI_PRICE_DEVIATION_BPS")?,
+                rebuild_expired_bundles: env::var("OVERMIND_REBUILD_EXPIRED_BUNDLES")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_REBUILD_EXPIRED_BUNDLES")?,
+                bundle_retry_attempts: env::var("OVERMIND_BUNDLE_RETRY_ATTEMPTS")
+                    .unwrap_or_else(|_| "3".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_BUNDLE_RETRY_ATTEMPTS")?,
+                bundle_rebuild_tip_multiplier: env::var("OVERMIND_BUNDLE_REBUILD_TIP_MULTIPLIER")
+                    .unwrap_or_else(|_| "1.25".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_BUNDLE_REBUILD_TIP_MULTIPLIER")?,
+                replace_pending_bundles: env::var("OVERMIND_REPLACE_PENDING_BUNDLES")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_REPLACE_PENDING_BUNDLES")?,
+                bundle_replace_fee_multiplier: env::var("OVERMIND_BUNDLE_REPLACE_FEE_MULTIPLIER")
+                    .unwrap_or_else(|_| "1.5".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_BUNDLE_REPLACE_FEE_MULTIPLIER")?,
+                jito_backend: env::var("OVERMIND_JITO_BACKEND")
+                    .unwrap_or_else(|_| "block_engine".to_string()),
+                adaptive_latency_budget: env::var("OVERMIND_ADAPTIVE_LATENCY_BUDGET")
+                    .unwrap_or_else(|_| "true".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_ADAPTIVE_LATENCY_BUDGET")?,
+                min_latency_leg_fraction: env::var("OVERMIND_MIN_LATENCY_LEG_FRACTION")
+                    .unwrap_or_else(|_| "0.2".to_string())
+                    .parse()
+                    .context("Invalid OVERMIND_MIN_LATENCY_LEG_FRACTION")?,
+            },
+            message_bus: MessageBusConfig {
+                nats_url: env::var("SNIPER_EVENT_BUS_NATS_URL").ok(),
+                subject_prefix: env::var("SNIPER_EVENT_BUS_SUBJECT_PREFIX")
+                    .unwrap_or_else(|_| "snipercor".to_string()),
             },
         };
 
@@ -172,13 +787,87 @@ impl Config {
             anyhow::bail!("max_daily_loss must be positive");
         }
 
+        if !self.trading.max_portfolio_exposure_sol.is_finite()
+            || self.trading.max_portfolio_exposure_sol < 0.0
+        {
+            anyhow::bail!("max_portfolio_exposure_sol must not be negative");
+        }
+
+        if self.overmind.min_jito_tip_lamports > self.overmind.max_jito_tip_lamports {
+            anyhow::bail!("min_jito_tip_lamports must not exceed max_jito_tip_lamports");
+        }
+
+        if !(0.0..=1.0).contains(&self.overmind.max_tip_profit_fraction) {
+            anyhow::bail!("max_tip_profit_fraction must be between 0.0 and 1.0");
+        }
+
         if self.server.port == 0 {
             anyhow::bail!("server port must be valid");
         }
 
+        if self.server.dependency_probe_interval_secs == 0 || self.server.dependency_probe_timeout_ms == 0 {
+            anyhow::bail!("dependency probe interval and timeout must be positive");
+        }
+
         Ok(())
     }
 
+    /// Blocking issues for live trading; empty in paper mode or when the checks are disabled
+    pub fn validate_live(&self) -> Vec<String> {
+        let trading = &self.trading;
+        if !self.is_live_trading() || !trading.live_safety_checks_enabled {
+            return Vec::new();
+        }
+        let mut issues = Vec::new();
+
+        if trading.max_position_size == TEST_DEFAULT_MAX_POSITION_SIZE {
+            issues.push(format!(
+                "max_position_size {} is the test default; set SNIPER_MAX_POSITION_SIZE",
+                trading.max_position_size
+            ));
+        }
+        if trading.max_daily_loss == TEST_DEFAULT_MAX_DAILY_LOSS {
+            issues.push(format!(
+                "max_daily_loss {} is the test default; set SNIPER_MAX_DAILY_LOSS",
+                trading.max_daily_loss
+            ));
+        }
+
+        if self.overmind.enabled {
+            if self.overmind.ai_confidence_threshold < trading.live_min_confidence_threshold {
+                issues.push(format!(
+                    "AI confidence threshold {} is below the live floor {}",
+                    self.overmind.ai_confidence_threshold, trading.live_min_confidence_threshold
+                ));
+            }
+            if self.overmind.max_execution_latency_ms > trading.live_max_execution_latency_ms {
+                issues.push(format!(
+                    "max_execution_latency_ms {} exceeds the live ceiling {}",
+                    self.overmind.max_execution_latency_ms, trading.live_max_execution_latency_ms
+                ));
+            }
+        }
+
+        let credentials = [
+            ("SNIPER_WALLET_PRIVATE_KEY", &self.solana.wallet_private_key),
+            ("SNIPER_HELIUS_API_KEY", &self.api.helius_api_key),
+            ("SNIPER_QUICKNODE_API_KEY", &self.api.quicknode_api_key),
+            ("SNIPER_SOLANA_RPC_URL", &self.solana.rpc_url),
+        ];
+        for (name, value) in credentials {
+            if value.trim().is_empty() {
+                issues.push(format!("{} is empty", name));
+            } else if let Some(marker) = placeholder_marker(value) {
+                issues.push(format!(
+                    "{} looks like a placeholder (contains \"{}\")",
+                    name, marker
+                ));
+            }
+        }
+
+        issues
+    }
+
     /// Check if running in live trading mode
     pub fn is_live_trading(&self) -> bool {
         matches!(self.trading.mode, TradingMode::Live)
@@ -219,12 +908,84 @@ mod tests {
                 mode: TradingMode::Paper,
                 max_position_size: 1000.0,
                 max_daily_loss: 500.0,
+                max_portfolio_exposure_sol: 0.0,
+                max_consecutive_losses: 5,
+                losing_streak_cooldown_secs: 1800,
+                post_execution_cooldown_secs: 30,
+                allocation_rebalancer_enabled: false,
+                allocation_rebalance_interval_secs: 300,
+                confirmation_commitment: "confirmed".to_string(),
+                strategy_confirmation_commitments: None,
+                max_bundle_age_slots: 0,
+                paper_confirmation_simulated: false,
+                paper_confirmation_latencies_ms: None,
+                honeypot_check_enabled: true,
+                honeypot_cache_ttl_secs: 60,
+                target_price_bands: None,
+                slippage_curves: None,
+                strategy_market_filters: None,
+                disabled_strategies: None,
+                observe_only_strategies: None,
+                require_active_strategies: true,
+                token_metadata_cache_enabled: true,
+                token_metadata_ttl_secs: 300,
+                token_metadata_fetch_timeout_ms: 250,
+                token_metadata_warm_mints: None,
+                swap_fee_bps: 25.0,
+                default_priority_fee_lamports: 10_000,
+                max_clock_skew_ms: 5000,
+                clock_skew_check_interval_secs: 60,
+                slippage_learning_enabled: false,
+                slippage_params_path: None,
+                slippage_params_frozen: false,
+                slippage_calibration_interval_secs: 3600,
+                reconcile_on_startup: true,
+                reconcile_rebuild_positions: false,
+                position_snapshot_path: "positions.json".to_string(),
+                trade_log_path: None,
+                persist_module_events: true,
+                record_rejected_signals: false,
+                strategy_sandbox_enabled: true,
+                strategy_max_panics: 1,
+                simulation_guard_window: 50,
+                simulation_guard_min_samples: 10,
+                simulation_guard_max_failure_rate: 0.5,
+                simulation_guard_recovery_streak: 20,
+                shadow_divergence_window: 200,
+                shadow_divergence_min_samples: 50,
+                shadow_divergence_max_rate: 0.2,
+                shadow_sniping_entry_price: None,
+                wallet_selection_audit_size: 100,
+                developer_tracker_enabled: false,
+                capture_symbols: None,
+                capture_signal_ids: None,
+                capture_max_traces: 100,
+                token_list_path: None,
+                max_tick_age_ms: 500,
+                replay_dedup_window_ms: 0,
+                replay_dedup_ttl_ms: 30000,
+                record_hold_decisions: true,
+                idempotency_store_url: None,
+                idempotency_ttl_secs: 86400,
+                idempotency_fail_open: true,
+                live_safety_checks_enabled: true,
+                live_min_confidence_threshold: 0.6,
+                live_max_execution_latency_ms: 1000,
+                max_notional_per_minute: 0.0,
+                strategy_notional_rate_limits: None,
+                missing_price_policy: "signal_price".to_string(),
+                strategy_missing_price_policies: None,
+                daily_fee_budget_sol: 0.0,
+                strategy_fee_budgets: None,
             },
             solana: SolanaConfig {
                 rpc_url: "https://api.mainnet-beta.solana.com".to_string(),
                 wallet_private_key: "test_key".to_string(),
                 multi_wallet_enabled: false,
                 default_wallet_id: None,
+                compromise_delta_threshold_sol: 0.0,
+                compromise_safe_wallet: None,
+                balance_probe_interval_secs: 30,
             },
             api: ApiConfig {
                 helius_api_key: "test_key".to_string(),
@@ -235,21 +996,90 @@ mod tests {
             },
             database: DatabaseConfig {
                 url: "postgresql://test".to_string(),
+                persistence_batch_size: 100,
+                persistence_flush_interval_ms: 1000,
+            },
+            server: ServerConfig {
+                port: 8080,
+                dependency_probe_interval_secs: 15,
+                dependency_probe_timeout_ms: 2000,
+                admin_token: None,
             },
-            server: ServerConfig { port: 8080 },
             logging: LoggingConfig {
                 level: "info".to_string(),
+                shutdown_report_enabled: true,
+                shutdown_report_path: "shutdown_report.json".to_string(),
             },
             overmind: OvermindConfig {
                 enabled: false,
                 tensorzero_gateway_url: "http://localhost:3000".to_string(),
+                dragonfly_url: "redis://localhost:6379".to_string(),
                 jito_endpoint: "https://mainnet.block-engine.jito.wtf".to_string(),
                 max_execution_latency_ms: 25,
                 ai_confidence_threshold: 0.7,
+                max_priority_fee_lamports: 100_000,
+                skip_on_priority_fee_cap: false,
+                min_jito_tip_lamports: 1_000,
+                max_jito_tip_lamports: 100_000,
+                max_tip_profit_fraction: 0.1,
+                max_concurrent_inferences: 8,
+                inference_queue_timeout_ms: 5,
+                max_bundles_in_flight: 4,
+                bundle_queue_timeout_ms: 5,
+                latency_ewma_half_life: 50,
+                ai_feedback_enabled: false,
+                ai_feedback_metric_name: "trade_pnl".to_string(),
+                ai_accuracy_window: 500,
+                ai_funnel_metrics_enabled: false,
+                jito_tip_account: None,
+                jito_tip_accounts_override: None,
+                jito_tip_accounts_cache_path: None,
+                jito_tip_rotation: "fixed".to_string(),
+                jito_tip_rotation_seed: None,
+                standard_path_actions: None,
+                max_ai_price_deviation_bps: 500.0,
+                rebuild_expired_bundles: true,
+                bundle_retry_attempts: 3,
+                bundle_rebuild_tip_multiplier: 1.25,
+                replace_pending_bundles: true,
+                bundle_replace_fee_multiplier: 1.5,
+                jito_backend: "block_engine".to_string(),
+                adaptive_latency_budget: true,
+                min_latency_leg_fraction: 0.2,
+            },
+            message_bus: MessageBusConfig {
+                nats_url: None,
+                subject_prefix: "snipercor".to_string(),
             },
         };
 
         assert!(config.validate().is_ok());
+        assert!(config.validate_live().is_empty());
+
+        // Test live safety checks on the test defaults
+        config.trading.mode = TradingMode::Live;
+        config.overmind.enabled = true;
+        config.overmind.ai_confidence_threshold = 0.3;
+
+        let issues = config.validate_live();
+        assert!(issues.iter().any(|i| i.starts_with("max_position_size")));
+        assert!(issues.iter().any(|i| i.starts_with("max_daily_loss")));
+        assert!(issues
+            .iter()
+            .any(|i| i.starts_with("AI confidence threshold")));
+        assert!(issues
+            .iter()
+            .any(|i| i.starts_with("SNIPER_WALLET_PRIVATE_KEY looks like a placeholder")));
+
+        config.trading.max_position_size = 0.5;
+        config.trading.max_daily_loss = 2.0;
+        config.overmind.ai_confidence_threshold = 0.8;
+        config.solana.wallet_private_key =
+            "4vJ9JU1bJJE96FWSJKvHsmmFADCg4gpZQff4P3bkLKi".to_string();
+        config.solana.rpc_url = "https://mainnet.helius-rpc.com".to_string();
+        config.api.helius_api_key = "9f1c2a7e".to_string();
+        config.api.quicknode_api_key = "qn_5d8e3b".to_string();
+        assert!(config.validate_live().is_empty());
 
         // Test invalid position size
         config.trading.max_position_size = -100.0;
@@ -263,12 +1093,84 @@ mod tests {
                 mode: TradingMode::Paper,
                 max_position_size: 1000.0,
                 max_daily_loss: 500.0,
+                max_portfolio_exposure_sol: 0.0,
+                max_consecutive_losses: 5,
+                losing_streak_cooldown_secs: 1800,
+                post_execution_cooldown_secs: 30,
+                allocation_rebalancer_enabled: false,
+                allocation_rebalance_interval_secs: 300,
+                confirmation_commitment: "confirmed".to_string(),
+                strategy_confirmation_commitments: None,
+                max_bundle_age_slots: 0,
+                paper_confirmation_simulated: false,
+                paper_confirmation_latencies_ms: None,
+                honeypot_check_enabled: true,
+                honeypot_cache_ttl_secs: 60,
+                target_price_bands: None,
+                slippage_curves: None,
+                strategy_market_filters: None,
+                disabled_strategies: None,
+                observe_only_strategies: None,
+                require_active_strategies: true,
+                token_metadata_cache_enabled: true,
+                token_metadata_ttl_secs: 300,
+                token_metadata_fetch_timeout_ms: 250,
+                token_metadata_warm_mints: None,
+                swap_fee_bps: 25.0,
+                default_priority_fee_lamports: 10_000,
+                max_clock_skew_ms: 5000,
+                clock_skew_check_interval_secs: 60,
+                slippage_learning_enabled: false,
+                slippage_params_path: None,
+                slippage_params_frozen: false,
+                slippage_calibration_interval_secs: 3600,
+                reconcile_on_startup: true,
+                reconcile_rebuild_positions: false,
+                position_snapshot_path: "positions.json".to_string(),
+                trade_log_path: None,
+                persist_module_events: true,
+                record_rejected_signals: false,
+                strategy_sandbox_enabled: true,
+                strategy_max_panics: 1,
+                simulation_guard_window: 50,
+                simulation_guard_min_samples: 10,
+                simulation_guard_max_failure_rate: 0.5,
+                simulation_guard_recovery_streak: 20,
+                shadow_divergence_window: 200,
+                shadow_divergence_min_samples: 50,
+                shadow_divergence_max_rate: 0.2,
+                shadow_sniping_entry_price: None,
+                wallet_selection_audit_size: 100,
+                developer_tracker_enabled: false,
+                capture_symbols: None,
+                capture_signal_ids: None,
+                capture_max_traces: 100,
+                token_list_path: None,
+                max_tick_age_ms: 500,
+                replay_dedup_window_ms: 0,
+                replay_dedup_ttl_ms: 30000,
+                record_hold_decisions: true,
+                idempotency_store_url: None,
+                idempotency_ttl_secs: 86400,
+                idempotency_fail_open: true,
+                live_safety_checks_enabled: true,
+                live_min_confidence_threshold: 0.6,
+                live_max_execution_latency_ms: 1000,
+                max_notional_per_minute: 0.0,
+                strategy_notional_rate_limits: None,
+                missing_price_policy: "signal_price".to_string(),
+                strategy_missing_price_policies: None,
+                daily_fee_budget_sol: 0.0,
+                strategy_fee_budgets: None,
             },
             solana: SolanaConfig {
                 rpc_url: "test".to_string(),
                 wallet_private_key: "test".to_string(),
                 multi_wallet_enabled: false,
                 default_wallet_id: None,
+                compromise_delta_threshold_sol: 0.0,
+                compromise_safe_wallet: None,
+                balance_probe_interval_secs: 30,
             },
             api: ApiConfig {
                 helius_api_key: "test".to_string(),
@@ -279,21 +1181,74 @@ mod tests {
             },
             database: DatabaseConfig {
                 url: "test".to_string(),
+                persistence_batch_size: 100,
+                persistence_flush_interval_ms: 1000,
+            },
+            server: ServerConfig {
+                port: 8080,
+                dependency_probe_interval_secs: 15,
+                dependency_probe_timeout_ms: 2000,
+                admin_token: None,
             },
-            server: ServerConfig { port: 8080 },
             logging: LoggingConfig {
                 level: "info".to_string(),
+                shutdown_report_enabled: true,
+                shutdown_report_path: "shutdown_report.json".to_string(),
             },
             overmind: OvermindConfig {
                 enabled: false,
                 tensorzero_gateway_url: "http://localhost:3000".to_string(),
+                dragonfly_url: "redis://localhost:6379".to_string(),
                 jito_endpoint: "https://mainnet.block-engine.jito.wtf".to_string(),
                 max_execution_latency_ms: 25,
                 ai_confidence_threshold: 0.7,
+                max_priority_fee_lamports: 100_000,
+                skip_on_priority_fee_cap: false,
+                min_jito_tip_lamports: 1_000,
+                max_jito_tip_lamports: 100_000,
+                max_tip_profit_fraction: 0.1,
+                max_concurrent_inferences: 8,
+                inference_queue_timeout_ms: 5,
+                max_bundles_in_flight: 4,
+                bundle_queue_timeout_ms: 5,
+                latency_ewma_half_life: 50,
+                ai_feedback_enabled: false,
+                ai_feedback_metric_name: "trade_pnl".to_string(),
+                ai_accuracy_window: 500,
+                ai_funnel_metrics_enabled: false,
+                jito_tip_account: None,
+                jito_tip_accounts_override: None,
+                jito_tip_accounts_cache_path: None,
+                jito_tip_rotation: "fixed".to_string(),
+                jito_tip_rotation_seed: None,
+                standard_path_actions: None,
+                max_ai_price_deviation_bps: 500.0,
+                rebuild_expired_bundles: true,
+                bundle_retry_attempts: 3,
+                bundle_rebuild_tip_multiplier: 1.25,
+                replace_pending_bundles: true,
+                bundle_replace_fee_multiplier: 1.5,
+                jito_backend: "block_engine".to_string(),
+                adaptive_latency_budget: true,
+                min_latency_leg_fraction: 0.2,
+            },
+            message_bus: MessageBusConfig {
+                nats_url: None,
+                subject_prefix: "snipercor".to_string(),
             },
         };
 
         assert!(!config.is_live_trading());
         assert_eq!(config.trading_mode_str(), "paper");
     }
+
+    #[test]
+    fn test_placeholder_markers_match_whole_words() {
+        assert_eq!(placeholder_marker("your_helius_api_key"), Some("your"));
+        assert_eq!(placeholder_marker("CHANGEME"), Some("changeme"));
+        assert_eq!(placeholder_marker("https://rpc.example.com"), Some("example"));
+        // Base58 keys and real hosts that merely contain a marker are not placeholders
+        assert_eq!(placeholder_marker("3xxxTestYourkey9AbPLaceholder5"), None);
+        assert_eq!(placeholder_marker("https://api.testnet.solana.com"), None);
+    }
 }
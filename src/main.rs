@@ -5,22 +5,53 @@ mod config;
 mod modules;
 mod monitoring;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 // use uuid::Uuid; // Commented out to avoid unused import warning
 
 use config::Config;
 use modules::{
-    ai_connector::{AIConnectorConfig},
-    data_ingestor::{DataIngestor, MarketData},
+    ai_connector::{AIConnector, AIConnectorConfig, MarketEvent},
+    ai_feedback::{AIFeedbackConfig, AIFeedbackReporter, AIFeedbackTracker},
+    ai_funnel::AIDecisionFunnel,
+    confirmation::{ConfirmationConfig, ConfirmationTracker, PaperConfirmationModel},
+    allocation::{AllocationRebalancer, RebalancerConfig, StrategyAllocations},
+    clock_skew::{ClockSkewConfig, ClockSkewMonitor},
+    data_ingestor::{DataIngestor, MarketData, ReplayDedup, ReplayDedupConfig},
+    dev_tracker::{DeveloperTracker, TokenLaunch, TrackingConfig},
     executor::{ExecutionResult, Executor},
+    fees::FeeConfig,
     hft_engine::HFTConfig,
-    persistence::{PersistenceManager, PersistenceMessage},
+    honeypot::{HoneypotConfig, HoneypotGuard, MintAuthorityCheck},
+    idempotency::{DragonflyIdempotencyStore, IdempotencyConfig, IdempotencyGuard},
+    jito_tip_accounts::{check_tip_accounts, TipAccountCheckConfig},
+    market_prices::{MarketPriceCache, MissingPriceConfig},
+    fee_budget::{FeeBudgetConfig, FeeBudgets},
+    notional_rate::NotionalRateConfig,
+    portfolio_exposure::PortfolioExposureCap,
+    pending_replacement::PendingReplacements,
+    pipeline_capture::{PipelineCapture, PipelineCaptureConfig},
+    persistence::{PersistenceBatchConfig, PersistenceManager, PersistenceMessage},
+    reconciliation::{PositionJournal, PositionReconciler, ReconciliationConfig},
     risk::{ApprovedSignal, RiskManager, RiskParameters},
-    strategy::{StrategyEngine, TradingSignal},
+    shadow_divergence::{ShadowDivergence, ShadowDivergenceConfig, TokenSnipingCandidate},
+    simulation_guard::{RpcPreflightSimulator, SimulationGuard, SimulationGuardConfig},
+    slippage_curve::SlippageCurveConfig,
+    slippage_model::{SlippageCalibrator, SlippageModel, SlippageModelConfig},
+    shutdown_report::{wait_for_shutdown_signal, ShutdownReport, ShutdownReportConfig},
+    strategy::{StrategyEngine, TokenSnipingParams, TradingSignal},
+    strategy_cooldown::{parse_strategy_type, ExecutionCooldowns, LosingStreakConfig, StrategyCooldowns},
+    strategy_routing::parse_market_filters,
+    strategy_sandbox::{StrategySandbox, StrategySandboxConfig},
+    symbol_resolver::SymbolResolver,
+    target_price::TargetPriceConfig,
+    token_metadata::{TokenMetadataCache, TokenMetadataConfig},
+    wallet_manager::{
+        CompromiseContainmentConfig, WalletBalanceProbe, WalletConfigBuilder, WalletManager, WalletSelectionAudit,
+    },
 };
-use monitoring::{create_monitoring_router, MonitoringState};
+use monitoring::{create_monitoring_router, DependencyProbeConfig, DependencyProber, MonitoringState};
 
 #[tokio::main(worker_threads = 6)]
 async fn main() -> Result<()> {
@@ -42,6 +73,17 @@ async fn main() -> Result<()> {
         config::TradingMode::Live => {
             warn!("🔴 LIVE TRADING MODE ENABLED - Real money at risk!");
             warn!("🔴 Ensure all risk parameters are properly configured");
+
+            if !config.trading.live_safety_checks_enabled {
+                warn!("⚠️ Live safety checks DISABLED via SNIPER_LIVE_SAFETY_CHECKS_ENABLED");
+            }
+            let issues = config.validate_live();
+            if !issues.is_empty() {
+                for issue in &issues {
+                    error!("🚫 Unsafe live config: {}", issue);
+                }
+                anyhow::bail!("Refusing to start live trading: {} blocking config issue(s)", issues.len());
+            }
         }
     }
 
@@ -57,17 +99,322 @@ async fn main() -> Result<()> {
         info!("🤖 THE OVERMIND PROTOCOL: DISABLED (Standard mode)");
     }
 
+    // A wrong tip account only shows up as poor bundle landing, so verify it before trading live
+    let tip_accounts = if config.is_live_trading() && config.is_overmind_enabled() {
+        let tip_check = TipAccountCheckConfig {
+            jito_endpoint: config.overmind.jito_endpoint.clone(),
+            tip_account: config.overmind.jito_tip_account.clone(),
+            accounts_override: config.overmind.jito_tip_accounts_override.clone(),
+            cache_path: config.overmind.jito_tip_accounts_cache_path.clone().map(Into::into),
+            ..TipAccountCheckConfig::default()
+        };
+        let mut selector = check_tip_accounts(&tip_check, config.overmind.jito_tip_rotation.parse()?)
+            .await
+            .context("Jito tip account self-test failed; refusing to start live trading")?;
+        if let Some(seed) = config.overmind.jito_tip_rotation_seed {
+            selector = selector.with_seed(seed);
+        }
+        Some(selector)
+    } else {
+        None
+    };
+
     // Create communication channels between modules
     let (market_data_tx, market_data_rx) = mpsc::unbounded_channel::<MarketData>();
     let (signal_tx, signal_rx) = mpsc::unbounded_channel::<TradingSignal>();
     let (execution_tx, execution_rx) = mpsc::unbounded_channel::<ApprovedSignal>();
     let (execution_result_tx, execution_result_rx) = mpsc::unbounded_channel::<ExecutionResult>();
-    let (_persistence_tx, persistence_rx) = mpsc::unbounded_channel::<PersistenceMessage>();
+    let (persistence_tx, persistence_rx) = mpsc::unbounded_channel::<PersistenceMessage>();
+    // Modules persist their own events (signals, risk decisions, wallet changes) when enabled
+    let module_events_tx = config.trading.persist_module_events.then(|| persistence_tx.clone());
 
     info!("📡 Communication channels established");
 
+    // Losing-streak cool-downs are shared between risk checks and the HTTP API
+    let strategy_cooldowns = StrategyCooldowns::shared(LosingStreakConfig {
+        max_consecutive_losses: config.trading.max_consecutive_losses,
+        cooldown_secs: config.trading.losing_streak_cooldown_secs,
+    });
+
+    // Post-execution cool-downs are started by the executor and checked by risk
+    let execution_cooldowns =
+        ExecutionCooldowns::shared(config.trading.post_execution_cooldown_secs);
+
+    // Strategies whose fees paid today reach their budget are rejected until UTC midnight
+    let mut fee_budget_config = FeeBudgetConfig {
+        daily_budget_sol: config.trading.daily_fee_budget_sol,
+        ..FeeBudgetConfig::default()
+    };
+    if let Some(budgets) = &config.trading.strategy_fee_budgets {
+        fee_budget_config = fee_budget_config.with_strategy_budgets(budgets)?;
+    }
+    let fee_budgets = FeeBudgets::shared(fee_budget_config);
+
+    // Strategy allocations scale position limits; overrides apply even with the rebalancer off
+    let strategy_allocations = StrategyAllocations::shared(RebalancerConfig {
+        interval: std::time::Duration::from_secs(config.trading.allocation_rebalance_interval_secs),
+        ..RebalancerConfig::default()
+    });
+    if config.trading.allocation_rebalancer_enabled {
+        tokio::spawn(AllocationRebalancer::new(strategy_allocations.clone()).run());
+    }
+
+    // Strategy panics are caught per strategy and reported through the HTTP API
+    let strategy_sandbox = StrategySandbox::shared(StrategySandboxConfig {
+        max_panics: config.trading.strategy_max_panics,
+    });
+
+    // Live trading halts when pre-flight simulations keep failing; lifted by a healthy
+    // simulation streak or POST /trading/live/enable
+    let simulation_guard = SimulationGuard::shared(SimulationGuardConfig {
+        window: config.trading.simulation_guard_window,
+        min_samples: config.trading.simulation_guard_min_samples,
+        max_failure_rate: config.trading.simulation_guard_max_failure_rate,
+        recovery_streak: config.trading.simulation_guard_recovery_streak,
+    });
+
+    // A shadow strategy's decisions are compared with live before it is promoted
+    let shadow_divergence = ShadowDivergence::shared(ShadowDivergenceConfig {
+        window: config.trading.shadow_divergence_window,
+        min_samples: config.trading.shadow_divergence_min_samples,
+        max_disagreement_rate: config.trading.shadow_divergence_max_rate,
+    });
+
+    // Stuck pending bundles can be replaced with a fee-bumped rebuild through the HTTP API
+    let pending_replacements = PendingReplacements::shared();
+
+    // Wallet selection decisions are recorded for the admin trace endpoint; only multi-wallet
+    // routing selects wallets, the single-wallet executor always trades from "primary"
+    let wallet_selection_audit = WalletSelectionAudit::shared(config.trading.wallet_selection_audit_size);
+
+    // Positions opened by confirmed fills are tracked for the portfolio exposure cap;
+    // after a restart, persisted positions are checked against what the wallet actually holds
+    let mut wallet_manager = WalletManager::new()
+        .with_selection_audit(wallet_selection_audit.clone());
+    if let Some(tx) = &module_events_tx {
+        wallet_manager = wallet_manager.with_persistence(tx.clone());
+    }
+    let primary_wallet = WalletConfigBuilder::new(
+        "primary".to_string(),
+        "Primary".to_string(),
+        config.solana.wallet_private_key.clone(),
+    )?
+    .build();
+    let primary_pubkey: solana_sdk::pubkey::Pubkey = primary_wallet.public_key.parse()?;
+    // A live wallet that loses more SOL than its fills explain is contained and optionally swept
+    let contain_compromise = config.is_live_trading() && config.solana.compromise_delta_threshold_sol > 0.0;
+    if contain_compromise {
+        let safe_wallet = config
+            .solana
+            .compromise_safe_wallet
+            .as_deref()
+            .map(str::parse)
+            .transpose()
+            .context("Invalid OVERMIND_COMPROMISE_SAFE_WALLET")?;
+        wallet_manager = wallet_manager.with_compromise_containment(
+            CompromiseContainmentConfig {
+                unexplained_delta_threshold: config.solana.compromise_delta_threshold_sol,
+                safe_wallet,
+            },
+            std::sync::Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(
+                config.solana.rpc_url.clone(),
+            )),
+        );
+    }
+    wallet_manager.initialize(vec![primary_wallet]).await?;
+    if config.is_live_trading() && config.trading.reconcile_on_startup {
+        let reconciliation_config = ReconciliationConfig {
+            snapshot_path: config.trading.position_snapshot_path.clone().into(),
+            trade_log_path: config.trading.trade_log_path.clone().map(Into::into),
+            rebuild_positions: config.trading.reconcile_rebuild_positions,
+            ..ReconciliationConfig::default()
+        };
+        let rpc_client = std::sync::Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(
+            config.solana.rpc_url.clone(),
+        ));
+        let reconciler = PositionReconciler::new(reconciliation_config, rpc_client);
+        match reconciler.reconcile_wallet_manager(&wallet_manager).await {
+            Ok(report) => report.log_summary(),
+            Err(e) => warn!("Position reconciliation failed: {}", e),
+        }
+    }
+    let wallet_manager = std::sync::Arc::new(wallet_manager);
+    if contain_compromise {
+        let probe = WalletBalanceProbe::new(
+            wallet_manager.clone(),
+            std::sync::Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(
+                config.solana.rpc_url.clone(),
+            )),
+            std::time::Duration::from_secs(config.solana.balance_probe_interval_secs.max(1)),
+        );
+        tokio::spawn(probe.run());
+    }
+    // Live fills keep the snapshot and trade log current for the next startup's reconciliation
+    let position_journal = config.is_live_trading().then(|| PositionJournal {
+        snapshot_path: config.trading.position_snapshot_path.clone().into(),
+        trade_log_path: config.trading.trade_log_path.clone().map(Into::into),
+    });
+
+    // Latest tick prices, used by the HFT engine to catch off-market AI decisions, by the
+    // risk manager to spot signals that arrive before any price for their symbol, and to
+    // convert non-SOL notionals for the portfolio exposure cap
+    let market_prices = MarketPriceCache::shared(chrono::Duration::seconds(30));
+
+    // Final safety net on the SOL deployed in open positions across all wallets
+    let portfolio_exposure = if config.trading.max_portfolio_exposure_sol > 0.0 {
+        info!(
+            "🛡️ Portfolio exposure capped at {} SOL",
+            config.trading.max_portfolio_exposure_sol
+        );
+        Some(std::sync::Arc::new(
+            PortfolioExposureCap::new(config.trading.max_portfolio_exposure_sol, wallet_manager.clone())
+                .with_market_prices(market_prices.clone()),
+        ))
+    } else {
+        None
+    };
+
+    // Sniping buys are screened for unsellable tokens before approval
+    let honeypot_guard = if config.trading.honeypot_check_enabled {
+        let honeypot_config = HoneypotConfig {
+            cache_ttl: std::time::Duration::from_secs(config.trading.honeypot_cache_ttl_secs),
+            ..HoneypotConfig::default()
+        };
+        let rpc_client = std::sync::Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(
+            config.solana.rpc_url.clone(),
+        ));
+        let check = MintAuthorityCheck::new(rpc_client, honeypot_config.clone());
+        Some(std::sync::Arc::new(HoneypotGuard::new(honeypot_config, std::sync::Arc::new(check))))
+    } else {
+        None
+    };
+
+    // Mint decimals, symbols and authorities shared by the risk manager and executor
+    let token_metadata = if config.trading.token_metadata_cache_enabled {
+        let rpc_client = std::sync::Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(
+            config.solana.rpc_url.clone(),
+        ));
+        let cache = TokenMetadataCache::shared(
+            TokenMetadataConfig {
+                ttl: std::time::Duration::from_secs(config.trading.token_metadata_ttl_secs),
+                fetch_timeout: std::time::Duration::from_millis(config.trading.token_metadata_fetch_timeout_ms),
+            },
+            rpc_client,
+        );
+        if let Some(mints) = &config.trading.token_metadata_warm_mints {
+            let mints = mints
+                .split(',')
+                .map(|m| m.trim().parse())
+                .collect::<Result<Vec<solana_sdk::pubkey::Pubkey>, _>>()
+                .context("Invalid SNIPER_TOKEN_METADATA_WARM_MINTS")?;
+            cache.warm(&mints).await;
+        }
+        Some(cache)
+    } else {
+        None
+    };
+
+    // AI decisions are correlated with their realized P&L and reported back to TensorZero
+    let ai_feedback = if config.is_overmind_enabled() && config.overmind.ai_feedback_enabled {
+        let feedback_config = AIFeedbackConfig {
+            metric_name: config.overmind.ai_feedback_metric_name.clone(),
+            accuracy_window: config.overmind.ai_accuracy_window,
+            ..AIFeedbackConfig::default()
+        };
+        let tracker = AIFeedbackTracker::shared(feedback_config.max_pending);
+        let reporter = AIFeedbackReporter::new(
+            feedback_config,
+            tracker,
+            config.overmind.tensorzero_gateway_url.clone(),
+        )?
+        .with_persistence(persistence_tx.clone());
+        info!(
+            "🧠 AI feedback enabled: realized P&L reported as '{}'",
+            config.overmind.ai_feedback_metric_name
+        );
+        Some(std::sync::Arc::new(reporter))
+    } else {
+        None
+    };
+
+    // AI decisions are counted at each stage from receipt to confirmation
+    let ai_funnel = (config.is_overmind_enabled() && config.overmind.ai_funnel_metrics_enabled)
+        .then(AIDecisionFunnel::shared);
+
+    // Targeted symbols and signals have every pipeline artifact captured into one trace
+    let capture_config = PipelineCaptureConfig {
+        max_traces: config.trading.capture_max_traces,
+        ..PipelineCaptureConfig::default()
+    }
+    .with_targets(
+        config.trading.capture_symbols.as_deref().unwrap_or_default(),
+        config.trading.capture_signal_ids.as_deref().unwrap_or_default(),
+    );
+    let pipeline_capture = capture_config.has_targets().then(|| {
+        info!(
+            "🔬 Capturing pipelines of {} symbol(s) and {} signal(s)",
+            capture_config.symbols.len(),
+            capture_config.signal_ids.len()
+        );
+        std::sync::Arc::new(
+            PipelineCapture::new(capture_config).with_persistence(persistence_tx.clone()),
+        )
+    });
+
+    // Ticks providers replay after a reconnect are dropped before reaching strategies
+    let replay_dedup = (config.trading.replay_dedup_window_ms > 0).then(|| {
+        ReplayDedup::new(ReplayDedupConfig::from_millis(
+            config.trading.replay_dedup_window_ms,
+            config.trading.replay_dedup_ttl_ms,
+        ))
+    });
+
+    // Developer launches become sniping signals; the tracker's bounded money-flow history
+    // exports its evictions through monitoring
+    let (token_launch_tx, token_launch_rx) = mpsc::unbounded_channel::<TokenLaunch>();
+    let launch_signal_tx = signal_tx.clone();
+    let developer_tracker = config
+        .trading
+        .developer_tracker_enabled
+        .then(|| DeveloperTracker::new(token_launch_tx, TrackingConfig::default()));
+
     // Initialize monitoring
-    let monitoring_state = MonitoringState::new();
+    let mut monitoring_state = MonitoringState::new()
+        .with_strategy_cooldowns(strategy_cooldowns.clone())
+        .with_execution_cooldowns(execution_cooldowns.clone())
+        .with_fee_budgets(fee_budgets.clone())
+        .with_strategy_allocations(strategy_allocations.clone())
+        .with_wallet_selection_audit(wallet_selection_audit.clone())
+        .with_strategy_sandbox(strategy_sandbox.clone())
+        .with_simulation_guard(simulation_guard.clone())
+        .with_shadow_divergence(shadow_divergence)
+        .with_pending_replacements(pending_replacements.clone())
+        .with_required_active_strategies(config.trading.require_active_strategies);
+    if let Some(guard) = &honeypot_guard {
+        monitoring_state = monitoring_state.with_honeypot_counter(guard.blocked_counter());
+    }
+    if let Some(reporter) = &ai_feedback {
+        monitoring_state = monitoring_state.with_ai_feedback(reporter.clone());
+    }
+    if let Some(funnel) = &ai_funnel {
+        monitoring_state = monitoring_state.with_ai_funnel(funnel.clone());
+    }
+    if let Some(capture) = &pipeline_capture {
+        monitoring_state = monitoring_state.with_pipeline_capture(capture.clone());
+    }
+    if let Some(dedup) = &replay_dedup {
+        monitoring_state = monitoring_state.with_replay_counter(dedup.suppressed_counter());
+    }
+    if let Some(tracker) = &developer_tracker {
+        monitoring_state = monitoring_state.with_money_flow_eviction_counter(tracker.money_flow_eviction_counter());
+    }
+    if let Some(exposure_cap) = &portfolio_exposure {
+        monitoring_state = monitoring_state.with_portfolio_exposure_cap(exposure_cap.clone());
+    }
+    match &config.server.admin_token {
+        Some(token) => monitoring_state = monitoring_state.with_admin_token(token),
+        None => warn!("🔒 SNIPER_ADMIN_TOKEN not set, admin routes are disabled"),
+    }
     let monitoring_router = create_monitoring_router(monitoring_state.clone());
 
     // Start monitoring server
@@ -82,14 +429,130 @@ async fn main() -> Result<()> {
         axum::serve(listener, monitoring_router).await.unwrap();
     });
 
+    // Start background dependency probing for /health
+    let probe_config = DependencyProbeConfig {
+        solana_rpc_url: Some(config.solana.rpc_url.clone()),
+        database_url: Some(config.database.url.clone()),
+        jito_endpoint: config
+            .is_overmind_enabled()
+            .then(|| config.overmind.jito_endpoint.clone()),
+        tensorzero_gateway_url: config
+            .is_overmind_enabled()
+            .then(|| config.overmind.tensorzero_gateway_url.clone()),
+        dragonfly_url: config
+            .is_overmind_enabled()
+            .then(|| config.overmind.dragonfly_url.clone()),
+        probe_interval: std::time::Duration::from_secs(config.server.dependency_probe_interval_secs),
+        probe_timeout: std::time::Duration::from_millis(config.server.dependency_probe_timeout_ms),
+    };
+    let dependency_prober = DependencyProber::new(probe_config, monitoring_state.clone())?;
+    let _dependency_prober = tokio::spawn(dependency_prober.run());
+
+    // Check the local clock against cluster time before trusting any time-based gate
+    let clock_skew_config = ClockSkewConfig {
+        max_skew: std::time::Duration::from_millis(config.trading.max_clock_skew_ms),
+        check_interval: std::time::Duration::from_secs(config.trading.clock_skew_check_interval_secs),
+        ..ClockSkewConfig::default()
+    };
+    let clock_skew_monitor = ClockSkewMonitor::new(
+        clock_skew_config,
+        config.solana.rpc_url.clone(),
+        monitoring_state.clone(),
+    )?;
+    if let Err(e) = clock_skew_monitor.check_once().await {
+        warn!("🕰️ Startup clock skew check failed: {}", e);
+    }
+    let _clock_skew_monitor = tokio::spawn(clock_skew_monitor.run());
+
     // Initialize all modules
     let mut data_ingestor = DataIngestor::new(
         market_data_tx,
         config.api.helius_api_key.clone(),
         config.api.quicknode_api_key.clone(),
     );
+    if let Some(dedup) = replay_dedup {
+        data_ingestor = data_ingestor.with_replay_dedup(dedup);
+    }
 
-    let mut strategy_engine = StrategyEngine::new(market_data_rx, signal_tx);
+    // The brain's decisions reach the risk manager as signals next to the strategy engine's,
+    // counted through the AI funnel from receipt onwards
+    let (_market_event_tx, market_event_rx) = mpsc::unbounded_channel::<MarketEvent>();
+    let (ai_connector_shutdown_tx, ai_connector_shutdown_rx) = tokio::sync::oneshot::channel::<()>();
+    let ai_connector_task = if config.is_overmind_enabled() {
+        let connector_config = AIConnectorConfig {
+            dragonfly_url: config.overmind.dragonfly_url.clone(),
+            confidence_threshold: config.overmind.ai_confidence_threshold,
+            latency_ewma_half_life: config.overmind.latency_ewma_half_life,
+            ..AIConnectorConfig::default()
+        };
+        match AIConnector::new(connector_config, signal_tx.clone(), market_event_rx).await {
+            Ok(mut connector) => {
+                if let Some(funnel) = &ai_funnel {
+                    connector = connector.with_ai_funnel(funnel.clone());
+                }
+                Some(tokio::spawn(async move {
+                    let shutdown = async {
+                        let _ = ai_connector_shutdown_rx.await;
+                    };
+                    if let Err(e) = connector.run_until_shutdown(shutdown).await {
+                        error!("AIConnector failed: {}", e);
+                    }
+                }))
+            }
+            Err(e) => {
+                warn!("🧠 AI connector disabled, DragonflyDB unreachable: {}", e);
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let mut strategy_engine = StrategyEngine::new(market_data_rx, signal_tx)
+        .with_max_tick_age_ms(config.trading.max_tick_age_ms)
+        .with_hold_decisions(config.trading.record_hold_decisions)
+        .with_monitoring(monitoring_state.clone())
+        .with_market_prices(market_prices.clone());
+    if let Some(tx) = &module_events_tx {
+        strategy_engine = strategy_engine.with_persistence(tx.clone());
+    }
+    if let Some(capture) = &pipeline_capture {
+        strategy_engine = strategy_engine.with_pipeline_capture(capture.clone());
+    }
+    if let Some(filters) = &config.trading.strategy_market_filters {
+        for (strategy, filter) in parse_market_filters(filters)? {
+            info!("🔀 Routing {:?} ticks for {} symbol(s)", strategy, filter.symbols.len());
+            strategy_engine = strategy_engine.with_strategy_filter(strategy, filter);
+        }
+    }
+    if let Some(disabled) = &config.trading.disabled_strategies {
+        for strategy in disabled.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let strategy = parse_strategy_type(strategy)?;
+            info!("⏸️ Strategy {:?} disabled by configuration", strategy);
+            strategy_engine = strategy_engine.without_strategy(&strategy);
+        }
+    }
+    if let Some(observed) = &config.trading.observe_only_strategies {
+        for strategy in observed.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let strategy = parse_strategy_type(strategy)?;
+            info!("👁️ Strategy {:?} runs observe-only", strategy);
+            strategy_engine = strategy_engine.with_observe_only(strategy);
+        }
+    }
+    if config.trading.strategy_sandbox_enabled {
+        strategy_engine = strategy_engine.with_sandbox(strategy_sandbox);
+    }
+    if let Some(entry_price) = config.trading.shadow_sniping_entry_price {
+        info!("👥 Shadowing token sniping with entry price {}", entry_price);
+        let candidate = TokenSnipingCandidate::new(TokenSnipingParams {
+            entry_price,
+            ..TokenSnipingParams::default()
+        });
+        strategy_engine = strategy_engine.with_shadow_strategy(
+            std::sync::Arc::new(candidate),
+            monitoring_state.shadow_divergence.clone(),
+        );
+    }
 
     let risk_params = RiskParameters {
         max_position_size: config.trading.max_position_size,
@@ -97,19 +560,129 @@ async fn main() -> Result<()> {
         min_confidence_threshold: 0.6, // Default confidence threshold
     };
 
-    let mut risk_manager = RiskManager::new(signal_rx, execution_tx, risk_params);
+    let mut symbol_resolver = SymbolResolver::new();
+    if let Some(path) = &config.trading.token_list_path {
+        let token_list = std::fs::read_to_string(path)?;
+        symbol_resolver = symbol_resolver.with_token_list_json(&token_list)?;
+        info!("🪙 Loaded token list from {}", path);
+    }
+
+    let mut target_prices = TargetPriceConfig::default();
+    if let Some(bands) = &config.trading.target_price_bands {
+        target_prices = target_prices.with_strategy_bands(bands)?;
+    }
+
+    if let Some(mut tracker) = developer_tracker {
+        let target_prices = target_prices.clone();
+        let mut token_launch_rx = token_launch_rx;
+        tokio::spawn(async move {
+            while let Some(launch) = token_launch_rx.recv().await {
+                match launch.to_trading_signal(&target_prices) {
+                    Ok(signal) => {
+                        if launch_signal_tx.send(signal).is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => warn!("👨‍💻 Skipping launch of {}: {}", launch.token_symbol, e),
+                }
+            }
+        });
+        tokio::spawn(async move {
+            if let Err(e) = tracker.start().await {
+                error!("DeveloperTracker failed: {}", e);
+            }
+        });
+    }
+
+    let mut slippage_curves = SlippageCurveConfig::default();
+    if let Some(curves) = &config.trading.slippage_curves {
+        slippage_curves = slippage_curves.with_strategy_curves(curves)?;
+    }
+
+    let mut notional_rate = NotionalRateConfig {
+        max_notional_per_window: config.trading.max_notional_per_minute,
+        ..NotionalRateConfig::default()
+    };
+    if let Some(limits) = &config.trading.strategy_notional_rate_limits {
+        notional_rate = notional_rate.with_strategy_limits(limits)?;
+    }
+
+    // Signals for symbols with no price yet are traded, held or rejected per strategy
+    let mut missing_price = MissingPriceConfig {
+        default_policy: config
+            .trading
+            .missing_price_policy
+            .parse()
+            .context("Invalid SNIPER_MISSING_PRICE_POLICY")?,
+        ..MissingPriceConfig::default()
+    };
+    if let Some(policies) = &config.trading.strategy_missing_price_policies {
+        missing_price = missing_price.with_strategy_policies(policies)?;
+    }
+
+    let mut risk_manager = RiskManager::new(signal_rx, execution_tx, risk_params)
+        .with_strategy_cooldowns(strategy_cooldowns)
+        .with_execution_cooldowns(execution_cooldowns.clone())
+        .with_fee_budgets(fee_budgets.clone())
+        .with_strategy_allocations(strategy_allocations)
+        .with_symbol_resolver(symbol_resolver)
+        .with_target_prices(target_prices)
+        .with_slippage_curves(slippage_curves)
+        .with_notional_rate_limit(notional_rate)
+        .with_missing_price_policy(market_prices.clone(), missing_price);
+    if let Some(guard) = honeypot_guard {
+        risk_manager = risk_manager.with_honeypot_guard(guard);
+    }
+    if let Some(tx) = module_events_tx {
+        risk_manager = risk_manager.with_persistence(tx);
+    }
+    if config.trading.record_rejected_signals {
+        risk_manager = risk_manager.with_rejected_signal_log(persistence_tx.clone());
+    }
+    if let Some(cache) = &token_metadata {
+        risk_manager = risk_manager.with_token_metadata(cache.clone());
+    }
+    if let Some(funnel) = &ai_funnel {
+        risk_manager = risk_manager.with_ai_funnel(funnel.clone());
+    }
+    if let Some(capture) = &pipeline_capture {
+        risk_manager = risk_manager.with_pipeline_capture(capture.clone());
+    }
+    if let Some(exposure_cap) = &portfolio_exposure {
+        risk_manager = risk_manager.with_portfolio_exposure_cap(exposure_cap.clone());
+    }
 
     // Initialize Executor with optional HFT Engine
-    let mut executor = if config.is_overmind_enabled() {
+    let executor = if config.is_overmind_enabled() {
         info!("🧠 Initializing THE OVERMIND PROTOCOL Executor with AI enhancement...");
 
         let hft_config = HFTConfig {
             tensorzero_gateway_url: config.overmind.tensorzero_gateway_url.clone(),
             jito_endpoint: config.overmind.jito_endpoint.clone(),
             max_execution_latency_ms: config.overmind.max_execution_latency_ms,
+            jito_backend: config.overmind.jito_backend.parse()?,
             max_bundle_size: 5,
-            retry_attempts: 3,
+            retry_attempts: config.overmind.bundle_retry_attempts,
             ai_confidence_threshold: config.overmind.ai_confidence_threshold,
+            max_priority_fee_lamports: config.overmind.max_priority_fee_lamports,
+            skip_on_priority_fee_cap: config.overmind.skip_on_priority_fee_cap,
+            min_jito_tip_lamports: config.overmind.min_jito_tip_lamports,
+            max_jito_tip_lamports: config.overmind.max_jito_tip_lamports,
+            max_tip_profit_fraction: config.overmind.max_tip_profit_fraction,
+            max_concurrent_inferences: config.overmind.max_concurrent_inferences,
+            inference_queue_timeout_ms: config.overmind.inference_queue_timeout_ms,
+            max_bundles_in_flight: config.overmind.max_bundles_in_flight,
+            bundle_queue_timeout_ms: config.overmind.bundle_queue_timeout_ms,
+            latency_ewma_half_life: config.overmind.latency_ewma_half_life,
+            max_ai_price_deviation_bps: config.overmind.max_ai_price_deviation_bps,
+            rebuild_expired_bundles: config.overmind.rebuild_expired_bundles,
+            rebuild_tip_multiplier: config.overmind.bundle_rebuild_tip_multiplier,
+            replace_pending_bundles: config.overmind.replace_pending_bundles,
+            replace_fee_multiplier: config.overmind.bundle_replace_fee_multiplier,
+            adaptive_latency_budget: config.overmind.adaptive_latency_budget,
+            min_latency_leg_fraction: config.overmind.min_latency_leg_fraction,
+            tip_accounts,
+            ..HFTConfig::default()
         };
 
         // Create HFT-enabled executor
@@ -142,11 +715,150 @@ async fn main() -> Result<()> {
         )
     };
 
+    let mut executor = executor
+        .with_monitoring(monitoring_state.clone())
+        .with_execution_cooldowns(execution_cooldowns)
+        .with_fee_budgets(fee_budgets)
+        .with_pending_replacements(pending_replacements)
+        .with_market_prices(market_prices)
+        .with_fee_config(FeeConfig {
+            swap_fee_bps: config.trading.swap_fee_bps,
+            default_priority_fee_lamports: config.trading.default_priority_fee_lamports,
+            ..FeeConfig::default()
+        });
+    if let Some(cache) = token_metadata {
+        executor = executor.with_token_metadata(cache);
+    }
+    if let Some(actions) = &config.overmind.standard_path_actions {
+        executor = executor.with_standard_path_actions(actions)?;
+    }
+    if let Some(reporter) = &ai_feedback {
+        executor = executor.with_ai_feedback(reporter.clone());
+    }
+    if let Some(funnel) = ai_funnel {
+        executor = executor.with_ai_funnel(funnel);
+    }
+    if let Some(capture) = pipeline_capture {
+        executor = executor.with_pipeline_capture(capture);
+    }
+    executor = executor
+        .with_positions(wallet_manager.clone(), "primary".to_string())
+        .with_trade_results(risk_manager.trade_result_sender());
+    if let Some(journal) = &position_journal {
+        executor = executor.with_position_journal(journal.clone());
+    }
+    if let Some(exposure_cap) = &portfolio_exposure {
+        executor = executor.with_portfolio_exposure_cap(exposure_cap.clone());
+    }
+
+    // Executed signal IDs survive restarts and are shared with other instances
+    if let Some(url) = &config.trading.idempotency_store_url {
+        let idempotency_config = IdempotencyConfig {
+            ttl: std::time::Duration::from_secs(config.trading.idempotency_ttl_secs),
+            fail_open: config.trading.idempotency_fail_open,
+            ..IdempotencyConfig::default()
+        };
+        match DragonflyIdempotencyStore::connect(url, &idempotency_config).await {
+            Ok(store) => {
+                info!("🔁 Durable idempotency enabled ({})", if idempotency_config.fail_open { "fail-open" } else { "fail-closed" });
+                executor = executor.with_idempotency(IdempotencyGuard::new(std::sync::Arc::new(store), &idempotency_config));
+            }
+            Err(e) if idempotency_config.fail_open => {
+                warn!("🔁 Durable idempotency disabled, store unreachable: {}", e);
+            }
+            Err(e) => return Err(e.context("Idempotency store required (SNIPER_IDEMPOTENCY_FAIL_OPEN=false)")),
+        }
+    }
+
+    // Live trades are simulated before submission; a high failure rate halts live trading
+    if config.is_live_trading() {
+        let rpc_client = std::sync::Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(
+            config.solana.rpc_url.clone(),
+        ));
+        executor = executor.with_preflight_simulation(
+            std::sync::Arc::new(RpcPreflightSimulator::new(rpc_client, primary_pubkey)),
+            simulation_guard,
+        );
+    }
+
+    // Paper fills use slippage learned from live fills; parameters persist across restarts
+    if config.trading.slippage_learning_enabled {
+        let mut slippage_model = SlippageModel::new(SlippageModelConfig {
+            calibration_interval: std::time::Duration::from_secs(config.trading.slippage_calibration_interval_secs),
+            frozen: config.trading.slippage_params_frozen,
+            params_path: config.trading.slippage_params_path.clone().map(Into::into),
+            ..SlippageModelConfig::default()
+        });
+        if let Some(path) = &config.trading.slippage_params_path {
+            match slippage_model.load(std::path::Path::new(path)) {
+                Ok(buckets) => info!("📐 Loaded slippage parameters for {} buckets from {}", buckets, path),
+                Err(e) => warn!("Starting without learned slippage parameters: {}", e),
+            }
+        }
+        let slippage_model = std::sync::Arc::new(std::sync::Mutex::new(slippage_model));
+        if !config.trading.slippage_params_frozen {
+            tokio::spawn(SlippageCalibrator::new(slippage_model.clone()).run());
+        }
+        executor = executor.with_slippage_model(slippage_model);
+    }
+
+    // Live trades are only reported Confirmed once they reach the configured commitment
+    let mut confirmation_config = ConfirmationConfig {
+        default_level: config.trading.confirmation_commitment.parse()?,
+        max_bundle_age_slots: config.trading.max_bundle_age_slots,
+        ..ConfirmationConfig::default()
+    };
+    if let Some(levels) = &config.trading.strategy_confirmation_commitments {
+        confirmation_config = confirmation_config.with_strategy_levels(levels)?;
+    }
+    if config.is_live_trading() {
+        let rpc_client = solana_client::nonblocking::rpc_client::RpcClient::new(
+            config.solana.rpc_url.clone(),
+        );
+        executor = executor.with_confirmation_tracker(ConfirmationTracker::new(
+            confirmation_config,
+            std::sync::Arc::new(rpc_client),
+        ));
+    } else if config.trading.paper_confirmation_simulated {
+        // Paper trades wait as long as a live trade at the same commitment would
+        let mut paper_confirmation = PaperConfirmationModel::new(confirmation_config);
+        if let Some(latencies) = &config.trading.paper_confirmation_latencies_ms {
+            paper_confirmation = paper_confirmation.with_latencies(latencies)?;
+        }
+        executor = executor.with_paper_confirmation(paper_confirmation);
+    }
+
+    // Optional execution event streaming to NATS
+    if let Some(nats_url) = &config.message_bus.nats_url {
+        #[cfg(feature = "event-bus")]
+        {
+            let (publisher, event_bus) =
+                modules::event_bus::EventBusPublisher::new(modules::event_bus::EventBusConfig {
+                    nats_url: nats_url.clone(),
+                    subject_prefix: config.message_bus.subject_prefix.clone(),
+                    ..Default::default()
+                });
+            tokio::spawn(publisher.run());
+            executor = executor.with_event_bus(event_bus);
+        }
+        #[cfg(not(feature = "event-bus"))]
+        warn!(
+            "📤 SNIPER_EVENT_BUS_NATS_URL={} ignored: built without the event-bus feature",
+            nats_url
+        );
+    }
+
     let mut persistence_manager = PersistenceManager::new(
         persistence_rx,
         execution_result_rx,
         config.database.url.clone(),
-    );
+    )
+    .with_batching(PersistenceBatchConfig {
+        max_batch_size: config.database.persistence_batch_size,
+        flush_interval: std::time::Duration::from_millis(
+            config.database.persistence_flush_interval_ms.max(1),
+        ),
+    });
 
     info!("🔧 All modules initialized");
 
@@ -181,6 +893,7 @@ async fn main() -> Result<()> {
         if let Err(e) = persistence_manager.start().await {
             error!("PersistenceManager failed: {}", e);
         }
+        persistence_manager.final_flush().cloned()
     });
 
     info!("✅ All modules started successfully");
@@ -201,14 +914,75 @@ async fn main() -> Result<()> {
     );
     info!("🛡️ Max Daily Loss: ${}", config.trading.max_daily_loss);
 
-    // Wait for all tasks to complete (or fail)
-    tokio::try_join!(
-        data_ingestor_task,
-        strategy_engine_task,
-        risk_manager_task,
-        executor_task,
-        persistence_task,
-    )?;
+    // Run until the pipeline stops (or fails) or a shutdown signal arrives
+    tokio::select! {
+        result = async {
+            tokio::try_join!(
+                data_ingestor_task,
+                strategy_engine_task,
+                risk_manager_task,
+                executor_task,
+            )
+        } => {
+            result?;
+        }
+        _ = wait_for_shutdown_signal() => {
+            info!("🛑 Shutdown signal received");
+        }
+    }
+
+    // Fresh decisions still queued for the brain's connector are forwarded before shutdown
+    if let Some(task) = ai_connector_task {
+        let _ = ai_connector_shutdown_tx.send(());
+        if tokio::time::timeout(std::time::Duration::from_secs(10), task).await.is_err() {
+            warn!("⏱️ AI connector did not stop within 10s");
+        }
+    }
+
+    if let Some(journal) = &position_journal {
+        match journal.save_snapshot(&wallet_manager).await {
+            Ok(()) => info!("🧮 Position snapshot saved to {:?}", journal.snapshot_path),
+            Err(e) => warn!("Failed to save position snapshot: {}", e),
+        }
+    }
+
+    // Persistence flushes its buffered records and stops on its final message
+    let report_config = ShutdownReportConfig {
+        file_path: config.logging.shutdown_report_path.clone().into(),
+    };
+    let mut report = None;
+    if config.logging.shutdown_report_enabled {
+        let (total_signals, executed_trades) = monitoring_state
+            .metrics
+            .lock()
+            .map(|m| (m.trading_metrics.total_signals, m.trading_metrics.executed_trades))
+            .unwrap_or_default();
+        let shutdown_report = ShutdownReport::new(
+            config.trading_mode_str().to_string(),
+            monitoring_state.start_time.elapsed().as_secs(),
+        )
+        .with_trade_counts(total_signals, executed_trades)
+        .with_strategy_allocations(&monitoring_state.strategy_allocations)
+        .with_strategy_cooldowns(&monitoring_state.strategy_cooldowns)
+        .with_wallet_manager(&wallet_manager)
+        .await;
+        if shutdown_report.persist(&report_config, &persistence_tx) {
+            report = Some(shutdown_report);
+        }
+    } else {
+        let _ = persistence_tx.send(PersistenceMessage::Shutdown);
+    }
+    drop(persistence_tx);
+
+    match tokio::time::timeout(std::time::Duration::from_secs(5), persistence_task).await {
+        Ok(Ok(Some(flush))) => {
+            if let Some(report) = report.as_mut() {
+                report.confirm_persistence_flush(flush, &report_config);
+            }
+        }
+        Ok(_) => warn!("💾 Persistence stopped without confirming its final flush"),
+        Err(_) => warn!("💾 Persistence did not finish its final flush in time"),
+    }
 
     if config.is_overmind_enabled() {
         info!("🛑 THE OVERMIND PROTOCOL shutdown complete");
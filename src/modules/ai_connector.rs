@@ -6,13 +6,18 @@ use anyhow::Result;
 use redis::aio::ConnectionManager;
 use redis::{AsyncCommands, Client};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::{mpsc, RwLock};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, VecDeque};
+use std::hash::{Hash, Hasher};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{mpsc, Notify, RwLock};
 use tokio::time::{Duration, Instant};
-use tracing::{error, info, warn, instrument};
+use tracing::{debug, error, info, warn, instrument};
 use uuid::Uuid;
 
+use crate::modules::ai_funnel::{AIDecisionFunnel, AIFunnelStage, SharedAIDecisionFunnel};
+use crate::modules::ewma::{Ewma, DEFAULT_EWMA_HALF_LIFE_SAMPLES};
 use crate::modules::strategy::TradingSignal;
 
 // ============================================================================
@@ -69,10 +74,78 @@ pub struct MarketEvent {
     pub volume: f64,
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub event_type: MarketEventType,
-    pub metadata: HashMap<String, serde_json::Value>,
+    pub metadata: MarketEventMetadata,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Well-known market event fields shared by producers and the brain. They serialize as
+/// keys of the same `metadata` object as `extra`, which holds anything else.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MarketEventMetadata {
+    /// Pool liquidity in USD
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub liquidity: Option<f64>,
+    /// Share of supply held by the largest holders (0.0 - 1.0)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub holder_concentration: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pool_address: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dev_wallet: Option<String>,
+    /// Free-form extensions; must not reuse a well-known key
+    #[serde(flatten)]
+    pub extra: HashMap<String, serde_json::Value>,
+}
+
+#[allow(dead_code)]
+impl MarketEventMetadata {
+    pub const WELL_KNOWN_KEYS: [&'static str; 4] =
+        ["liquidity", "holder_concentration", "pool_address", "dev_wallet"];
+
+    pub fn with_liquidity(mut self, liquidity: f64) -> Self {
+        self.liquidity = Some(liquidity);
+        self
+    }
+
+    pub fn with_holder_concentration(mut self, holder_concentration: f64) -> Self {
+        self.holder_concentration = Some(holder_concentration);
+        self
+    }
+
+    pub fn with_pool_address(mut self, pool_address: impl Into<String>) -> Self {
+        self.pool_address = Some(pool_address.into());
+        self
+    }
+
+    pub fn with_dev_wallet(mut self, dev_wallet: impl Into<String>) -> Self {
+        self.dev_wallet = Some(dev_wallet.into());
+        self
+    }
+
+    pub fn with_extra(mut self, key: impl Into<String>, value: serde_json::Value) -> Self {
+        self.extra.insert(key.into(), value);
+        self
+    }
+
+    /// Ok when the well-known fields are in range and no extension shadows one of them
+    pub fn validate(&self) -> Result<()> {
+        if let Some(liquidity) = self.liquidity {
+            if !(liquidity.is_finite() && liquidity >= 0.0) {
+                return Err(anyhow::anyhow!("invalid liquidity {}", liquidity));
+            }
+        }
+        if let Some(concentration) = self.holder_concentration {
+            if !(0.0..=1.0).contains(&concentration) {
+                return Err(anyhow::anyhow!("holder concentration {} outside [0, 1]", concentration));
+            }
+        }
+        if let Some(key) = Self::WELL_KNOWN_KEYS.iter().find(|key| self.extra.contains_key(**key)) {
+            return Err(anyhow::anyhow!("extension key {} shadows a well-known field", key));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum MarketEventType {
     PriceChange,
     VolumeSpike,
@@ -106,13 +179,17 @@ pub struct AIConnector {
     /// Channel to receive market events from data ingestor
     market_event_receiver: mpsc::UnboundedReceiver<MarketEvent>,
     /// Vector memory cache for performance
-    vector_cache: Arc<RwLock<HashMap<String, VectorContext>>>,
-    /// AI performance metrics
-    metrics: AIMetrics,
+    vector_cache: Arc<RwLock<VectorContextCache>>,
+    /// AI performance metrics, shared with the spawned decision listener
+    metrics: Arc<Mutex<AIMetrics>>,
     /// Configuration
     config: AIConnectorConfig,
     /// Connection status
     is_connected: Arc<RwLock<bool>>,
+    /// Cleared on shutdown so the decision listener stops taking new decisions
+    accepting_decisions: Arc<AtomicBool>,
+    /// Decisions reaching each stage, continued by the risk manager and executor
+    ai_funnel: SharedAIDecisionFunnel,
 }
 
 #[derive(Debug, Clone)]
@@ -122,7 +199,25 @@ pub struct AIConnectorConfig {
     pub max_decision_age: Duration,
     pub confidence_threshold: f64,
     pub vector_cache_size: usize,
+    /// Cached vector context older than this is treated as a miss
+    pub vector_cache_ttl: Duration,
+    /// Retries of a DragonflyDB operation after a connection error
     pub retry_attempts: u32,
+    /// Backoff before the first retry; doubles per retry up to `max_retry_backoff`
+    pub retry_backoff: Duration,
+    pub max_retry_backoff: Duration,
+    /// Number of concurrent market-event push workers (symbols are pinned to one worker)
+    pub market_event_concurrency: usize,
+    /// Maximum events sent to DragonflyDB in a single LPUSH
+    pub market_event_batch_size: usize,
+    /// Half-life, in decisions, of the reported average decision latency
+    pub latency_ewma_half_life: u32,
+    /// Time allowed on shutdown to forward decisions still queued in DragonflyDB
+    /// (zero leaves them queued)
+    pub shutdown_drain_timeout: Duration,
+    /// Drop market events whose metadata fails `MarketEventMetadata::validate` instead
+    /// of forwarding them to the brain
+    pub validate_market_event_metadata: bool,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -130,10 +225,252 @@ pub struct AIMetrics {
     pub decisions_received: u64,
     pub decisions_processed: u64,
     pub decisions_rejected: u64,
+    /// Exponentially-weighted average, so it reflects recent decisions
     pub avg_decision_latency: Duration,
     pub brain_connection_errors: u64,
     pub vector_cache_hits: u64,
     pub vector_cache_misses: u64,
+    pub market_events_coalesced: u64,
+    /// Market events dropped for invalid metadata
+    pub market_events_invalid: u64,
+    decision_latency_ewma: Ewma,
+}
+
+impl AIMetrics {
+    fn record_decision_latency(&mut self, latency: Duration) {
+        self.decisions_processed += 1;
+        let avg_ms = self.decision_latency_ewma.record(latency.as_secs_f64() * 1000.0);
+        self.avg_decision_latency = Duration::from_secs_f64(avg_ms / 1000.0);
+    }
+}
+
+/// Vector memory context by symbol. Entries older than the TTL are misses, so callers
+/// refresh them instead of relying on memories from a past market regime.
+#[derive(Debug)]
+pub struct VectorContextCache {
+    capacity: usize,
+    ttl: Duration,
+    entries: HashMap<String, (VectorContext, Instant)>,
+}
+
+/// Size and freshness of the vector context cache
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct VectorCacheStats {
+    pub entries: usize,
+    pub average_age: Duration,
+}
+
+#[allow(dead_code)]
+impl VectorContextCache {
+    pub fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity: capacity.max(1),
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// A fresh entry for `key`; an expired one is removed and reported as a miss
+    pub fn get(&mut self, key: &str) -> Option<VectorContext> {
+        let (context, inserted_at) = self.entries.get(key)?;
+        if inserted_at.elapsed() <= self.ttl {
+            return Some(context.clone());
+        }
+        self.entries.remove(key);
+        None
+    }
+
+    /// Store `context`, evicting expired entries and then the oldest one when full
+    pub fn insert(&mut self, key: String, context: VectorContext) {
+        if !self.entries.contains_key(&key) && self.entries.len() >= self.capacity {
+            self.purge_expired();
+            if self.entries.len() >= self.capacity {
+                let oldest = self
+                    .entries
+                    .iter()
+                    .min_by_key(|(_, (_, inserted_at))| *inserted_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(oldest) = oldest {
+                    self.entries.remove(&oldest);
+                }
+            }
+        }
+        self.entries.insert(key, (context, Instant::now()));
+    }
+
+    /// Drop every expired entry, returning how many were dropped
+    pub fn purge_expired(&mut self) -> usize {
+        let before = self.entries.len();
+        let ttl = self.ttl;
+        self.entries.retain(|_, (_, inserted_at)| inserted_at.elapsed() <= ttl);
+        before - self.entries.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn stats(&self) -> VectorCacheStats {
+        let total: Duration = self.entries.values().map(|(_, inserted_at)| inserted_at.elapsed()).sum();
+        VectorCacheStats {
+            entries: self.entries.len(),
+            average_age: total.checked_div(self.entries.len() as u32).unwrap_or_default(),
+        }
+    }
+}
+
+/// Market events waiting to be pushed to the brain. A newer event of the same type for
+/// a symbol that is still pending replaces the older one and moves to the back of the
+/// queue, so a symbol's events still drain in the order they were last observed.
+#[derive(Debug, Default)]
+pub struct MarketEventCoalescer {
+    order: VecDeque<(String, MarketEventType)>,
+    pending: HashMap<(String, MarketEventType), MarketEvent>,
+}
+
+#[allow(dead_code)]
+impl MarketEventCoalescer {
+    /// Queue an event, returning true if it replaced a pending one of the same symbol and type
+    pub fn push(&mut self, event: MarketEvent) -> bool {
+        let key = (event.symbol.clone(), event.event_type.clone());
+        let replaced = self.pending.insert(key.clone(), event).is_some();
+        if replaced {
+            self.order.retain(|pending| pending != &key);
+        }
+        self.order.push_back(key);
+        replaced
+    }
+
+    /// Take up to `max` pending events in the order they were last pushed
+    pub fn drain_batch(&mut self, max: usize) -> Vec<MarketEvent> {
+        let count = max.min(self.order.len());
+        self.order
+            .drain(..count)
+            .filter_map(|key| self.pending.remove(&key))
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+}
+
+/// One market-event push worker and its pending queue
+#[derive(Default)]
+struct MarketEventShard {
+    queue: Mutex<MarketEventCoalescer>,
+    notify: Notify,
+    closed: AtomicBool,
+}
+
+/// Pin a symbol to a dispatch worker so its events are pushed in order
+fn market_event_shard(symbol: &str, shards: usize) -> usize {
+    let mut hasher = DefaultHasher::new();
+    symbol.hash(&mut hasher);
+    (hasher.finish() % shards.max(1) as u64) as usize
+}
+
+// ============================================================================
+// DRAGONFLYDB RETRIES
+// ============================================================================
+
+/// Result of a DragonflyDB operation after retries
+#[derive(Debug, Clone, PartialEq)]
+pub enum DragonflyOutcome<T> {
+    Success(T),
+    /// The server rejected the command; retrying won't help, so callers log and skip
+    CommandFailed(String),
+    /// The connection stayed unusable through every attempt; callers reconnect
+    ConnectionLost(String),
+}
+
+#[allow(dead_code)]
+impl<T> DragonflyOutcome<T> {
+    pub fn map<U>(self, f: impl FnOnce(T) -> U) -> DragonflyOutcome<U> {
+        match self {
+            DragonflyOutcome::Success(value) => DragonflyOutcome::Success(f(value)),
+            DragonflyOutcome::CommandFailed(e) => DragonflyOutcome::CommandFailed(e),
+            DragonflyOutcome::ConnectionLost(e) => DragonflyOutcome::ConnectionLost(e),
+        }
+    }
+
+    pub fn is_success(&self) -> bool {
+        matches!(self, DragonflyOutcome::Success(_))
+    }
+}
+
+/// Retry budget for DragonflyDB operations; only connection errors are retried
+#[derive(Debug, Clone)]
+pub struct DragonflyRetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl DragonflyRetryPolicy {
+    /// Exponential backoff before retry `attempt` (1-based)
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+/// Whether a Redis error means the connection is unusable (as opposed to a rejected command)
+pub fn is_connection_error(error: &redis::RedisError) -> bool {
+    error.is_io_error()
+        || matches!(
+            error.kind(),
+            redis::ErrorKind::IoError
+                | redis::ErrorKind::BusyLoadingError
+                | redis::ErrorKind::TryAgain
+                | redis::ErrorKind::ClusterDown
+        )
+}
+
+/// Run `op`, retrying connection errors with backoff up to the policy's budget.
+/// Command errors are returned immediately.
+pub async fn with_dragonfly_retry<T, F, Fut>(
+    policy: &DragonflyRetryPolicy,
+    operation: &str,
+    mut op: F,
+) -> DragonflyOutcome<T>
+where
+    F: FnMut() -> Fut,
+    Fut: std::future::Future<Output = redis::RedisResult<T>>,
+{
+    let mut attempt = 0;
+    loop {
+        match op().await {
+            Ok(value) => return DragonflyOutcome::Success(value),
+            Err(e) if !is_connection_error(&e) => {
+                return DragonflyOutcome::CommandFailed(format!("{} failed: {}", operation, e));
+            }
+            Err(e) if attempt >= policy.max_retries => {
+                return DragonflyOutcome::ConnectionLost(format!(
+                    "{} failed after {} attempts: {}",
+                    operation,
+                    attempt + 1,
+                    e
+                ));
+            }
+            Err(e) => {
+                attempt += 1;
+                let backoff = policy.backoff(attempt);
+                warn!("{} connection error, retry {}/{} in {:?}: {}",
+                      operation, attempt, policy.max_retries, backoff, e);
+                tokio::time::sleep(backoff).await;
+            }
+        }
+    }
 }
 
 // ============================================================================
@@ -162,13 +499,27 @@ impl AIConnector {
             dragonfly_client,
             decision_sender,
             market_event_receiver,
-            vector_cache: Arc::new(RwLock::new(HashMap::new())),
-            metrics: AIMetrics::default(),
+            vector_cache: Arc::new(RwLock::new(VectorContextCache::new(
+                config.vector_cache_size,
+                config.vector_cache_ttl,
+            ))),
+            metrics: Arc::new(Mutex::new(AIMetrics {
+                decision_latency_ewma: Ewma::new(config.latency_ewma_half_life),
+                ..AIMetrics::default()
+            })),
             config,
             is_connected: Arc::new(RwLock::new(true)),
+            accepting_decisions: Arc::new(AtomicBool::new(true)),
+            ai_funnel: AIDecisionFunnel::shared(),
         })
     }
 
+    /// Count decisions into a funnel shared with the risk manager and executor
+    pub fn with_ai_funnel(mut self, ai_funnel: SharedAIDecisionFunnel) -> Self {
+        self.ai_funnel = ai_funnel;
+        self
+    }
+
     #[instrument(skip(self))]
     pub async fn start(&mut self) -> Result<()> {
         info!("🚀 Starting AI Connector - Bridge between Python Brain and Rust Executor");
@@ -178,6 +529,9 @@ impl AIConnector {
         let dragonfly_client = self.dragonfly_client.clone();
         let decision_sender = self.decision_sender.clone();
         let is_connected = self.is_connected.clone();
+        let metrics = self.metrics.clone();
+        let accepting_decisions = self.accepting_decisions.clone();
+        let ai_funnel = self.ai_funnel.clone();
 
         // Start brain listener task
         let brain_listener = {
@@ -185,7 +539,8 @@ impl AIConnector {
             let dragonfly_client = dragonfly_client.clone();
             let decision_sender = decision_sender.clone();
             tokio::spawn(async move {
-                Self::run_brain_listener(config, dragonfly_client, decision_sender).await
+                Self::run_brain_listener(config, dragonfly_client, decision_sender, metrics, accepting_decisions, ai_funnel)
+                    .await
             })
         };
 
@@ -212,100 +567,196 @@ impl AIConnector {
         Ok(())
     }
 
-    async fn start_brain_listener(&self) -> Result<()> {
-        info!("👂 Starting AI Brain decision listener");
-        
+    /// Run the connector until `shutdown` resolves, then drain queued decisions
+    pub async fn run_until_shutdown(
+        &mut self,
+        shutdown: impl std::future::Future<Output = ()>,
+    ) -> Result<()> {
+        tokio::select! {
+            result = self.start() => return result,
+            _ = shutdown => {}
+        }
+
+        self.shutdown().await;
+        Ok(())
+    }
+
+    /// Stop taking new decisions from the brain and forward the fresh ones still
+    /// queued in DragonflyDB, for at most `shutdown_drain_timeout`. Stale decisions
+    /// are discarded as usual. Returns the number of queued decisions processed.
+    pub async fn shutdown(&self) -> usize {
+        self.accepting_decisions.store(false, Ordering::Release);
+        if self.config.shutdown_drain_timeout.is_zero() {
+            info!("🛑 AI Connector stopped without draining queued decisions");
+            return 0;
+        }
+
+        info!("🛑 AI Connector shutting down, draining queued AI decisions");
         let mut conn = self.dragonfly_client.clone();
-        let decision_sender = self.decision_sender.clone();
-        let _config = self.config.clone();
+        let mut processed = 0;
+        let drain = Self::drain_queued_decisions(
+            &self.config,
+            &mut conn,
+            &self.decision_sender,
+            &self.metrics,
+            &self.ai_funnel,
+            &mut processed,
+        );
+        if tokio::time::timeout(self.config.shutdown_drain_timeout, drain).await.is_err() {
+            warn!("⏱️ Shutdown drain timed out after {:?}; remaining decisions stay queued",
+                  self.config.shutdown_drain_timeout);
+        }
 
-        loop {
-            match self.listen_for_ai_decisions(&mut conn).await {
-                Ok(Some(ai_decision)) => {
-                    if let Err(e) = self.process_ai_decision(ai_decision, &decision_sender).await {
-                        error!("Failed to process AI decision: {}", e);
+        info!("✅ Drained {} queued AI decision(s) on shutdown", processed);
+        processed
+    }
+
+    async fn start_brain_listener(&self) -> Result<()> {
+        Self::run_brain_listener(
+            self.config.clone(),
+            self.dragonfly_client.clone(),
+            self.decision_sender.clone(),
+            self.metrics.clone(),
+            self.accepting_decisions.clone(),
+            self.ai_funnel.clone(),
+        )
+        .await
+    }
+
+    async fn start_market_event_processor(&mut self) -> Result<()> {
+        let shard_count = self.config.market_event_concurrency.max(1);
+        let batch_size = self.config.market_event_batch_size.max(1);
+        info!(
+            "📊 Starting market event processor ({} workers, batch size {})",
+            shard_count, batch_size
+        );
+
+        let shards: Vec<Arc<MarketEventShard>> = (0..shard_count)
+            .map(|_| Arc::new(MarketEventShard::default()))
+            .collect();
+        let workers: Vec<_> = shards
+            .iter()
+            .map(|shard| {
+                let shard = shard.clone();
+                let conn = self.dragonfly_client.clone();
+                tokio::spawn(Self::run_market_event_worker(shard, conn, batch_size, self.config.clone()))
+            })
+            .collect();
+
+        while let Some(market_event) = self.market_event_receiver.recv().await {
+            if self.config.validate_market_event_metadata {
+                if let Err(e) = market_event.metadata.validate() {
+                    warn!("Dropping market event {} for {}: {}", market_event.event_id, market_event.symbol, e);
+                    if let Ok(mut metrics) = self.metrics.lock() {
+                        metrics.market_events_invalid += 1;
                     }
+                    continue;
                 }
-                Ok(None) => {
-                    // No decision received, continue listening
-                    tokio::time::sleep(Duration::from_millis(100)).await;
+            }
+            let shard = &shards[market_event_shard(&market_event.symbol, shard_count)];
+            let coalesced = match shard.queue.lock() {
+                Ok(mut queue) => queue.push(market_event),
+                Err(_) => {
+                    error!("Market event queue poisoned, dropping event");
+                    continue;
                 }
-                Err(e) => {
-                    error!("Error listening for AI decisions: {}", e);
-                    tokio::time::sleep(Duration::from_secs(1)).await;
+            };
+            if coalesced {
+                if let Ok(mut metrics) = self.metrics.lock() {
+                    metrics.market_events_coalesced += 1;
                 }
             }
+            shard.notify.notify_one();
         }
-    }
 
-    async fn start_market_event_processor(&mut self) -> Result<()> {
-        info!("📊 Starting market event processor");
-        
-        let mut conn = self.dragonfly_client.clone();
-
-        while let Some(market_event) = self.market_event_receiver.recv().await {
-            if let Err(e) = self.send_market_event_to_brain(&mut conn, market_event).await {
-                error!("Failed to send market event to brain: {}", e);
-            }
+        // Input closed: let workers flush what is still pending
+        for shard in &shards {
+            shard.closed.store(true, Ordering::Release);
+            shard.notify.notify_one();
+        }
+        for worker in workers {
+            worker.await?;
         }
 
         Ok(())
     }
 
-    async fn start_health_monitor(&self) -> Result<()> {
-        info!("💓 Starting AI Connector health monitor");
-        
-        let mut interval = tokio::time::interval(Duration::from_secs(30));
-        let mut conn = self.dragonfly_client.clone();
-
+    async fn run_market_event_worker(
+        shard: Arc<MarketEventShard>,
+        mut conn: ConnectionManager,
+        batch_size: usize,
+        config: AIConnectorConfig,
+    ) {
         loop {
-            interval.tick().await;
-            
-            match self.check_brain_health(&mut conn).await {
-                Ok(is_healthy) => {
-                    let mut connected = self.is_connected.write().await;
-                    *connected = is_healthy;
-                    
-                    if !is_healthy {
-                        warn!("🔴 AI Brain connection unhealthy");
-                    }
+            let batch = match shard.queue.lock() {
+                Ok(mut queue) => queue.drain_batch(batch_size),
+                Err(_) => return,
+            };
+
+            if batch.is_empty() {
+                if shard.closed.load(Ordering::Acquire) {
+                    return;
                 }
-                Err(e) => {
-                    error!("Health check failed: {}", e);
-                    let mut connected = self.is_connected.write().await;
-                    *connected = false;
+                shard.notify.notified().await;
+                continue;
+            }
+
+            match Self::send_market_events_to_brain(&config, &mut conn, &batch).await {
+                DragonflyOutcome::Success(()) => {}
+                DragonflyOutcome::CommandFailed(e) => {
+                    error!("Dropping {} market events: {}", batch.len(), e);
+                }
+                DragonflyOutcome::ConnectionLost(e) => {
+                    error!("Dropping {} market events: {}", batch.len(), e);
+                    Self::reconnect(&config, &mut conn).await;
                 }
             }
         }
     }
 
+    /// Push a batch of events with a single LPUSH, preserving their order
+    async fn send_market_events_to_brain(
+        config: &AIConnectorConfig,
+        conn: &mut ConnectionManager,
+        market_events: &[MarketEvent],
+    ) -> DragonflyOutcome<()> {
+        let events_json = match market_events
+            .iter()
+            .map(serde_json::to_string)
+            .collect::<Result<Vec<_>, _>>()
+        {
+            Ok(events_json) => events_json,
+            Err(e) => return DragonflyOutcome::CommandFailed(format!("Failed to serialize market events: {}", e)),
+        };
+
+        let outcome = with_dragonfly_retry(&config.retry_policy(), "LPUSH overmind:market_events", || {
+            let mut conn = conn.clone();
+            let events_json = events_json.clone();
+            async move { conn.lpush("overmind:market_events", events_json).await }
+        })
+        .await;
+
+        if outcome.is_success() {
+            debug!("📤 Sent {} market events to AI Brain", market_events.len());
+        }
+        outcome
+    }
+
+    async fn start_health_monitor(&self) -> Result<()> {
+        Self::run_health_monitor(
+            self.config.clone(),
+            self.dragonfly_client.clone(),
+            self.is_connected.clone(),
+        )
+        .await
+    }
+
     #[instrument(skip(self, conn))]
     async fn listen_for_ai_decisions(
         &self,
         conn: &mut ConnectionManager,
-    ) -> Result<Option<AIDecision>> {
-        // Listen for AI decisions from Python Brain
-        let result: Option<(String, String)> = conn
-            .blpop("overmind:trading_commands", self.config.brain_request_timeout.as_secs() as f64)
-            .await?;
-
-        if let Some((_, decision_json)) = result {
-            let ai_decision: AIDecision = serde_json::from_str(&decision_json)?;
-            
-            // Check decision age
-            let decision_age = chrono::Utc::now() - ai_decision.timestamp;
-            if decision_age > chrono::Duration::from_std(self.config.max_decision_age)? {
-                warn!("Rejecting stale AI decision: {} seconds old", decision_age.num_seconds());
-                return Ok(None);
-            }
-
-            info!("🧠 Received AI decision: {} {} (confidence: {:.2})",
-                  ai_decision.action, ai_decision.symbol, ai_decision.confidence);
-            
-            Ok(Some(ai_decision))
-        } else {
-            Ok(None)
-        }
+    ) -> DragonflyOutcome<Option<AIDecision>> {
+        Self::listen_for_ai_decisions_static(&self.config, conn, &self.ai_funnel).await
     }
 
     #[instrument(skip(self, decision_sender))]
@@ -314,29 +765,7 @@ impl AIConnector {
         ai_decision: AIDecision,
         decision_sender: &mpsc::UnboundedSender<TradingSignal>,
     ) -> Result<()> {
-        let start_time = Instant::now();
-
-        // Validate AI decision
-        if ai_decision.confidence < self.config.confidence_threshold {
-            warn!("Rejecting low-confidence AI decision: {:.2} < {:.2}",
-                  ai_decision.confidence, self.config.confidence_threshold);
-            return Ok(());
-        }
-
-        // Convert AI decision to trading signal
-        let trading_signal = self.convert_ai_decision_to_signal(ai_decision).await?;
-
-        // Send to strategy engine
-        if let Err(e) = decision_sender.send(trading_signal) {
-            error!("Failed to send trading signal: {}", e);
-            return Err(anyhow::anyhow!("Failed to send trading signal"));
-        }
-
-        // Update metrics
-        let processing_time = start_time.elapsed();
-        info!("✅ Processed AI decision in {:?}", processing_time);
-
-        Ok(())
+        Self::process_ai_decision_static(ai_decision, decision_sender, &self.config, &self.metrics, &self.ai_funnel).await
     }
 
     async fn convert_ai_decision_to_signal(&self, ai_decision: AIDecision) -> Result<TradingSignal> {
@@ -359,46 +788,38 @@ impl AIConnector {
             confidence: ai_decision.confidence,
             timestamp: ai_decision.timestamp,
             strategy_type: StrategyType::AIDecision, // New strategy type for AI decisions
+            tick_received_at: None,
+            pool_liquidity: None,
         })
     }
 
-    #[instrument(skip(self, conn, market_event))]
-    async fn send_market_event_to_brain(
-        &self,
-        conn: &mut ConnectionManager,
-        market_event: MarketEvent,
-    ) -> Result<()> {
-        let event_json = serde_json::to_string(&market_event)?;
-        
-        // Send to Python Brain via DragonflyDB
-        let _: () = conn.lpush("overmind:market_events", event_json).await?;
-        
-        info!("📤 Sent market event to AI Brain: {} {}", 
-              market_event.symbol, market_event.event_type);
-
-        Ok(())
+    async fn check_brain_health(&self, conn: &mut ConnectionManager) -> DragonflyOutcome<bool> {
+        Self::check_brain_health_static(&self.config, conn).await
     }
 
-    async fn check_brain_health(&self, conn: &mut ConnectionManager) -> Result<bool> {
-        // Send ping to brain health channel
-        let health_check = serde_json::json!({
-            "type": "health_check",
-            "timestamp": chrono::Utc::now(),
-            "source": "rust_executor"
-        });
-
-        let _: () = conn.lpush("overmind:health_check", health_check.to_string()).await?;
+    pub async fn get_metrics(&self) -> AIMetrics {
+        self.metrics.lock().map(|metrics| metrics.clone()).unwrap_or_default()
+    }
 
-        // Wait for response (with timeout)
-        let response: Option<(String, String)> = conn
-            .blpop("overmind:health_response", 5.0) // 5 second timeout
-            .await?;
+    /// Fresh cached vector context for `symbol`, counted as a cache hit or miss
+    pub async fn cached_vector_context(&self, symbol: &str) -> Option<VectorContext> {
+        let context = self.vector_cache.write().await.get(symbol);
+        if let Ok(mut metrics) = self.metrics.lock() {
+            if context.is_some() {
+                metrics.vector_cache_hits += 1;
+            } else {
+                metrics.vector_cache_misses += 1;
+            }
+        }
+        context
+    }
 
-        Ok(response.is_some())
+    pub async fn cache_vector_context(&self, symbol: String, context: VectorContext) {
+        self.vector_cache.write().await.insert(symbol, context);
     }
 
-    pub async fn get_metrics(&self) -> AIMetrics {
-        self.metrics.clone()
+    pub async fn vector_cache_stats(&self) -> VectorCacheStats {
+        self.vector_cache.read().await.stats()
     }
 
     pub async fn is_brain_connected(&self) -> bool {
@@ -410,32 +831,89 @@ impl AIConnector {
         config: AIConnectorConfig,
         dragonfly_client: ConnectionManager,
         decision_sender: mpsc::UnboundedSender<TradingSignal>,
+        metrics: Arc<Mutex<AIMetrics>>,
+        accepting_decisions: Arc<AtomicBool>,
+        ai_funnel: SharedAIDecisionFunnel,
     ) -> Result<()> {
         info!("👂 Starting AI Brain decision listener");
 
         let mut conn = dragonfly_client.clone();
 
-        loop {
-            match Self::listen_for_ai_decisions_static(&config, &mut conn).await {
-                Ok(Some(ai_decision)) => {
-                    if let Err(e) = Self::process_ai_decision_static(ai_decision, &decision_sender, &config).await {
+        while accepting_decisions.load(Ordering::Acquire) {
+            match Self::listen_for_ai_decisions_static(&config, &mut conn, &ai_funnel).await {
+                DragonflyOutcome::Success(Some(ai_decision)) => {
+                    if let Err(e) =
+                        Self::process_ai_decision_static(ai_decision, &decision_sender, &config, &metrics, &ai_funnel).await
+                    {
                         error!("Failed to process AI decision: {}", e);
                     }
                 }
-                Ok(None) => {
+                DragonflyOutcome::Success(None) => {
                     // No decision received, continue listening
                     tokio::time::sleep(Duration::from_millis(100)).await;
                 }
-                Err(e) => {
-                    error!("Error listening for AI decisions: {}", e);
-                    tokio::time::sleep(Duration::from_secs(1)).await;
+                DragonflyOutcome::CommandFailed(e) => {
+                    error!("Skipping AI decision poll: {}", e);
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                }
+                DragonflyOutcome::ConnectionLost(e) => {
+                    error!("Lost DragonflyDB connection while listening for AI decisions: {}", e);
+                    Self::reconnect(&config, &mut conn).await;
+                }
+            }
+        }
+
+        info!("👂 AI Brain decision listener stopped");
+        Ok(())
+    }
+
+    /// Pop queued decisions without blocking until the list is empty
+    async fn drain_queued_decisions(
+        config: &AIConnectorConfig,
+        conn: &mut ConnectionManager,
+        decision_sender: &mpsc::UnboundedSender<TradingSignal>,
+        metrics: &Mutex<AIMetrics>,
+        ai_funnel: &AIDecisionFunnel,
+        processed: &mut usize,
+    ) {
+        loop {
+            let decision_json: Option<String> = match with_dragonfly_retry(
+                &config.retry_policy(),
+                "LPOP overmind:trading_commands",
+                || {
+                    let mut conn = conn.clone();
+                    async move { conn.lpop("overmind:trading_commands", None).await }
+                },
+            )
+            .await
+            {
+                DragonflyOutcome::Success(decision_json) => decision_json,
+                DragonflyOutcome::CommandFailed(e) | DragonflyOutcome::ConnectionLost(e) => {
+                    error!("Stopping shutdown drain of AI decisions: {}", e);
+                    return;
+                }
+            };
+            let Some(decision_json) = decision_json else {
+                return;
+            };
+
+            match Self::decode_ai_decision(config, &decision_json, ai_funnel) {
+                DragonflyOutcome::Success(Some(ai_decision)) => {
+                    match Self::process_ai_decision_static(ai_decision, decision_sender, config, metrics, ai_funnel).await {
+                        Ok(()) => *processed += 1,
+                        Err(e) => error!("Failed to process queued AI decision: {}", e),
+                    }
+                }
+                DragonflyOutcome::Success(None) => {}
+                DragonflyOutcome::CommandFailed(e) | DragonflyOutcome::ConnectionLost(e) => {
+                    warn!("Discarding queued AI decision: {}", e);
                 }
             }
         }
     }
 
     async fn run_health_monitor(
-        _config: AIConnectorConfig,
+        config: AIConnectorConfig,
         dragonfly_client: ConnectionManager,
         is_connected: Arc<RwLock<bool>>,
     ) -> Result<()> {
@@ -447,8 +925,8 @@ impl AIConnector {
         loop {
             interval.tick().await;
 
-            match Self::check_brain_health_static(&mut conn).await {
-                Ok(is_healthy) => {
+            match Self::check_brain_health_static(&config, &mut conn).await {
+                DragonflyOutcome::Success(is_healthy) => {
                     let mut connected = is_connected.write().await;
                     *connected = is_healthy;
 
@@ -456,11 +934,16 @@ impl AIConnector {
                         warn!("🔴 AI Brain connection unhealthy");
                     }
                 }
-                Err(e) => {
+                DragonflyOutcome::CommandFailed(e) => {
                     error!("Health check failed: {}", e);
                     let mut connected = is_connected.write().await;
                     *connected = false;
                 }
+                DragonflyOutcome::ConnectionLost(e) => {
+                    error!("Health check failed: {}", e);
+                    *is_connected.write().await = false;
+                    Self::reconnect(&config, &mut conn).await;
+                }
             }
         }
     }
@@ -468,44 +951,84 @@ impl AIConnector {
     async fn listen_for_ai_decisions_static(
         config: &AIConnectorConfig,
         conn: &mut ConnectionManager,
-    ) -> Result<Option<AIDecision>> {
+        ai_funnel: &AIDecisionFunnel,
+    ) -> DragonflyOutcome<Option<AIDecision>> {
         // Listen for AI decisions from Python Brain
-        let result: Option<(String, String)> = conn
-            .blpop("overmind:trading_commands", config.brain_request_timeout.as_secs() as f64)
-            .await?;
-
-        if let Some((_, decision_json)) = result {
-            let ai_decision: AIDecision = serde_json::from_str(&decision_json)?;
-
-            // Check decision age
-            let decision_age = chrono::Utc::now() - ai_decision.timestamp;
-            if decision_age > chrono::Duration::from_std(config.max_decision_age)? {
-                warn!("Rejecting stale AI decision: {} seconds old", decision_age.num_seconds());
-                return Ok(None);
-            }
+        let timeout = config.brain_request_timeout.as_secs() as f64;
+        let result: Option<(String, String)> = match with_dragonfly_retry(
+            &config.retry_policy(),
+            "BLPOP overmind:trading_commands",
+            || {
+                let mut conn = conn.clone();
+                async move { conn.blpop("overmind:trading_commands", timeout).await }
+            },
+        )
+        .await
+        {
+            DragonflyOutcome::Success(result) => result,
+            failure => return failure.map(|_| None),
+        };
 
-            info!("🧠 Received AI decision: {} {} (confidence: {:.2})",
-                  ai_decision.action, ai_decision.symbol, ai_decision.confidence);
+        let Some((_, decision_json)) = result else {
+            return DragonflyOutcome::Success(None);
+        };
+        let ai_decision = match Self::decode_ai_decision(config, &decision_json, ai_funnel) {
+            DragonflyOutcome::Success(Some(ai_decision)) => ai_decision,
+            other => return other,
+        };
 
-            Ok(Some(ai_decision))
-        } else {
-            Ok(None)
+        info!("🧠 Received AI decision: {} {} (confidence: {:.2})",
+              ai_decision.action, ai_decision.symbol, ai_decision.confidence);
+
+        DragonflyOutcome::Success(Some(ai_decision))
+    }
+
+    /// Parse a queued decision; stale decisions are dropped as `Success(None)`
+    fn decode_ai_decision(
+        config: &AIConnectorConfig,
+        decision_json: &str,
+        ai_funnel: &AIDecisionFunnel,
+    ) -> DragonflyOutcome<Option<AIDecision>> {
+        let ai_decision: AIDecision = match serde_json::from_str(decision_json) {
+            Ok(ai_decision) => ai_decision,
+            Err(e) => return DragonflyOutcome::CommandFailed(format!("Malformed AI decision: {}", e)),
+        };
+        ai_funnel.record(AIFunnelStage::Received);
+
+        // Check decision age
+        let decision_age = chrono::Utc::now() - ai_decision.timestamp;
+        let max_age = chrono::Duration::from_std(config.max_decision_age).unwrap_or(chrono::Duration::MAX);
+        if decision_age > max_age {
+            warn!("Rejecting stale AI decision: {} seconds old", decision_age.num_seconds());
+            return DragonflyOutcome::Success(None);
         }
+        ai_funnel.record(AIFunnelStage::Fresh);
+
+        DragonflyOutcome::Success(Some(ai_decision))
     }
 
     async fn process_ai_decision_static(
         ai_decision: AIDecision,
         decision_sender: &mpsc::UnboundedSender<TradingSignal>,
         config: &AIConnectorConfig,
+        metrics: &Mutex<AIMetrics>,
+        ai_funnel: &AIDecisionFunnel,
     ) -> Result<()> {
         let start_time = Instant::now();
+        if let Ok(mut metrics) = metrics.lock() {
+            metrics.decisions_received += 1;
+        }
 
         // Validate AI decision
         if ai_decision.confidence < config.confidence_threshold {
             warn!("Rejecting low-confidence AI decision: {:.2} < {:.2}",
                   ai_decision.confidence, config.confidence_threshold);
+            if let Ok(mut metrics) = metrics.lock() {
+                metrics.decisions_rejected += 1;
+            }
             return Ok(());
         }
+        ai_funnel.record(AIFunnelStage::Confident);
 
         // Convert AI decision to trading signal
         let trading_signal = Self::convert_ai_decision_to_signal_static(ai_decision).await?;
@@ -515,10 +1038,14 @@ impl AIConnector {
             error!("Failed to send trading signal: {}", e);
             return Err(anyhow::anyhow!("Failed to send trading signal"));
         }
+        ai_funnel.record(AIFunnelStage::Converted);
 
         // Update metrics
         let processing_time = start_time.elapsed();
         info!("✅ Processed AI decision in {:?}", processing_time);
+        if let Ok(mut metrics) = metrics.lock() {
+            metrics.record_decision_latency(processing_time);
+        }
 
         Ok(())
     }
@@ -543,25 +1070,72 @@ impl AIConnector {
             confidence: ai_decision.confidence,
             timestamp: ai_decision.timestamp,
             strategy_type: StrategyType::AIDecision, // New strategy type for AI decisions
+            tick_received_at: None,
+            pool_liquidity: None,
         })
     }
 
-    async fn check_brain_health_static(conn: &mut ConnectionManager) -> Result<bool> {
+    async fn check_brain_health_static(
+        config: &AIConnectorConfig,
+        conn: &mut ConnectionManager,
+    ) -> DragonflyOutcome<bool> {
         // Send ping to brain health channel
         let health_check = serde_json::json!({
             "type": "health_check",
             "timestamp": chrono::Utc::now(),
             "source": "rust_executor"
-        });
+        })
+        .to_string();
 
-        let _: () = conn.lpush("overmind:health_check", health_check.to_string()).await?;
+        let policy = config.retry_policy();
+        let pushed: DragonflyOutcome<()> = with_dragonfly_retry(&policy, "LPUSH overmind:health_check", || {
+            let mut conn = conn.clone();
+            let health_check = health_check.clone();
+            async move { conn.lpush("overmind:health_check", health_check).await }
+        })
+        .await;
+        if !pushed.is_success() {
+            return pushed.map(|_| false);
+        }
 
         // Wait for response (with timeout)
-        let response: Option<(String, String)> = conn
-            .blpop("overmind:health_response", 5.0) // 5 second timeout
-            .await?;
+        with_dragonfly_retry(&policy, "BLPOP overmind:health_response", || {
+            let mut conn = conn.clone();
+            async move {
+                conn.blpop::<_, Option<(String, String)>>("overmind:health_response", 5.0) // 5 second timeout
+                    .await
+            }
+        })
+        .await
+        .map(|response| response.is_some())
+    }
 
-        Ok(response.is_some())
+    /// Replace a connection that stayed unusable through its retries
+    async fn reconnect(config: &AIConnectorConfig, conn: &mut ConnectionManager) {
+        let reconnected = match Client::open(config.dragonfly_url.as_str()) {
+            Ok(client) => ConnectionManager::new(client).await,
+            Err(e) => Err(e),
+        };
+        match reconnected {
+            Ok(new_conn) => {
+                info!("🔌 Reconnected to DragonflyDB at {}", config.dragonfly_url);
+                *conn = new_conn;
+            }
+            Err(e) => {
+                error!("Failed to reconnect to DragonflyDB: {}", e);
+                tokio::time::sleep(config.max_retry_backoff).await;
+            }
+        }
+    }
+}
+
+impl AIConnectorConfig {
+    pub fn retry_policy(&self) -> DragonflyRetryPolicy {
+        DragonflyRetryPolicy {
+            max_retries: self.retry_attempts,
+            initial_backoff: self.retry_backoff,
+            max_backoff: self.max_retry_backoff,
+        }
     }
 }
 
@@ -573,7 +1147,15 @@ impl Default for AIConnectorConfig {
             max_decision_age: Duration::from_secs(30),
             confidence_threshold: 0.7,
             vector_cache_size: 1000,
+            vector_cache_ttl: Duration::from_secs(300),
             retry_attempts: 3,
+            retry_backoff: Duration::from_millis(100),
+            max_retry_backoff: Duration::from_secs(5),
+            market_event_concurrency: 4,
+            market_event_batch_size: 64,
+            latency_ewma_half_life: DEFAULT_EWMA_HALF_LIFE_SAMPLES,
+            shutdown_drain_timeout: Duration::from_secs(5),
+            validate_market_event_metadata: true,
         }
     }
 }
@@ -587,6 +1169,7 @@ pub fn create_market_event(
     price: f64,
     volume: f64,
     event_type: MarketEventType,
+    metadata: MarketEventMetadata,
 ) -> MarketEvent {
     MarketEvent {
         event_id: Uuid::new_v4().to_string(),
@@ -595,7 +1178,7 @@ pub fn create_market_event(
         volume,
         timestamp: chrono::Utc::now(),
         event_type,
-        metadata: HashMap::new(),
+        metadata,
     }
 }
 
@@ -604,6 +1187,147 @@ mod tests {
     use super::*;
     use tokio::sync::mpsc;
 
+    #[test]
+    fn test_market_event_coalescing() {
+        let mut queue = MarketEventCoalescer::default();
+        assert!(!queue.push(create_market_event("SOL/USDC".to_string(), 100.0, 1.0, MarketEventType::PriceChange, MarketEventMetadata::default())));
+        assert!(!queue.push(create_market_event("BONK/SOL".to_string(), 0.1, 1.0, MarketEventType::VolumeSpike, MarketEventMetadata::default())));
+        assert!(queue.push(create_market_event("SOL/USDC".to_string(), 101.0, 1.0, MarketEventType::PriceChange, MarketEventMetadata::default())));
+        assert_eq!(queue.len(), 2);
+
+        // The merged event moved behind the one that arrived before it
+        let batch = queue.drain_batch(1);
+        assert_eq!(batch.len(), 1);
+        assert_eq!(batch[0].symbol, "BONK/SOL");
+
+        let batch = queue.drain_batch(10);
+        assert_eq!(batch[0].symbol, "SOL/USDC");
+        assert_eq!(batch[0].price, 101.0);
+        assert!(queue.is_empty());
+
+        assert_eq!(
+            market_event_shard("SOL/USDC", 4),
+            market_event_shard("SOL/USDC", 4)
+        );
+    }
+
+    #[test]
+    fn test_market_event_coalescer_keeps_each_event_type_of_a_symbol() {
+        let mut queue = MarketEventCoalescer::default();
+        assert!(!queue.push(create_market_event("BONK/SOL".to_string(), 0.1, 1.0, MarketEventType::PriceChange, MarketEventMetadata::default())));
+        assert!(!queue.push(create_market_event("BONK/SOL".to_string(), 0.1, 5.0, MarketEventType::VolumeSpike, MarketEventMetadata::default())));
+        assert_eq!(queue.len(), 2);
+
+        // A newer price change is pushed after the volume spike it followed
+        assert!(queue.push(create_market_event("BONK/SOL".to_string(), 0.2, 1.0, MarketEventType::PriceChange, MarketEventMetadata::default())));
+        assert_eq!(queue.len(), 2);
+
+        let batch = queue.drain_batch(10);
+        let types: Vec<_> = batch.iter().map(|e| e.event_type.clone()).collect();
+        assert_eq!(types, vec![MarketEventType::VolumeSpike, MarketEventType::PriceChange]);
+        assert_eq!(batch[1].price, 0.2);
+        assert!(queue.is_empty());
+    }
+
+    #[test]
+    fn test_market_event_metadata_round_trips_and_validates() {
+        let metadata = MarketEventMetadata::default()
+            .with_liquidity(25_000.0)
+            .with_holder_concentration(0.3)
+            .with_pool_address("pool_1")
+            .with_dev_wallet("dev_1")
+            .with_extra("launchpad", serde_json::json!("pump.fun"));
+        let event = create_market_event("BONK/SOL".to_string(), 0.1, 1.0, MarketEventType::NewToken, metadata.clone());
+
+        // Well-known fields and extensions share one flat metadata object on the wire
+        let json = serde_json::to_value(&event).unwrap();
+        assert_eq!(json["metadata"]["liquidity"], 25_000.0);
+        assert_eq!(json["metadata"]["launchpad"], "pump.fun");
+        let decoded: MarketEvent = serde_json::from_value(json).unwrap();
+        assert_eq!(decoded.metadata, metadata);
+        assert!(metadata.validate().is_ok());
+
+        assert!(MarketEventMetadata::default().with_holder_concentration(30.0).validate().is_err());
+        assert!(MarketEventMetadata::default().with_liquidity(f64::NAN).validate().is_err());
+        assert!(MarketEventMetadata::default()
+            .with_extra("dev_wallet", serde_json::json!("dev_2"))
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn test_vector_context_expires_after_ttl() {
+        let context = |score| VectorContext {
+            similar_situations: vec!["breakout".to_string()],
+            confidence_score: score,
+            memory_relevance: 0.9,
+        };
+        let mut cache = VectorContextCache::new(2, Duration::from_millis(100));
+        cache.insert("SOL/USDC".to_string(), context(0.8));
+        std::thread::sleep(std::time::Duration::from_millis(60));
+        cache.insert("BONK/SOL".to_string(), context(0.6));
+        let stats = cache.stats();
+        assert_eq!(stats.entries, 2);
+        assert!(stats.average_age >= Duration::from_millis(30) && stats.average_age < Duration::from_millis(100));
+
+        std::thread::sleep(std::time::Duration::from_millis(60));
+        // Older than the TTL: a miss, and no longer cached
+        assert!(cache.get("SOL/USDC").is_none());
+        assert!(cache.get("BONK/SOL").is_some());
+        assert_eq!(cache.len(), 1);
+
+        // Full: the oldest entry makes room
+        cache.insert("WIF/SOL".to_string(), context(0.5));
+        cache.insert("JUP/SOL".to_string(), context(0.7));
+        assert!(cache.get("BONK/SOL").is_none());
+        assert_eq!(cache.len(), 2);
+
+        std::thread::sleep(std::time::Duration::from_millis(110));
+        assert_eq!(cache.purge_expired(), 2);
+        assert!(cache.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_dragonfly_retry_outcomes() {
+        let policy = DragonflyRetryPolicy {
+            max_retries: 2,
+            initial_backoff: Duration::from_millis(1),
+            max_backoff: Duration::from_millis(2),
+        };
+        assert_eq!(policy.backoff(1), Duration::from_millis(1));
+        assert_eq!(policy.backoff(5), Duration::from_millis(2));
+        let dropped = || redis::RedisError::from(std::io::Error::from(std::io::ErrorKind::BrokenPipe));
+
+        // Connection errors are retried until the command goes through
+        let attempts = std::sync::atomic::AtomicU32::new(0);
+        let outcome = with_dragonfly_retry(&policy, "LPUSH", || {
+            let attempt = attempts.fetch_add(1, Ordering::SeqCst);
+            async move { if attempt < 2 { Err(dropped()) } else { Ok(7) } }
+        })
+        .await;
+        assert_eq!(outcome, DragonflyOutcome::Success(7));
+
+        // ...and reported as a lost connection once the budget is spent
+        attempts.store(0, Ordering::SeqCst);
+        let outcome: DragonflyOutcome<()> = with_dragonfly_retry(&policy, "BLPOP", || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(dropped()) }
+        })
+        .await;
+        assert!(matches!(outcome, DragonflyOutcome::ConnectionLost(_)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 3);
+
+        // Command errors are not retried
+        attempts.store(0, Ordering::SeqCst);
+        let outcome: DragonflyOutcome<()> = with_dragonfly_retry(&policy, "LPUSH", || {
+            attempts.fetch_add(1, Ordering::SeqCst);
+            async { Err(redis::RedisError::from((redis::ErrorKind::TypeError, "WRONGTYPE"))) }
+        })
+        .await;
+        assert!(matches!(outcome, DragonflyOutcome::CommandFailed(_)));
+        assert_eq!(attempts.load(Ordering::SeqCst), 1);
+    }
+
     #[tokio::test]
     async fn test_ai_decision_conversion() {
         let ai_decision = AIDecision {
@@ -628,4 +1352,41 @@ mod tests {
         assert_eq!(ai_decision.confidence, 0.85);
         assert_eq!(ai_decision.symbol, "SOL/USDC");
     }
+
+    #[test]
+    fn test_queued_decisions_are_decoded_and_stale_ones_dropped() {
+        let config = AIConnectorConfig::default();
+        let funnel = AIDecisionFunnel::default();
+        let decision = |age: chrono::Duration| {
+            serde_json::to_string(&AIDecision {
+                decision_id: "queued".to_string(),
+                symbol: "SOL/USDC".to_string(),
+                action: AIAction::Sell,
+                confidence: 0.9,
+                reasoning: "Drained on shutdown".to_string(),
+                quantity: 1.0,
+                target_price: None,
+                ai_context: None,
+                timestamp: chrono::Utc::now() - age,
+                vector_memory_context: None,
+            })
+            .unwrap()
+        };
+
+        assert!(matches!(
+            AIConnector::decode_ai_decision(&config, &decision(chrono::Duration::seconds(1)), &funnel),
+            DragonflyOutcome::Success(Some(d)) if d.decision_id == "queued"
+        ));
+        assert!(matches!(
+            AIConnector::decode_ai_decision(&config, &decision(chrono::Duration::seconds(60)), &funnel),
+            DragonflyOutcome::Success(None)
+        ));
+        assert!(matches!(
+            AIConnector::decode_ai_decision(&config, "not json", &funnel),
+            DragonflyOutcome::CommandFailed(_)
+        ));
+        // Malformed decisions never enter the funnel; stale ones stop before Fresh
+        assert_eq!(funnel.count(AIFunnelStage::Received), 2);
+        assert_eq!(funnel.count(AIFunnelStage::Fresh), 1);
+    }
 }
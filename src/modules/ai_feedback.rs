@@ -0,0 +1,391 @@
+// AI Feedback Module
+// Correlates each AI-driven trade with the TensorZero inference that decided it and,
+// once the trade's P&L is realized, reports that outcome back to the gateway so model
+// variants can be optimized on actual trading results. Realized P&L is also compared
+// with the AI's estimated profit to show how far its estimates can be trusted.
+
+use crate::modules::hft_engine::{TensorZeroClient, TensorZeroFeedbackRequest};
+use crate::modules::persistence::{AIDecisionOutcome, PersistenceMessage};
+use crate::modules::strategy::StrategyType;
+use anyhow::Result;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+use uuid::Uuid;
+
+#[derive(Debug, Clone)]
+pub struct AIFeedbackConfig {
+    /// TensorZero metric the realized P&L is reported under
+    pub metric_name: String,
+    /// Executed decisions awaiting an outcome; the oldest are dropped beyond this
+    pub max_pending: usize,
+    pub request_timeout: Duration,
+    /// Settled trades per strategy the estimate-accuracy statistics are computed over
+    pub accuracy_window: usize,
+}
+
+impl Default for AIFeedbackConfig {
+    fn default() -> Self {
+        Self {
+            metric_name: "trade_pnl".to_string(),
+            max_pending: 10_000,
+            request_timeout: Duration::from_secs(5),
+            accuracy_window: 500,
+        }
+    }
+}
+
+/// An executed AI decision whose outcome is not known yet
+#[derive(Debug, Clone)]
+pub struct PendingDecision {
+    pub inference_id: Uuid,
+    pub episode_id: Option<Uuid>,
+    pub strategy_type: StrategyType,
+    /// Profit the AI expected when it made the decision, in SOL
+    pub estimated_profit: f64,
+    pub executed_at: DateTime<Utc>,
+}
+
+/// Executed AI decisions by signal ID, bounded to `max_pending`
+#[derive(Debug)]
+pub struct AIFeedbackTracker {
+    max_pending: usize,
+    pending: HashMap<String, PendingDecision>,
+    order: VecDeque<String>,
+}
+
+pub type SharedAIFeedbackTracker = Arc<Mutex<AIFeedbackTracker>>;
+
+#[allow(dead_code)]
+impl AIFeedbackTracker {
+    pub fn new(max_pending: usize) -> Self {
+        Self {
+            max_pending: max_pending.max(1),
+            pending: HashMap::new(),
+            order: VecDeque::new(),
+        }
+    }
+
+    pub fn shared(max_pending: usize) -> SharedAIFeedbackTracker {
+        Arc::new(Mutex::new(Self::new(max_pending)))
+    }
+
+    /// Remember which inference produced the trade for `signal_id`, and what it expected to earn
+    pub fn record(
+        &mut self,
+        signal_id: &str,
+        inference_id: Uuid,
+        episode_id: Option<Uuid>,
+        strategy_type: StrategyType,
+        estimated_profit: f64,
+    ) {
+        let decision = PendingDecision {
+            inference_id,
+            episode_id,
+            strategy_type,
+            estimated_profit,
+            executed_at: Utc::now(),
+        };
+        if self
+            .pending
+            .insert(signal_id.to_string(), decision)
+            .is_none()
+        {
+            self.order.push_back(signal_id.to_string());
+        }
+
+        while self.pending.len() > self.max_pending {
+            match self.order.pop_front() {
+                Some(oldest) => {
+                    self.pending.remove(&oldest);
+                }
+                None => break,
+            }
+        }
+    }
+
+    /// Remove and return the decision behind `signal_id`, if it is still tracked
+    pub fn take(&mut self, signal_id: &str) -> Option<PendingDecision> {
+        let decision = self.pending.remove(signal_id)?;
+        self.order.retain(|id| id != signal_id);
+        Some(decision)
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+/// How well the AI's estimated profit predicted realized P&L for one strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EstimateAccuracy {
+    pub strategy_type: StrategyType,
+    pub trades: usize,
+    pub mean_estimated_profit: f64,
+    pub mean_realized_pnl: f64,
+    /// Mean of estimated minus realized; positive means the AI is optimistic
+    pub mean_error: f64,
+    pub mean_absolute_error: f64,
+    /// Total realized over total estimated profit (0.33 = estimates are 3x too optimistic);
+    /// None while the estimates sum to zero or less
+    pub realization_ratio: Option<f64>,
+    /// Pearson correlation of estimated and realized; None with fewer than two trades
+    /// or when either side does not vary
+    pub correlation: Option<f64>,
+}
+
+/// Rolling (estimated, realized) pairs per strategy
+#[derive(Debug)]
+pub struct EstimateAccuracyTracker {
+    window: usize,
+    samples: HashMap<StrategyType, VecDeque<(f64, f64)>>,
+}
+
+#[allow(dead_code)]
+impl EstimateAccuracyTracker {
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(1),
+            samples: HashMap::new(),
+        }
+    }
+
+    pub fn record(&mut self, strategy_type: StrategyType, estimated_profit: f64, realized_pnl: f64) {
+        let samples = self.samples.entry(strategy_type).or_default();
+        samples.push_back((estimated_profit, realized_pnl));
+        while samples.len() > self.window {
+            samples.pop_front();
+        }
+    }
+
+    /// Accuracy per strategy, ordered by strategy name
+    pub fn report(&self) -> Vec<EstimateAccuracy> {
+        let by_name: BTreeMap<String, EstimateAccuracy> = self
+            .samples
+            .iter()
+            .filter(|(_, samples)| !samples.is_empty())
+            .map(|(strategy_type, samples)| {
+                (format!("{:?}", strategy_type), Self::accuracy(strategy_type, samples))
+            })
+            .collect();
+        by_name.into_values().collect()
+    }
+
+    fn accuracy(strategy_type: &StrategyType, samples: &VecDeque<(f64, f64)>) -> EstimateAccuracy {
+        let n = samples.len() as f64;
+        let total_estimated: f64 = samples.iter().map(|(e, _)| e).sum();
+        let total_realized: f64 = samples.iter().map(|(_, r)| r).sum();
+        let mean_estimated = total_estimated / n;
+        let mean_realized = total_realized / n;
+
+        let mut covariance = 0.0;
+        let mut estimated_variance = 0.0;
+        let mut realized_variance = 0.0;
+        for (estimated, realized) in samples {
+            covariance += (estimated - mean_estimated) * (realized - mean_realized);
+            estimated_variance += (estimated - mean_estimated).powi(2);
+            realized_variance += (realized - mean_realized).powi(2);
+        }
+        let correlation = (samples.len() >= 2 && estimated_variance > 0.0 && realized_variance > 0.0)
+            .then(|| covariance / (estimated_variance * realized_variance).sqrt());
+
+        EstimateAccuracy {
+            strategy_type: strategy_type.clone(),
+            trades: samples.len(),
+            mean_estimated_profit: mean_estimated,
+            mean_realized_pnl: mean_realized,
+            mean_error: mean_estimated - mean_realized,
+            mean_absolute_error: samples.iter().map(|(e, r)| (e - r).abs()).sum::<f64>() / n,
+            realization_ratio: (total_estimated > 0.0).then(|| total_realized / total_estimated),
+            correlation,
+        }
+    }
+}
+
+/// Sends realized outcomes of tracked decisions to TensorZero and persists them
+#[derive(Debug)]
+pub struct AIFeedbackReporter {
+    config: AIFeedbackConfig,
+    tracker: SharedAIFeedbackTracker,
+    client: TensorZeroClient,
+    persistence_sender: Option<mpsc::UnboundedSender<PersistenceMessage>>,
+    accuracy: Mutex<EstimateAccuracyTracker>,
+}
+
+#[allow(dead_code)]
+impl AIFeedbackReporter {
+    pub fn new(
+        config: AIFeedbackConfig,
+        tracker: SharedAIFeedbackTracker,
+        gateway_url: String,
+    ) -> Result<Self> {
+        let client = TensorZeroClient::with_timeout(gateway_url, config.request_timeout)?;
+        let accuracy = Mutex::new(EstimateAccuracyTracker::new(config.accuracy_window));
+        Ok(Self {
+            config,
+            tracker,
+            client,
+            persistence_sender: None,
+            accuracy,
+        })
+    }
+
+    /// Store every reported outcome, including ones the gateway did not accept
+    pub fn with_persistence(mut self, sender: mpsc::UnboundedSender<PersistenceMessage>) -> Self {
+        self.persistence_sender = Some(sender);
+        self
+    }
+
+    pub fn tracker(&self) -> SharedAIFeedbackTracker {
+        self.tracker.clone()
+    }
+
+    /// Rolling accuracy of the AI's estimated profit against realized P&L, per strategy
+    pub fn accuracy_report(&self) -> Vec<EstimateAccuracy> {
+        self.accuracy
+            .lock()
+            .map(|accuracy| accuracy.report())
+            .unwrap_or_default()
+    }
+
+    /// Report the realized P&L of the trade for `signal_id`. Returns None when the
+    /// signal was not an AI decision or is no longer tracked. A gateway failure is
+    /// logged and recorded as `feedback_sent: false` rather than returned.
+    pub async fn report_outcome(
+        &self,
+        signal_id: &str,
+        realized_pnl: f64,
+    ) -> Result<Option<AIDecisionOutcome>> {
+        let decision = match self.tracker.lock() {
+            Ok(mut tracker) => tracker.take(signal_id),
+            Err(_) => return Err(anyhow::anyhow!("AI feedback tracker lock poisoned")),
+        };
+        let Some(decision) = decision else {
+            return Ok(None);
+        };
+        if let Ok(mut accuracy) = self.accuracy.lock() {
+            accuracy.record(decision.strategy_type.clone(), decision.estimated_profit, realized_pnl);
+        }
+
+        let request = TensorZeroFeedbackRequest {
+            metric_name: self.config.metric_name.clone(),
+            inference_id: decision.inference_id,
+            value: serde_json::json!(realized_pnl),
+            tags: HashMap::from([("signal_id".to_string(), signal_id.to_string())]),
+        };
+        let feedback_sent = match self.client.feedback(request).await {
+            Ok(()) => {
+                debug!(
+                    "🧠 Reported {} = {:.4} for inference {}",
+                    self.config.metric_name, realized_pnl, decision.inference_id
+                );
+                true
+            }
+            Err(e) => {
+                warn!(
+                    "🧠 Failed to send AI feedback for signal {}: {}",
+                    signal_id, e
+                );
+                false
+            }
+        };
+
+        let outcome = AIDecisionOutcome {
+            signal_id: signal_id.to_string(),
+            inference_id: decision.inference_id,
+            episode_id: decision.episode_id,
+            metric_name: self.config.metric_name.clone(),
+            realized_pnl,
+            estimated_profit: Some(decision.estimated_profit),
+            executed_at: decision.executed_at,
+            settled_at: Utc::now(),
+            feedback_sent,
+        };
+        if let Some(sender) = &self.persistence_sender {
+            let _ = sender.send(PersistenceMessage::AIDecisionOutcome(outcome.clone()));
+        }
+        Ok(Some(outcome))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_outcome_is_correlated_and_persisted_once() {
+        let tracker = AIFeedbackTracker::shared(2);
+        let inference_id = Uuid::new_v4();
+        {
+            let mut tracker = tracker.lock().unwrap();
+            tracker.record("a", Uuid::new_v4(), None, StrategyType::AIDecision, 1.0);
+            tracker.record("b", inference_id, Some(Uuid::new_v4()), StrategyType::AIDecision, 1.0);
+            tracker.record("c", Uuid::new_v4(), None, StrategyType::AIDecision, 1.0);
+            // The oldest decision is dropped beyond the cap
+            assert_eq!(tracker.len(), 2);
+            assert!(tracker.take("a").is_none());
+        }
+
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        // Nothing listens here, so the gateway call fails and the outcome is kept anyway
+        let reporter = AIFeedbackReporter::new(
+            AIFeedbackConfig {
+                request_timeout: Duration::from_millis(200),
+                ..AIFeedbackConfig::default()
+            },
+            tracker.clone(),
+            "http://127.0.0.1:9".to_string(),
+        )
+        .unwrap()
+        .with_persistence(tx);
+
+        let outcome = reporter.report_outcome("b", -1.5).await.unwrap().unwrap();
+        assert_eq!(outcome.inference_id, inference_id);
+        assert_eq!(outcome.realized_pnl, -1.5);
+        assert!(!outcome.feedback_sent);
+        assert!(matches!(
+            rx.try_recv(),
+            Ok(PersistenceMessage::AIDecisionOutcome(stored)) if stored.signal_id == "b"
+        ));
+
+        // Each decision is reported at most once
+        assert!(reporter.report_outcome("b", 2.0).await.unwrap().is_none());
+        assert_eq!(tracker.lock().unwrap().len(), 1);
+
+        let accuracy = reporter.accuracy_report();
+        assert_eq!(accuracy.len(), 1);
+        assert_eq!(accuracy[0].trades, 1);
+        assert_eq!(accuracy[0].mean_error, 2.5);
+    }
+
+    #[test]
+    fn test_estimate_accuracy_detects_optimism() {
+        let mut accuracy = EstimateAccuracyTracker::new(3);
+        // Evicted by the window
+        accuracy.record(StrategyType::AIDecision, 100.0, -100.0);
+        for (estimated, realized) in [(3.0, 1.0), (6.0, 2.0), (9.0, 3.0)] {
+            accuracy.record(StrategyType::AIDecision, estimated, realized);
+        }
+        accuracy.record(StrategyType::TokenSniping, 1.0, 1.0);
+
+        let report = accuracy.report();
+        assert_eq!(report.len(), 2);
+        let ai = &report[0];
+        assert_eq!(ai.strategy_type, StrategyType::AIDecision);
+        assert_eq!(ai.trades, 3);
+        assert_eq!(ai.mean_error, 4.0);
+        // Estimates run 3x too optimistic but rank trades perfectly
+        assert!((ai.realization_ratio.unwrap() - 1.0 / 3.0).abs() < 1e-9);
+        assert!((ai.correlation.unwrap() - 1.0).abs() < 1e-9);
+        // A single trade has no correlation
+        assert_eq!(report[1].correlation, None);
+    }
+}
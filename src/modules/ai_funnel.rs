@@ -0,0 +1,146 @@
+// AI Decision Funnel Module
+// Counts AI decisions at each stage from arrival to confirmation, so the stage where
+// decisions are dropped (stale, low confidence, risk rejection, failed execution) is visible
+
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Stages an AI decision passes, in order; each stage only counts decisions that passed the previous one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AIFunnelStage {
+    /// Decoded from the brain's queue
+    Received,
+    /// Younger than the maximum decision age
+    Fresh,
+    /// At or above the confidence threshold
+    Confident,
+    /// Converted to a trading signal and handed to the risk manager
+    Converted,
+    /// Approved by the risk manager
+    Approved,
+    /// Submitted or filled by the executor
+    Executed,
+    /// Reached the required commitment (paper fills after their simulated delay)
+    Confirmed,
+}
+
+impl AIFunnelStage {
+    pub const ALL: [AIFunnelStage; 7] = [
+        AIFunnelStage::Received,
+        AIFunnelStage::Fresh,
+        AIFunnelStage::Confident,
+        AIFunnelStage::Converted,
+        AIFunnelStage::Approved,
+        AIFunnelStage::Executed,
+        AIFunnelStage::Confirmed,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            AIFunnelStage::Received => "received",
+            AIFunnelStage::Fresh => "fresh",
+            AIFunnelStage::Confident => "confident",
+            AIFunnelStage::Converted => "converted",
+            AIFunnelStage::Approved => "approved",
+            AIFunnelStage::Executed => "executed",
+            AIFunnelStage::Confirmed => "confirmed",
+        }
+    }
+}
+
+/// Per-stage decision counters, shared by the AI connector, risk manager and executor
+#[derive(Debug, Default)]
+pub struct AIDecisionFunnel {
+    counts: [AtomicU64; 7],
+}
+
+pub type SharedAIDecisionFunnel = Arc<AIDecisionFunnel>;
+
+/// Count of one stage and its share of the previous stage
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AIFunnelStageCount {
+    pub stage: AIFunnelStage,
+    pub count: u64,
+    /// None for the first stage and while the previous stage is empty
+    pub pass_rate: Option<f64>,
+}
+
+#[allow(dead_code)]
+impl AIDecisionFunnel {
+    pub fn shared() -> SharedAIDecisionFunnel {
+        Arc::new(Self::default())
+    }
+
+    pub fn record(&self, stage: AIFunnelStage) {
+        self.counts[stage as usize].fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn count(&self, stage: AIFunnelStage) -> u64 {
+        self.counts[stage as usize].load(Ordering::Relaxed)
+    }
+
+    pub fn snapshot(&self) -> Vec<AIFunnelStageCount> {
+        let mut previous: Option<u64> = None;
+        AIFunnelStage::ALL
+            .iter()
+            .map(|&stage| {
+                let count = self.count(stage);
+                let pass_rate = previous
+                    .filter(|p| *p > 0)
+                    .map(|p| count as f64 / p as f64);
+                previous = Some(count);
+                AIFunnelStageCount {
+                    stage,
+                    count,
+                    pass_rate,
+                }
+            })
+            .collect()
+    }
+
+    pub fn to_prometheus(&self) -> String {
+        let mut out = String::from(
+            "# HELP sniper_ai_decision_funnel AI decisions reaching each stage from receipt to confirmation\n\
+             # TYPE sniper_ai_decision_funnel counter\n",
+        );
+        for stage in AIFunnelStage::ALL {
+            out.push_str(&format!(
+                "sniper_ai_decision_funnel{{stage=\"{}\"}} {}\n",
+                stage.label(),
+                self.count(stage)
+            ));
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_funnel_reports_counts_and_pass_rates() {
+        let funnel = AIDecisionFunnel::default();
+        for _ in 0..10 {
+            funnel.record(AIFunnelStage::Received);
+            funnel.record(AIFunnelStage::Fresh);
+        }
+        for _ in 0..2 {
+            funnel.record(AIFunnelStage::Confident);
+        }
+
+        let snapshot = funnel.snapshot();
+        assert_eq!(snapshot[0].pass_rate, None);
+        assert_eq!(snapshot[1].pass_rate, Some(1.0));
+        // 80% of fresh decisions were dropped on confidence
+        assert_eq!(snapshot[2].count, 2);
+        assert_eq!(snapshot[2].pass_rate, Some(0.2));
+        assert_eq!(snapshot[3].pass_rate, Some(0.0));
+        assert_eq!(snapshot[4].pass_rate, None);
+
+        let prometheus = funnel.to_prometheus();
+        assert!(prometheus.contains("sniper_ai_decision_funnel{stage=\"confident\"} 2\n"));
+        assert!(prometheus.contains("sniper_ai_decision_funnel{stage=\"confirmed\"} 0\n"));
+    }
+}
@@ -0,0 +1,338 @@
+// Capital Allocation Module
+// Shifts each strategy's position-size allocation toward strategies with better
+// trailing risk-adjusted returns
+
+use crate::modules::strategy::StrategyType;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{debug, error, info};
+
+#[derive(Debug, Clone)]
+pub struct RebalancerConfig {
+    pub interval: Duration,
+    /// Number of most recent trades each strategy's score is computed over
+    pub lookback_trades: usize,
+    /// Strategies with fewer trades keep their current allocation
+    pub min_trades: usize,
+    /// Lower bound of the position-size multiplier
+    pub min_multiplier: f64,
+    /// Upper bound of the position-size multiplier
+    pub max_multiplier: f64,
+    /// Multiplier change per unit of Sharpe-like score
+    pub sensitivity: f64,
+    /// Fraction of the gap to the target closed per rebalance (0.0 - 1.0)
+    pub smoothing: f64,
+}
+
+impl Default for RebalancerConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(300),
+            lookback_trades: 50,
+            min_trades: 10,
+            min_multiplier: 0.25,
+            max_multiplier: 2.0,
+            sensitivity: 0.5,
+            smoothing: 0.3,
+        }
+    }
+}
+
+/// Allocation of a single strategy as exposed through the HTTP API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyAllocation {
+    pub strategy: StrategyType,
+    /// Multiplier applied to `max_position_size`, override included
+    pub effective_multiplier: f64,
+    pub rebalanced_multiplier: f64,
+    pub override_multiplier: Option<f64>,
+    pub score: Option<f64>,
+    pub trades: usize,
+}
+
+/// Realized P&L attributed to a strategy since startup
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyPnlTotal {
+    pub strategy: StrategyType,
+    pub trades: u64,
+    pub realized_pnl: f64,
+}
+
+/// A multiplier change made by one rebalance pass
+#[derive(Debug, Clone)]
+pub struct AllocationChange {
+    pub strategy: StrategyType,
+    pub previous: f64,
+    pub current: f64,
+    pub score: f64,
+}
+
+/// Per-strategy trailing P&L and the allocation multipliers derived from it
+#[derive(Debug)]
+pub struct StrategyAllocations {
+    config: RebalancerConfig,
+    pnl_history: HashMap<StrategyType, VecDeque<f64>>,
+    multipliers: HashMap<StrategyType, f64>,
+    overrides: HashMap<StrategyType, f64>,
+    /// Lifetime (trades, realized P&L), unlike the trailing window in `pnl_history`
+    totals: HashMap<StrategyType, (u64, f64)>,
+}
+
+pub type SharedStrategyAllocations = Arc<Mutex<StrategyAllocations>>;
+
+#[allow(dead_code)]
+impl StrategyAllocations {
+    pub fn new(config: RebalancerConfig) -> Self {
+        Self {
+            config,
+            pnl_history: HashMap::new(),
+            multipliers: HashMap::new(),
+            overrides: HashMap::new(),
+            totals: HashMap::new(),
+        }
+    }
+
+    pub fn shared(config: RebalancerConfig) -> SharedStrategyAllocations {
+        Arc::new(Mutex::new(Self::new(config)))
+    }
+
+    /// Attribute a closed trade's realized P&L to its strategy
+    pub fn record_trade(&mut self, strategy: &StrategyType, realized_pnl: f64) {
+        let history = self.pnl_history.entry(strategy.clone()).or_default();
+        history.push_back(realized_pnl);
+        while history.len() > self.config.lookback_trades.max(1) {
+            history.pop_front();
+        }
+
+        let total = self.totals.entry(strategy.clone()).or_default();
+        total.0 += 1;
+        total.1 += realized_pnl;
+    }
+
+    /// Position-size multiplier for the strategy; manual overrides win
+    pub fn multiplier(&self, strategy: &StrategyType) -> f64 {
+        self.overrides
+            .get(strategy)
+            .or_else(|| self.multipliers.get(strategy))
+            .copied()
+            .unwrap_or(1.0)
+    }
+
+    /// Pin a strategy's multiplier, bypassing the rebalancer
+    pub fn set_override(&mut self, strategy: &StrategyType, multiplier: f64) {
+        let multiplier = multiplier.max(0.0);
+        self.overrides.insert(strategy.clone(), multiplier);
+        info!(
+            "💰 Allocation for {:?} overridden to {:.2}x",
+            strategy, multiplier
+        );
+    }
+
+    pub fn clear_override(&mut self, strategy: &StrategyType) {
+        if self.overrides.remove(strategy).is_some() {
+            info!("💰 Allocation override for {:?} cleared", strategy);
+        }
+    }
+
+    /// Sharpe-like score (mean / standard deviation) of the strategy's trailing P&L
+    pub fn score(&self, strategy: &StrategyType) -> Option<f64> {
+        let history = self.pnl_history.get(strategy)?;
+        if history.len() < self.config.min_trades.max(2) {
+            return None;
+        }
+
+        let n = history.len() as f64;
+        let mean = history.iter().sum::<f64>() / n;
+        let variance = history.iter().map(|p| (p - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        let std_dev = variance.sqrt();
+
+        if std_dev <= f64::EPSILON {
+            // Identical returns: treat as maximally consistent, in the direction of the mean
+            return Some(if mean > 0.0 {
+                f64::INFINITY
+            } else if mean < 0.0 {
+                f64::NEG_INFINITY
+            } else {
+                0.0
+            });
+        }
+        Some(mean / std_dev)
+    }
+
+    /// Move each scored strategy's multiplier part of the way toward its
+    /// bounded target and return the changes made
+    pub fn rebalance(&mut self) -> Vec<AllocationChange> {
+        let (min, max) = (
+            self.config.min_multiplier.min(self.config.max_multiplier),
+            self.config.max_multiplier.max(self.config.min_multiplier),
+        );
+        let smoothing = self.config.smoothing.clamp(0.0, 1.0);
+
+        let strategies: Vec<StrategyType> = self.pnl_history.keys().cloned().collect();
+        let mut changes = Vec::new();
+        for strategy in strategies {
+            let Some(score) = self.score(&strategy) else {
+                continue;
+            };
+
+            let target = (1.0 + self.config.sensitivity * score).clamp(min, max);
+            let previous = self.multipliers.get(&strategy).copied().unwrap_or(1.0);
+            let current = (previous + smoothing * (target - previous)).clamp(min, max);
+
+            if (current - previous).abs() > 1e-9 {
+                self.multipliers.insert(strategy.clone(), current);
+                changes.push(AllocationChange {
+                    strategy,
+                    previous,
+                    current,
+                    score,
+                });
+            }
+        }
+        changes
+    }
+
+    /// Lifetime realized P&L per strategy, sorted by strategy name
+    pub fn pnl_totals(&self) -> Vec<StrategyPnlTotal> {
+        let mut totals: Vec<StrategyPnlTotal> = self
+            .totals
+            .iter()
+            .map(|(strategy, (trades, realized_pnl))| StrategyPnlTotal {
+                strategy: strategy.clone(),
+                trades: *trades,
+                realized_pnl: *realized_pnl,
+            })
+            .collect();
+        totals.sort_by_key(|t| format!("{:?}", t.strategy));
+        totals
+    }
+
+    pub fn allocations(&self) -> Vec<StrategyAllocation> {
+        let mut strategies: Vec<&StrategyType> = self
+            .pnl_history
+            .keys()
+            .chain(self.overrides.keys())
+            .collect();
+        strategies.sort_by_key(|s| format!("{:?}", s));
+        strategies.dedup();
+
+        strategies
+            .into_iter()
+            .map(|strategy| StrategyAllocation {
+                strategy: strategy.clone(),
+                effective_multiplier: self.multiplier(strategy),
+                rebalanced_multiplier: self.multipliers.get(strategy).copied().unwrap_or(1.0),
+                override_multiplier: self.overrides.get(strategy).copied(),
+                score: self.score(strategy).filter(|s| s.is_finite()),
+                trades: self.pnl_history.get(strategy).map_or(0, |h| h.len()),
+            })
+            .collect()
+    }
+}
+
+/// Periodically rebalances the shared allocation multipliers
+pub struct AllocationRebalancer {
+    allocations: SharedStrategyAllocations,
+    interval: Duration,
+}
+
+impl AllocationRebalancer {
+    pub fn new(allocations: SharedStrategyAllocations) -> Self {
+        let interval = allocations
+            .lock()
+            .map(|a| a.config.interval)
+            .unwrap_or_else(|_| RebalancerConfig::default().interval);
+        Self {
+            allocations,
+            interval,
+        }
+    }
+
+    pub async fn run(self) {
+        info!(
+            "💰 Allocation rebalancer starting (interval: {:?})",
+            self.interval
+        );
+        let mut interval = tokio::time::interval(self.interval);
+
+        loop {
+            interval.tick().await;
+            let changes = match self.allocations.lock() {
+                Ok(mut allocations) => allocations.rebalance(),
+                Err(_) => {
+                    error!("Strategy allocation state poisoned, stopping rebalancer");
+                    return;
+                }
+            };
+
+            if changes.is_empty() {
+                debug!("💰 Rebalance pass made no allocation changes");
+            }
+            for change in changes {
+                info!(
+                    "💰 Rebalanced {:?}: {:.2}x -> {:.2}x (score {:.2})",
+                    change.strategy, change.previous, change.current, change.score
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> RebalancerConfig {
+        RebalancerConfig {
+            min_trades: 4,
+            smoothing: 0.5,
+            ..RebalancerConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_rebalance_favors_winners_within_bounds() {
+        let mut allocations = StrategyAllocations::new(config());
+        for pnl in [2.0, 1.0, 3.0, 2.0, 1.5] {
+            allocations.record_trade(&StrategyType::Arbitrage, pnl);
+        }
+        for pnl in [-2.0, -1.0, -3.0, -2.0, -1.5] {
+            allocations.record_trade(&StrategyType::TokenSniping, pnl);
+        }
+        // Too few trades to be scored
+        allocations.record_trade(&StrategyType::MomentumTrading, 5.0);
+
+        let changes = allocations.rebalance();
+        assert_eq!(changes.len(), 2);
+
+        let winner = allocations.multiplier(&StrategyType::Arbitrage);
+        let loser = allocations.multiplier(&StrategyType::TokenSniping);
+        assert!(winner > 1.0 && winner < 2.0, "winner at {}", winner);
+        assert!((0.25..1.0).contains(&loser), "loser at {}", loser);
+        assert_eq!(allocations.multiplier(&StrategyType::MomentumTrading), 1.0);
+
+        // Repeated passes converge on the bounds without overshooting
+        for _ in 0..50 {
+            allocations.rebalance();
+        }
+        assert!((allocations.multiplier(&StrategyType::Arbitrage) - 2.0).abs() < 1e-6);
+        assert!((allocations.multiplier(&StrategyType::TokenSniping) - 0.25).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_override_wins_over_rebalanced_multiplier() {
+        let mut allocations = StrategyAllocations::new(config());
+        for pnl in [-2.0, -1.0, -3.0, -2.0] {
+            allocations.record_trade(&StrategyType::TokenSniping, pnl);
+        }
+        allocations.rebalance();
+
+        allocations.set_override(&StrategyType::TokenSniping, 1.5);
+        assert_eq!(allocations.multiplier(&StrategyType::TokenSniping), 1.5);
+
+        allocations.clear_override(&StrategyType::TokenSniping);
+        assert!(allocations.multiplier(&StrategyType::TokenSniping) < 1.0);
+    }
+}
@@ -0,0 +1,242 @@
+// THE OVERMIND PROTOCOL - Multi-leg Arbitrage
+// Concrete arbitrage routes (buy on A, sell on B, ...) executed as one atomic bundle
+
+use anyhow::{anyhow, Result};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use uuid::Uuid;
+
+use crate::modules::symbol_resolver::TokenPair;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LegDirection {
+    /// Spend the quote token to receive the base token
+    Buy,
+    /// Spend the base token to receive the quote token
+    Sell,
+}
+
+/// One hop of an arbitrage route
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArbitrageLeg {
+    pub venue: String,
+    pub pair: TokenPair,
+    pub direction: LegDirection,
+    /// Expected execution price (quote per base)
+    pub expected_price: f64,
+    /// Venue fee for this hop in basis points
+    pub fee_bps: f64,
+}
+
+impl ArbitrageLeg {
+    fn input_mint(&self) -> Pubkey {
+        match self.direction {
+            LegDirection::Buy => self.pair.quote_mint,
+            LegDirection::Sell => self.pair.base_mint,
+        }
+    }
+
+    fn output_mint(&self) -> Pubkey {
+        match self.direction {
+            LegDirection::Buy => self.pair.base_mint,
+            LegDirection::Sell => self.pair.quote_mint,
+        }
+    }
+
+    /// Amount received for `amount_in`, after fees, at `price`
+    fn output_amount(&self, amount_in: f64, price: f64) -> f64 {
+        let gross = match self.direction {
+            LegDirection::Buy => amount_in / price,
+            LegDirection::Sell => amount_in * price,
+        };
+        gross * (1.0 - self.fee_bps / 10_000.0)
+    }
+}
+
+/// Ordered multi-hop route that must start and end in the same token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArbitrageSignal {
+    pub signal_id: Uuid,
+    pub legs: Vec<ArbitrageLeg>,
+    /// Amount of the starting token put through the route
+    pub input_amount: f64,
+    /// Trade size expressed in SOL, used to size the Jito tip
+    pub notional_sol: f64,
+    /// Minimum net edge (after fees) worth executing, in basis points
+    pub min_net_edge_bps: f64,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+#[allow(dead_code)]
+impl ArbitrageSignal {
+    /// Check that the legs form a closed, connected route
+    pub fn validate(&self) -> Result<()> {
+        if self.legs.len() < 2 {
+            return Err(anyhow!("Arbitrage route needs at least two legs"));
+        }
+        if self.input_amount <= 0.0 {
+            return Err(anyhow!("Arbitrage input amount must be positive"));
+        }
+
+        for (i, pair) in self.legs.windows(2).enumerate() {
+            if pair[0].output_mint() != pair[1].input_mint() {
+                return Err(anyhow!(
+                    "Leg {} output does not feed leg {} input",
+                    i,
+                    i + 1
+                ));
+            }
+        }
+
+        let first = &self.legs[0];
+        let last = &self.legs[self.legs.len() - 1];
+        if last.output_mint() != first.input_mint() {
+            return Err(anyhow!(
+                "Arbitrage route does not return to its starting token"
+            ));
+        }
+
+        if let Some(leg) = self.legs.iter().find(|l| l.expected_price <= 0.0) {
+            return Err(anyhow!("Leg on {} has non-positive price", leg.venue));
+        }
+        Ok(())
+    }
+
+    /// Net edge in basis points at the given per-leg prices
+    pub fn net_edge_bps_at(&self, prices: &[f64]) -> Result<f64> {
+        if prices.len() != self.legs.len() {
+            return Err(anyhow!(
+                "Expected {} leg prices, got {}",
+                self.legs.len(),
+                prices.len()
+            ));
+        }
+        if prices.iter().any(|p| *p <= 0.0) {
+            return Err(anyhow!("Leg prices must be positive"));
+        }
+
+        let output = self
+            .legs
+            .iter()
+            .zip(prices)
+            .fold(self.input_amount, |amount, (leg, price)| {
+                leg.output_amount(amount, *price)
+            });
+        Ok((output / self.input_amount - 1.0) * 10_000.0)
+    }
+
+    /// Net edge in basis points at the prices the signal was generated with
+    pub fn expected_net_edge_bps(&self) -> Result<f64> {
+        let prices: Vec<f64> = self.legs.iter().map(|l| l.expected_price).collect();
+        self.net_edge_bps_at(&prices)
+    }
+
+    /// Re-quote every leg and return the current net edge, or `None` if it
+    /// has fallen below `min_net_edge_bps`
+    pub async fn revalidate(&self, quotes: &dyn ArbitrageQuoteSource) -> Result<Option<f64>> {
+        let mut prices = Vec::with_capacity(self.legs.len());
+        for leg in &self.legs {
+            prices.push(quotes.quote(leg).await?);
+        }
+
+        let edge_bps = self.net_edge_bps_at(&prices)?;
+        Ok((edge_bps >= self.min_net_edge_bps).then_some(edge_bps))
+    }
+}
+
+/// Supplies fresh prices for arbitrage legs right before submission
+pub trait ArbitrageQuoteSource: Send + Sync {
+    fn quote<'a>(&'a self, leg: &'a ArbitrageLeg) -> BoxFuture<'a, Result<f64>>;
+}
+
+/// Outcome of an arbitrage execution attempt
+#[derive(Debug)]
+pub enum ArbitrageExecutionResult {
+    Executed {
+        signal_id: Uuid,
+        bundle_id: String,
+        legs: usize,
+        net_edge_bps: f64,
+        tip_lamports: u64,
+        latency_ms: u64,
+    },
+    Skipped {
+        signal_id: Uuid,
+        reason: String,
+        latency_ms: u64,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedQuotes(Vec<f64>);
+
+    impl ArbitrageQuoteSource for FixedQuotes {
+        fn quote<'a>(&'a self, leg: &'a ArbitrageLeg) -> BoxFuture<'a, Result<f64>> {
+            let price = if leg.direction == LegDirection::Buy {
+                self.0[0]
+            } else {
+                self.0[1]
+            };
+            Box::pin(async move { Ok(price) })
+        }
+    }
+
+    fn two_venue_arb(buy_price: f64, sell_price: f64) -> ArbitrageSignal {
+        let pair = TokenPair {
+            base_mint: Pubkey::new_unique(),
+            quote_mint: Pubkey::new_unique(),
+        };
+        let leg = |venue: &str, direction, price| ArbitrageLeg {
+            venue: venue.to_string(),
+            pair,
+            direction,
+            expected_price: price,
+            fee_bps: 25.0,
+        };
+
+        ArbitrageSignal {
+            signal_id: Uuid::new_v4(),
+            legs: vec![
+                leg("raydium", LegDirection::Buy, buy_price),
+                leg("orca", LegDirection::Sell, sell_price),
+            ],
+            input_amount: 1_000.0,
+            notional_sol: 10.0,
+            min_net_edge_bps: 10.0,
+            timestamp: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_route_validation_and_edge() {
+        let signal = two_venue_arb(100.0, 101.0);
+        signal.validate().unwrap();
+
+        // 1% gross spread minus two 25bps fees
+        let edge = signal.expected_net_edge_bps().unwrap();
+        assert!((edge - 49.56).abs() < 0.01, "edge was {}", edge);
+
+        let mut open_route = signal.clone();
+        open_route.legs.truncate(1);
+        assert!(open_route.validate().is_err());
+
+        let mut disconnected = signal;
+        disconnected.legs[1].pair.base_mint = Pubkey::new_unique();
+        assert!(disconnected.validate().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_revalidate_skips_closed_edge() {
+        let signal = two_venue_arb(100.0, 101.0);
+
+        let still_open = signal.revalidate(&FixedQuotes(vec![100.0, 101.0])).await;
+        assert!(still_open.unwrap().is_some());
+
+        let closed = signal.revalidate(&FixedQuotes(vec![100.0, 100.4])).await;
+        assert!(closed.unwrap().is_none());
+    }
+}
@@ -0,0 +1,368 @@
+// Axiom Meme Coin scanner for SNIPERCOR
+// Screens freshly launched meme coins for early momentum entries
+
+#![allow(dead_code)]
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::{debug, error, info, warn};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemeCoinCandidate {
+    pub token_address: String,
+    pub token_symbol: String,
+    pub price_usd: f64,
+    pub age_minutes: u32,
+    pub market_cap_usd: f64,
+    pub liquidity_usd: f64,
+    pub holder_count: u32,
+    pub top_10_percentage: f64,
+    pub dev_percentage: f64,
+    pub volume_5m_usd: f64,
+    pub buys_5m: u32,
+    pub sells_5m: u32,
+    pub price_change_5m_percentage: f64,
+    /// Bonding curve completion (0-100), 100 once migrated to an AMM pool
+    pub bonding_curve_progress: f64,
+    pub mint_authority_revoked: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntryRejection {
+    TooYoung,
+    TooOld,
+    MarketCapOutOfRange,
+    InsufficientLiquidity,
+    TooFewHolders,
+    ConcentratedHolders,
+    DevHoldingTooHigh,
+    LowVolume,
+    WeakBuyPressure,
+    NoMomentum,
+    Overextended,
+    BondingCurveOutOfRange,
+    MintAuthorityActive,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AxiomMemeCoinFilters {
+    pub min_age_minutes: u32,       // Default: 2 (skip the launch bundle)
+    pub max_age_minutes: u32,       // Default: 60
+    pub min_market_cap_usd: f64,    // Default: 30_000
+    pub max_market_cap_usd: f64,    // Default: 500_000
+    pub min_liquidity_usd: f64,     // Default: 15_000
+    pub min_holder_count: u32,      // Default: 150
+    pub max_top_10_percentage: f64, // Default: 35.0
+    pub max_dev_percentage: f64,    // Default: 5.0
+    pub min_volume_5m_usd: f64,     // Default: 10_000
+    pub min_buy_sell_ratio: f64,    // Default: 1.3
+    pub min_price_change_5m_percentage: f64, // Default: 5.0
+    pub max_price_change_5m_percentage: f64, // Default: 80.0 (don't chase vertical candles)
+    pub min_bonding_curve_progress: f64, // Default: 40.0
+    pub require_mint_authority_revoked: bool, // Default: true
+}
+
+impl Default for AxiomMemeCoinFilters {
+    fn default() -> Self {
+        Self {
+            min_age_minutes: 2,
+            max_age_minutes: 60,
+            min_market_cap_usd: 30_000.0,
+            max_market_cap_usd: 500_000.0,
+            min_liquidity_usd: 15_000.0,
+            min_holder_count: 150,
+            max_top_10_percentage: 35.0,
+            max_dev_percentage: 5.0,
+            min_volume_5m_usd: 10_000.0,
+            min_buy_sell_ratio: 1.3,
+            min_price_change_5m_percentage: 5.0,
+            max_price_change_5m_percentage: 80.0,
+            min_bonding_curve_progress: 40.0,
+            require_mint_authority_revoked: true,
+        }
+    }
+}
+
+impl AxiomMemeCoinFilters {
+    /// First entry criterion the candidate fails, or None when it qualifies
+    pub fn check(&self, candidate: &MemeCoinCandidate) -> Option<EntryRejection> {
+        if candidate.age_minutes < self.min_age_minutes {
+            return Some(EntryRejection::TooYoung);
+        }
+        if candidate.age_minutes > self.max_age_minutes {
+            return Some(EntryRejection::TooOld);
+        }
+        if candidate.market_cap_usd < self.min_market_cap_usd
+            || candidate.market_cap_usd > self.max_market_cap_usd
+        {
+            return Some(EntryRejection::MarketCapOutOfRange);
+        }
+        if candidate.liquidity_usd < self.min_liquidity_usd {
+            return Some(EntryRejection::InsufficientLiquidity);
+        }
+        if candidate.holder_count < self.min_holder_count {
+            return Some(EntryRejection::TooFewHolders);
+        }
+        if candidate.top_10_percentage > self.max_top_10_percentage {
+            return Some(EntryRejection::ConcentratedHolders);
+        }
+        if candidate.dev_percentage > self.max_dev_percentage {
+            return Some(EntryRejection::DevHoldingTooHigh);
+        }
+        if candidate.volume_5m_usd < self.min_volume_5m_usd {
+            return Some(EntryRejection::LowVolume);
+        }
+        if candidate.buy_sell_ratio() < self.min_buy_sell_ratio {
+            return Some(EntryRejection::WeakBuyPressure);
+        }
+        if candidate.price_change_5m_percentage < self.min_price_change_5m_percentage {
+            return Some(EntryRejection::NoMomentum);
+        }
+        if candidate.price_change_5m_percentage > self.max_price_change_5m_percentage {
+            return Some(EntryRejection::Overextended);
+        }
+        if candidate.bonding_curve_progress < self.min_bonding_curve_progress {
+            return Some(EntryRejection::BondingCurveOutOfRange);
+        }
+        if self.require_mint_authority_revoked && !candidate.mint_authority_revoked {
+            return Some(EntryRejection::MintAuthorityActive);
+        }
+        None
+    }
+}
+
+pub struct AxiomMemeCoinScanner {
+    filters: AxiomMemeCoinFilters,
+    candidate_sender: mpsc::UnboundedSender<MemeCoinCandidate>,
+    api_client: reqwest::Client,
+}
+
+impl AxiomMemeCoinScanner {
+    pub fn new(
+        filters: AxiomMemeCoinFilters,
+        candidate_sender: mpsc::UnboundedSender<MemeCoinCandidate>,
+    ) -> Self {
+        Self {
+            filters,
+            candidate_sender,
+            api_client: reqwest::Client::new(),
+        }
+    }
+
+    pub async fn start(&mut self) -> Result<()> {
+        info!("🐸 Axiom Meme Coin Scanner starting...");
+
+        let mut interval = tokio::time::interval(tokio::time::Duration::from_secs(15));
+
+        loop {
+            interval.tick().await;
+
+            match self.scan_new_tokens().await {
+                Ok(candidates) => {
+                    info!("📊 Found {} meme coin candidates", candidates.len());
+
+                    for candidate in candidates {
+                        match self.filters.check(&candidate) {
+                            None => {
+                                info!(
+                                    "✅ {} meets entry criteria - MC: ${:.0}, 5m: {:+.1}%",
+                                    candidate.token_symbol,
+                                    candidate.market_cap_usd,
+                                    candidate.price_change_5m_percentage
+                                );
+
+                                if let Err(e) = self.candidate_sender.send(candidate) {
+                                    error!("Failed to send meme coin candidate: {}", e);
+                                }
+                            }
+                            Some(reason) => {
+                                debug!("⏭️ {} rejected: {:?}", candidate.token_symbol, reason);
+                            }
+                        }
+                    }
+                }
+                Err(e) => {
+                    warn!("Failed to scan meme coins: {}", e);
+                }
+            }
+        }
+    }
+
+    async fn scan_new_tokens(&self) -> Result<Vec<MemeCoinCandidate>> {
+        // Simulate Axiom trending feed integration
+        // In real implementation, this would poll Axiom's new pairs feed
+
+        let mut candidates = Vec::new();
+
+        for i in 0..5 {
+            let candidate = MemeCoinCandidate {
+                token_address: format!("meme_token_{}", i),
+                token_symbol: format!("MEME{}", i),
+                price_usd: 0.00008 + (i as f64 * 0.00002),
+                age_minutes: 3 + (i * 8),
+                market_cap_usd: 80_000.0 + (i as f64 * 20_000.0),
+                liquidity_usd: 20_000.0 + (i as f64 * 4_000.0),
+                holder_count: 200 + (i * 60),
+                top_10_percentage: 22.0 + (i as f64 * 4.0),
+                dev_percentage: 2.0 + (i as f64 * 1.0),
+                volume_5m_usd: 15_000.0 + (i as f64 * 5_000.0),
+                buys_5m: 120 - (i * 10),
+                sells_5m: 60 + (i * 10),
+                price_change_5m_percentage: 12.0 + (i as f64 * 6.0),
+                bonding_curve_progress: 55.0 + (i as f64 * 10.0),
+                mint_authority_revoked: true,
+            };
+
+            candidates.push(candidate);
+        }
+
+        Ok(candidates)
+    }
+
+    pub fn update_filters(&mut self, new_filters: AxiomMemeCoinFilters) {
+        self.filters = new_filters;
+        info!("🔧 Axiom meme coin filters updated");
+    }
+}
+
+// Integration with existing strategy engine
+impl MemeCoinCandidate {
+    pub fn buy_sell_ratio(&self) -> f64 {
+        if self.sells_5m == 0 {
+            return self.buys_5m as f64;
+        }
+        self.buys_5m as f64 / self.sells_5m as f64
+    }
+
+    pub fn to_trading_signal(&self) -> crate::modules::strategy::TradingSignal {
+        use crate::modules::strategy::{StrategyType, TradeAction, TradingSignal};
+        use uuid::Uuid;
+
+        let confidence = self.calculate_confidence();
+
+        // Meme coins are the riskiest book, so size stays small and scales with conviction
+        let quantity = if confidence >= 0.75 { 50.0 } else { 25.0 };
+
+        TradingSignal {
+            signal_id: Uuid::new_v4().to_string(),
+            symbol: self.token_symbol.clone(),
+            action: TradeAction::Buy,
+            quantity,
+            target_price: self.price_usd,
+            confidence,
+            timestamp: chrono::Utc::now(),
+            strategy_type: StrategyType::AxiomMemeCoin,
+            tick_received_at: None,
+            pool_liquidity: None,
+        }
+    }
+
+    fn calculate_confidence(&self) -> f64 {
+        let mut confidence: f64 = 0.5; // Base confidence
+
+        // Boost confidence for organic demand
+        if self.buy_sell_ratio() >= 2.0 {
+            confidence += 0.1;
+        }
+        if self.holder_count >= 300 {
+            confidence += 0.1;
+        }
+        if self.top_10_percentage < 25.0 {
+            confidence += 0.1;
+        }
+        if self.volume_5m_usd >= 25_000.0 {
+            confidence += 0.05;
+        }
+        if self.bonding_curve_progress >= 100.0 {
+            confidence += 0.05; // Migrated, deeper liquidity for the exit
+        }
+
+        // Reduce confidence for risk factors
+        if self.dev_percentage > 3.0 {
+            confidence -= 0.1;
+        }
+        if self.price_change_5m_percentage > 50.0 {
+            confidence -= 0.1;
+        }
+
+        confidence.clamp(0.0, 1.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::strategy::StrategyType;
+
+    fn candidate() -> MemeCoinCandidate {
+        MemeCoinCandidate {
+            token_address: "meme_token".to_string(),
+            token_symbol: "MEME".to_string(),
+            price_usd: 0.0001,
+            age_minutes: 12,
+            market_cap_usd: 120_000.0,
+            liquidity_usd: 25_000.0,
+            holder_count: 350,
+            top_10_percentage: 20.0,
+            dev_percentage: 2.0,
+            volume_5m_usd: 30_000.0,
+            buys_5m: 200,
+            sells_5m: 80,
+            price_change_5m_percentage: 18.0,
+            bonding_curve_progress: 70.0,
+            mint_authority_revoked: true,
+        }
+    }
+
+    #[test]
+    fn test_entry_criteria() {
+        let filters = AxiomMemeCoinFilters::default();
+        assert_eq!(filters.check(&candidate()), None);
+
+        let launch_bundle = MemeCoinCandidate {
+            age_minutes: 1,
+            ..candidate()
+        };
+        assert_eq!(
+            filters.check(&launch_bundle),
+            Some(EntryRejection::TooYoung)
+        );
+
+        let dumping = MemeCoinCandidate {
+            buys_5m: 50,
+            sells_5m: 100,
+            ..candidate()
+        };
+        assert_eq!(
+            filters.check(&dumping),
+            Some(EntryRejection::WeakBuyPressure)
+        );
+
+        let vertical = MemeCoinCandidate {
+            price_change_5m_percentage: 150.0,
+            ..candidate()
+        };
+        assert_eq!(filters.check(&vertical), Some(EntryRejection::Overextended));
+
+        let mintable = MemeCoinCandidate {
+            mint_authority_revoked: false,
+            ..candidate()
+        };
+        assert_eq!(
+            filters.check(&mintable),
+            Some(EntryRejection::MintAuthorityActive)
+        );
+    }
+
+    #[test]
+    fn test_to_trading_signal() {
+        let signal = candidate().to_trading_signal();
+
+        assert_eq!(signal.strategy_type, StrategyType::AxiomMemeCoin);
+        assert_eq!(signal.symbol, "MEME");
+        assert_eq!(signal.target_price, 0.0001);
+        assert!(signal.confidence > 0.75);
+        assert_eq!(signal.quantity, 50.0);
+    }
+}
@@ -0,0 +1,191 @@
+// Clock Skew Module
+// Compares the local clock against the Solana cluster's block time so time-based gates
+// (decision age, deadlines, heartbeats) are not silently wrong on a drifting host
+
+use crate::monitoring::MonitoringState;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+
+#[derive(Debug, Clone)]
+pub struct ClockSkewConfig {
+    /// Largest acceptable absolute difference between local and cluster time
+    pub max_skew: Duration,
+    pub check_interval: Duration,
+    pub request_timeout: Duration,
+}
+
+impl Default for ClockSkewConfig {
+    fn default() -> Self {
+        Self {
+            // Block times have one-second resolution and trail the tip slightly
+            max_skew: Duration::from_secs(5),
+            check_interval: Duration::from_secs(60),
+            request_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Latest clock skew measurement, exposed through /health and /ready
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClockSkewStatus {
+    /// Local minus cluster time; positive means the local clock runs ahead
+    pub skew_ms: i64,
+    pub max_skew_ms: i64,
+    pub within_tolerance: bool,
+    pub cluster_time: DateTime<Utc>,
+    pub last_checked: DateTime<Utc>,
+    pub source: String,
+}
+
+/// Skew of the local clock against `cluster_time`, using the midpoint of the request to
+/// cancel out round-trip latency
+pub fn measure_skew(
+    local_before: DateTime<Utc>,
+    local_after: DateTime<Utc>,
+    cluster_time: DateTime<Utc>,
+) -> chrono::Duration {
+    let local_midpoint = local_before + (local_after - local_before) / 2;
+    local_midpoint - cluster_time
+}
+
+/// Periodically measures clock skew against an RPC node and records it in the monitoring state
+pub struct ClockSkewMonitor {
+    config: ClockSkewConfig,
+    rpc_url: String,
+    client: reqwest::Client,
+    state: MonitoringState,
+}
+
+#[allow(dead_code)]
+impl ClockSkewMonitor {
+    pub fn new(config: ClockSkewConfig, rpc_url: String, state: MonitoringState) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(config.request_timeout)
+            .build()
+            .context("Failed to create clock skew HTTP client")?;
+
+        Ok(Self {
+            config,
+            rpc_url,
+            client,
+            state,
+        })
+    }
+
+    /// Re-check every interval; call `check_once` first for the startup check
+    pub async fn run(self) {
+        info!(
+            "🕰️ Clock skew monitor starting (max skew: {:?}, interval: {:?})",
+            self.config.max_skew, self.config.check_interval
+        );
+        let mut interval = tokio::time::interval_at(
+            tokio::time::Instant::now() + self.config.check_interval,
+            self.config.check_interval,
+        );
+
+        loop {
+            interval.tick().await;
+            if let Err(e) = self.check_once().await {
+                warn!("🕰️ Clock skew check failed: {}", e);
+            }
+        }
+    }
+
+    /// Measure skew once, record it and warn loudly when it exceeds the threshold
+    pub async fn check_once(&self) -> Result<ClockSkewStatus> {
+        let local_before = Utc::now();
+        let cluster_time = self.fetch_cluster_time().await?;
+        let local_after = Utc::now();
+
+        let skew = measure_skew(local_before, local_after, cluster_time);
+        let max_skew_ms = self.config.max_skew.as_millis() as i64;
+        let status = ClockSkewStatus {
+            skew_ms: skew.num_milliseconds(),
+            max_skew_ms,
+            within_tolerance: skew.num_milliseconds().abs() <= max_skew_ms,
+            cluster_time,
+            last_checked: local_after,
+            source: "solana_block_time".to_string(),
+        };
+
+        if status.within_tolerance {
+            debug!("🕰️ Clock skew {}ms", status.skew_ms);
+        } else {
+            error!(
+                "🚨 CLOCK SKEW {}ms EXCEEDS {}ms - local clock is {} the cluster; time-based checks are unreliable",
+                status.skew_ms,
+                max_skew_ms,
+                if status.skew_ms > 0 { "ahead of" } else { "behind" }
+            );
+        }
+
+        self.state.update_clock_skew(status.clone());
+        Ok(status)
+    }
+
+    /// Block time of the latest confirmed slot
+    async fn fetch_cluster_time(&self) -> Result<DateTime<Utc>> {
+        let slot = self
+            .rpc_call("getSlot", serde_json::json!([{"commitment": "confirmed"}]))
+            .await?
+            .as_u64()
+            .context("getSlot returned a non-numeric slot")?;
+
+        let timestamp = self
+            .rpc_call("getBlockTime", serde_json::json!([slot]))
+            .await?
+            .as_i64()
+            .with_context(|| format!("No block time available for slot {}", slot))?;
+
+        Utc.timestamp_opt(timestamp, 0)
+            .single()
+            .ok_or_else(|| anyhow!("Invalid block time {}", timestamp))
+    }
+
+    async fn rpc_call(&self, method: &str, params: serde_json::Value) -> Result<serde_json::Value> {
+        let body: serde_json::Value = self
+            .client
+            .post(&self.rpc_url)
+            .json(
+                &serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": method, "params": params}),
+            )
+            .send()
+            .await?
+            .json()
+            .await?;
+
+        if let Some(error) = body.get("error") {
+            return Err(anyhow!("{} failed: {}", method, error));
+        }
+        body.get("result")
+            .cloned()
+            .with_context(|| format!("{} returned no result", method))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure_skew_uses_request_midpoint() {
+        let cluster_time = Utc.timestamp_opt(1_700_000_000, 0).unwrap();
+        let before = cluster_time + chrono::Duration::milliseconds(2_900);
+        let after = before + chrono::Duration::milliseconds(200);
+
+        // Midpoint is 3s ahead of the cluster
+        assert_eq!(
+            measure_skew(before, after, cluster_time).num_milliseconds(),
+            3_000
+        );
+
+        let behind = cluster_time - chrono::Duration::seconds(10);
+        assert_eq!(
+            measure_skew(behind, behind, cluster_time).num_milliseconds(),
+            -10_000
+        );
+    }
+}
@@ -0,0 +1,470 @@
+// Confirmation Tracking Module
+// Polls signature statuses until a submitted transaction reaches the required commitment
+
+use crate::modules::strategy::StrategyType;
+use anyhow::{Context, Result};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::Signature;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, warn};
+
+/// Commitment a trade must reach before it is reported as confirmed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ConfirmationLevel {
+    Processed,
+    Confirmed,
+    Finalized,
+}
+
+impl std::str::FromStr for ConfirmationLevel {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "processed" => Ok(ConfirmationLevel::Processed),
+            "confirmed" => Ok(ConfirmationLevel::Confirmed),
+            "finalized" => Ok(ConfirmationLevel::Finalized),
+            other => Err(anyhow::anyhow!("Unknown commitment level: {}", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct ConfirmationConfig {
+    pub default_level: ConfirmationLevel,
+    /// Per-strategy overrides, e.g. looser confirmation for fast exits
+    pub per_strategy: HashMap<StrategyType, ConfirmationLevel>,
+    pub poll_interval: Duration,
+    /// A signature not at the required level by then is reported as dropped
+    pub timeout: Duration,
+    /// Slots a submission may stay unconfirmed before it is reported as expired,
+    /// so its bundle can be rebuilt (0 disables)
+    pub max_bundle_age_slots: u64,
+}
+
+impl Default for ConfirmationConfig {
+    fn default() -> Self {
+        Self {
+            default_level: ConfirmationLevel::Confirmed,
+            per_strategy: HashMap::new(),
+            poll_interval: Duration::from_millis(400),
+            // Roughly the lifetime of a recent blockhash
+            timeout: Duration::from_secs(60),
+            max_bundle_age_slots: 0,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl ConfirmationConfig {
+    /// Parse per-strategy overrides of the form "TokenSniping=processed,Arbitrage=finalized"
+    pub fn with_strategy_levels(mut self, spec: &str) -> Result<Self> {
+        for entry in spec.split(',').filter(|e| !e.trim().is_empty()) {
+            let (strategy, level) = entry
+                .split_once('=')
+                .with_context(|| format!("Invalid strategy commitment entry: {}", entry))?;
+            let strategy = crate::modules::strategy_cooldown::parse_strategy_type(strategy.trim())?;
+            self.per_strategy.insert(strategy, level.parse()?);
+        }
+        Ok(self)
+    }
+
+    pub fn level_for(&self, strategy: &StrategyType) -> ConfirmationLevel {
+        self.per_strategy
+            .get(strategy)
+            .copied()
+            .unwrap_or(self.default_level)
+    }
+}
+
+/// Confirmation latency observed for one commitment level, modelled as log-normal
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConfirmationLatency {
+    pub median: Duration,
+    pub p90: Duration,
+}
+
+impl ConfirmationLatency {
+    /// z-score of the 90th percentile of a standard normal
+    const Z_P90: f64 = 1.2816;
+
+    fn new(median_ms: u64, p90_ms: u64) -> Self {
+        Self {
+            median: Duration::from_millis(median_ms),
+            p90: Duration::from_millis(p90_ms),
+        }
+    }
+
+    /// Draw a delay; `uniform` supplies independent samples from [0, 1)
+    pub fn sample(&self, mut uniform: impl FnMut() -> f64) -> Duration {
+        let median = self.median.as_secs_f64();
+        if median <= 0.0 {
+            return Duration::ZERO;
+        }
+        let sigma = (self.p90.as_secs_f64() / median).ln().max(0.0) / Self::Z_P90;
+        // Box-Muller; 1 - u keeps the logarithm away from zero
+        let z = (-2.0 * (1.0 - uniform()).ln()).sqrt() * (2.0 * std::f64::consts::PI * uniform()).cos();
+        Duration::from_secs_f64(median * (sigma * z).exp())
+    }
+}
+
+/// Simulated confirmation delays for paper trades, so paper timing and time-based exits
+/// see the latency a live trade waiting for the same commitment would
+#[derive(Debug, Clone)]
+pub struct PaperConfirmationModel {
+    /// Commitment each strategy waits for, as on the live path
+    pub levels: ConfirmationConfig,
+    pub latencies: HashMap<ConfirmationLevel, ConfirmationLatency>,
+}
+
+#[allow(dead_code)]
+impl PaperConfirmationModel {
+    /// Approximate mainnet latencies: a processed slot, optimistic confirmation
+    /// a few slots later, and finality after 32 slots
+    pub fn new(levels: ConfirmationConfig) -> Self {
+        let latencies = HashMap::from([
+            (ConfirmationLevel::Processed, ConfirmationLatency::new(400, 900)),
+            (ConfirmationLevel::Confirmed, ConfirmationLatency::new(1_300, 2_500)),
+            (ConfirmationLevel::Finalized, ConfirmationLatency::new(13_000, 16_000)),
+        ]);
+        Self { levels, latencies }
+    }
+
+    /// Parse latency overrides in milliseconds of the form "processed=400:900,finalized=13000:16000"
+    /// (median:p90)
+    pub fn with_latencies(mut self, spec: &str) -> Result<Self> {
+        for entry in spec.split(',').filter(|e| !e.trim().is_empty()) {
+            let (level, latency) = entry
+                .split_once('=')
+                .with_context(|| format!("Invalid confirmation latency entry: {}", entry))?;
+            let (median, p90) = latency
+                .split_once(':')
+                .with_context(|| format!("Invalid confirmation latency entry: {}", entry))?;
+            let median: u64 = median.trim().parse().with_context(|| format!("Invalid median in {}", entry))?;
+            let p90: u64 = p90.trim().parse().with_context(|| format!("Invalid p90 in {}", entry))?;
+            if p90 < median {
+                anyhow::bail!("p90 below median in confirmation latency entry: {}", entry);
+            }
+            self.latencies.insert(level.parse()?, ConfirmationLatency::new(median, p90));
+        }
+        Ok(self)
+    }
+
+    /// Delay a paper trade of `strategy` waits before reporting Confirmed, capped at the
+    /// confirmation timeout after which a live trade would be reported dropped
+    pub fn delay_for(&self, strategy: &StrategyType) -> Duration {
+        let delay = self
+            .latencies
+            .get(&self.levels.level_for(strategy))
+            .map_or(Duration::ZERO, |latency| latency.sample(rand::random::<f64>));
+        delay.min(self.levels.timeout)
+    }
+}
+
+/// Status of a signature as seen by the cluster
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignatureStatus {
+    /// Highest commitment level the transaction has reached
+    pub level: ConfirmationLevel,
+    /// Set when the transaction landed but failed
+    pub error: Option<String>,
+}
+
+/// Source of signature statuses (`getSignatureStatuses`)
+pub trait SignatureStatusSource: Send + Sync {
+    /// None while the cluster has not seen the signature
+    fn signature_status<'a>(
+        &'a self,
+        signature: &'a Signature,
+    ) -> BoxFuture<'a, Result<Option<SignatureStatus>>>;
+
+    /// Current cluster slot (`getSlot`); None when the source cannot tell
+    fn current_slot(&self) -> BoxFuture<'_, Result<Option<u64>>> {
+        Box::pin(async { Ok(None) })
+    }
+}
+
+impl SignatureStatusSource for RpcClient {
+    fn signature_status<'a>(
+        &'a self,
+        signature: &'a Signature,
+    ) -> BoxFuture<'a, Result<Option<SignatureStatus>>> {
+        Box::pin(async move {
+            let response = self
+                .get_signature_statuses(std::slice::from_ref(signature))
+                .await
+                .context("getSignatureStatuses failed")?;
+
+            Ok(response.value.into_iter().next().flatten().map(|status| {
+                let level = if status.satisfies_commitment(CommitmentConfig::finalized()) {
+                    ConfirmationLevel::Finalized
+                } else if status.satisfies_commitment(CommitmentConfig::confirmed()) {
+                    ConfirmationLevel::Confirmed
+                } else {
+                    ConfirmationLevel::Processed
+                };
+                SignatureStatus {
+                    level,
+                    error: status.err.map(|e| e.to_string()),
+                }
+            }))
+        })
+    }
+
+    fn current_slot(&self) -> BoxFuture<'_, Result<Option<u64>>> {
+        Box::pin(async move { Ok(Some(self.get_slot().await.context("getSlot failed")?)) })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfirmationOutcome {
+    Confirmed(ConfirmationLevel),
+    /// The transaction landed with an error
+    Failed(String),
+    /// The transaction never reached the required level before the timeout
+    Dropped,
+    /// Still unconfirmed `age_slots` after submission; worth rebuilding rather than waiting
+    Expired {
+        age_slots: u64,
+    },
+}
+
+#[derive(Clone)]
+pub struct ConfirmationTracker {
+    config: ConfirmationConfig,
+    source: Arc<dyn SignatureStatusSource>,
+}
+
+#[allow(dead_code)]
+impl ConfirmationTracker {
+    pub fn new(config: ConfirmationConfig, source: Arc<dyn SignatureStatusSource>) -> Self {
+        Self { config, source }
+    }
+
+    pub fn level_for(&self, strategy: &StrategyType) -> ConfirmationLevel {
+        self.config.level_for(strategy)
+    }
+
+    /// Poll until `signature` reaches `level`, fails, expires, or times out
+    pub async fn wait_for(
+        &self,
+        signature: &Signature,
+        level: ConfirmationLevel,
+    ) -> ConfirmationOutcome {
+        let start = Instant::now();
+        let mut first_slot = None;
+
+        loop {
+            match self.source.signature_status(signature).await {
+                Ok(Some(status)) => {
+                    if let Some(error) = status.error {
+                        return ConfirmationOutcome::Failed(error);
+                    }
+                    if status.level >= level {
+                        return ConfirmationOutcome::Confirmed(status.level);
+                    }
+                    debug!(
+                        "⏳ {} at {:?}, waiting for {:?}",
+                        signature, status.level, level
+                    );
+                }
+                Ok(None) => debug!("⏳ {} not yet seen by the cluster", signature),
+                // Transient RPC errors must not fail the trade; keep polling until the timeout
+                Err(e) => warn!("Signature status poll for {} failed: {}", signature, e),
+            }
+
+            if let Some(age_slots) = self.slots_since(&mut first_slot).await {
+                if age_slots >= self.config.max_bundle_age_slots {
+                    return ConfirmationOutcome::Expired { age_slots };
+                }
+            }
+            if start.elapsed() >= self.config.timeout {
+                return ConfirmationOutcome::Dropped;
+            }
+            tokio::time::sleep(self.config.poll_interval).await;
+        }
+    }
+
+    /// Slots elapsed since the first poll; None while bundle expiry is disabled
+    /// or the slot is unknown
+    async fn slots_since(&self, first_slot: &mut Option<u64>) -> Option<u64> {
+        if self.config.max_bundle_age_slots == 0 {
+            return None;
+        }
+        match self.source.current_slot().await {
+            Ok(Some(slot)) => Some(slot.saturating_sub(*first_slot.get_or_insert(slot))),
+            Ok(None) => None,
+            Err(e) => {
+                warn!("Slot poll failed: {}", e);
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    /// Replays a fixed sequence of statuses, repeating the last one
+    struct ScriptedStatuses(Mutex<Vec<Option<SignatureStatus>>>);
+
+    impl SignatureStatusSource for ScriptedStatuses {
+        fn signature_status<'a>(
+            &'a self,
+            _signature: &'a Signature,
+        ) -> BoxFuture<'a, Result<Option<SignatureStatus>>> {
+            let mut statuses = self.0.lock().unwrap();
+            let status = if statuses.len() > 1 {
+                statuses.remove(0)
+            } else {
+                statuses[0].clone()
+            };
+            Box::pin(async move { Ok(status) })
+        }
+    }
+
+    fn tracker(statuses: Vec<Option<SignatureStatus>>) -> ConfirmationTracker {
+        let config = ConfirmationConfig {
+            poll_interval: Duration::from_millis(1),
+            timeout: Duration::from_millis(50),
+            ..ConfirmationConfig::default()
+        };
+        ConfirmationTracker::new(config, Arc::new(ScriptedStatuses(Mutex::new(statuses))))
+    }
+
+    fn at(level: ConfirmationLevel) -> Option<SignatureStatus> {
+        Some(SignatureStatus { level, error: None })
+    }
+
+    #[tokio::test]
+    async fn test_waits_for_required_commitment() {
+        let signature = Signature::default();
+        let statuses = vec![
+            None,
+            at(ConfirmationLevel::Processed),
+            at(ConfirmationLevel::Confirmed),
+        ];
+
+        let outcome = tracker(statuses.clone())
+            .wait_for(&signature, ConfirmationLevel::Confirmed)
+            .await;
+        assert_eq!(
+            outcome,
+            ConfirmationOutcome::Confirmed(ConfirmationLevel::Confirmed)
+        );
+
+        // Never finalized within the timeout
+        let outcome = tracker(statuses)
+            .wait_for(&signature, ConfirmationLevel::Finalized)
+            .await;
+        assert_eq!(outcome, ConfirmationOutcome::Dropped);
+
+        let failed = Some(SignatureStatus {
+            level: ConfirmationLevel::Processed,
+            error: Some("InstructionError".to_string()),
+        });
+        let outcome = tracker(vec![failed])
+            .wait_for(&signature, ConfirmationLevel::Processed)
+            .await;
+        assert!(matches!(outcome, ConfirmationOutcome::Failed(_)));
+    }
+
+    /// Never sees the signature while the cluster advances one slot per poll
+    struct Unlanded(Mutex<u64>);
+
+    impl SignatureStatusSource for Unlanded {
+        fn signature_status<'a>(
+            &'a self,
+            _signature: &'a Signature,
+        ) -> BoxFuture<'a, Result<Option<SignatureStatus>>> {
+            Box::pin(async { Ok(None) })
+        }
+
+        fn current_slot(&self) -> BoxFuture<'_, Result<Option<u64>>> {
+            let mut slot = self.0.lock().unwrap();
+            *slot += 1;
+            let slot = *slot;
+            Box::pin(async move { Ok(Some(slot)) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_unconfirmed_submission_expires_by_slot_age() {
+        let config = ConfirmationConfig {
+            poll_interval: Duration::from_millis(1),
+            max_bundle_age_slots: 4,
+            ..ConfirmationConfig::default()
+        };
+        let tracker = ConfirmationTracker::new(config, Arc::new(Unlanded(Mutex::new(1_000))));
+
+        let outcome = tracker
+            .wait_for(&Signature::default(), ConfirmationLevel::Confirmed)
+            .await;
+        assert_eq!(outcome, ConfirmationOutcome::Expired { age_slots: 4 });
+    }
+
+    #[test]
+    fn test_per_strategy_levels() {
+        let config = ConfirmationConfig::default()
+            .with_strategy_levels("TokenSniping=processed, Arbitrage=finalized")
+            .unwrap();
+
+        assert_eq!(
+            config.level_for(&StrategyType::TokenSniping),
+            ConfirmationLevel::Processed
+        );
+        assert_eq!(
+            config.level_for(&StrategyType::Arbitrage),
+            ConfirmationLevel::Finalized
+        );
+        assert_eq!(
+            config.level_for(&StrategyType::MomentumTrading),
+            ConfirmationLevel::Confirmed
+        );
+        assert!(ConfirmationConfig::default()
+            .with_strategy_levels("TokenSniping=eventually")
+            .is_err());
+    }
+
+    #[test]
+    fn test_paper_confirmation_delay_follows_strategy_commitment() {
+        let levels = ConfirmationConfig {
+            timeout: Duration::from_secs(15),
+            ..ConfirmationConfig::default()
+        }
+        .with_strategy_levels("TokenSniping=processed,Arbitrage=finalized")
+        .unwrap();
+        let model = PaperConfirmationModel::new(levels)
+            .with_latencies("processed=100:100,finalized=20000:20000")
+            .unwrap();
+
+        // A p90 equal to the median leaves no spread
+        assert_eq!(model.delay_for(&StrategyType::TokenSniping), Duration::from_millis(100));
+        // Capped at the timeout a live trade would be dropped after
+        assert_eq!(model.delay_for(&StrategyType::Arbitrage), Duration::from_secs(15));
+        assert!(PaperConfirmationModel::new(ConfirmationConfig::default())
+            .with_latencies("confirmed=500:100")
+            .is_err());
+    }
+
+    #[test]
+    fn test_confirmation_latency_sample_matches_percentiles() {
+        let latency = ConfirmationLatency::new(1_000, 2_000);
+        // u2 = 0.25 zeroes the cosine, giving the median
+        let mut draws = [0.5, 0.25].into_iter();
+        assert_eq!(latency.sample(|| draws.next().unwrap()).as_millis(), 1_000);
+
+        // u2 = 0 leaves z at the radius set by u1
+
+        let mut draws = [1.0 - (-(ConfirmationLatency::Z_P90.powi(2)) / 2.0).exp(), 0.0].into_iter();
+        let p90 = latency.sample(|| draws.next().unwrap());
+        assert!((p90.as_secs_f64() - 2.0).abs() < 1e-6);
+    }
+}
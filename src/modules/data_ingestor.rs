@@ -3,8 +3,11 @@
 
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use tokio::sync::mpsc;
-use tracing::{error, info};
+use tracing::{debug, error, info};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MarketData {
@@ -13,19 +16,95 @@ pub struct MarketData {
     pub volume: f64,
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub source: DataSource,
+    /// Local time the tick arrived, used as the start of tick-to-trade latency
+    pub received_at: chrono::DateTime<chrono::Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum DataSource {
     Helius,
     QuickNode,
 }
 
+/// Suppression of ticks providers replay after a resubscribe
+#[derive(Debug, Clone)]
+pub struct ReplayDedupConfig {
+    /// How long after a reconnect exact replays are dropped
+    pub window: chrono::Duration,
+    /// How long a forwarded tick is remembered for matching replays
+    pub ttl: chrono::Duration,
+}
+
+impl ReplayDedupConfig {
+    pub fn from_millis(window_ms: u64, ttl_ms: u64) -> Self {
+        Self {
+            window: chrono::Duration::milliseconds(window_ms as i64),
+            ttl: chrono::Duration::milliseconds(ttl_ms as i64),
+        }
+    }
+}
+
+/// Drops ticks matching one already forwarded on (symbol, timestamp, price),
+/// but only inside the window following a reconnect
+#[derive(Debug)]
+pub struct ReplayDedup {
+    config: ReplayDedupConfig,
+    seen: HashMap<(String, i64, u64), chrono::DateTime<chrono::Utc>>,
+    reconnected_at: Option<chrono::DateTime<chrono::Utc>>,
+    suppressed: Arc<AtomicU64>,
+}
+
+#[allow(dead_code)]
+impl ReplayDedup {
+    pub fn new(config: ReplayDedupConfig) -> Self {
+        Self {
+            config,
+            seen: HashMap::new(),
+            reconnected_at: None,
+            suppressed: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Open the replay window; called after resubscribing to a provider
+    pub fn mark_reconnected(&mut self, at: chrono::DateTime<chrono::Utc>) {
+        self.reconnected_at = Some(at);
+    }
+
+    /// Whether the tick should be forwarded; replays inside the window are counted and dropped
+    pub fn admit(&mut self, data: &MarketData) -> bool {
+        let now = data.received_at;
+        let ttl = self.config.ttl;
+        self.seen.retain(|_, forwarded_at| now - *forwarded_at <= ttl);
+
+        let key = (
+            data.symbol.clone(),
+            data.timestamp.timestamp_micros(),
+            data.price.to_bits(),
+        );
+        let in_window = self
+            .reconnected_at
+            .is_some_and(|at| now - at <= self.config.window);
+        if in_window && self.seen.contains_key(&key) {
+            self.suppressed.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+
+        self.seen.insert(key, now);
+        true
+    }
+
+    /// Shared handle on the suppressed-replay counter for metrics export
+    pub fn suppressed_counter(&self) -> Arc<AtomicU64> {
+        self.suppressed.clone()
+    }
+}
+
 #[allow(dead_code)]
 pub struct DataIngestor {
     market_data_sender: mpsc::UnboundedSender<MarketData>,
     helius_api_key: String,
     quicknode_api_key: String,
+    replay_dedup: Option<ReplayDedup>,
     is_running: bool,
 }
 
@@ -40,10 +119,25 @@ impl DataIngestor {
             market_data_sender,
             helius_api_key,
             quicknode_api_key,
+            replay_dedup: None,
             is_running: false,
         }
     }
 
+    /// Drop exact tick replays in the window after each reconnect
+    pub fn with_replay_dedup(mut self, replay_dedup: ReplayDedup) -> Self {
+        self.replay_dedup = Some(replay_dedup);
+        self
+    }
+
+    /// Called once a provider connection is re-established and resubscribed
+    pub fn on_reconnect(&mut self) {
+        if let Some(dedup) = &mut self.replay_dedup {
+            info!("🔁 Market data reconnected, suppressing replayed ticks");
+            dedup.mark_reconnected(chrono::Utc::now());
+        }
+    }
+
     pub async fn start(&mut self) -> Result<()> {
         info!("🔄 DataIngestor starting...");
         self.is_running = true;
@@ -60,7 +154,22 @@ impl DataIngestor {
         self.is_running = false;
     }
 
-    async fn simulate_market_data(&self) -> Result<()> {
+    /// Forward a tick to the strategy engine unless it is a post-reconnect replay
+    fn publish(&mut self, market_data: MarketData) -> Result<()> {
+        if let Some(dedup) = &mut self.replay_dedup {
+            if !dedup.admit(&market_data) {
+                debug!(
+                    "Suppressed replayed tick {} @ {} ({})",
+                    market_data.symbol, market_data.price, market_data.timestamp
+                );
+                return Ok(());
+            }
+        }
+        self.market_data_sender.send(market_data)?;
+        Ok(())
+    }
+
+    async fn simulate_market_data(&mut self) -> Result<()> {
         let mut interval = tokio::time::interval(tokio::time::Duration::from_millis(100));
 
         let mut price_base = 100.0;
@@ -81,9 +190,10 @@ impl DataIngestor {
                 volume: 1000.0 + (chrono::Utc::now().timestamp_millis() % 500) as f64,
                 timestamp: chrono::Utc::now(),
                 source: DataSource::Helius,
+                received_at: chrono::Utc::now(),
             };
 
-            if let Err(e) = self.market_data_sender.send(market_data) {
+            if let Err(e) = self.publish(market_data) {
                 error!("Failed to send market data: {}", e);
                 break;
             }
@@ -108,4 +218,45 @@ mod tests {
 
         assert!(!ingestor.is_running);
     }
+
+    #[test]
+    fn test_replays_suppressed_only_after_reconnect() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let dedup = ReplayDedup::new(ReplayDedupConfig::from_millis(5_000, 30_000));
+        let suppressed = dedup.suppressed_counter();
+        let mut ingestor = DataIngestor::new(tx, String::new(), String::new()).with_replay_dedup(dedup);
+
+        let tick = MarketData {
+            symbol: "SOL/USDC".to_string(),
+            price: 100.0,
+            volume: 1000.0,
+            timestamp: chrono::Utc::now(),
+            source: DataSource::Helius,
+            received_at: chrono::Utc::now(),
+        };
+
+        // A repeated tick outside a reconnect window is passed through
+        ingestor.publish(tick.clone()).unwrap();
+        ingestor.publish(tick.clone()).unwrap();
+
+        ingestor.on_reconnect();
+        let replay = MarketData {
+            received_at: chrono::Utc::now(),
+            ..tick.clone()
+        };
+        ingestor.publish(replay.clone()).unwrap();
+        ingestor
+            .publish(MarketData {
+                price: 100.5,
+                ..replay
+            })
+            .unwrap();
+
+        let mut forwarded = Vec::new();
+        while let Ok(data) = rx.try_recv() {
+            forwarded.push(data.price);
+        }
+        assert_eq!(forwarded, vec![100.0, 100.0, 100.5]);
+        assert_eq!(suppressed.load(Ordering::Relaxed), 1);
+    }
 }
@@ -3,9 +3,12 @@
 
 #![allow(dead_code)]
 
-use anyhow::Result;
+use crate::modules::target_price::{PoolReserves, TargetPriceConfig};
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{error, info, warn};
 
@@ -36,6 +39,9 @@ pub struct TokenLaunch {
     pub launch_timestamp: chrono::DateTime<chrono::Utc>,
     pub initial_market_cap: f64,
     pub liquidity_amount: f64,
+    /// Launch pool state; entries are priced off it and skipped without it
+    #[serde(default)]
+    pub pool_reserves: Option<PoolReserves>,
     pub predicted_success_probability: f64,
     pub entry_window_seconds: u64, // How long we have to enter
 }
@@ -63,6 +69,8 @@ pub struct DeveloperTracker {
     money_flows: Vec<MoneyFlow>,
     launch_sender: mpsc::UnboundedSender<TokenLaunch>,
     tracking_config: TrackingConfig,
+    /// Flows dropped by the count cap while still inside the retention window
+    money_flows_evicted: Arc<AtomicU64>,
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +81,10 @@ pub struct TrackingConfig {
     pub preferred_wallet_ratio: WalletRatio, // 40% fresh, 60% aged
     pub max_tracking_wallets: usize,
     pub sniper_tool: SniperTool,
+    /// Money flows older than this are dropped
+    pub money_flow_retention: chrono::Duration,
+    /// Hard cap on retained money flows; the oldest are evicted first
+    pub max_money_flows: usize,
 }
 
 #[derive(Debug, Clone)]
@@ -94,9 +106,15 @@ impl DeveloperTracker {
             money_flows: Vec::new(),
             launch_sender,
             tracking_config: config,
+            money_flows_evicted: Arc::new(AtomicU64::new(0)),
         }
     }
 
+    /// Count of flows evicted by `max_money_flows`, for export as a metric
+    pub fn money_flow_eviction_counter(&self) -> Arc<AtomicU64> {
+        self.money_flows_evicted.clone()
+    }
+
     pub async fn start(&mut self) -> Result<()> {
         info!("👨‍💻 Developer Tracker starting...");
         info!("🎯 Target: 6k-8k market cap entries with 20-40% profit potential");
@@ -140,13 +158,32 @@ impl DeveloperTracker {
             }
         }
 
-        // Keep only recent flows (last 24 hours)
-        let cutoff = chrono::Utc::now() - chrono::Duration::hours(24);
-        self.money_flows.retain(|flow| flow.timestamp > cutoff);
+        self.prune_money_flows(chrono::Utc::now());
 
         Ok(())
     }
 
+    /// Apply the retention window, then evict the oldest flows beyond the count cap
+    fn prune_money_flows(&mut self, now: chrono::DateTime<chrono::Utc>) {
+        let cutoff = now - self.tracking_config.money_flow_retention;
+        self.money_flows.retain(|flow| flow.timestamp > cutoff);
+
+        let excess = self
+            .money_flows
+            .len()
+            .saturating_sub(self.tracking_config.max_money_flows);
+        if excess > 0 {
+            self.money_flows.sort_by_key(|flow| flow.timestamp);
+            self.money_flows.drain(..excess);
+            self.money_flows_evicted
+                .fetch_add(excess as u64, Ordering::Relaxed);
+            warn!(
+                "Money flow cap of {} reached, evicted {} oldest flows",
+                self.tracking_config.max_money_flows, excess
+            );
+        }
+    }
+
     async fn detect_money_flows(&self) -> Result<Vec<MoneyFlow>> {
         let mut flows = Vec::new();
 
@@ -179,6 +216,10 @@ impl DeveloperTracker {
                     launch_timestamp: chrono::Utc::now(),
                     initial_market_cap: 7_500.0, // Target range 6k-8k
                     liquidity_amount: flow.amount_sol * 0.8,
+                    pool_reserves: Some(PoolReserves {
+                        base_reserve: 800_000_000.0,
+                        quote_reserve: flow.amount_sol * 0.8,
+                    }),
                     predicted_success_probability: dev_profile.success_rate,
                     entry_window_seconds: 30, // Very short window
                 });
@@ -312,26 +353,40 @@ impl Default for TrackingConfig {
             },
             max_tracking_wallets: 20,
             sniper_tool: SniperTool::Kabal,
+            money_flow_retention: chrono::Duration::hours(24),
+            max_money_flows: 50_000,
         }
     }
 }
 
 // Integration with main strategy engine
 impl TokenLaunch {
-    pub fn to_trading_signal(&self) -> crate::modules::strategy::TradingSignal {
+    /// Signal priced at the launch pool's spot price
+    pub fn to_trading_signal(
+        &self,
+        prices: &TargetPriceConfig,
+    ) -> Result<crate::modules::strategy::TradingSignal> {
         use crate::modules::strategy::{StrategyType, TradeAction, TradingSignal};
         use uuid::Uuid;
 
-        TradingSignal {
+        let target_price = self
+            .pool_reserves
+            .and_then(|pool| pool.spot_price())
+            .with_context(|| format!("No pool state to price {}", self.token_symbol))?;
+        let target_price = prices.validate(&StrategyType::DeveloperTracking, target_price)?;
+
+        Ok(TradingSignal {
             signal_id: Uuid::new_v4().to_string(),
             symbol: self.token_symbol.clone(),
             action: TradeAction::Buy,
             quantity: 25.0, // Small position for very early entry
-            target_price: self.initial_market_cap / 1_000_000.0,
+            target_price,
             confidence: self.predicted_success_probability,
             timestamp: chrono::Utc::now(),
             strategy_type: StrategyType::DeveloperTracking,
-        }
+            tick_received_at: None,
+            pool_liquidity: None,
+        })
     }
 }
 
@@ -356,4 +411,41 @@ mod tests {
         let profile = tracker.create_developer_profile("dev", &flow_refs);
         assert!(profile.success_rate > 0.0);
     }
+
+    #[test]
+    fn test_money_flows_capped_by_age_and_count() {
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let mut tracker = DeveloperTracker::new(
+            tx,
+            TrackingConfig {
+                max_money_flows: 3,
+                ..TrackingConfig::default()
+            },
+        );
+        let now = chrono::Utc::now();
+        let flow = |age_minutes: i64| MoneyFlow {
+            from_wallet: format!("wallet_{}", age_minutes),
+            to_wallet: "dev".to_string(),
+            amount_sol: 1.0,
+            timestamp: now - chrono::Duration::minutes(age_minutes),
+            transaction_type: TransactionType::WalletToWallet,
+        };
+        tracker.money_flows = vec![flow(5), flow(25 * 60), flow(1), flow(3), flow(4)];
+
+        tracker.prune_money_flows(now);
+
+        // The expired flow is not counted as an eviction; the oldest in-window one is
+        let kept: Vec<_> = tracker
+            .money_flows
+            .iter()
+            .map(|f| f.from_wallet.as_str())
+            .collect();
+        assert_eq!(kept, vec!["wallet_4", "wallet_3", "wallet_1"]);
+        assert_eq!(
+            tracker
+                .money_flow_eviction_counter()
+                .load(Ordering::Relaxed),
+            1
+        );
+    }
 }
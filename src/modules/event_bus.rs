@@ -0,0 +1,346 @@
+// Event Bus Sink Module
+// Streams execution results and order lifecycle events to a NATS subject as JSON.
+// Compiled only with the `event-bus` feature.
+
+use crate::modules::executor::ExecutionResult;
+use crate::modules::lifecycle::OrderState;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+use tokio::sync::mpsc;
+use tokio::time::timeout;
+use tracing::{debug, info, warn};
+
+#[derive(Debug, Clone)]
+pub struct EventBusConfig {
+    /// e.g. "nats://localhost:4222"
+    pub nats_url: String,
+    /// Events go to `<prefix>.executions` and `<prefix>.lifecycle`
+    pub subject_prefix: String,
+    /// Events buffered while the broker is slow or down; newer events are dropped beyond this
+    pub buffer_capacity: usize,
+    /// Events published before waiting for the broker to acknowledge them
+    pub batch_size: usize,
+    pub ack_timeout: Duration,
+    pub min_reconnect_backoff: Duration,
+    pub max_reconnect_backoff: Duration,
+}
+
+impl Default for EventBusConfig {
+    fn default() -> Self {
+        Self {
+            nats_url: "nats://localhost:4222".to_string(),
+            subject_prefix: "snipercor".to_string(),
+            buffer_capacity: 10_000,
+            batch_size: 64,
+            ack_timeout: Duration::from_secs(2),
+            min_reconnect_backoff: Duration::from_millis(500),
+            max_reconnect_backoff: Duration::from_secs(30),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum BusEvent {
+    Execution(Box<ExecutionResult>),
+    OrderState {
+        signal_id: String,
+        state: OrderState,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    },
+}
+
+impl BusEvent {
+    fn subject(&self, prefix: &str) -> String {
+        match self {
+            BusEvent::Execution(_) => format!("{}.executions", prefix),
+            BusEvent::OrderState { .. } => format!("{}.lifecycle", prefix),
+        }
+    }
+}
+
+/// Non-blocking producer side handed to the executor
+#[derive(Debug, Clone)]
+pub struct EventBusHandle {
+    sender: mpsc::Sender<BusEvent>,
+    dropped: Arc<AtomicU64>,
+}
+
+impl EventBusHandle {
+    /// Queue an event; drops it if the outbound buffer is full rather than blocking
+    pub fn publish(&self, event: BusEvent) {
+        if self.sender.try_send(event).is_err() {
+            let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+            if dropped.is_power_of_two() {
+                warn!(
+                    "📤 Event bus buffer full, {} events dropped so far",
+                    dropped
+                );
+            }
+        }
+    }
+
+    pub fn dropped_events(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+}
+
+struct NatsConnection {
+    reader: BufReader<OwnedReadHalf>,
+    writer: OwnedWriteHalf,
+}
+
+impl NatsConnection {
+    async fn connect(url: &str) -> Result<Self> {
+        let address = url.strip_prefix("nats://").unwrap_or(url);
+        let stream = TcpStream::connect(address)
+            .await
+            .with_context(|| format!("Failed to connect to NATS at {}", address))?;
+        stream.set_nodelay(true)?;
+
+        let (reader, writer) = stream.into_split();
+        let mut connection = Self {
+            reader: BufReader::new(reader),
+            writer,
+        };
+
+        let info = connection.read_line().await?;
+        if !info.starts_with("INFO") {
+            return Err(anyhow!("Unexpected NATS greeting: {}", info));
+        }
+        connection
+            .writer
+            .write_all(b"CONNECT {\"verbose\":false,\"pedantic\":false,\"name\":\"snipercor\"}\r\n")
+            .await?;
+        connection.flush_and_wait().await?;
+        Ok(connection)
+    }
+
+    async fn read_line(&mut self) -> Result<String> {
+        let mut line = String::new();
+        if self.reader.read_line(&mut line).await? == 0 {
+            return Err(anyhow!("NATS connection closed"));
+        }
+        Ok(line.trim_end().to_string())
+    }
+
+    async fn publish(&mut self, subject: &str, payload: &[u8]) -> Result<()> {
+        self.writer
+            .write_all(format!("PUB {} {}\r\n", subject, payload.len()).as_bytes())
+            .await?;
+        self.writer.write_all(payload).await?;
+        self.writer.write_all(b"\r\n").await?;
+        Ok(())
+    }
+
+    /// PING and wait for the PONG; the server processes commands in order, so
+    /// everything published before the PING has been accepted once it answers
+    async fn flush_and_wait(&mut self) -> Result<()> {
+        self.writer.write_all(b"PING\r\n").await?;
+        loop {
+            let line = self.read_line().await?;
+            match line.as_str() {
+                "PONG" => return Ok(()),
+                "PING" => self.writer.write_all(b"PONG\r\n").await?,
+                "+OK" => {}
+                _ if line.starts_with("-ERR") => return Err(anyhow!("NATS error: {}", line)),
+                _ if line.starts_with("INFO") => {}
+                _ => debug!("Ignoring NATS line: {}", line),
+            }
+        }
+    }
+}
+
+/// Drains the outbound buffer into NATS with at-least-once delivery: a batch
+/// stays in flight until the broker acknowledges it and is re-sent after a reconnect
+pub struct EventBusPublisher {
+    config: EventBusConfig,
+    receiver: mpsc::Receiver<BusEvent>,
+    in_flight: VecDeque<BusEvent>,
+}
+
+impl EventBusPublisher {
+    pub fn new(config: EventBusConfig) -> (Self, EventBusHandle) {
+        let (sender, receiver) = mpsc::channel(config.buffer_capacity.max(1));
+        let handle = EventBusHandle {
+            sender,
+            dropped: Arc::new(AtomicU64::new(0)),
+        };
+        (
+            Self {
+                config,
+                receiver,
+                in_flight: VecDeque::new(),
+            },
+            handle,
+        )
+    }
+
+    pub async fn run(mut self) {
+        info!(
+            "📤 Event bus publisher starting ({}, prefix: {})",
+            self.config.nats_url, self.config.subject_prefix
+        );
+        let mut backoff = self.config.min_reconnect_backoff;
+
+        loop {
+            let mut connection = match NatsConnection::connect(&self.config.nats_url).await {
+                Ok(connection) => {
+                    info!("📤 Connected to NATS at {}", self.config.nats_url);
+                    backoff = self.config.min_reconnect_backoff;
+                    connection
+                }
+                Err(e) => {
+                    warn!("📤 NATS unavailable, retrying in {:?}: {}", backoff, e);
+                    tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(self.config.max_reconnect_backoff);
+                    continue;
+                }
+            };
+
+            match self.publish_until_error(&mut connection).await {
+                Ok(()) => {
+                    info!("📤 Event bus producers gone, publisher stopping");
+                    return;
+                }
+                Err(e) => warn!(
+                    "📤 NATS connection lost with {} unacknowledged events: {}",
+                    self.in_flight.len(),
+                    e
+                ),
+            }
+        }
+    }
+
+    /// Returns Ok once every producer handle is dropped and the buffer is drained
+    async fn publish_until_error(&mut self, connection: &mut NatsConnection) -> Result<()> {
+        loop {
+            if self.in_flight.is_empty() {
+                match self.receiver.recv().await {
+                    Some(event) => self.in_flight.push_back(event),
+                    None => return Ok(()),
+                }
+                while self.in_flight.len() < self.config.batch_size.max(1) {
+                    match self.receiver.try_recv() {
+                        Ok(event) => self.in_flight.push_back(event),
+                        Err(_) => break,
+                    }
+                }
+            }
+
+            for event in &self.in_flight {
+                let payload = serde_json::to_vec(event)?;
+                connection
+                    .publish(&event.subject(&self.config.subject_prefix), &payload)
+                    .await?;
+            }
+            timeout(self.config.ack_timeout, connection.flush_and_wait())
+                .await
+                .map_err(|_| anyhow!("NATS acknowledgement timed out"))??;
+            self.in_flight.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::executor::ExecutionStatus;
+    use tokio::net::TcpListener;
+
+    fn execution(signal_id: &str) -> BusEvent {
+        BusEvent::Execution(Box::new(ExecutionResult {
+            signal_id: signal_id.to_string(),
+            transaction_id: "tx".to_string(),
+            status: ExecutionStatus::Confirmed,
+            executed_quantity: 1.0,
+            executed_price: 100.0,
+            fees: 0.1,
+            fee_breakdown: Default::default(),
+            timestamp: chrono::Utc::now(),
+            error_message: None,
+            strategy_type: None,
+            wallet_id: None,
+        }))
+    }
+
+    /// Minimal NATS server: acknowledges PINGs unless `drop_first_pub` is set,
+    /// in which case the connection is closed on the first PUB
+    async fn serve_connection(
+        stream: TcpStream,
+        drop_first_pub: bool,
+        published: mpsc::UnboundedSender<(String, String)>,
+    ) {
+        let (reader, mut writer) = stream.into_split();
+        let mut reader = BufReader::new(reader);
+        writer.write_all(b"INFO {}\r\n").await.unwrap();
+
+        let mut line = String::new();
+        while reader.read_line(&mut line).await.unwrap_or(0) > 0 {
+            let command = line.trim_end().to_string();
+            line.clear();
+            if command == "PING" {
+                writer.write_all(b"PONG\r\n").await.unwrap();
+            } else if let Some(header) = command.strip_prefix("PUB ") {
+                if drop_first_pub {
+                    return;
+                }
+                let subject = header.split(' ').next().unwrap().to_string();
+                reader.read_line(&mut line).await.unwrap();
+                published
+                    .send((subject, line.trim_end().to_string()))
+                    .unwrap();
+                line.clear();
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_events_resent_after_reconnect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (published_tx, mut published_rx) = mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let (first, _) = listener.accept().await.unwrap();
+            serve_connection(first, true, published_tx.clone()).await;
+            let (second, _) = listener.accept().await.unwrap();
+            serve_connection(second, false, published_tx).await;
+        });
+
+        let (publisher, handle) = EventBusPublisher::new(EventBusConfig {
+            nats_url: format!("nats://{}", address),
+            min_reconnect_backoff: Duration::from_millis(10),
+            ..EventBusConfig::default()
+        });
+        tokio::spawn(publisher.run());
+
+        handle.publish(execution("sig-1"));
+        handle.publish(BusEvent::OrderState {
+            signal_id: "sig-1".to_string(),
+            state: OrderState::Confirmed,
+            timestamp: chrono::Utc::now(),
+        });
+
+        let mut received = Vec::new();
+        while received.len() < 2 {
+            let message = timeout(Duration::from_secs(5), published_rx.recv())
+                .await
+                .expect("events not delivered after reconnect")
+                .unwrap();
+            received.push(message);
+        }
+
+        assert_eq!(received[0].0, "snipercor.executions");
+        assert!(received[0].1.contains("\"signal_id\":\"sig-1\""));
+        assert_eq!(received[1].0, "snipercor.lifecycle");
+        assert!(received[1].1.contains("\"state\":\"Confirmed\""));
+        assert_eq!(handle.dropped_events(), 0);
+    }
+}
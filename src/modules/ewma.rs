@@ -0,0 +1,84 @@
+// Moving Average Module
+// Exponentially-weighted moving averages for latency gauges, so a spike long ago stops
+// dragging the reported number and recent regressions show up quickly
+
+/// Half-life used when a metric is not configured explicitly (in samples)
+pub const DEFAULT_EWMA_HALF_LIFE_SAMPLES: u32 = 50;
+
+/// EWMA whose half-life is measured in samples: a sample recorded `half_life` samples
+/// ago carries half the weight of the newest one
+#[derive(Debug, Clone, Copy)]
+pub struct Ewma {
+    half_life: u32,
+    /// Weight kept by the previous average on each new sample
+    decay: f64,
+    value: Option<f64>,
+}
+
+impl Default for Ewma {
+    fn default() -> Self {
+        Self::new(DEFAULT_EWMA_HALF_LIFE_SAMPLES)
+    }
+}
+
+#[allow(dead_code)]
+impl Ewma {
+    /// A half-life of 0 keeps only the latest sample
+    pub fn new(half_life: u32) -> Self {
+        let decay = if half_life == 0 {
+            0.0
+        } else {
+            0.5f64.powf(1.0 / half_life as f64)
+        };
+        Self {
+            half_life,
+            decay,
+            value: None,
+        }
+    }
+
+    /// Fold in a sample and return the updated average; the first sample seeds it
+    pub fn record(&mut self, sample: f64) -> f64 {
+        let value = match self.value {
+            Some(previous) => previous * self.decay + sample * (1.0 - self.decay),
+            None => sample,
+        };
+        self.value = Some(value);
+        value
+    }
+
+    /// Current average, 0.0 before the first sample
+    pub fn value(&self) -> f64 {
+        self.value.unwrap_or(0.0)
+    }
+
+    pub fn half_life(&self) -> u32 {
+        self.half_life
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_old_spike_decays_by_half_life() {
+        let mut ewma = Ewma::new(10);
+        assert_eq!(ewma.record(1_000.0), 1_000.0);
+
+        // After one half-life of normal samples the spike's excess has halved
+        for _ in 0..10 {
+            ewma.record(10.0);
+        }
+        assert!((ewma.value() - (10.0 + 990.0 / 2.0)).abs() < 1e-6);
+
+        for _ in 0..200 {
+            ewma.record(10.0);
+        }
+        assert!((ewma.value() - 10.0).abs() < 0.01);
+
+        let mut latest_only = Ewma::new(0);
+        latest_only.record(100.0);
+        assert_eq!(latest_only.record(5.0), 5.0);
+    }
+}
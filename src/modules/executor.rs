@@ -2,10 +2,39 @@
 // Handles AI-enhanced trade execution on Solana blockchain with TensorZero optimization
 
 use crate::config::TradingMode;
-use crate::modules::risk::ApprovedSignal;
-use crate::modules::hft_engine::{OvermindHFTEngine, HFTConfig, ExecutionResult as HFTExecutionResult};
-use anyhow::Result;
+use crate::modules::ai_feedback::AIFeedbackReporter;
+use crate::modules::ai_funnel::{AIFunnelStage, SharedAIDecisionFunnel};
+#[cfg(feature = "event-bus")]
+use crate::modules::event_bus::{BusEvent, EventBusHandle};
+use crate::modules::arbitrage::{ArbitrageExecutionResult, ArbitrageQuoteSource, ArbitrageSignal};
+use crate::modules::risk::{ApprovedSignal, SettledTrade};
+use crate::modules::hft_engine::{BundleRebuild, BundleSlot, OvermindHFTEngine, HFTConfig, ExecutionResult as HFTExecutionResult};
+use crate::modules::confirmation::{
+    ConfirmationLevel, ConfirmationOutcome, ConfirmationTracker, PaperConfirmationModel,
+};
+use crate::modules::fee_budget::SharedFeeBudgets;
+use crate::modules::fees::{FeeBreakdown, FeeConfig, FeeModel};
+use crate::modules::idempotency::{IdempotencyCheck, IdempotencyGuard};
+use crate::modules::lifecycle::{OrderState, OrderTracker, PositionState};
+use crate::modules::market_prices::SharedMarketPrices;
+use crate::modules::pending_replacement::{PendingReplacements, SharedPendingReplacements};
+use crate::modules::pipeline_capture::{CaptureArtifact, SharedPipelineCapture};
+use crate::modules::portfolio_exposure::PortfolioExposureCap;
+use crate::modules::reconciliation::{PositionJournal, TradeLogEntry};
+use crate::modules::simulation_guard::{PreflightSimulator, SharedSimulationGuard};
+use crate::modules::slippage_curve::realized_slippage_bps;
+use crate::modules::slippage_model::SharedSlippageModel;
+use crate::modules::strategy::{StrategyType, TradeAction};
+use crate::modules::symbol_resolver::TokenPair;
+use crate::modules::strategy_cooldown::SharedExecutionCooldowns;
+use crate::modules::token_metadata::{SharedTokenMetadataCache, TokenMetadata};
+use crate::modules::wallet_manager::{fill_sol_delta, Position, WalletManager};
+use crate::monitoring::MonitoringState;
+use anyhow::{anyhow, Result};
+use futures::stream::{FuturesUnordered, StreamExt};
 use serde::{Deserialize, Serialize};
+use solana_sdk::signature::Signature;
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
@@ -16,9 +45,18 @@ pub struct ExecutionResult {
     pub status: ExecutionStatus,
     pub executed_quantity: f64,
     pub executed_price: f64,
+    /// Total execution cost in SOL; `fee_breakdown` itemizes it
     pub fees: f64,
+    #[serde(default)]
+    pub fee_breakdown: FeeBreakdown,
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub error_message: Option<String>,
+    /// Strategy of the executed signal
+    #[serde(default)]
+    pub strategy_type: Option<StrategyType>,
+    /// Wallet that executed the signal, on multi-wallet paths
+    #[serde(default)]
+    pub wallet_id: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +67,40 @@ pub enum ExecutionStatus {
     Cancelled,
 }
 
+/// What an execution result is attributed to, kept until the trade is final
+#[derive(Debug, Clone)]
+struct ExecutionContext {
+    signal_id: String,
+    strategy_type: StrategyType,
+    symbol: String,
+    token_pair: TokenPair,
+    tick_received_at: Option<chrono::DateTime<chrono::Utc>>,
+    action: TradeAction,
+    requested_quantity: f64,
+    requested_price: f64,
+    allowed_slippage_bps: Option<f64>,
+}
+
+/// Why a live bundle was handed back to the executor before reaching a verdict
+#[derive(Debug, Clone, Copy)]
+enum ResubmitReason {
+    /// Unconfirmed after `age_slots`; rebuilt within the retry attempts
+    Expired { age_slots: u64 },
+    /// Still pending, replaced on request with escalated fees
+    Replace,
+}
+
+/// A live bundle handed back to the executor to rebuild and resubmit
+struct ResubmitBundle {
+    context: ExecutionContext,
+    result: ExecutionResult,
+    rebuild: Box<BundleRebuild>,
+    bundle_slot: Option<BundleSlot>,
+    /// Earlier submissions of the same trade that may still land
+    superseded: Vec<Signature>,
+    reason: ResubmitReason,
+}
+
 #[allow(dead_code)]
 pub struct Executor {
     signal_receiver: mpsc::UnboundedReceiver<ApprovedSignal>,
@@ -40,6 +112,57 @@ pub struct Executor {
     // THE OVERMIND PROTOCOL - HFT Engine integration
     hft_engine: Option<OvermindHFTEngine>,
     hft_mode_enabled: bool,
+    // Actions that bypass the AI even in HFT mode (e.g. exits whose decision is already made)
+    standard_path_actions: Vec<TradeAction>,
+    // Order lifecycle tracking
+    orders: OrderTracker,
+    // Durable executed-signal set shared across restarts and instances
+    idempotency: Option<IdempotencyGuard>,
+    // Pre-flight simulation of live trades, halting them when simulations keep failing
+    preflight: Option<(Arc<dyn PreflightSimulator>, SharedSimulationGuard)>,
+    // Records tick-to-trade latency when attached
+    monitoring: Option<MonitoringState>,
+    // Post-execution cool-downs started on each fill
+    execution_cooldowns: Option<SharedExecutionCooldowns>,
+    // Per-strategy daily fee spend, charged with each confirmed execution's fees
+    fee_budgets: Option<SharedFeeBudgets>,
+    // Paper fill slippage, calibrated from confirmed live fills
+    slippage_model: Option<SharedSlippageModel>,
+    // Mint metadata for signals the risk manager could not annotate
+    token_metadata: Option<SharedTokenMetadataCache>,
+    // Itemizes priority fees, tips, base fees and swap fees per execution
+    fee_model: FeeModel,
+    // Holds live trades at Pending until they reach the required commitment
+    confirmation_tracker: Option<ConfirmationTracker>,
+    // Confirmation delay paper trades simulate; a flat 50ms without one
+    paper_confirmation: Option<PaperConfirmationModel>,
+    // Slot of the bundle just submitted, held until its confirmation verdict
+    pending_bundle_slot: Option<BundleSlot>,
+    // AI decision behind that bundle, kept so it can be rebuilt if it expires
+    pending_bundle_rebuild: Option<Box<BundleRebuild>>,
+    // Inference behind each AI-executed trade, for reporting its outcome to TensorZero
+    ai_feedback: Option<Arc<AIFeedbackReporter>>,
+    // Counts AI decisions executed and confirmed into the AI acceptance funnel
+    ai_funnel: Option<SharedAIDecisionFunnel>,
+    confirmation_sender: mpsc::UnboundedSender<(ExecutionContext, ExecutionResult)>,
+    confirmation_receiver: mpsc::UnboundedReceiver<(ExecutionContext, ExecutionResult)>,
+    resubmit_sender: mpsc::UnboundedSender<ResubmitBundle>,
+    resubmit_receiver: mpsc::UnboundedReceiver<ResubmitBundle>,
+    // Pending live bundles that can be replaced with a fee-bumped rebuild on request
+    pending_replacements: SharedPendingReplacements,
+    // Adds AI exchanges, submissions, confirmations and results to captured pipeline traces
+    capture: Option<SharedPipelineCapture>,
+    // Wallet manager and wallet ID that confirmed buys open and confirmed sells close positions in
+    positions: Option<(Arc<WalletManager>, String)>,
+    // Snapshot and trade log that startup reconciliation reads back after a restart
+    position_journal: Option<PositionJournal>,
+    // Portfolio cap whose reservation for each approved buy is released once it settles
+    exposure_cap: Option<Arc<PortfolioExposureCap>>,
+    // Reports the realized P&L of confirmed sells to the risk manager
+    trade_results: Option<mpsc::UnboundedSender<SettledTrade>>,
+    // Streams results and lifecycle events to the message bus when configured
+    #[cfg(feature = "event-bus")]
+    event_bus: Option<EventBusHandle>,
 }
 
 #[allow(dead_code)]
@@ -51,6 +174,8 @@ impl Executor {
         solana_rpc_url: String,
         wallet_private_key: String,
     ) -> Self {
+        let (confirmation_sender, confirmation_receiver) = mpsc::unbounded_channel();
+        let (resubmit_sender, resubmit_receiver) = mpsc::unbounded_channel();
         Self {
             signal_receiver,
             persistence_sender,
@@ -60,6 +185,34 @@ impl Executor {
             is_running: false,
             hft_engine: None,
             hft_mode_enabled: false,
+            standard_path_actions: Vec::new(),
+            orders: OrderTracker::for_orders(),
+            idempotency: None,
+            preflight: None,
+            monitoring: None,
+            execution_cooldowns: None,
+            fee_budgets: None,
+            slippage_model: None,
+            token_metadata: None,
+            fee_model: FeeModel::default(),
+            confirmation_tracker: None,
+            paper_confirmation: None,
+            pending_bundle_slot: None,
+            pending_bundle_rebuild: None,
+            ai_feedback: None,
+            ai_funnel: None,
+            confirmation_sender,
+            confirmation_receiver,
+            resubmit_sender,
+            resubmit_receiver,
+            pending_replacements: PendingReplacements::shared(),
+            capture: None,
+            positions: None,
+            position_journal: None,
+            exposure_cap: None,
+            trade_results: None,
+            #[cfg(feature = "event-bus")]
+            event_bus: None,
         }
     }
 
@@ -73,6 +226,8 @@ impl Executor {
         hft_config: HFTConfig,
     ) -> Result<Self> {
         let hft_engine = OvermindHFTEngine::new(hft_config)?;
+        let (confirmation_sender, confirmation_receiver) = mpsc::unbounded_channel();
+        let (resubmit_sender, resubmit_receiver) = mpsc::unbounded_channel();
 
         Ok(Self {
             signal_receiver,
@@ -83,9 +238,392 @@ impl Executor {
             is_running: false,
             hft_engine: Some(hft_engine),
             hft_mode_enabled: true,
+            standard_path_actions: Vec::new(),
+            orders: OrderTracker::for_orders(),
+            idempotency: None,
+            preflight: None,
+            monitoring: None,
+            execution_cooldowns: None,
+            fee_budgets: None,
+            slippage_model: None,
+            token_metadata: None,
+            fee_model: FeeModel::default(),
+            confirmation_tracker: None,
+            paper_confirmation: None,
+            pending_bundle_slot: None,
+            pending_bundle_rebuild: None,
+            ai_feedback: None,
+            ai_funnel: None,
+            confirmation_sender,
+            confirmation_receiver,
+            resubmit_sender,
+            resubmit_receiver,
+            pending_replacements: PendingReplacements::shared(),
+            capture: None,
+            positions: None,
+            position_journal: None,
+            exposure_cap: None,
+            trade_results: None,
+            #[cfg(feature = "event-bus")]
+            event_bus: None,
         })
     }
 
+    /// Record tick-to-trade latency into the monitoring metrics, and let monitoring
+    /// read the HFT engine's metrics while this executor runs
+    pub fn with_monitoring(mut self, monitoring: MonitoringState) -> Self {
+        let bundles_in_flight = monitoring.jito_bundles_in_flight.clone();
+        let hft_metrics = monitoring.hft_metrics.clone();
+        self.hft_engine = self.hft_engine.take().map(|engine| {
+            engine
+                .with_bundles_in_flight_gauge(bundles_in_flight)
+                .with_shared_metrics(hft_metrics)
+        });
+        self.monitoring = Some(monitoring);
+        self
+    }
+
+    /// Publish every execution result and order state change to the message bus
+    #[cfg(feature = "event-bus")]
+    pub fn with_event_bus(mut self, event_bus: EventBusHandle) -> Self {
+        self.event_bus = Some(event_bus);
+        self
+    }
+
+    /// Check every signal against a durable executed-signal set before executing it
+    pub fn with_idempotency(mut self, idempotency: IdempotencyGuard) -> Self {
+        self.idempotency = Some(idempotency);
+        self
+    }
+
+    /// Simulate every live trade before submitting it; `guard` halts live trading when
+    /// the simulation failure rate gets too high
+    pub fn with_preflight_simulation(
+        mut self,
+        simulator: Arc<dyn PreflightSimulator>,
+        guard: SharedSimulationGuard,
+    ) -> Self {
+        self.preflight = Some((simulator, guard));
+        self
+    }
+
+    /// Share the registry through which pending trades are replaced, e.g. with the HTTP API
+    pub fn with_pending_replacements(mut self, pending_replacements: SharedPendingReplacements) -> Self {
+        self.pending_replacements = pending_replacements;
+        self
+    }
+
+    /// Replace `signal_id`'s pending bundle with a rebuild at a higher priority fee and tip,
+    /// bounded by the fee ceilings. Whichever submission lands first settles the trade.
+    pub fn replace_pending(&self, signal_id: &str) -> Result<()> {
+        self.pending_replacements.replace_pending(signal_id)
+    }
+
+    /// Remember which TensorZero inference produced each AI-executed trade
+    pub fn with_ai_feedback(mut self, reporter: Arc<AIFeedbackReporter>) -> Self {
+        self.ai_feedback = Some(reporter);
+        self
+    }
+
+    /// Record the execution of targeted signals into their captured pipeline traces
+    pub fn with_pipeline_capture(mut self, capture: SharedPipelineCapture) -> Self {
+        self.capture = Some(capture);
+        self
+    }
+
+    /// Track positions opened and closed by confirmed fills under `wallet_id`
+    pub fn with_positions(mut self, wallet_manager: Arc<WalletManager>, wallet_id: String) -> Self {
+        self.positions = Some((wallet_manager, wallet_id));
+        self
+    }
+
+    /// Log confirmed fills and snapshot the tracked positions after each one
+    pub fn with_position_journal(mut self, journal: PositionJournal) -> Self {
+        self.position_journal = Some(journal);
+        self
+    }
+
+    /// Release each signal's portfolio cap reservation once its execution settles
+    pub fn with_portfolio_exposure_cap(mut self, exposure_cap: Arc<PortfolioExposureCap>) -> Self {
+        self.exposure_cap = Some(exposure_cap);
+        self
+    }
+
+    /// Report each confirmed sell's realized P&L against its tracked entries
+    pub fn with_trade_results(mut self, sender: mpsc::UnboundedSender<SettledTrade>) -> Self {
+        self.trade_results = Some(sender);
+        self
+    }
+
+    fn release_exposure(&self, signal_id: &str) {
+        if let Some(exposure_cap) = &self.exposure_cap {
+            exposure_cap.release(signal_id);
+        }
+    }
+
+    /// Open a position for a confirmed buy, or close the quantity a confirmed sell filled
+    /// and report its realized P&L net of the sell's fees
+    async fn track_position(&self, context: &ExecutionContext, result: &ExecutionResult) {
+        let Some((wallet_manager, wallet_id)) = &self.positions else {
+            return;
+        };
+        self.journal_fill(wallet_id, context, result);
+        // Our own fills explain the balance changes the compromise probe sees
+        let sol_delta = fill_sol_delta(
+            &context.action,
+            &context.token_pair,
+            result.executed_quantity,
+            result.executed_price,
+            result.fees,
+        );
+        wallet_manager.record_own_balance_change(wallet_id, sol_delta).await;
+        match context.action {
+            TradeAction::Buy => {
+                let mut position = Position::opening(
+                    context.signal_id.clone(),
+                    wallet_id.clone(),
+                    context.symbol.clone(),
+                    context.strategy_type.clone(),
+                    result.executed_quantity,
+                    result.executed_price,
+                );
+                position.token_pair = Some(context.token_pair);
+                match position.transition(PositionState::Open) {
+                    Ok(()) => wallet_manager.record_open_position(position).await,
+                    Err(e) => warn!("{}", e),
+                }
+            }
+            TradeAction::Sell => {
+                // Positions are priced in the quote currency and fees are paid in SOL;
+                // P&L is reported in SOL
+                let sol_per_quote = self.fee_model.sol_per_quote(&context.symbol);
+                let release = wallet_manager
+                    .release_position_quantity(
+                        wallet_id,
+                        &context.strategy_type,
+                        &context.symbol,
+                        result.executed_quantity,
+                        result.executed_price,
+                        result.fees / sol_per_quote,
+                    )
+                    .await;
+                if release.quantity <= 0.0 {
+                    return;
+                }
+                if let Some(sender) = &self.trade_results {
+                    let trade = SettledTrade {
+                        signal_id: context.signal_id.clone(),
+                        strategy_type: context.strategy_type.clone(),
+                        realized_pnl: (release.quantity * result.executed_price - release.cost_basis) * sol_per_quote
+                            - result.fees,
+                    };
+                    if let Err(e) = sender.send(trade) {
+                        error!("Failed to report realized P&L to risk: {}", e);
+                    }
+                }
+                // A closed position settles the AI decision that opened it
+                if let Some(reporter) = &self.ai_feedback {
+                    for position in release.closed {
+                        let reporter = reporter.clone();
+                        let realized_pnl = position.realized_pnl * sol_per_quote;
+                        tokio::spawn(async move {
+                            if let Err(e) = reporter.report_outcome(&position.position_id, realized_pnl).await {
+                                warn!("🧠 {}", e);
+                            }
+                        });
+                    }
+                }
+            }
+            TradeAction::Hold => return,
+        }
+        if let Some(journal) = &self.position_journal {
+            if let Err(e) = journal.save_snapshot(wallet_manager).await {
+                warn!("{}", e);
+            }
+        }
+    }
+
+    /// Append a confirmed fill to the trade log read back by startup reconciliation
+    fn journal_fill(&self, wallet_id: &str, context: &ExecutionContext, result: &ExecutionResult) {
+        let Some(journal) = &self.position_journal else {
+            return;
+        };
+        if context.action == TradeAction::Hold {
+            return;
+        }
+        let entry = TradeLogEntry {
+            wallet_id: wallet_id.to_string(),
+            symbol: context.symbol.clone(),
+            mint: context.token_pair.base_mint.to_string(),
+            strategy_type: context.strategy_type.clone(),
+            action: context.action.clone(),
+            quantity: result.executed_quantity,
+            price: result.executed_price,
+            timestamp: result.timestamp,
+        };
+        if let Err(e) = journal.record_fill(&entry) {
+            warn!("{}", e);
+        }
+    }
+
+    fn capture(&self, signal_id: &str, artifact: CaptureArtifact) {
+        if let Some(capture) = &self.capture {
+            capture.record(signal_id, artifact);
+        }
+    }
+
+    /// Capture the market context sent to the AI and the decision it came back with
+    fn capture_ai_exchange(&self, signal_id: &str, market_data: &str, hft_result: &HFTExecutionResult) {
+        if self.capture.is_none() {
+            return;
+        }
+        let market_data = serde_json::from_str(market_data)
+            .unwrap_or_else(|_| serde_json::Value::String(market_data.to_string()));
+        self.capture(signal_id, CaptureArtifact::AiRequest { market_data });
+
+        let response = match hft_result {
+            HFTExecutionResult::Executed {
+                latency_ms,
+                ai_confidence,
+                estimated_profit,
+                inference_id,
+                ..
+            } => CaptureArtifact::AiResponse {
+                outcome: "executed".to_string(),
+                latency_ms: *latency_ms,
+                ai_confidence: Some(*ai_confidence),
+                estimated_profit: Some(*estimated_profit),
+                inference_id: *inference_id,
+                detail: None,
+            },
+            HFTExecutionResult::Skipped { reason, latency_ms } => CaptureArtifact::AiResponse {
+                outcome: "skipped".to_string(),
+                latency_ms: *latency_ms,
+                ai_confidence: None,
+                estimated_profit: None,
+                inference_id: None,
+                detail: Some(reason.clone()),
+            },
+            HFTExecutionResult::Failed { error, latency_ms, .. } => CaptureArtifact::AiResponse {
+                outcome: "failed".to_string(),
+                latency_ms: *latency_ms,
+                ai_confidence: None,
+                estimated_profit: None,
+                inference_id: None,
+                detail: Some(error.clone()),
+            },
+        };
+        self.capture(signal_id, response);
+    }
+
+    /// Track the inference behind an executed AI decision, and the profit it estimated,
+    /// until its outcome is reported
+    fn record_ai_decision(
+        &self,
+        signal: &ApprovedSignal,
+        inference_id: Option<uuid::Uuid>,
+        episode_id: Option<uuid::Uuid>,
+        estimated_profit: f64,
+    ) {
+        if let (Some(reporter), Some(inference_id)) = (&self.ai_feedback, inference_id) {
+            if let Ok(mut tracker) = reporter.tracker().lock() {
+                tracker.record(
+                    &signal.original_signal.signal_id,
+                    inference_id,
+                    episode_id,
+                    signal.original_signal.strategy_type.clone(),
+                    estimated_profit,
+                );
+            }
+        }
+    }
+
+    /// Require live trades to reach a commitment level before reporting them Confirmed
+    pub fn with_confirmation_tracker(mut self, confirmation_tracker: ConfirmationTracker) -> Self {
+        self.confirmation_tracker = Some(confirmation_tracker);
+        self
+    }
+
+    /// Count executed and confirmed AI decisions into the AI acceptance funnel
+    pub fn with_ai_funnel(mut self, ai_funnel: SharedAIDecisionFunnel) -> Self {
+        self.ai_funnel = Some(ai_funnel);
+        self
+    }
+
+    /// Confirm paper trades after the confirmation latency of their strategy's commitment level
+    pub fn with_paper_confirmation(mut self, paper_confirmation: PaperConfirmationModel) -> Self {
+        self.paper_confirmation = Some(paper_confirmation);
+        self
+    }
+
+    /// Start a per-(strategy, symbol) cool-down whenever a trade is confirmed
+    pub fn with_execution_cooldowns(mut self, execution_cooldowns: SharedExecutionCooldowns) -> Self {
+        self.execution_cooldowns = Some(execution_cooldowns);
+        self
+    }
+
+    /// Charge each confirmed execution's fees to its strategy's daily fee budget
+    pub fn with_fee_budgets(mut self, fee_budgets: SharedFeeBudgets) -> Self {
+        self.fee_budgets = Some(fee_budgets);
+        self
+    }
+
+    /// Price paper fills with the model's slippage and feed it confirmed live fills
+    pub fn with_slippage_model(mut self, slippage_model: SharedSlippageModel) -> Self {
+        self.slippage_model = Some(slippage_model);
+        self
+    }
+
+    /// Let the HFT engine reject AI decisions priced far from the latest market price
+    pub fn with_market_prices(mut self, market_prices: SharedMarketPrices) -> Self {
+        self.fee_model = self.fee_model.with_market_prices(market_prices.clone());
+        self.hft_engine = self
+            .hft_engine
+            .take()
+            .map(|engine| engine.with_market_prices(market_prices));
+        self
+    }
+
+    /// Swap fee rate and default priority fee used for fee accounting
+    pub fn with_fee_config(mut self, fee_config: FeeConfig) -> Self {
+        let market_prices = self.fee_model.market_prices();
+        self.fee_model = FeeModel::new(fee_config);
+        if let Some(market_prices) = market_prices {
+            self.fee_model = self.fee_model.with_market_prices(market_prices);
+        }
+        self
+    }
+
+    /// Fees for the standard paths, which pay the default priority fee and no tip
+    fn standard_fees(&self, signal: &ApprovedSignal) -> FeeBreakdown {
+        self.fee_model
+            .for_signal(signal, self.fee_model.config().default_priority_fee_lamports, 0)
+    }
+
+    /// Look up mint metadata from the shared cache without blocking execution
+    pub fn with_token_metadata(mut self, token_metadata: SharedTokenMetadataCache) -> Self {
+        self.token_metadata = Some(token_metadata);
+        self
+    }
+
+    /// Send signals with these actions (e.g. "Sell") straight to standard submission,
+    /// skipping the TensorZero call even when HFT mode is enabled
+    pub fn with_standard_path_actions(mut self, actions: &str) -> Result<Self> {
+        for action in actions.split(',').map(str::trim).filter(|a| !a.is_empty()) {
+            let action: TradeAction = serde_json::from_value(serde_json::Value::String(action.to_string()))
+                .map_err(|_| anyhow!("Unknown trade action: {}", action))?;
+            if !self.standard_path_actions.contains(&action) {
+                self.standard_path_actions.push(action);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Whether this signal goes through the AI-enhanced path
+    fn uses_ai_path(&self, action: &TradeAction) -> bool {
+        self.hft_mode_enabled && !self.standard_path_actions.contains(action)
+    }
+
     pub async fn start(&mut self) -> Result<()> {
         if self.hft_mode_enabled {
             info!("🧠 THE OVERMIND PROTOCOL Executor starting in {:?} mode with AI enhancement...", self.trading_mode);
@@ -104,8 +642,16 @@ impl Executor {
         self.is_running = true;
 
         while self.is_running {
-            if let Some(approved_signal) = self.signal_receiver.recv().await {
-                self.execute_signal(approved_signal).await?;
+            tokio::select! {
+                Some(approved_signal) = self.signal_receiver.recv() => {
+                    self.execute_signal(approved_signal).await?;
+                }
+                Some((context, result)) = self.confirmation_receiver.recv() => {
+                    self.finish_execution(&context, result).await;
+                }
+                Some(resubmit) = self.resubmit_receiver.recv() => {
+                    self.resubmit_bundle(resubmit).await;
+                }
             }
         }
 
@@ -118,13 +664,30 @@ impl Executor {
     }
 
     async fn execute_signal(&mut self, signal: ApprovedSignal) -> Result<()> {
-        let signal_id = signal.original_signal.signal_id.clone();
+        let context = ExecutionContext {
+            signal_id: signal.original_signal.signal_id.clone(),
+            strategy_type: signal.original_signal.strategy_type.clone(),
+            symbol: signal.original_signal.symbol.clone(),
+            token_pair: signal.token_pair,
+            tick_received_at: signal.original_signal.tick_received_at,
+            action: signal.original_signal.action.clone(),
+            requested_quantity: signal.approved_quantity,
+            requested_price: signal.original_signal.target_price,
+            allowed_slippage_bps: signal.allowed_slippage_bps,
+        };
+        let signal_id = context.signal_id.clone();
+        let use_ai = self.uses_ai_path(&context.action);
 
-        if self.hft_mode_enabled {
+        if use_ai {
             info!(
                 "🧠 THE OVERMIND PROTOCOL executing AI-enhanced signal: {} with quantity: {}",
                 signal_id, signal.approved_quantity
             );
+        } else if self.hft_mode_enabled {
+            info!(
+                "⚡ Executing {:?} signal: {} with quantity: {} on the standard path (AI bypassed)",
+                context.action, signal_id, signal.approved_quantity
+            );
         } else {
             info!(
                 "🎯 Executing signal: {} with quantity: {}",
@@ -132,21 +695,387 @@ impl Executor {
             );
         }
 
-        let result = match (&self.trading_mode, self.hft_mode_enabled) {
-            (&TradingMode::Paper, false) => self.execute_paper_trade(signal).await?,
-            (&TradingMode::Paper, true) => self.execute_ai_paper_trade(signal).await?,
-            (&TradingMode::Live, false) => self.execute_live_trade(signal).await?,
-            (&TradingMode::Live, true) => self.execute_ai_live_trade(signal).await?,
+        // Signals executed before a restart or by another instance are never re-executed
+        if let Some(idempotency) = &self.idempotency {
+            match idempotency.check(&signal_id).await {
+                IdempotencyCheck::Fresh => {}
+                IdempotencyCheck::Duplicate => {
+                    warn!("🚫 Skipping signal {}: already executed", signal_id);
+                    self.release_exposure(&signal_id);
+                    return Ok(());
+                }
+                IdempotencyCheck::Unavailable(e) => {
+                    error!("🚫 Rejecting signal {}: idempotency store unavailable ({})", signal_id, e);
+                    self.release_exposure(&signal_id);
+                    return Ok(());
+                }
+            }
+        }
+
+        // Register the order; a signal that was already seen is never re-executed
+        if let Err(e) = self.orders.create(&signal_id) {
+            error!("🚫 Rejecting signal {}: {}", signal_id, e);
+            self.release_exposure(&signal_id);
+            return Ok(());
+        }
+        self.orders.transition(&signal_id, OrderState::Submitted)?;
+        self.publish_order_state(&signal_id, OrderState::Submitted);
+
+        if let Some(reason) = self.preflight_rejection(&signal).await {
+            warn!("🚫 Not submitting signal {}: {}", signal_id, reason);
+            let result = ExecutionResult {
+                signal_id: signal_id.clone(),
+                transaction_id: format!("preflight_{}", signal_id),
+                status: ExecutionStatus::Cancelled,
+                executed_quantity: 0.0,
+                executed_price: 0.0,
+                fees: 0.0,
+                fee_breakdown: FeeBreakdown::default(),
+                timestamp: chrono::Utc::now(),
+                error_message: Some(reason),
+                strategy_type: Some(context.strategy_type.clone()),
+                wallet_id: None,
+            };
+            self.finish_execution(&context, result).await;
+            return Ok(());
+        }
+
+        let result = match (&self.trading_mode, use_ai) {
+            (&TradingMode::Paper, false) => self.execute_paper_trade(signal).await,
+            (&TradingMode::Paper, true) => self.execute_ai_paper_trade(signal).await,
+            (&TradingMode::Live, false) => self.execute_live_trade(signal).await,
+            (&TradingMode::Live, true) => self.execute_ai_live_trade(signal).await,
+        };
+        // An execution error is final; never leave its order Submitted
+        let result = match result {
+            Ok(result) => result,
+            Err(e) => {
+                if let Err(lifecycle_error) = self.orders.transition(&signal_id, OrderState::Failed) {
+                    error!("Order lifecycle violation: {}", lifecycle_error);
+                } else {
+                    self.publish_order_state(&signal_id, OrderState::Failed);
+                }
+                self.release_exposure(&signal_id);
+                return Err(e);
+            }
+        };
+
+        // A fill past the signal's slippage allowance is treated as a failed trade
+        let result = self.enforce_slippage_limit(&context, result);
+
+        // Live trades stay Pending until their signature reaches the required commitment
+        let bundle_slot = self.pending_bundle_slot.take();
+        let rebuild = self.pending_bundle_rebuild.take();
+        let result = self.track_confirmation(&context, result, bundle_slot, rebuild, Vec::new());
+        let result = self.simulate_paper_confirmation(&context, result);
+        if matches!(result.status, ExecutionStatus::Confirmed | ExecutionStatus::Pending) {
+            self.record_ai_funnel(&context, AIFunnelStage::Executed);
+        }
+
+        if let Some(tick_received_at) = context.tick_received_at {
+            self.record_tick_to_trade(tick_received_at, &result);
+        }
+        self.finish_execution(&context, result).await;
+
+        Ok(())
+    }
+
+    /// Hand an accepted live signature to a background confirmation watcher and
+    /// report it as Pending; the watcher's verdict arrives via `confirmation_receiver`,
+    /// or via `resubmit_receiver` when an expired or to-be-replaced bundle can be rebuilt.
+    /// `superseded` are earlier submissions of the trade; the first one to land settles it.
+    fn track_confirmation(
+        &self,
+        context: &ExecutionContext,
+        result: ExecutionResult,
+        bundle_slot: Option<BundleSlot>,
+        rebuild: Option<Box<BundleRebuild>>,
+        superseded: Vec<Signature>,
+    ) -> ExecutionResult {
+        let Some(tracker) = self.confirmation_tracker.clone() else {
+            return result;
+        };
+        if !matches!(self.trading_mode, TradingMode::Live)
+            || !matches!(result.status, ExecutionStatus::Confirmed)
+        {
+            return result;
+        }
+        let Ok(signature) = result.transaction_id.parse::<Signature>() else {
+            debug!(
+                "Not tracking confirmation of {}: {} is not a transaction signature",
+                result.signal_id, result.transaction_id
+            );
+            return result;
+        };
+
+        let level = tracker.level_for(&context.strategy_type);
+        let pending = ExecutionResult {
+            status: ExecutionStatus::Pending,
+            ..result.clone()
         };
 
+        let confirmation_sender = self.confirmation_sender.clone();
+        let resubmit_sender = self.resubmit_sender.clone();
+        let pending_replacements = self.pending_replacements.clone();
+        let replace_requested = rebuild
+            .as_ref()
+            .map(|_| pending_replacements.register(&context.signal_id));
+        let context = context.clone();
+        let capture = self.capture.clone();
+        tokio::spawn(async move {
+            let mut result = result;
+            let landing = wait_for_landing(&tracker, signature, &superseded, level);
+            let replace = async {
+                let requested = match replace_requested {
+                    Some(requested) => requested.await.is_ok(),
+                    None => false,
+                };
+                if !requested {
+                    std::future::pending::<()>().await;
+                }
+            };
+            let (landed, outcome) = tokio::select! {
+                landed = landing => landed,
+                _ = replace => {
+                    // Still in flight: the executor resubmits it with escalated fees
+                    if let Some(rebuild) = rebuild {
+                        let _ = resubmit_sender.send(ResubmitBundle {
+                            context,
+                            result,
+                            rebuild,
+                            bundle_slot,
+                            superseded,
+                            reason: ResubmitReason::Replace,
+                        });
+                    }
+                    return;
+                }
+            };
+            pending_replacements.unregister(&context.signal_id);
+
+            if let (ConfirmationOutcome::Expired { age_slots }, Some(rebuild)) = (&outcome, rebuild) {
+                // Still in flight: the executor rebuilds it or fails the trade
+                let _ = resubmit_sender.send(ResubmitBundle {
+                    context,
+                    result,
+                    rebuild,
+                    bundle_slot,
+                    superseded,
+                    reason: ResubmitReason::Expired { age_slots: *age_slots },
+                });
+                return;
+            }
+            if landed != signature {
+                // Only the submission that landed is counted; the replacement settles nothing
+                info!(
+                    "🔁 Superseded submission {} of {} landed before its replacement {}",
+                    landed, context.signal_id, signature
+                );
+                result.transaction_id = landed.to_string();
+            }
+            // The bundle stops counting against the in-flight limit once it has a verdict
+            drop(bundle_slot);
+            if let Some(capture) = &capture {
+                capture.record(
+                    &context.signal_id,
+                    CaptureArtifact::Confirmation {
+                        transaction_id: result.transaction_id.clone(),
+                        outcome: format!("{:?}", outcome),
+                    },
+                );
+            }
+            match outcome {
+                ConfirmationOutcome::Confirmed(reached) => {
+                    debug!("✅ {} reached {:?}", signature, reached);
+                }
+                ConfirmationOutcome::Failed(error) => {
+                    result.status = ExecutionStatus::Failed;
+                    result.error_message = Some(format!("Transaction failed on-chain: {}", error));
+                }
+                ConfirmationOutcome::Dropped => {
+                    result.status = ExecutionStatus::Failed;
+                    result.error_message =
+                        Some(format!("Transaction dropped before reaching {:?}", level));
+                }
+                ConfirmationOutcome::Expired { age_slots } => {
+                    result.status = ExecutionStatus::Failed;
+                    result.error_message =
+                        Some(format!("Transaction not at {:?} after {} slots", level, age_slots));
+                }
+            }
+            result.timestamp = chrono::Utc::now();
+            let _ = confirmation_sender.send((context, result));
+        });
+
+        pending
+    }
+
+    /// Rebuild and resubmit a bundle that expired unconfirmed or was asked to be replaced.
+    /// An expired trade fails once rebuilding is disabled or its retry attempts are used up;
+    /// a replacement that cannot be made leaves the original pending.
+    async fn resubmit_bundle(&mut self, resubmit: ResubmitBundle) {
+        let ResubmitBundle { context, mut result, mut rebuild, bundle_slot, mut superseded, reason } = resubmit;
+        let age_slots = match reason {
+            ResubmitReason::Expired { age_slots } => age_slots,
+            ResubmitReason::Replace => return self.replace_bundle(context, result, rebuild, bundle_slot, superseded).await,
+        };
+        let rebuilt = match self.hft_engine.as_mut() {
+            Some(engine) => engine.rebuild_bundle(&mut rebuild).await,
+            None => Ok(None),
+        };
+
+        let error_message = match rebuilt {
+            Ok(Some(bundle)) => {
+                info!(
+                    "🔁 Bundle {} unconfirmed after {} slots; resubmitted as {} (rebuild {}, tip {} lamports)",
+                    result.transaction_id, age_slots, bundle.bundle_id, rebuild.attempts, rebuild.tip_lamports
+                );
+                // The expired submission and those it replaced can still land late; they are
+                // watched next to the rebuild until its verdict arrives
+                if let Ok(expired) = result.transaction_id.parse::<Signature>() {
+                    superseded.push(expired);
+                }
+                result.transaction_id = bundle.bundle_id;
+                let result = self.track_confirmation(&context, result, bundle_slot, Some(rebuild), superseded);
+                if !matches!(result.status, ExecutionStatus::Pending) {
+                    self.finish_execution(&context, result).await;
+                }
+                return;
+            }
+            Ok(None) => format!(
+                "Bundle not confirmed after {} slots and {} rebuild(s)",
+                age_slots, rebuild.attempts
+            ),
+            Err(e) => format!("Rebuilding expired bundle failed: {}", e),
+        };
+
+        warn!("⌛ {}: {}", context.signal_id, error_message);
+        drop(bundle_slot);
+        result.status = ExecutionStatus::Failed;
+        result.error_message = Some(error_message);
+        result.timestamp = chrono::Utc::now();
+        self.finish_execution(&context, result).await;
+    }
+
+    /// Resubmit a pending bundle with escalated fees and keep watching the original next to
+    /// the replacement. When no replacement can be made the original keeps waiting alone.
+    async fn replace_bundle(
+        &mut self,
+        context: ExecutionContext,
+        mut result: ExecutionResult,
+        mut rebuild: Box<BundleRebuild>,
+        bundle_slot: Option<BundleSlot>,
+        mut superseded: Vec<Signature>,
+    ) {
+        let replaced = match self.hft_engine.as_mut() {
+            Some(engine) => engine.replace_bundle(&mut rebuild).await,
+            None => Ok(None),
+        };
+
+        match replaced {
+            Ok(Some(bundle)) => {
+                info!(
+                    "⛽ Pending bundle {} of {} replaced by {} (priority fee {} lamports, tip {} lamports)",
+                    result.transaction_id, context.signal_id, bundle.bundle_id,
+                    rebuild.signal.action.priority_fee, rebuild.tip_lamports
+                );
+                if let Ok(original) = result.transaction_id.parse::<Signature>() {
+                    superseded.push(original);
+                }
+                result.transaction_id = bundle.bundle_id;
+            }
+            Ok(None) => warn!(
+                "Not replacing {}: replacement disabled or fees already at their ceilings",
+                context.signal_id
+            ),
+            Err(e) => warn!("Replacing {} failed, keeping the original pending: {}", context.signal_id, e),
+        }
+
+        let result = self.track_confirmation(&context, result, bundle_slot, Some(rebuild), superseded);
+        if !matches!(result.status, ExecutionStatus::Pending) {
+            self.finish_execution(&context, result).await;
+        }
+    }
+
+    /// Count an AI decision's trade into the acceptance funnel
+    fn record_ai_funnel(&self, context: &ExecutionContext, stage: AIFunnelStage) {
+        if context.strategy_type != StrategyType::AIDecision {
+            return;
+        }
+        if let Some(ai_funnel) = &self.ai_funnel {
+            ai_funnel.record(stage);
+        }
+    }
+
+    /// Apply an execution result: lifecycle, cool-downs, persistence and logging
+    async fn finish_execution(&mut self, context: &ExecutionContext, result: ExecutionResult) {
+        let signal_id = &context.signal_id;
+
+        let order_state = OrderState::from(&result.status);
+        if order_state != OrderState::Submitted {
+            if let Err(e) = self.orders.transition(signal_id, order_state) {
+                error!("Order lifecycle violation: {}", e);
+            } else {
+                self.publish_order_state(signal_id, order_state);
+            }
+        }
+        self.orders
+            .prune_terminal_before(chrono::Utc::now() - chrono::Duration::hours(1));
+
+        // Recorded before the reservation is released so the cap never sees the buy missing
+        if matches!(result.status, ExecutionStatus::Confirmed) {
+            self.track_position(context, &result).await;
+        }
+        if !matches!(result.status, ExecutionStatus::Pending) {
+            self.release_exposure(signal_id);
+        }
+
+        if matches!(result.status, ExecutionStatus::Confirmed) {
+            self.record_ai_funnel(context, AIFunnelStage::Confirmed);
+            if let Some(cooldowns) = &self.execution_cooldowns {
+                if let Ok(mut cooldowns) = cooldowns.lock() {
+                    cooldowns.record_execution(&context.strategy_type, &context.symbol, context.token_pair);
+                }
+            }
+            if let Some(fee_budgets) = &self.fee_budgets {
+                if let Ok(mut budgets) = fee_budgets.lock() {
+                    budgets.record_fees(&context.strategy_type, &result.fee_breakdown);
+                }
+            }
+        }
+
+        if matches!(self.trading_mode, TradingMode::Live) && matches!(result.status, ExecutionStatus::Confirmed) {
+            if let Some(model) = &self.slippage_model {
+                if let Ok(mut model) = model.lock() {
+                    model.record_live_fill(
+                        &context.symbol,
+                        &context.action,
+                        context.requested_quantity,
+                        context.requested_price,
+                        result.executed_price,
+                    );
+                }
+            }
+        }
+
+        if let Some(capture) = &self.capture {
+            capture.record(signal_id, CaptureArtifact::Execution(result.clone()));
+            if !matches!(result.status, ExecutionStatus::Pending) {
+                capture.complete(signal_id);
+            }
+        }
+
         // Send result to persistence
         if let Err(e) = self.persistence_sender.send(result.clone()) {
             error!("Failed to send execution result to persistence: {}", e);
         }
 
-        self.log_execution_result(&result);
+        #[cfg(feature = "event-bus")]
+        if let Some(event_bus) = &self.event_bus {
+            event_bus.publish(BusEvent::Execution(Box::new(result.clone())));
+        }
 
-        Ok(())
+        self.log_execution_result(&result);
     }
 
     async fn execute_paper_trade(&self, signal: ApprovedSignal) -> Result<ExecutionResult> {
@@ -155,28 +1084,87 @@ impl Executor {
             signal.original_signal.signal_id
         );
 
-        // Simulate execution delay
-        tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
-
+        let executed_price = self.paper_fill_price(&signal);
+        let fee_breakdown = self.standard_fees(&signal);
         let result = ExecutionResult {
             signal_id: signal.original_signal.signal_id,
             transaction_id: format!("paper_{}", uuid::Uuid::new_v4()),
             status: ExecutionStatus::Confirmed,
             executed_quantity: signal.approved_quantity,
-            executed_price: signal.original_signal.target_price,
-            fees: signal.approved_quantity * signal.original_signal.target_price * 0.001, // 0.1% fee
+            executed_price,
+            fees: fee_breakdown.total_sol(),
+            fee_breakdown,
             timestamp: chrono::Utc::now(),
             error_message: None,
+            strategy_type: Some(signal.original_signal.strategy_type.clone()),
+            wallet_id: None,
         };
 
         Ok(result)
     }
 
+    /// Report a paper fill as Pending and confirm it via `confirmation_receiver` once a live
+    /// trade of the same strategy would have reached its commitment
+    fn simulate_paper_confirmation(&self, context: &ExecutionContext, result: ExecutionResult) -> ExecutionResult {
+        if !matches!(self.trading_mode, TradingMode::Paper)
+            || !matches!(result.status, ExecutionStatus::Confirmed)
+        {
+            return result;
+        }
+        let delay = match &self.paper_confirmation {
+            Some(model) => model.delay_for(&context.strategy_type),
+            None => tokio::time::Duration::from_millis(50),
+        };
+        let pending = ExecutionResult {
+            status: ExecutionStatus::Pending,
+            ..result.clone()
+        };
+
+        let confirmation_sender = self.confirmation_sender.clone();
+        let context = context.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(delay).await;
+            let _ = confirmation_sender.send((context, result));
+        });
+        pending
+    }
+
+    /// Simulated fill price; the requested price unless a slippage model is attached
+    fn paper_fill_price(&self, signal: &ApprovedSignal) -> f64 {
+        let requested_price = signal.original_signal.target_price;
+        match self.slippage_model.as_ref().and_then(|m| m.lock().ok()) {
+            Some(model) => model.paper_fill_price(
+                &signal.original_signal.symbol,
+                &signal.original_signal.action,
+                signal.approved_quantity,
+                requested_price,
+            ),
+            None => requested_price,
+        }
+    }
+
+    /// Base mint metadata from the signal, else from the cache; never fetches on the hot path
+    fn base_metadata(&self, signal: &ApprovedSignal) -> Option<TokenMetadata> {
+        signal.base_metadata.clone().or_else(|| {
+            self.token_metadata
+                .as_ref()
+                .and_then(|cache| cache.get_cached(&signal.token_pair.base_mint))
+                .map(|metadata| metadata.as_ref().clone())
+        })
+    }
+
     async fn execute_live_trade(&self, signal: ApprovedSignal) -> Result<ExecutionResult> {
         warn!(
             "🔴 EXECUTING LIVE TRADE - Signal ID: {}",
             signal.original_signal.signal_id
         );
+        match self.base_metadata(&signal) {
+            Some(metadata) => debug!(
+                "🪙 {} ({:?}): {} decimals",
+                metadata.mint, metadata.symbol, metadata.decimals
+            ),
+            None => debug!("🪙 Metadata unavailable for {}", signal.token_pair.base_mint),
+        }
 
         // TODO: Implement actual Solana transaction execution
         // This would involve:
@@ -191,15 +1179,19 @@ impl Executor {
         let success = true; // Always succeed for now
 
         let result = if success {
+            let fee_breakdown = self.standard_fees(&signal);
             ExecutionResult {
                 signal_id: signal.original_signal.signal_id,
                 transaction_id: uuid::Uuid::new_v4().to_string(),
                 status: ExecutionStatus::Confirmed,
                 executed_quantity: signal.approved_quantity,
                 executed_price: signal.original_signal.target_price * 1.005, // Small slippage
-                fees: signal.approved_quantity * signal.original_signal.target_price * 0.0025, // 0.25% fee
+                fees: fee_breakdown.total_sol(),
+                fee_breakdown,
                 timestamp: chrono::Utc::now(),
                 error_message: None,
+                strategy_type: Some(signal.original_signal.strategy_type.clone()),
+                wallet_id: None,
             }
         } else {
             ExecutionResult {
@@ -209,8 +1201,11 @@ impl Executor {
                 executed_quantity: 0.0,
                 executed_price: 0.0,
                 fees: 0.0,
+                fee_breakdown: FeeBreakdown::default(),
                 timestamp: chrono::Utc::now(),
                 error_message: Some("Transaction failed due to network congestion".to_string()),
+                strategy_type: Some(signal.original_signal.strategy_type.clone()),
+                wallet_id: None,
             }
         };
 
@@ -232,36 +1227,47 @@ impl Executor {
             // Get AI decision and execute with TensorZero optimization
             match hft_engine.execute_ai_signal(&market_data).await {
                 Ok(hft_result) => {
+                    self.capture_ai_exchange(&signal.original_signal.signal_id, &market_data, &hft_result);
                     match hft_result {
                         HFTExecutionResult::Executed {
                             signal_id: _,
                             latency_ms,
                             estimated_profit,
                             ai_confidence,
+                            priority_fee_lamports,
+                            tip,
+                            inference_id,
+                            episode_id,
                             ..
                         } => {
                             info!(
-                                "🧠 AI paper trade executed - Latency: {}ms, Confidence: {:.2}, Profit: ${:.2}",
+                                "🧠 AI paper trade executed - Latency: {}ms, Confidence: {:.2}, Profit: {:.6} SOL",
                                 latency_ms, ai_confidence, estimated_profit
                             );
 
                             let signal_id = signal.original_signal.signal_id.clone();
+                            self.record_ai_decision(&signal, inference_id, episode_id, estimated_profit);
+                            let fee_breakdown =
+                                self.fee_model.for_signal(&signal, priority_fee_lamports, tip.tip_lamports);
                             Ok(ExecutionResult {
                                 signal_id: signal_id.clone(),
                                 transaction_id: format!("ai_paper_{}", signal_id),
                                 status: ExecutionStatus::Confirmed,
                                 executed_quantity: signal.approved_quantity,
-                                executed_price: signal.original_signal.target_price,
-                                fees: signal.approved_quantity * signal.original_signal.target_price * 0.0005, // Lower fees with AI
+                                executed_price: self.paper_fill_price(&signal),
+                                fees: fee_breakdown.total_sol(),
+                                fee_breakdown,
                                 timestamp: chrono::Utc::now(),
                                 error_message: None,
+                                strategy_type: Some(signal.original_signal.strategy_type.clone()),
+                                wallet_id: None,
                             })
                         },
                         HFTExecutionResult::Skipped { reason, latency_ms } => {
                             warn!("🧠 AI skipped trade: {} ({}ms)", reason, latency_ms);
                             self.execute_paper_trade(signal).await // Fallback to standard paper trade
                         },
-                        HFTExecutionResult::Failed { error, latency_ms } => {
+                        HFTExecutionResult::Failed { error, latency_ms, .. } => {
                             error!("🧠 AI trade failed: {} ({}ms)", error, latency_ms);
                             self.execute_paper_trade(signal).await // Fallback to standard paper trade
                         },
@@ -293,37 +1299,76 @@ impl Executor {
             // Get AI decision and execute with TensorZero + Jito Bundle optimization
             match hft_engine.execute_ai_signal(&market_data).await {
                 Ok(hft_result) => {
+                    self.capture_ai_exchange(&signal.original_signal.signal_id, &market_data, &hft_result);
                     match hft_result {
                         HFTExecutionResult::Executed {
                             signal_id: _,
                             bundle_id,
                             latency_ms,
                             estimated_profit,
-                            ai_confidence
+                            ai_confidence,
+                            priority_fee_lamports,
+                            tip,
+                            bundle_slot,
+                            rebuild,
+                            inference_id,
+                            episode_id,
                         } => {
                             info!(
-                                "🧠 AI live trade executed - Bundle: {}, Latency: {}ms, Confidence: {:.2}, Profit: ${:.2}",
-                                bundle_id, latency_ms, ai_confidence, estimated_profit
+                                "🧠 AI live trade executed - Bundle: {}, Latency: {}ms, Confidence: {:.2}, Profit: {:.6} SOL, Tip: {} lamports",
+                                bundle_id, latency_ms, ai_confidence, estimated_profit, tip.tip_lamports
                             );
 
+                            self.record_ai_decision(&signal, inference_id, episode_id, estimated_profit);
+                            self.capture(
+                                &signal.original_signal.signal_id,
+                                CaptureArtifact::BundleSubmission {
+                                    bundle_id: bundle_id.clone(),
+                                    tip_lamports: tip.tip_lamports,
+                                    priority_fee_lamports,
+                                },
+                            );
+                            self.pending_bundle_slot = Some(bundle_slot);
+                            self.pending_bundle_rebuild = Some(rebuild);
+                            let fee_breakdown =
+                                self.fee_model.for_signal(&signal, priority_fee_lamports, tip.tip_lamports);
                             Ok(ExecutionResult {
                                 signal_id: signal.original_signal.signal_id,
                                 transaction_id: bundle_id,
                                 status: ExecutionStatus::Confirmed,
                                 executed_quantity: signal.approved_quantity,
                                 executed_price: signal.original_signal.target_price * 1.002, // Minimal slippage with AI
-                                fees: signal.approved_quantity * signal.original_signal.target_price * 0.0015, // Lower fees with Jito
+                                fees: fee_breakdown.total_sol(),
+                                fee_breakdown,
                                 timestamp: chrono::Utc::now(),
                                 error_message: None,
+                                strategy_type: Some(signal.original_signal.strategy_type.clone()),
+                                wallet_id: None,
                             })
                         },
                         HFTExecutionResult::Skipped { reason, latency_ms } => {
                             warn!("🧠 AI skipped live trade: {} ({}ms)", reason, latency_ms);
                             self.execute_live_trade(signal).await // Fallback to standard live trade
                         },
-                        HFTExecutionResult::Failed { error, latency_ms } => {
+                        HFTExecutionResult::Failed { error, latency_ms, rejection } => {
                             error!("🧠 AI live trade failed: {} ({}ms)", error, latency_ms);
-                            self.execute_live_trade(signal).await // Fallback to standard live trade
+                            match rejection {
+                                // The bundle may already have landed; falling back could trade twice
+                                Some(rejection) if !rejection.reason.allows_fallback() => Ok(ExecutionResult {
+                                    signal_id: signal.original_signal.signal_id.clone(),
+                                    transaction_id: format!("jito_rejected_{}", signal.original_signal.signal_id),
+                                    status: ExecutionStatus::Failed,
+                                    executed_quantity: 0.0,
+                                    executed_price: 0.0,
+                                    fees: 0.0,
+                                    fee_breakdown: FeeBreakdown::default(),
+                                    timestamp: chrono::Utc::now(),
+                                    error_message: Some(rejection.to_string()),
+                                    strategy_type: Some(signal.original_signal.strategy_type.clone()),
+                                    wallet_id: None,
+                                }),
+                                _ => self.execute_live_trade(signal).await, // Fallback to standard live trade
+                            }
                         },
                     }
                 },
@@ -338,6 +1383,62 @@ impl Executor {
         }
     }
 
+    /// Execute a multi-leg arbitrage route atomically: either the whole route
+    /// lands in one Jito bundle or nothing does
+    pub async fn execute_arbitrage(
+        &mut self,
+        signal: ArbitrageSignal,
+        quotes: &dyn ArbitrageQuoteSource,
+    ) -> Result<ArbitrageExecutionResult> {
+        info!(
+            "🔁 Executing {}-leg arbitrage {}",
+            signal.legs.len(),
+            signal.signal_id
+        );
+
+        let result = match (&self.trading_mode, self.hft_engine.as_mut()) {
+            (&TradingMode::Live, Some(hft_engine)) => {
+                hft_engine.execute_arbitrage(&signal, quotes).await?
+            }
+            (&TradingMode::Live, None) => {
+                return Err(anyhow!("Live arbitrage requires the HFT engine for atomic bundle execution"));
+            }
+            (&TradingMode::Paper, _) => {
+                let start_time = std::time::Instant::now();
+                signal.validate()?;
+                match signal.revalidate(quotes).await? {
+                    Some(net_edge_bps) => ArbitrageExecutionResult::Executed {
+                        signal_id: signal.signal_id,
+                        bundle_id: format!("paper_{}", uuid::Uuid::new_v4()),
+                        legs: signal.legs.len(),
+                        net_edge_bps,
+                        tip_lamports: 0,
+                        latency_ms: start_time.elapsed().as_millis() as u64,
+                    },
+                    None => ArbitrageExecutionResult::Skipped {
+                        signal_id: signal.signal_id,
+                        reason: "Arbitrage edge closed before submission".to_string(),
+                        latency_ms: start_time.elapsed().as_millis() as u64,
+                    },
+                }
+            }
+        };
+
+        match &result {
+            ArbitrageExecutionResult::Executed { signal_id, bundle_id, legs, net_edge_bps, tip_lamports, latency_ms } => {
+                info!(
+                    "✅ Arbitrage {} submitted as {}-leg bundle {} at {:.1}bps net edge (tip {} lamports, {}ms)",
+                    signal_id, legs, bundle_id, net_edge_bps, tip_lamports, latency_ms
+                );
+            }
+            ArbitrageExecutionResult::Skipped { signal_id, reason, latency_ms } => {
+                warn!("⏭️ Arbitrage {} skipped after {}ms: {}", signal_id, latency_ms, reason);
+            }
+        }
+
+        Ok(result)
+    }
+
     /// Convert ApprovedSignal to market data string for AI analysis
     fn signal_to_market_data(&self, signal: &ApprovedSignal) -> String {
         serde_json::json!({
@@ -345,6 +1446,8 @@ impl Executor {
             "strategy_type": format!("{:?}", signal.original_signal.strategy_type),
             "action": format!("{:?}", signal.original_signal.action),
             "symbol": signal.original_signal.symbol,
+            "base_mint": signal.token_pair.base_mint.to_string(),
+            "quote_mint": signal.token_pair.quote_mint.to_string(),
             "quantity": signal.original_signal.quantity,
             "target_price": signal.original_signal.target_price,
             "approved_quantity": signal.approved_quantity,
@@ -354,6 +1457,96 @@ impl Executor {
         }).to_string()
     }
 
+    /// Why a live trade must not be submitted: its simulation failed, or live trading is
+    /// halted. Simulations still run while halted so a healthy streak can lift the halt.
+    async fn preflight_rejection(&self, signal: &ApprovedSignal) -> Option<String> {
+        if !matches!(self.trading_mode, TradingMode::Live) {
+            return None;
+        }
+        let (simulator, guard) = self.preflight.as_ref()?;
+        let outcome = simulator.simulate(signal).await;
+        let halted = match guard.lock() {
+            Ok(mut guard) => {
+                guard.record(&outcome);
+                guard.is_halted()
+            }
+            Err(_) => true,
+        };
+
+        match outcome {
+            Err(e) => Some(format!("Pre-flight simulation failed: {}", e)),
+            Ok(()) if halted => Some("Live trading halted after repeated simulation failures".to_string()),
+            Ok(()) => None,
+        }
+    }
+
+    /// Fail a confirmed fill whose adverse slippage exceeds the allowance approved for it
+    fn enforce_slippage_limit(&self, context: &ExecutionContext, result: ExecutionResult) -> ExecutionResult {
+        let Some(allowed) = context.allowed_slippage_bps else {
+            return result;
+        };
+        if !matches!(result.status, ExecutionStatus::Confirmed) {
+            return result;
+        }
+        let slippage = realized_slippage_bps(&context.action, context.requested_price, result.executed_price);
+        if slippage <= allowed {
+            return result;
+        }
+
+        warn!(
+            "🚫 Signal {} filled at {:.1} bps slippage, above the {:.1} bps allowed",
+            context.signal_id, slippage, allowed
+        );
+        ExecutionResult {
+            status: ExecutionStatus::Failed,
+            executed_quantity: 0.0,
+            executed_price: 0.0,
+            error_message: Some(format!(
+                "Slippage {:.1} bps exceeds the {:.1} bps allowed",
+                slippage, allowed
+            )),
+            ..result
+        }
+    }
+
+    /// Record latency from market tick ingestion to submission of the resulting trade
+    fn record_tick_to_trade(
+        &self,
+        tick_received_at: chrono::DateTime<chrono::Utc>,
+        result: &ExecutionResult,
+    ) {
+        if !matches!(result.status, ExecutionStatus::Confirmed | ExecutionStatus::Pending) {
+            return;
+        }
+
+        let latency_ms = (result.timestamp - tick_received_at)
+            .num_microseconds()
+            .unwrap_or(i64::MAX) as f64
+            / 1000.0;
+        info!(
+            "⏱️ Tick-to-trade latency for {}: {:.3}ms",
+            result.signal_id, latency_ms
+        );
+
+        if let Some(monitoring) = &self.monitoring {
+            monitoring.record_tick_to_trade(latency_ms);
+        }
+    }
+
+    #[cfg(feature = "event-bus")]
+    fn publish_order_state(&self, signal_id: &str, state: OrderState) {
+        if let Some(event_bus) = &self.event_bus {
+            event_bus.publish(BusEvent::OrderState {
+                signal_id: signal_id.to_string(),
+                state,
+                timestamp: chrono::Utc::now(),
+            });
+        }
+    }
+
+    #[cfg(not(feature = "event-bus"))]
+    fn publish_order_state(&self, _signal_id: &str, _state: OrderState) {}
+
     fn log_execution_result(&self, result: &ExecutionResult) {
         match result.status {
             ExecutionStatus::Confirmed => {
@@ -382,11 +1575,75 @@ impl Executor {
     }
 }
 
+/// Wait for `primary` and every superseded submission of the same trade. The first one
+/// confirmed is returned; otherwise the primary's outcome once all of them are settled.
+async fn wait_for_landing(
+    tracker: &ConfirmationTracker,
+    primary: Signature,
+    superseded: &[Signature],
+    level: ConfirmationLevel,
+) -> (Signature, ConfirmationOutcome) {
+    if superseded.is_empty() {
+        return (primary, tracker.wait_for(&primary, level).await);
+    }
+
+    let mut waits: FuturesUnordered<_> = std::iter::once(primary)
+        .chain(superseded.iter().copied())
+        .map(|signature| async move { (signature, tracker.wait_for(&signature, level).await) })
+        .collect();
+    let mut primary_outcome = None;
+    while let Some((signature, outcome)) = waits.next().await {
+        if matches!(outcome, ConfirmationOutcome::Confirmed(_)) {
+            return (signature, outcome);
+        }
+        if signature == primary {
+            primary_outcome = Some(outcome);
+        }
+    }
+    (primary, primary_outcome.unwrap_or(ConfirmationOutcome::Dropped))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    // use crate::modules::risk::ApprovedSignal;
-    // use crate::modules::strategy::{StrategyType, TradeAction, TradingSignal};
+    use crate::modules::allocation::{RebalancerConfig, StrategyAllocations};
+    use crate::modules::risk::{RiskManager, RiskParameters};
+    use crate::modules::strategy::TradingSignal;
+    use crate::modules::strategy_cooldown::{LosingStreakConfig, StrategyCooldowns};
+    use solana_sdk::pubkey::Pubkey;
+
+    fn approved(signal_id: &str, action: TradeAction, quantity: f64, price: f64) -> ApprovedSignal {
+        ApprovedSignal {
+            original_signal: TradingSignal {
+                signal_id: signal_id.to_string(),
+                symbol: "BONK/SOL".to_string(),
+                action,
+                quantity,
+                target_price: price,
+                confidence: 0.9,
+                timestamp: chrono::Utc::now(),
+                strategy_type: StrategyType::TokenSniping,
+                tick_received_at: None,
+                pool_liquidity: None,
+            },
+            token_pair: TokenPair {
+                base_mint: Pubkey::new_unique(),
+                quote_mint: Pubkey::new_unique(),
+            },
+            approved_quantity: quantity,
+            risk_score: 0.1,
+            approval_timestamp: chrono::Utc::now(),
+            base_metadata: None,
+            allowed_slippage_bps: None,
+        }
+    }
+
+    /// Execute a paper signal and apply its simulated confirmation
+    async fn execute_and_confirm(executor: &mut Executor, signal: ApprovedSignal) {
+        executor.execute_signal(signal).await.unwrap();
+        let (context, result) = executor.confirmation_receiver.recv().await.unwrap();
+        executor.finish_execution(&context, result).await;
+    }
 
     #[tokio::test]
     async fn test_executor_creation() {
@@ -403,4 +1660,280 @@ mod tests {
 
         assert!(!executor.is_running);
     }
+
+    #[tokio::test]
+    async fn test_standard_path_actions_bypass_ai() {
+        let (_signal_tx, signal_rx) = mpsc::unbounded_channel();
+        let (persistence_tx, _persistence_rx) = mpsc::unbounded_channel();
+
+        let executor = Executor::new_with_hft(
+            signal_rx,
+            persistence_tx,
+            TradingMode::Paper,
+            "https://api.mainnet-beta.solana.com".to_string(),
+            "test_key".to_string(),
+            HFTConfig::default(),
+        )
+        .unwrap()
+        .with_standard_path_actions("Sell")
+        .unwrap();
+
+        assert!(executor.uses_ai_path(&TradeAction::Buy));
+        assert!(!executor.uses_ai_path(&TradeAction::Sell));
+        assert!(executor.with_standard_path_actions("Exit").is_err());
+    }
+
+    #[tokio::test]
+    async fn test_confirmed_fills_move_portfolio_exposure() {
+        let (_signal_tx, signal_rx) = mpsc::unbounded_channel();
+        let (persistence_tx, _persistence_rx) = mpsc::unbounded_channel();
+        let wallet_manager = Arc::new(WalletManager::new());
+        let exposure_cap = Arc::new(PortfolioExposureCap::new(10.0, wallet_manager.clone()));
+
+        let mut executor = Executor::new(
+            signal_rx,
+            persistence_tx,
+            TradingMode::Paper,
+            "https://api.mainnet-beta.solana.com".to_string(),
+            "test_key".to_string(),
+        )
+        .with_positions(wallet_manager, "primary".to_string())
+        .with_portfolio_exposure_cap(exposure_cap.clone());
+
+        // Reserved at approval, then replaced by the position the fill opens once confirmed
+        exposure_cap.reserve("buy-1", 4.0);
+        executor.execute_signal(approved("buy-1", TradeAction::Buy, 2.0, 2.0)).await.unwrap();
+        assert_eq!(executor.orders.state("buy-1"), Some(OrderState::Submitted));
+        assert_eq!(exposure_cap.exposure().await.reserved_sol, 4.0);
+        let (context, result) = executor.confirmation_receiver.recv().await.unwrap();
+        executor.finish_execution(&context, result).await;
+        let exposure = exposure_cap.exposure().await;
+        assert_eq!(exposure.reserved_sol, 0.0);
+        assert_eq!(exposure.deployed_sol, 4.0);
+
+        execute_and_confirm(&mut executor, approved("sell-1", TradeAction::Sell, 2.0, 3.0)).await;
+        assert_eq!(exposure_cap.exposure().await.deployed_sol, 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_closing_a_position_reports_the_opening_ai_decision() {
+        use crate::modules::ai_feedback::{AIFeedbackConfig, AIFeedbackTracker};
+        use crate::modules::persistence::PersistenceMessage;
+
+        let (_signal_tx, signal_rx) = mpsc::unbounded_channel();
+        let (persistence_tx, _persistence_rx) = mpsc::unbounded_channel();
+        let (outcome_tx, mut outcome_rx) = mpsc::unbounded_channel();
+        let feedback_config = AIFeedbackConfig {
+            request_timeout: std::time::Duration::from_millis(100),
+            ..AIFeedbackConfig::default()
+        };
+        let tracker = AIFeedbackTracker::shared(feedback_config.max_pending);
+        tracker.lock().unwrap().record(
+            "buy-1",
+            uuid::Uuid::new_v4(),
+            None,
+            StrategyType::TokenSniping,
+            1.0,
+        );
+        let reporter = AIFeedbackReporter::new(feedback_config, tracker, "http://127.0.0.1:9".to_string())
+            .unwrap()
+            .with_persistence(outcome_tx);
+
+        let mut executor = Executor::new(
+            signal_rx,
+            persistence_tx,
+            TradingMode::Paper,
+            "https://api.mainnet-beta.solana.com".to_string(),
+            "test_key".to_string(),
+        )
+        .with_positions(Arc::new(WalletManager::new()), "primary".to_string())
+        .with_ai_feedback(Arc::new(reporter));
+
+        execute_and_confirm(&mut executor, approved("buy-1", TradeAction::Buy, 2.0, 2.0)).await;
+        // A partial sell leaves the position, and the decision, open
+        execute_and_confirm(&mut executor, approved("sell-1", TradeAction::Sell, 1.0, 3.0)).await;
+        execute_and_confirm(&mut executor, approved("sell-2", TradeAction::Sell, 1.0, 4.0)).await;
+
+        let message = tokio::time::timeout(std::time::Duration::from_secs(2), outcome_rx.recv())
+            .await
+            .unwrap()
+            .unwrap();
+        let PersistenceMessage::AIDecisionOutcome(outcome) = message else {
+            panic!("expected an AI decision outcome");
+        };
+        assert_eq!(outcome.signal_id, "buy-1");
+        assert!(!outcome.feedback_sent);
+        // Both sells realized their gain, less the fees each paid
+        assert!((2.9..3.0).contains(&outcome.realized_pnl), "{}", outcome.realized_pnl);
+        assert!(outcome_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_losing_sell_pauses_strategy() {
+        let (_risk_signal_tx, risk_signal_rx) = mpsc::unbounded_channel();
+        let (execution_tx, _execution_rx) = mpsc::unbounded_channel();
+        let strategy_cooldowns = StrategyCooldowns::shared(LosingStreakConfig {
+            max_consecutive_losses: 1,
+            cooldown_secs: 60,
+        });
+        let mut risk_manager = RiskManager::new(
+            risk_signal_rx,
+            execution_tx,
+            RiskParameters {
+                max_position_size: 1000.0,
+                max_daily_loss: 500.0,
+                min_confidence_threshold: 0.6,
+            },
+        )
+        .with_strategy_cooldowns(strategy_cooldowns.clone());
+
+        let (_signal_tx, signal_rx) = mpsc::unbounded_channel();
+        let (persistence_tx, _persistence_rx) = mpsc::unbounded_channel();
+        let mut executor = Executor::new(
+            signal_rx,
+            persistence_tx,
+            TradingMode::Paper,
+            "https://api.mainnet-beta.solana.com".to_string(),
+            "test_key".to_string(),
+        )
+        .with_positions(Arc::new(WalletManager::new()), "primary".to_string())
+        .with_trade_results(risk_manager.trade_result_sender());
+        tokio::spawn(async move { risk_manager.start().await });
+
+        execute_and_confirm(&mut executor, approved("buy-1", TradeAction::Buy, 2.0, 2.0)).await;
+        execute_and_confirm(&mut executor, approved("sell-1", TradeAction::Sell, 2.0, 1.0)).await;
+
+        for _ in 0..50 {
+            if !strategy_cooldowns.lock().unwrap().is_enabled(&StrategyType::TokenSniping) {
+                return;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+        panic!("losing sell did not pause the strategy");
+    }
+
+    #[tokio::test]
+    async fn test_stablecoin_sell_reports_realized_pnl_in_sol() {
+        use crate::modules::symbol_resolver::USDC_MINT;
+        use std::str::FromStr;
+
+        let (_signal_tx, signal_rx) = mpsc::unbounded_channel();
+        let (persistence_tx, _persistence_rx) = mpsc::unbounded_channel();
+        let (trade_tx, mut trade_rx) = mpsc::unbounded_channel();
+        let market_prices = crate::modules::market_prices::MarketPriceCache::shared(chrono::Duration::seconds(30));
+        market_prices.record("SOL/USDC", 100.0, chrono::Utc::now());
+        let mut executor = Executor::new(
+            signal_rx,
+            persistence_tx,
+            TradingMode::Paper,
+            "https://api.mainnet-beta.solana.com".to_string(),
+            "test_key".to_string(),
+        )
+        .with_market_prices(market_prices)
+        .with_positions(Arc::new(WalletManager::new()), "primary".to_string())
+        .with_trade_results(trade_tx);
+
+        let usdc = |mut signal: ApprovedSignal| {
+            signal.original_signal.symbol = "JUP/USDC".to_string();
+            signal.token_pair.quote_mint = Pubkey::from_str(USDC_MINT).unwrap();
+            signal
+        };
+        execute_and_confirm(&mut executor, usdc(approved("buy-1", TradeAction::Buy, 10.0, 1.0))).await;
+        execute_and_confirm(&mut executor, usdc(approved("sell-1", TradeAction::Sell, 10.0, 2.0))).await;
+
+        // 10 USDC of gain is 0.1 SOL, less the sell's 0.05 USDC swap fee and 15_000 lamports
+        let trade = trade_rx.recv().await.unwrap();
+        assert!((trade.realized_pnl - (0.1 - 0.0005 - 0.000015)).abs() < 1e-9);
+    }
+
+    #[tokio::test]
+    async fn test_late_landing_of_a_superseded_submission_settles_the_trade() {
+        use crate::modules::confirmation::{ConfirmationConfig, SignatureStatus, SignatureStatusSource};
+        use futures::future::BoxFuture;
+
+        /// Only `landed` ever reaches the cluster
+        struct LandsOnly(Signature);
+
+        impl SignatureStatusSource for LandsOnly {
+            fn signature_status<'a>(
+                &'a self,
+                signature: &'a Signature,
+            ) -> BoxFuture<'a, Result<Option<SignatureStatus>>> {
+                let status = (*signature == self.0).then_some(SignatureStatus {
+                    level: ConfirmationLevel::Confirmed,
+                    error: None,
+                });
+                Box::pin(async move { Ok(status) })
+            }
+        }
+
+        let (expired, rebuild) = (Signature::new_unique(), Signature::new_unique());
+        let tracker = ConfirmationTracker::new(
+            ConfirmationConfig {
+                poll_interval: std::time::Duration::from_millis(1),
+                timeout: std::time::Duration::from_millis(50),
+                ..ConfirmationConfig::default()
+            },
+            Arc::new(LandsOnly(expired)),
+        );
+
+        let (landed, outcome) =
+            wait_for_landing(&tracker, rebuild, &[expired], ConfirmationLevel::Confirmed).await;
+        assert_eq!(landed, expired);
+        assert_eq!(outcome, ConfirmationOutcome::Confirmed(ConfirmationLevel::Confirmed));
+    }
+
+    #[tokio::test]
+    async fn test_realized_pnl_rebalances_allocations() {
+        let (_risk_signal_tx, risk_signal_rx) = mpsc::unbounded_channel();
+        let (execution_tx, _execution_rx) = mpsc::unbounded_channel();
+        let allocations = StrategyAllocations::shared(RebalancerConfig {
+            min_trades: 2,
+            ..RebalancerConfig::default()
+        });
+        let mut risk_manager = RiskManager::new(
+            risk_signal_rx,
+            execution_tx,
+            RiskParameters {
+                max_position_size: 1000.0,
+                max_daily_loss: 500.0,
+                min_confidence_threshold: 0.6,
+            },
+        )
+        .with_strategy_allocations(allocations.clone());
+
+        let (_signal_tx, signal_rx) = mpsc::unbounded_channel();
+        let (persistence_tx, _persistence_rx) = mpsc::unbounded_channel();
+        let mut executor = Executor::new(
+            signal_rx,
+            persistence_tx,
+            TradingMode::Paper,
+            "https://api.mainnet-beta.solana.com".to_string(),
+            "test_key".to_string(),
+        )
+        .with_positions(Arc::new(WalletManager::new()), "primary".to_string())
+        .with_trade_results(risk_manager.trade_result_sender());
+        tokio::spawn(async move { risk_manager.start().await });
+
+        for round in 0..2 {
+            let buy = approved(&format!("buy-{}", round), TradeAction::Buy, 2.0, 2.0);
+            let sell = approved(&format!("sell-{}", round), TradeAction::Sell, 2.0, 1.0);
+            execute_and_confirm(&mut executor, buy).await;
+            execute_and_confirm(&mut executor, sell).await;
+        }
+
+        for _ in 0..50 {
+            {
+                let mut allocations = allocations.lock().unwrap();
+                if allocations.pnl_totals().first().is_some_and(|t| t.trades == 2) {
+                    assert!(!allocations.rebalance().is_empty());
+                    assert!(allocations.multiplier(&StrategyType::TokenSniping) < 1.0);
+                    return;
+                }
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+        panic!("realized P&L never reached the allocations");
+    }
 }
+
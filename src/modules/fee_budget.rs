@@ -0,0 +1,233 @@
+// Fee Budget Module
+// Per-strategy daily budget for fees and tips paid, tracked from each execution's fee
+// breakdown, so a strategy such as DAMM fee farming cannot spend more SOL on fees than intended
+
+use crate::modules::fees::FeeBreakdown;
+use crate::modules::strategy::StrategyType;
+use crate::modules::strategy_cooldown::parse_strategy_type;
+use anyhow::{anyhow, Context, Result};
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::warn;
+
+pub const FEE_BUDGET_EXHAUSTED: &str = "fee budget exhausted";
+
+#[derive(Debug, Clone, Default)]
+pub struct FeeBudgetConfig {
+    /// Fees in SOL a strategy may pay per UTC day (0 leaves it unbudgeted)
+    pub daily_budget_sol: f64,
+    /// Overrides of `daily_budget_sol` for individual strategies
+    pub per_strategy: HashMap<StrategyType, f64>,
+}
+
+#[allow(dead_code)]
+impl FeeBudgetConfig {
+    /// Parse per-strategy budgets such as "MeteoraDAMM=0.05,TokenSniping=0.5"
+    pub fn with_strategy_budgets(mut self, spec: &str) -> Result<Self> {
+        for entry in spec.split(',').filter(|e| !e.trim().is_empty()) {
+            let (strategy, budget) = entry
+                .split_once('=')
+                .with_context(|| format!("Invalid fee budget entry: {}", entry))?;
+            let budget: f64 = budget
+                .trim()
+                .parse()
+                .with_context(|| format!("Invalid fee budget: {}", entry))?;
+            if !budget.is_finite() || budget < 0.0 {
+                return Err(anyhow!("Invalid fee budget: {}", entry));
+            }
+            self.per_strategy
+                .insert(parse_strategy_type(strategy.trim())?, budget);
+        }
+        Ok(self)
+    }
+
+    /// The strategy's daily budget in SOL; None when it is unbudgeted
+    pub fn budget_for(&self, strategy: &StrategyType) -> Option<f64> {
+        let budget = self
+            .per_strategy
+            .get(strategy)
+            .copied()
+            .unwrap_or(self.daily_budget_sol);
+        (budget > 0.0).then_some(budget)
+    }
+}
+
+/// A strategy's fee spend for the current day, exposed through the HTTP API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FeeBudgetStatus {
+    pub strategy_type: StrategyType,
+    pub day: NaiveDate,
+    pub budget_sol: Option<f64>,
+    pub spent_sol: f64,
+    /// None for unbudgeted strategies
+    pub remaining_sol: Option<f64>,
+    pub exhausted: bool,
+}
+
+#[derive(Debug)]
+pub struct FeeBudgets {
+    config: FeeBudgetConfig,
+    day: NaiveDate,
+    /// Lamports paid per strategy since the start of `day`
+    spent: HashMap<StrategyType, u64>,
+}
+
+pub type SharedFeeBudgets = Arc<Mutex<FeeBudgets>>;
+
+#[allow(dead_code)]
+impl FeeBudgets {
+    pub fn new(config: FeeBudgetConfig) -> Self {
+        Self {
+            config,
+            day: Utc::now().date_naive(),
+            spent: HashMap::new(),
+        }
+    }
+
+    pub fn shared(config: FeeBudgetConfig) -> SharedFeeBudgets {
+        Arc::new(Mutex::new(Self::new(config)))
+    }
+
+    /// Budgets reset at UTC midnight
+    fn roll_day(&mut self, today: NaiveDate) {
+        if today != self.day {
+            self.day = today;
+            self.spent.clear();
+        }
+    }
+
+    /// Add the priority fees, tips, base and swap fees an execution paid
+    pub fn record_fees(&mut self, strategy: &StrategyType, fees: &FeeBreakdown) {
+        self.record_fees_on(strategy, fees, Utc::now().date_naive());
+    }
+
+    fn record_fees_on(&mut self, strategy: &StrategyType, fees: &FeeBreakdown, day: NaiveDate) {
+        let was_within = !self.exhausted_on(strategy, day);
+        *self.spent.entry(strategy.clone()).or_default() += fees.total_lamports();
+
+        if was_within && self.exhausted_on(strategy, day) {
+            warn!(
+                "⛽ Strategy {:?} exhausted its daily fee budget ({:.6} SOL paid), rejecting its trades until UTC midnight",
+                strategy,
+                self.spent_sol(strategy)
+            );
+        }
+    }
+
+    /// Whether `strategy` paid at least its daily budget in fees today
+    pub fn is_exhausted(&mut self, strategy: &StrategyType) -> bool {
+        self.exhausted_on(strategy, Utc::now().date_naive())
+    }
+
+    fn exhausted_on(&mut self, strategy: &StrategyType, day: NaiveDate) -> bool {
+        self.roll_day(day);
+        self.config
+            .budget_for(strategy)
+            .is_some_and(|budget| self.spent_sol(strategy) >= budget)
+    }
+
+    pub fn spent_sol(&self, strategy: &StrategyType) -> f64 {
+        lamports_to_sol(self.spent.get(strategy).copied().unwrap_or(0))
+    }
+
+    /// Fee budget left today; None for unbudgeted strategies
+    pub fn remaining_sol(&mut self, strategy: &StrategyType) -> Option<f64> {
+        self.roll_day(Utc::now().date_naive());
+        self.config
+            .budget_for(strategy)
+            .map(|budget| (budget - self.spent_sol(strategy)).max(0.0))
+    }
+
+    /// Every budgeted strategy plus any strategy that paid fees today
+    pub fn status(&mut self) -> Vec<FeeBudgetStatus> {
+        self.roll_day(Utc::now().date_naive());
+        let mut strategies: Vec<StrategyType> = self
+            .config
+            .per_strategy
+            .keys()
+            .chain(self.spent.keys())
+            .cloned()
+            .collect();
+        strategies.sort_by_key(|s| format!("{:?}", s));
+        strategies.dedup();
+
+        strategies
+            .into_iter()
+            .map(|strategy| {
+                let budget_sol = self.config.budget_for(&strategy);
+                let spent_sol = self.spent_sol(&strategy);
+                FeeBudgetStatus {
+                    day: self.day,
+                    budget_sol,
+                    spent_sol,
+                    remaining_sol: budget_sol.map(|budget| (budget - spent_sol).max(0.0)),
+                    exhausted: budget_sol.is_some_and(|budget| spent_sol >= budget),
+                    strategy_type: strategy,
+                }
+            })
+            .collect()
+    }
+}
+
+fn lamports_to_sol(lamports: u64) -> f64 {
+    lamports as f64 / LAMPORTS_PER_SOL as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::fees::FeeComponent;
+
+    fn fees(priority_lamports: u64, tip_lamports: u64) -> FeeBreakdown {
+        FeeBreakdown {
+            priority_fee: FeeComponent {
+                lamports: priority_lamports,
+                ..FeeComponent::default()
+            },
+            jito_tip: FeeComponent {
+                lamports: tip_lamports,
+                ..FeeComponent::default()
+            },
+            ..FeeBreakdown::default()
+        }
+    }
+
+    #[test]
+    fn test_budget_exhausts_per_strategy_and_resets_daily() {
+        let config = FeeBudgetConfig::default()
+            .with_strategy_budgets("MeteoraDAMM=0.001")
+            .unwrap();
+        let mut budgets = FeeBudgets::new(config);
+        let damm = StrategyType::MeteoraDAMM;
+        let today = Utc::now().date_naive();
+
+        budgets.record_fees_on(&damm, &fees(400_000, 100_000), today);
+        assert!(!budgets.exhausted_on(&damm, today));
+        assert!((budgets.remaining_sol(&damm).unwrap() - 0.0005).abs() < 1e-12);
+        budgets.record_fees_on(&damm, &fees(0, 500_000), today);
+        assert!(budgets.exhausted_on(&damm, today));
+
+        // Unbudgeted strategies are tracked but never exhausted
+        let sniping = StrategyType::TokenSniping;
+        budgets.record_fees_on(&sniping, &fees(5_000_000_000, 0), today);
+        assert!(!budgets.exhausted_on(&sniping, today));
+        assert_eq!(budgets.remaining_sol(&sniping), None);
+
+        let status = budgets.status();
+        assert_eq!(status.len(), 2);
+        assert!(status
+            .iter()
+            .any(|s| s.strategy_type == damm && s.exhausted && s.remaining_sol == Some(0.0)));
+
+        let tomorrow = today.succ_opt().unwrap();
+        assert!(!budgets.exhausted_on(&damm, tomorrow));
+        assert_eq!(budgets.spent_sol(&damm), 0.0);
+
+        assert!(FeeBudgetConfig::default()
+            .with_strategy_budgets("MeteoraDAMM=-1")
+            .is_err());
+    }
+}
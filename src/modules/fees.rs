@@ -0,0 +1,221 @@
+// Fee Accounting Module
+// Itemizes execution costs in lamports with SOL and USD equivalents instead of a flat notional rate
+
+use crate::modules::market_prices::SharedMarketPrices;
+use crate::modules::risk::ApprovedSignal;
+use crate::modules::symbol_resolver::{USDC_MINT, USDT_MINT};
+use serde::{Deserialize, Serialize};
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+
+/// Solana base fee charged per transaction signature
+pub const BASE_FEE_LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// One cost item; `usd` is None while no SOL/USD price is known
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct FeeComponent {
+    pub lamports: u64,
+    pub sol: f64,
+    pub usd: Option<f64>,
+}
+
+/// Costs of one execution, kept separate so cost analysis can attribute them
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct FeeBreakdown {
+    pub priority_fee: FeeComponent,
+    pub jito_tip: FeeComponent,
+    pub network_fee: FeeComponent,
+    /// Venue swap fee, converted from the quote currency
+    pub protocol_fee: FeeComponent,
+}
+
+#[allow(dead_code)]
+impl FeeBreakdown {
+    fn components(&self) -> [FeeComponent; 4] {
+        [
+            self.priority_fee,
+            self.jito_tip,
+            self.network_fee,
+            self.protocol_fee,
+        ]
+    }
+
+    pub fn total_lamports(&self) -> u64 {
+        self.components().iter().map(|c| c.lamports).sum()
+    }
+
+    pub fn total_sol(&self) -> f64 {
+        self.components().iter().map(|c| c.sol).sum()
+    }
+
+    pub fn total_usd(&self) -> Option<f64> {
+        self.components().iter().map(|c| c.usd).sum()
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FeeConfig {
+    /// Venue swap fee applied to the trade notional
+    pub swap_fee_bps: f64,
+    /// Priority fee assumed on paths that do not choose one per trade
+    pub default_priority_fee_lamports: u64,
+    pub base_fee_lamports_per_signature: u64,
+    /// Market price symbol used for SOL/USD conversion
+    pub sol_usd_symbol: String,
+}
+
+impl Default for FeeConfig {
+    fn default() -> Self {
+        Self {
+            swap_fee_bps: 25.0,
+            default_priority_fee_lamports: 10_000,
+            base_fee_lamports_per_signature: BASE_FEE_LAMPORTS_PER_SIGNATURE,
+            sol_usd_symbol: "SOL/USDC".to_string(),
+        }
+    }
+}
+
+/// Builds fee breakdowns, converting through the latest observed SOL/USD price
+#[derive(Clone, Default)]
+pub struct FeeModel {
+    config: FeeConfig,
+    market_prices: Option<SharedMarketPrices>,
+}
+
+#[allow(dead_code)]
+impl FeeModel {
+    pub fn new(config: FeeConfig) -> Self {
+        Self {
+            config,
+            market_prices: None,
+        }
+    }
+
+    pub fn with_market_prices(mut self, market_prices: SharedMarketPrices) -> Self {
+        self.market_prices = Some(market_prices);
+        self
+    }
+
+    pub fn config(&self) -> &FeeConfig {
+        &self.config
+    }
+
+    pub fn market_prices(&self) -> Option<SharedMarketPrices> {
+        self.market_prices.clone()
+    }
+
+    pub fn sol_usd_price(&self) -> Option<f64> {
+        self.market_prices
+            .as_ref()
+            .and_then(|prices| prices.latest(&self.config.sol_usd_symbol))
+    }
+
+    /// One unit of `symbol`'s quote currency in SOL; 1.0 while the quote's SOL price is unknown
+    pub fn sol_per_quote(&self, symbol: &str) -> f64 {
+        self.market_prices
+            .as_ref()
+            .and_then(|prices| prices.quote_to_sol(symbol, 1.0))
+            .unwrap_or(1.0)
+    }
+
+    /// Costs of executing `signal` in one transaction at its target price
+    pub fn for_signal(
+        &self,
+        signal: &ApprovedSignal,
+        priority_fee_lamports: u64,
+        jito_tip_lamports: u64,
+    ) -> FeeBreakdown {
+        self.breakdown(
+            &signal.token_pair.quote_mint,
+            signal.approved_quantity * signal.original_signal.target_price,
+            priority_fee_lamports,
+            jito_tip_lamports,
+            1,
+        )
+    }
+
+    /// Itemized costs of a trade of `notional` quote units. Stablecoin quotes are
+    /// converted through SOL/USD (their SOL side stays zero until a price is seen);
+    /// any other quote is treated as SOL-denominated.
+    pub fn breakdown(
+        &self,
+        quote_mint: &Pubkey,
+        notional: f64,
+        priority_fee_lamports: u64,
+        jito_tip_lamports: u64,
+        signatures: u64,
+    ) -> FeeBreakdown {
+        let sol_usd = self.sol_usd_price();
+        let swap_fee = notional.max(0.0) * self.config.swap_fee_bps.max(0.0) / 10_000.0;
+        let quote = quote_mint.to_string();
+
+        let protocol_fee = if quote == USDC_MINT || quote == USDT_MINT {
+            let sol = sol_usd.map(|price| swap_fee / price).unwrap_or(0.0);
+            FeeComponent {
+                lamports: sol_to_lamports(sol),
+                sol,
+                usd: Some(swap_fee),
+            }
+        } else {
+            FeeComponent {
+                lamports: sol_to_lamports(swap_fee),
+                sol: swap_fee,
+                usd: sol_usd.map(|price| swap_fee * price),
+            }
+        };
+
+        FeeBreakdown {
+            priority_fee: lamport_component(priority_fee_lamports, sol_usd),
+            jito_tip: lamport_component(jito_tip_lamports, sol_usd),
+            network_fee: lamport_component(
+                self.config.base_fee_lamports_per_signature * signatures,
+                sol_usd,
+            ),
+            protocol_fee,
+        }
+    }
+}
+
+fn sol_to_lamports(sol: f64) -> u64 {
+    (sol * LAMPORTS_PER_SOL as f64).round() as u64
+}
+
+fn lamport_component(lamports: u64, sol_usd: Option<f64>) -> FeeComponent {
+    let sol = lamports as f64 / LAMPORTS_PER_SOL as f64;
+    FeeComponent {
+        lamports,
+        sol,
+        usd: sol_usd.map(|price| sol * price),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::market_prices::MarketPriceCache;
+    use crate::modules::symbol_resolver::SOL_MINT;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_breakdown_converts_each_component() {
+        let prices = MarketPriceCache::shared(chrono::Duration::seconds(30));
+        prices.record("SOL/USDC", 100.0, chrono::Utc::now());
+        let model = FeeModel::new(FeeConfig::default()).with_market_prices(prices);
+
+        // 1_000 USDC at 25 bps is 2.50 USD, i.e. 0.025 SOL
+        let usdc = Pubkey::from_str(USDC_MINT).unwrap();
+        let fees = model.breakdown(&usdc, 1_000.0, 10_000, 50_000, 1);
+        assert_eq!(fees.protocol_fee.usd, Some(2.5));
+        assert_eq!(fees.protocol_fee.lamports, 25_000_000);
+        assert_eq!(fees.jito_tip.lamports, 50_000);
+        assert_eq!(fees.network_fee.lamports, BASE_FEE_LAMPORTS_PER_SIGNATURE);
+        assert_eq!(fees.total_lamports(), 25_065_000);
+        assert!((fees.total_usd().unwrap() - 2.5065).abs() < 1e-9);
+
+        // Without a SOL price the USD side is unknown, not guessed
+        let sol = Pubkey::from_str(SOL_MINT).unwrap();
+        let fees = FeeModel::default().breakdown(&sol, 2.0, 0, 0, 1);
+        assert_eq!(fees.protocol_fee.sol, 0.005);
+        assert_eq!(fees.total_usd(), None);
+    }
+}
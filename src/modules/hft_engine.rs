@@ -1,18 +1,32 @@
 // THE OVERMIND PROTOCOL - HFT Engine Module
 // Ultra-low latency execution with TensorZero optimization and Jito Bundle execution
 
-use anyhow::{Result, Context};
+use anyhow::{anyhow, Result, Context};
 use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
 use tokio::time::timeout;
+use tracing::{debug, warn};
 use uuid::Uuid;
 
+use crate::modules::arbitrage::{ArbitrageExecutionResult, ArbitrageQuoteSource, ArbitrageSignal};
+use crate::modules::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState};
+use crate::modules::ewma::{Ewma, DEFAULT_EWMA_HALF_LIFE_SAMPLES};
+use crate::modules::jito_client::{JitoBackend, JitoClient, JitoRejectReason, JitoRejection, LocalJitoClient};
+use crate::modules::jito_tip_accounts::TipAccountSelector;
+use crate::modules::latency_budget::LatencyBudget;
+use crate::modules::market_prices::SharedMarketPrices;
+
 // HTTP client for TensorZero Gateway
 use reqwest::Client;
 
 // Jito SDK for bundle execution
 use jito_sdk_rust::JitoJsonRpcSDK;
 // Use Solana SDK types for transactions
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transaction::Transaction;
 
 /// THE OVERMIND PROTOCOL HFT Engine
@@ -20,15 +34,32 @@ use solana_sdk::transaction::Transaction;
 pub struct OvermindHFTEngine {
     /// TensorZero Gateway HTTP client
     tensorzero_client: TensorZeroClient,
-    /// Jito SDK for bundle execution
-    jito_sdk: JitoJsonRpcSDK,
-    /// Performance metrics
-    metrics: HFTMetrics,
+    /// Bundle submission: the Jito SDK, or a local client without network access
+    jito_client: Arc<dyn JitoClient>,
+    /// Performance metrics, shareable with monitoring
+    metrics: SharedHFTMetrics,
+    /// Short-circuits AI calls while TensorZero is failing
+    tensorzero_breaker: CircuitBreaker,
+    /// Skips bundle submission while the block engine keeps rejecting for endpoint reasons
+    jito_breaker: CircuitBreaker,
+    /// Bounds concurrent TensorZero inferences; shareable across engines
+    inference_limiter: Arc<Semaphore>,
+    /// Bounds Jito bundles outstanding until confirmed; shareable across engines
+    bundle_limiter: Arc<Semaphore>,
+    /// Gauge of bundles currently holding a slot
+    bundles_in_flight: Arc<AtomicU64>,
+    /// Latest observed market prices, used to catch off-market AI decisions
+    market_prices: Option<SharedMarketPrices>,
+    /// Observed AI and bundle leg timings that decide the latency split
+    latency_budget: LatencyBudget,
+    /// Picks the tip account each bundle pays
+    tip_accounts: Option<TipAccountSelector>,
     /// Configuration
     config: HFTConfig,
 }
 
 /// TensorZero Gateway HTTP client
+#[derive(Debug)]
 pub struct TensorZeroClient {
     client: Client,
     gateway_url: String,
@@ -40,38 +71,153 @@ pub struct HFTConfig {
     pub tensorzero_gateway_url: String,
     pub jito_endpoint: String,
     pub max_execution_latency_ms: u64,
+    /// Where bundles are sent; `Local` accepts them without contacting Jito
+    pub jito_backend: JitoBackend,
     pub max_bundle_size: usize,
+    /// Times an expired bundle is rebuilt and resubmitted before the trade fails
     pub retry_attempts: u32,
     pub ai_confidence_threshold: f64,
+    /// Hard ceiling for any computed or AI-specified priority fee (lamports)
+    pub max_priority_fee_lamports: u64,
+    /// Skip trades that need more than the ceiling instead of clamping the fee
+    pub skip_on_priority_fee_cap: bool,
+    /// Jito tip paid by the least convincing trades (lamports)
+    pub min_jito_tip_lamports: u64,
+    /// Jito tip paid by the highest-conviction, highest-edge trades (lamports)
+    pub max_jito_tip_lamports: u64,
+    /// Upper bound on the tip as a fraction of estimated profit (0.0 - 1.0)
+    pub max_tip_profit_fraction: f64,
+    /// Sanity model for confidence vs. estimated profit
+    pub ai_consistency: AIConsistencyRule,
+    /// When to stop calling TensorZero and fall back to standard execution
+    pub tensorzero_breaker: CircuitBreakerConfig,
+    /// When to stop submitting bundles; only rate limiting and unknown errors count
+    pub jito_breaker: CircuitBreakerConfig,
+    /// TensorZero inferences allowed in flight at once
+    pub max_concurrent_inferences: usize,
+    /// How long a request waits for an inference slot before skipping
+    pub inference_queue_timeout_ms: u64,
+    /// Jito bundles allowed outstanding (submitted, not yet confirmed) at once
+    pub max_bundles_in_flight: usize,
+    /// How long an execution waits for a bundle slot before skipping
+    pub bundle_queue_timeout_ms: u64,
+    /// Skip AI decisions whose target price deviates further than this from the
+    /// latest market price for the symbol (basis points, 0 disables)
+    pub max_ai_price_deviation_bps: f64,
+    /// Half-life, in samples, of the reported average latencies
+    pub latency_ewma_half_life: u32,
+    /// Rebuild bundles that expire unconfirmed instead of failing the trade
+    pub rebuild_expired_bundles: bool,
+    /// Tip multiplier applied on each rebuild, capped at `max_jito_tip_lamports` (1.0 keeps the tip)
+    pub rebuild_tip_multiplier: f64,
+    /// Allow replacing a pending bundle with a fee-bumped rebuild on request
+    pub replace_pending_bundles: bool,
+    /// Priority fee and tip multiplier applied on each replacement, capped at
+    /// `max_priority_fee_lamports` and `max_jito_tip_lamports`
+    pub replace_fee_multiplier: f64,
+    /// Split `max_execution_latency_ms` by observed leg timings instead of a fixed 1/3 : 2/3
+    pub adaptive_latency_budget: bool,
+    /// Smallest share of the latency budget either leg is given when adapting (0.0 - 0.5)
+    pub min_latency_leg_fraction: f64,
+    /// Validated tip accounts and how each bundle picks one; None leaves tips unaddressed
+    pub tip_accounts: Option<TipAccountSelector>,
+}
+
+/// Flags AI decisions whose confidence and estimated profit disagree,
+/// which usually indicates a hallucinated or stale decision
+#[derive(Debug, Clone)]
+pub struct AIConsistencyRule {
+    pub enabled: bool,
+    /// Confidence above which a near-zero profit is suspicious
+    pub high_confidence: f64,
+    /// Minimum estimated profit (SOL) expected from a high-confidence decision
+    pub min_profit_at_high_confidence: f64,
+    /// Confidence below which a large profit claim is suspicious
+    pub low_confidence: f64,
+    /// Maximum plausible estimated profit (SOL) for a low-confidence decision
+    pub max_profit_at_low_confidence: f64,
 }
 
 /// Performance metrics for THE OVERMIND PROTOCOL
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct HFTMetrics {
     pub total_executions: u64,
     pub successful_executions: u64,
     pub failed_executions: u64,
+    /// Exponentially-weighted average, so it reflects recent executions
     pub avg_latency_ms: f64,
     pub ai_decisions_made: u64,
     pub bundles_submitted: u64,
+    pub ai_inconsistent_rejections: u64,
+    pub ai_calls_short_circuited: u64,
+    pub arbitrage_edge_closed_skips: u64,
+    pub inference_concurrency_skips: u64,
+    pub bundle_concurrency_skips: u64,
+    pub ai_price_off_market_skips: u64,
+    /// Exponentially-weighted average time spent waiting for an inference slot
+    pub avg_inference_wait_ms: f64,
+    pub inferences_admitted: u64,
+    /// Expired bundles rebuilt with a fresh transaction and resubmitted
+    pub bundles_rebuilt: u64,
+    /// Pending bundles replaced on request with escalated fees
+    pub bundles_replaced: u64,
+    /// Bundles the block engine rejected, by reason
+    pub jito_rejections: std::collections::HashMap<JitoRejectReason, u64>,
+    pub bundles_short_circuited: u64,
+    /// Latency budget given to each leg of the most recent AI execution
+    pub ai_leg_budget_ms: u64,
+    pub bundle_leg_budget_ms: u64,
+    /// Exponentially-weighted average time each leg actually took
+    pub avg_ai_leg_ms: f64,
+    pub avg_bundle_leg_ms: f64,
+    latency_ewma: Ewma,
+    inference_wait_ewma: Ewma,
+}
+
+pub type SharedHFTMetrics = Arc<Mutex<HFTMetrics>>;
+
+impl HFTMetrics {
+    pub fn shared(latency_ewma_half_life: u32) -> SharedHFTMetrics {
+        Arc::new(Mutex::new(Self {
+            latency_ewma: Ewma::new(latency_ewma_half_life),
+            inference_wait_ewma: Ewma::new(latency_ewma_half_life),
+            ..Self::default()
+        }))
+    }
 }
 
 /// AI-enhanced trading signal from TensorZero
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AITradingSignal {
     pub signal_id: Uuid,
     pub signal_type: String,
     pub confidence: f64,
     pub action: TradingAction,
+    /// Expected profit of the trade in SOL; convert with `estimated_profit_lamports`
     pub estimated_profit: f64,
     pub time_window_ms: u64,
     pub ai_reasoning: String,
+    /// Price the AI expects to trade at, when it states one
+    #[serde(default)]
+    pub target_price: Option<f64>,
+    /// TensorZero inference and episode that produced this decision, for outcome feedback
+    #[serde(default)]
+    pub inference_id: Option<Uuid>,
+    #[serde(default)]
+    pub episode_id: Option<Uuid>,
     #[serde(skip, default = "Instant::now")] // Skip serialization, use current time as default
     pub timestamp: Instant,
 }
 
+impl AITradingSignal {
+    /// Non-negative estimated profit in lamports
+    pub fn estimated_profit_lamports(&self) -> u64 {
+        (self.estimated_profit.max(0.0) * LAMPORTS_PER_SOL as f64).round() as u64
+    }
+}
+
 /// Trading action to execute
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradingAction {
     pub action_type: String, // "buy", "sell", "arbitrage", "mev"
     pub token_in: String,
@@ -111,11 +257,69 @@ pub struct TensorZeroResponse {
     pub usage: Option<TensorZeroUsage>,
 }
 
+/// Feedback on one inference, e.g. the realized P&L of the trade it produced
+#[derive(Debug, Serialize)]
+pub struct TensorZeroFeedbackRequest {
+    pub metric_name: String,
+    pub inference_id: Uuid,
+    pub value: serde_json::Value,
+    pub tags: std::collections::HashMap<String, String>,
+}
+
 #[derive(Debug, Deserialize)]
 pub struct TensorZeroContent {
     #[serde(rename = "type")]
     pub content_type: String,
-    pub text: String,
+    /// Set on `text` blocks
+    #[serde(default)]
+    pub text: Option<String>,
+    /// Set on `tool_call` blocks; `arguments` is the gateway's parsed form of `raw_arguments`
+    #[serde(default)]
+    pub arguments: Option<serde_json::Value>,
+    #[serde(default)]
+    pub raw_arguments: Option<String>,
+}
+
+/// The decision JSON from a TensorZero response's content blocks. A structured block (tool
+/// call arguments, or a text block that is a JSON object on its own) is preferred; otherwise
+/// the text blocks are joined in order, for decisions split across blocks.
+pub fn ai_decision_payload(content: &[TensorZeroContent]) -> Result<serde_json::Value> {
+    let parse_object = |text: &str| {
+        serde_json::from_str::<serde_json::Value>(text.trim())
+            .ok()
+            .filter(|value| value.is_object())
+    };
+
+    let tool_call = content
+        .iter()
+        .filter(|c| c.content_type == "tool_call")
+        .find_map(|c| {
+            c.arguments
+                .clone()
+                .filter(|arguments| arguments.is_object())
+                .or_else(|| c.raw_arguments.as_deref().and_then(parse_object))
+        });
+    if let Some(arguments) = tool_call {
+        return Ok(arguments);
+    }
+
+    let texts: Vec<&str> = content
+        .iter()
+        .filter(|c| c.content_type == "text")
+        .filter_map(|c| c.text.as_deref())
+        .collect();
+    if let Some(payload) = texts.iter().find_map(|text| parse_object(text)) {
+        return Ok(payload);
+    }
+    if let Some(payload) = parse_object(&texts.concat()) {
+        return Ok(payload);
+    }
+
+    let block_types: Vec<&str> = content.iter().map(|c| c.content_type.as_str()).collect();
+    Err(anyhow::anyhow!(
+        "No parseable AI decision in TensorZero response (content blocks: {:?})",
+        block_types
+    ))
 }
 
 #[derive(Debug, Deserialize)]
@@ -130,9 +334,43 @@ impl Default for HFTConfig {
             tensorzero_gateway_url: "http://localhost:3000".to_string(),
             jito_endpoint: "https://mainnet.block-engine.jito.wtf".to_string(),
             max_execution_latency_ms: 25, // Sub-25ms target
+            jito_backend: JitoBackend::BlockEngine,
             max_bundle_size: 5,
             retry_attempts: 3,
             ai_confidence_threshold: 0.7,
+            max_priority_fee_lamports: 100_000,
+            skip_on_priority_fee_cap: false,
+            min_jito_tip_lamports: 1_000,
+            max_jito_tip_lamports: 100_000,
+            max_tip_profit_fraction: 0.1,
+            ai_consistency: AIConsistencyRule::default(),
+            tensorzero_breaker: CircuitBreakerConfig::default(),
+            jito_breaker: CircuitBreakerConfig::default(),
+            max_concurrent_inferences: 8,
+            inference_queue_timeout_ms: 5,
+            max_bundles_in_flight: 4,
+            bundle_queue_timeout_ms: 5,
+            max_ai_price_deviation_bps: 500.0,
+            latency_ewma_half_life: DEFAULT_EWMA_HALF_LIFE_SAMPLES,
+            rebuild_expired_bundles: true,
+            rebuild_tip_multiplier: 1.25,
+            replace_pending_bundles: true,
+            replace_fee_multiplier: 1.5,
+            adaptive_latency_budget: true,
+            min_latency_leg_fraction: 0.2,
+            tip_accounts: None,
+        }
+    }
+}
+
+impl Default for AIConsistencyRule {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            high_confidence: 0.9,
+            min_profit_at_high_confidence: 0.0001,
+            low_confidence: 0.75,
+            max_profit_at_low_confidence: 10.0,
         }
     }
 }
@@ -141,27 +379,172 @@ impl OvermindHFTEngine {
     /// Create new OVERMIND HFT Engine
     pub fn new(config: HFTConfig) -> Result<Self> {
         let tensorzero_client = TensorZeroClient::new(config.tensorzero_gateway_url.clone())?;
-        let jito_sdk = JitoJsonRpcSDK::new(&config.jito_endpoint, None);
+        let jito_client: Arc<dyn JitoClient> = match config.jito_backend {
+            JitoBackend::BlockEngine => Arc::new(JitoJsonRpcSDK::new(&config.jito_endpoint, None)),
+            JitoBackend::Local => Arc::new(LocalJitoClient::new()),
+        };
+        let tensorzero_breaker =
+            CircuitBreaker::new("TensorZero", config.tensorzero_breaker.clone());
+        let jito_breaker = CircuitBreaker::new("Jito", config.jito_breaker.clone());
+        let inference_limiter = Arc::new(Semaphore::new(config.max_concurrent_inferences.max(1)));
+        let bundle_limiter = Arc::new(Semaphore::new(config.max_bundles_in_flight.max(1)));
         
         Ok(Self {
             tensorzero_client,
-            jito_sdk,
-            metrics: HFTMetrics::default(),
+            jito_client,
+            metrics: HFTMetrics::shared(config.latency_ewma_half_life),
+            tensorzero_breaker,
+            jito_breaker,
+            inference_limiter,
+            bundle_limiter,
+            bundles_in_flight: Arc::new(AtomicU64::new(0)),
+            market_prices: None,
+            latency_budget: LatencyBudget::new(
+                config.adaptive_latency_budget,
+                config.min_latency_leg_fraction,
+                config.latency_ewma_half_life,
+            ),
+            tip_accounts: config.tip_accounts.clone(),
             config,
         })
     }
 
+    /// Submit bundles through `jito_client` instead of the configured backend
+    pub fn with_jito_client(mut self, jito_client: Arc<dyn JitoClient>) -> Self {
+        self.jito_client = jito_client;
+        self
+    }
+
+    /// Compare AI target prices against the latest cached market prices
+    pub fn with_market_prices(mut self, market_prices: SharedMarketPrices) -> Self {
+        self.market_prices = Some(market_prices);
+        self
+    }
+
+    /// Share one inference limit across several engines (e.g. per-wallet executors)
+    pub fn with_inference_limiter(mut self, inference_limiter: Arc<Semaphore>) -> Self {
+        self.inference_limiter = inference_limiter;
+        self
+    }
+
+    /// Share one in-flight bundle limit across several engines
+    pub fn with_bundle_limiter(mut self, bundle_limiter: Arc<Semaphore>) -> Self {
+        self.bundle_limiter = bundle_limiter;
+        self
+    }
+
+    /// Record metrics into a handle owned elsewhere (e.g. monitoring), keeping this
+    /// engine's EWMA half-life
+    pub fn with_shared_metrics(mut self, metrics: SharedHFTMetrics) -> Self {
+        if let Ok(mut shared) = metrics.lock() {
+            shared.latency_ewma = Ewma::new(self.config.latency_ewma_half_life);
+            shared.inference_wait_ewma = Ewma::new(self.config.latency_ewma_half_life);
+        }
+        self.metrics = metrics;
+        self
+    }
+
+    /// Report bundles holding a slot through a gauge owned elsewhere (e.g. monitoring)
+    pub fn with_bundles_in_flight_gauge(mut self, bundles_in_flight: Arc<AtomicU64>) -> Self {
+        self.bundles_in_flight = bundles_in_flight;
+        self
+    }
+
+    /// Jito bundles currently submitted and not yet released
+    pub fn bundles_in_flight(&self) -> u64 {
+        self.bundles_in_flight.load(Ordering::Relaxed)
+    }
+
+    /// Wait briefly for a bundle slot; None (and a skip counted) when the limit is reached
+    async fn acquire_bundle_slot(&mut self) -> Option<BundleSlot> {
+        let limiter = self.bundle_limiter.clone();
+        match timeout(
+            Duration::from_millis(self.config.bundle_queue_timeout_ms),
+            limiter.acquire_owned(),
+        ).await {
+            Ok(Ok(permit)) => {
+                self.bundles_in_flight.fetch_add(1, Ordering::Relaxed);
+                Some(BundleSlot {
+                    _permit: permit,
+                    in_flight: self.bundles_in_flight.clone(),
+                })
+            }
+            Ok(Err(_)) | Err(_) => {
+                self.record_metric(|m| m.bundle_concurrency_skips += 1);
+                None
+            }
+        }
+    }
+
+    /// TensorZero inferences currently in flight on this engine's limiter
+    pub fn inferences_in_flight(&self) -> usize {
+        self.config
+            .max_concurrent_inferences
+            .max(1)
+            .saturating_sub(self.inference_limiter.available_permits())
+    }
+
     /// Execute AI-enhanced trading signal with ultra-low latency
     pub async fn execute_ai_signal(&mut self, market_data: &str) -> Result<ExecutionResult> {
         let start_time = Instant::now();
         
-        // Step 1: Get AI decision from TensorZero (target: <10ms)
-        let ai_signal = timeout(
-            Duration::from_millis(self.config.max_execution_latency_ms / 3),
+        // Step 1: While TensorZero is failing, skip the AI call entirely so the
+        // caller falls back to standard execution without spending latency budget
+        if !self.tensorzero_breaker.allow_request() {
+            self.record_metric(|m| m.ai_calls_short_circuited += 1);
+            return Ok(ExecutionResult::Skipped {
+                reason: "TensorZero circuit open".to_string(),
+                latency_ms: start_time.elapsed().as_millis() as u64,
+            });
+        }
+
+        // Wait briefly for an inference slot rather than stampeding the gateway
+        let wait_start = Instant::now();
+        let limiter = self.inference_limiter.clone();
+        let permit = match timeout(
+            Duration::from_millis(self.config.inference_queue_timeout_ms),
+            limiter.acquire_owned(),
+        ).await {
+            Ok(Ok(permit)) => permit,
+            Ok(Err(_)) | Err(_) => {
+                self.record_metric(|m| m.inference_concurrency_skips += 1);
+                return Ok(ExecutionResult::Skipped {
+                    reason: "inference concurrency limit".to_string(),
+                    latency_ms: start_time.elapsed().as_millis() as u64,
+                });
+            }
+        };
+        self.record_inference_wait(wait_start.elapsed());
+
+        let split = self.latency_budget.split(self.config.max_execution_latency_ms);
+        self.record_metric(|m| {
+            m.ai_leg_budget_ms = split.ai_leg_ms;
+            m.bundle_leg_budget_ms = split.bundle_leg_ms;
+        });
+
+        // Get AI decision from TensorZero (target: <10ms)
+        let ai_leg_start = Instant::now();
+        let decision = timeout(
+            Duration::from_millis(split.ai_leg_ms),
             self.get_ai_trading_decision(market_data)
-        ).await
-        .context("TensorZero AI decision timeout")?
-        .context("Failed to get AI trading decision")?;
+        ).await;
+        drop(permit);
+        self.latency_budget.record_ai_leg(ai_leg_start.elapsed().as_secs_f64() * 1000.0);
+        self.record_metric(|m| m.avg_ai_leg_ms = self.latency_budget.avg_ai_leg_ms());
+        let mut ai_signal = match decision {
+            Ok(Ok(ai_signal)) => {
+                self.tensorzero_breaker.record_success();
+                ai_signal
+            }
+            Ok(Err(e)) => {
+                self.tensorzero_breaker.record_failure();
+                return Err(e.context("Failed to get AI trading decision"));
+            }
+            Err(_) => {
+                self.tensorzero_breaker.record_failure();
+                return Err(anyhow!("TensorZero AI decision timeout"));
+            }
+        };
 
         // Step 2: Validate AI confidence
         if ai_signal.confidence < self.config.ai_confidence_threshold {
@@ -171,25 +554,101 @@ impl OvermindHFTEngine {
             });
         }
 
-        // Step 3: Execute via Jito Bundle (target: <15ms)
+        // Step 3: Reject decisions whose confidence and profit disagree
+        if let Some(reason) = self.check_ai_consistency(&ai_signal) {
+            self.record_metric(|m| m.ai_inconsistent_rejections += 1);
+            warn!("🧠 Rejecting inconsistent AI decision {}: {}", ai_signal.signal_id, reason);
+            return Ok(ExecutionResult::Skipped {
+                reason,
+                latency_ms: start_time.elapsed().as_millis() as u64,
+            });
+        }
+
+        // Step 4: Reject decisions priced far away from the current market
+        if let Some(reason) = self.check_market_price_deviation(&ai_signal, market_data) {
+            self.record_metric(|m| m.ai_price_off_market_skips += 1);
+            warn!("🧠 Skipping AI decision {}: {}", ai_signal.signal_id, reason);
+            return Ok(ExecutionResult::Skipped {
+                reason,
+                latency_ms: start_time.elapsed().as_millis() as u64,
+            });
+        }
+
+        // Step 5: Enforce the priority fee ceiling
+        if let Some(reason) = self.enforce_priority_fee_cap(&mut ai_signal) {
+            return Ok(ExecutionResult::Skipped {
+                reason,
+                latency_ms: start_time.elapsed().as_millis() as u64,
+            });
+        }
+
+        // Step 6: Scale the Jito tip to conviction and edge
+        let tip = self.compute_jito_tip(&ai_signal);
+
+        // Step 7: Never exceed the outstanding bundle limit, nor submit while Jito is failing
+        if !self.jito_breaker.allow_request() {
+            self.record_metric(|m| m.bundles_short_circuited += 1);
+            return Ok(ExecutionResult::Skipped {
+                reason: "Jito circuit open".to_string(),
+                latency_ms: start_time.elapsed().as_millis() as u64,
+            });
+        }
+        let Some(bundle_slot) = self.acquire_bundle_slot().await else {
+            return Ok(ExecutionResult::Skipped {
+                reason: "bundle concurrency limit".to_string(),
+                latency_ms: start_time.elapsed().as_millis() as u64,
+            });
+        };
+
+        // Step 8: Execute via Jito Bundle (target: <15ms)
+        let bundle_leg_start = Instant::now();
         let execution_result = timeout(
-            Duration::from_millis(self.config.max_execution_latency_ms * 2 / 3),
-            self.execute_jito_bundle(&ai_signal)
-        ).await
-        .context("Jito bundle execution timeout")?
-        .context("Failed to execute Jito bundle")?;
+            Duration::from_millis(split.bundle_leg_ms),
+            self.execute_jito_bundle(&ai_signal, tip.tip_lamports)
+        ).await;
+        self.latency_budget.record_bundle_leg(bundle_leg_start.elapsed().as_secs_f64() * 1000.0);
+        self.record_metric(|m| m.avg_bundle_leg_ms = self.latency_budget.avg_bundle_leg_ms());
+        let execution_result = match execution_result {
+            Ok(Ok(result)) => result,
+            Ok(Err(e)) => match e.downcast::<JitoRejection>() {
+                Ok(rejection) => {
+                    return Ok(ExecutionResult::Failed {
+                        error: rejection.to_string(),
+                        latency_ms: start_time.elapsed().as_millis() as u64,
+                        rejection: Some(rejection),
+                    });
+                }
+                Err(e) => return Err(e.context("Failed to execute Jito bundle")),
+            },
+            Err(_) => {
+                self.jito_breaker.record_failure();
+                return Err(anyhow!("Jito bundle execution timeout"));
+            }
+        };
 
         let total_latency = start_time.elapsed().as_millis() as u64;
         
         // Update metrics
         self.update_metrics(total_latency, true);
         
+        let inference_id = ai_signal.inference_id;
+        let episode_id = ai_signal.episode_id;
         Ok(ExecutionResult::Executed {
             signal_id: ai_signal.signal_id,
             bundle_id: execution_result.bundle_id,
             latency_ms: total_latency,
             estimated_profit: ai_signal.estimated_profit,
             ai_confidence: ai_signal.confidence,
+            priority_fee_lamports: ai_signal.action.priority_fee,
+            rebuild: Box::new(BundleRebuild {
+                signal: ai_signal,
+                tip_lamports: tip.tip_lamports,
+                attempts: 0,
+            }),
+            tip,
+            bundle_slot,
+            inference_id,
+            episode_id,
         })
     }
 
@@ -201,7 +660,7 @@ impl OvermindHFTEngine {
                 messages: vec![
                     TensorZeroMessage {
                         role: "system".to_string(),
-                        content: "You are THE OVERMIND PROTOCOL AI Brain. Analyze market data and provide ultra-fast trading decisions. Respond with JSON containing: signal_type, confidence (0-1), action_type, reasoning.".to_string(),
+                        content: "You are THE OVERMIND PROTOCOL AI Brain. Analyze market data and provide ultra-fast trading decisions. Respond with JSON containing: signal_type, confidence (0-1), action_type, target_price, reasoning.".to_string(),
                     },
                     TensorZeroMessage {
                         role: "user".to_string(),
@@ -219,32 +678,33 @@ impl OvermindHFTEngine {
         };
 
         let response = self.tensorzero_client.inference(request).await?;
-        self.metrics.ai_decisions_made += 1;
+        self.record_metric(|m| m.ai_decisions_made += 1);
         
         // Parse AI response into trading signal
         self.parse_ai_response(response)
     }
 
     /// Execute trading action via Jito Bundle
-    async fn execute_jito_bundle(&mut self, signal: &AITradingSignal) -> Result<JitoBundleResult> {
+    async fn execute_jito_bundle(&mut self, signal: &AITradingSignal, tip_lamports: u64) -> Result<JitoBundleResult> {
         // Create transaction based on AI signal
-        let transaction = self.create_transaction_from_signal(signal)?;
+        let tip_account = self.next_tip_account();
+        let transaction = self.create_transaction_from_signal(signal, tip_lamports, tip_account)?;
 
         // Prepare bundle parameters for Jito SDK
         let bundle_params = serde_json::json!({
             "transactions": vec![transaction]
         });
 
-        let bundle_response = self.jito_sdk.send_bundle(Some(bundle_params), None).await
-            .context("Failed to submit Jito bundle")?;
-
-        self.metrics.bundles_submitted += 1;
+        let bundle_response = match self.jito_client.send_bundle(bundle_params).await {
+            Ok(response) => response,
+            Err(e) => {
+                self.jito_breaker.record_failure();
+                return Err(e.context("Failed to submit Jito bundle"));
+            }
+        };
 
-        // Extract bundle ID from response
-        let bundle_id = bundle_response["result"]
-            .as_str()
-            .unwrap_or("unknown")
-            .to_string();
+        self.record_metric(|m| m.bundles_submitted += 1);
+        let bundle_id = self.accept_bundle_response(&bundle_response)?;
 
         Ok(JitoBundleResult {
             bundle_id,
@@ -252,18 +712,189 @@ impl OvermindHFTEngine {
         })
     }
 
+    /// Bundle ID of an accepted bundle. A rejection is counted by reason and returned as a
+    /// `JitoRejection` error; only endpoint failures count towards the Jito breaker.
+    fn accept_bundle_response(&mut self, response: &serde_json::Value) -> Result<String> {
+        match JitoRejection::bundle_id_from_response(response) {
+            Ok(bundle_id) => {
+                self.jito_breaker.record_success();
+                Ok(bundle_id)
+            }
+            Err(rejection) => {
+                self.record_metric(|m| *m.jito_rejections.entry(rejection.reason).or_insert(0) += 1);
+                if rejection.reason.is_endpoint_failure() {
+                    self.jito_breaker.record_failure();
+                } else {
+                    // The block engine answered properly; the bundle itself was bad
+                    self.jito_breaker.record_success();
+                }
+                warn!("🚫 {}", rejection);
+                Err(rejection.into())
+            }
+        }
+    }
+
+    pub fn jito_circuit_state(&self) -> CircuitState {
+        self.jito_breaker.state()
+    }
+
+    /// Rebuild an expired bundle with a fresh transaction and resubmit it, escalating the tip.
+    /// Retryable rejections are resubmitted while attempts remain and the Jito breaker is closed.
+    /// None when rebuilding is disabled or the bundle already used up its retry attempts.
+    pub async fn rebuild_bundle(&mut self, rebuild: &mut BundleRebuild) -> Result<Option<JitoBundleResult>> {
+        if !self.config.rebuild_expired_bundles || rebuild.attempts >= self.config.retry_attempts {
+            return Ok(None);
+        }
+
+        rebuild.tip_lamports = rebuild.escalated_tip(
+            self.config.rebuild_tip_multiplier,
+            self.config.max_jito_tip_lamports,
+        );
+        loop {
+            rebuild.attempts += 1;
+            match self.execute_jito_bundle(&rebuild.signal, rebuild.tip_lamports).await {
+                Ok(result) => {
+                    self.record_metric(|m| m.bundles_rebuilt += 1);
+                    return Ok(Some(result));
+                }
+                Err(e) if rebuild.attempts < self.config.retry_attempts
+                    && self.jito_circuit_state() != CircuitState::Open
+                    && e.downcast_ref::<JitoRejection>().is_some_and(|r| r.reason.is_retryable()) =>
+                {
+                    debug!("🔁 Retrying rebuilt bundle ({}/{}): {}", rebuild.attempts, self.config.retry_attempts, e);
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    /// Replace a pending bundle with a fresh transaction carrying the same decision at a
+    /// higher priority fee and tip. None when replacement is disabled or both fees are
+    /// already at their ceilings, in which case the original stays pending.
+    pub async fn replace_bundle(&mut self, rebuild: &mut BundleRebuild) -> Result<Option<JitoBundleResult>> {
+        if !self.config.replace_pending_bundles {
+            return Ok(None);
+        }
+
+        let multiplier = self.config.replace_fee_multiplier;
+        let priority_fee = rebuild.escalated_priority_fee(multiplier, self.config.max_priority_fee_lamports);
+        let tip_lamports = rebuild.escalated_tip(multiplier, self.config.max_jito_tip_lamports);
+        if priority_fee == rebuild.signal.action.priority_fee && tip_lamports == rebuild.tip_lamports {
+            return Ok(None);
+        }
+
+        let mut signal = rebuild.signal.clone();
+        signal.action.priority_fee = priority_fee;
+        let result = self.execute_jito_bundle(&signal, tip_lamports).await?;
+        rebuild.signal = signal;
+        rebuild.tip_lamports = tip_lamports;
+        self.record_metric(|m| m.bundles_replaced += 1);
+        Ok(Some(result))
+    }
+
+    /// Execute a multi-leg arbitrage route as a single atomic Jito bundle.
+    /// Every leg is re-quoted just before submission and the route is skipped
+    /// if its net edge has fallen below the signal's minimum.
+    pub async fn execute_arbitrage(
+        &mut self,
+        signal: &ArbitrageSignal,
+        quotes: &dyn ArbitrageQuoteSource,
+    ) -> Result<ArbitrageExecutionResult> {
+        let start_time = Instant::now();
+        signal.validate()?;
+
+        // One transaction per leg must fit in a single bundle, or atomicity is lost
+        if signal.legs.len() > self.config.max_bundle_size {
+            return Ok(ArbitrageExecutionResult::Skipped {
+                signal_id: signal.signal_id,
+                reason: format!(
+                    "Route has {} legs, bundle limit is {}",
+                    signal.legs.len(),
+                    self.config.max_bundle_size
+                ),
+                latency_ms: start_time.elapsed().as_millis() as u64,
+            });
+        }
+
+        let net_edge_bps = match timeout(
+            Duration::from_millis(self.config.max_execution_latency_ms / 3),
+            signal.revalidate(quotes)
+        ).await {
+            Ok(Ok(Some(edge_bps))) => edge_bps,
+            Ok(Ok(None)) => {
+                self.record_metric(|m| m.arbitrage_edge_closed_skips += 1);
+                return Ok(ArbitrageExecutionResult::Skipped {
+                    signal_id: signal.signal_id,
+                    reason: "Arbitrage edge closed before submission".to_string(),
+                    latency_ms: start_time.elapsed().as_millis() as u64,
+                });
+            }
+            Ok(Err(e)) => return Err(e.context("Failed to re-quote arbitrage legs")),
+            Err(_) => return Err(anyhow!("Arbitrage re-quote timeout")),
+        };
+
+        let tip_lamports = self.compute_arbitrage_tip(signal, net_edge_bps);
+
+        if !self.jito_breaker.allow_request() {
+            self.record_metric(|m| m.bundles_short_circuited += 1);
+            return Ok(ArbitrageExecutionResult::Skipped {
+                signal_id: signal.signal_id,
+                reason: "Jito circuit open".to_string(),
+                latency_ms: start_time.elapsed().as_millis() as u64,
+            });
+        }
+
+        // Arbitrage bundles are not confirmation-tracked, so the slot is held for submission only
+        let Some(_bundle_slot) = self.acquire_bundle_slot().await else {
+            return Ok(ArbitrageExecutionResult::Skipped {
+                signal_id: signal.signal_id,
+                reason: "bundle concurrency limit".to_string(),
+                latency_ms: start_time.elapsed().as_millis() as u64,
+            });
+        };
+
+        // The tip rides on the last leg so it is only paid if every leg lands
+        let tip_account = self.next_tip_account();
+        let mut transactions = Vec::with_capacity(signal.legs.len());
+        for i in 0..signal.legs.len() {
+            let (leg_tip, leg_tip_account) = if i + 1 == signal.legs.len() {
+                (tip_lamports, tip_account)
+            } else {
+                (0, None)
+            };
+            transactions.push(self.create_transaction_from_arbitrage_leg(signal, i, leg_tip, leg_tip_account)?);
+        }
+
+        let bundle_params = serde_json::json!({
+            "transactions": transactions
+        });
+        let bundle_response = timeout(
+            Duration::from_millis(self.config.max_execution_latency_ms * 2 / 3),
+            self.jito_client.send_bundle(bundle_params)
+        ).await
+        .context("Jito bundle execution timeout")?
+        .context("Failed to submit arbitrage Jito bundle")?;
+
+        self.record_metric(|m| m.bundles_submitted += 1);
+        let bundle_id = self.accept_bundle_response(&bundle_response)?;
+
+        let total_latency = start_time.elapsed().as_millis() as u64;
+        self.update_metrics(total_latency, true);
+
+        Ok(ArbitrageExecutionResult::Executed {
+            signal_id: signal.signal_id,
+            bundle_id,
+            legs: signal.legs.len(),
+            net_edge_bps,
+            tip_lamports,
+            latency_ms: total_latency,
+        })
+    }
+
     /// Parse TensorZero AI response into trading signal
     fn parse_ai_response(&self, response: TensorZeroResponse) -> Result<AITradingSignal> {
-        // Extract text content from TensorZero response
-        let ai_text = response.content
-            .into_iter()
-            .find(|c| c.content_type == "text")
-            .map(|c| c.text)
-            .context("No text content in TensorZero response")?;
-
-        // Parse JSON response from AI
-        let ai_data: serde_json::Value = serde_json::from_str(&ai_text)
-            .context("Failed to parse AI response as JSON")?;
+        let ai_data = ai_decision_payload(&response.content)?;
+        let (inference_id, episode_id) = (response.inference_id, response.episode_id);
 
         Ok(AITradingSignal {
             signal_id: Uuid::new_v4(),
@@ -281,12 +912,156 @@ impl OvermindHFTEngine {
             estimated_profit: ai_data["estimated_profit"].as_f64().unwrap_or(0.0),
             time_window_ms: ai_data["time_window_ms"].as_u64().unwrap_or(1000),
             ai_reasoning: ai_data["reasoning"].as_str().unwrap_or("").to_string(),
+            target_price: ai_data["target_price"].as_f64(),
+            inference_id: Some(inference_id),
+            episode_id: Some(episode_id),
             timestamp: Instant::now(),
         })
     }
 
+    /// Check the AI decision against the confidence/profit sanity model.
+    /// Returns a skip reason when the two are wildly inconsistent.
+    fn check_ai_consistency(&self, signal: &AITradingSignal) -> Option<String> {
+        let rule = &self.config.ai_consistency;
+        if !rule.enabled {
+            return None;
+        }
+
+        if signal.confidence > rule.high_confidence
+            && signal.estimated_profit < rule.min_profit_at_high_confidence
+        {
+            return Some(format!(
+                "ai_inconsistent: confidence {:.2} with negligible estimated profit {}",
+                signal.confidence, signal.estimated_profit
+            ));
+        }
+
+        if signal.confidence < rule.low_confidence
+            && signal.estimated_profit > rule.max_profit_at_low_confidence
+        {
+            return Some(format!(
+                "ai_inconsistent: confidence {:.2} with implausible estimated profit {}",
+                signal.confidence, signal.estimated_profit
+            ));
+        }
+
+        None
+    }
+
+    /// Compare the AI's target price with the latest market price for the symbol in
+    /// `market_data`. Returns a skip reason when it is outside the allowed band; decisions
+    /// without a target price or symbols without a fresh market price are not checked.
+    fn check_market_price_deviation(&self, signal: &AITradingSignal, market_data: &str) -> Option<String> {
+        let max_bps = self.config.max_ai_price_deviation_bps;
+        if max_bps <= 0.0 {
+            return None;
+        }
+        let target_price = signal.target_price.filter(|p| p.is_finite() && *p > 0.0)?;
+        let market: serde_json::Value = serde_json::from_str(market_data).ok()?;
+        let symbol = market["symbol"].as_str()?;
+        let market_price = self.market_prices.as_ref()?.latest(symbol)?;
+
+        let deviation_bps = (target_price - market_price).abs() / market_price * 10_000.0;
+        (deviation_bps > max_bps).then(|| {
+            format!(
+                "AI price off-market: target {} vs market {} for {} ({:.0} bps > {:.0} bps)",
+                target_price, market_price, symbol, deviation_bps, max_bps
+            )
+        })
+    }
+
+    /// Clamp the signal's priority fee to the configured ceiling.
+    /// Returns a skip reason when the trade should not be submitted at all.
+    fn enforce_priority_fee_cap(&self, signal: &mut AITradingSignal) -> Option<String> {
+        let requested = signal.action.priority_fee;
+        let cap = self.config.max_priority_fee_lamports;
+
+        if requested <= cap {
+            return None;
+        }
+
+        if self.config.skip_on_priority_fee_cap {
+            warn!(
+                "⛽ Priority fee {} lamports exceeds cap {} - skipping signal {}",
+                requested, cap, signal.signal_id
+            );
+            return Some("priority fee cap exceeded".to_string());
+        }
+
+        warn!(
+            "⛽ Clamping priority fee for signal {}: {} -> {} lamports",
+            signal.signal_id, requested, cap
+        );
+        signal.action.priority_fee = cap;
+        None
+    }
+
+    /// Scale the Jito tip between the configured min and max by confidence and
+    /// estimated profit, never exceeding the configured fraction of that profit
+    fn compute_jito_tip(&self, signal: &AITradingSignal) -> JitoTipDecision {
+        let min_tip = self.config.min_jito_tip_lamports as f64;
+        let max_tip = self.config.max_jito_tip_lamports.max(self.config.min_jito_tip_lamports) as f64;
+        let profit_lamports = signal.estimated_profit_lamports() as f64;
+
+        // Edge weight saturates towards 1.0 once profit dwarfs the maximum tip
+        let edge_weight = if profit_lamports > 0.0 {
+            profit_lamports / (profit_lamports + max_tip)
+        } else {
+            0.0
+        };
+        let scale = signal.confidence.clamp(0.0, 1.0) * edge_weight;
+        let scaled_tip = min_tip + (max_tip - min_tip) * scale;
+
+        let profit_cap_lamports =
+            (profit_lamports * self.config.max_tip_profit_fraction.clamp(0.0, 1.0)) as u64;
+        let tip_lamports = (scaled_tip as u64).min(profit_cap_lamports);
+
+        JitoTipDecision {
+            tip_lamports,
+            confidence: signal.confidence,
+            estimated_profit: signal.estimated_profit,
+            scale,
+            profit_cap_lamports,
+        }
+    }
+
+    /// Jito tip for an arbitrage bundle: the configured fraction of the
+    /// re-validated profit, kept within the min/max tip range
+    fn compute_arbitrage_tip(&self, signal: &ArbitrageSignal, net_edge_bps: f64) -> u64 {
+        let profit_lamports =
+            (net_edge_bps.max(0.0) / 10_000.0) * signal.notional_sol.max(0.0) * LAMPORTS_PER_SOL as f64;
+        let tip = (profit_lamports * self.config.max_tip_profit_fraction.clamp(0.0, 1.0)) as u64;
+        let max_tip = self.config.max_jito_tip_lamports.max(self.config.min_jito_tip_lamports);
+        tip.clamp(self.config.min_jito_tip_lamports, max_tip)
+    }
+
+    /// Tip account the next bundle pays, rotated across the validated list when configured
+    fn next_tip_account(&mut self) -> Option<Pubkey> {
+        let tip_account = self.tip_accounts.as_mut()?.next_account();
+        debug!("Bundle tip account: {}", tip_account);
+        Some(tip_account)
+    }
+
+    /// Create Solana transaction for one leg of an arbitrage route
+    fn create_transaction_from_arbitrage_leg(
+        &self,
+        _signal: &ArbitrageSignal,
+        _leg_index: usize,
+        _tip_lamports: u64,
+        _tip_account: Option<Pubkey>,
+    ) -> Result<Transaction> {
+        // TODO: Build the venue-specific swap instruction for the leg
+        // Placeholder until venue adapters exist, mirroring create_transaction_from_signal
+        Ok(Transaction::default())
+    }
+
     /// Create Solana transaction from AI trading signal
-    fn create_transaction_from_signal(&self, _signal: &AITradingSignal) -> Result<Transaction> {
+    fn create_transaction_from_signal(
+        &self,
+        _signal: &AITradingSignal,
+        _tip_lamports: u64,
+        _tip_account: Option<Pubkey>,
+    ) -> Result<Transaction> {
         // TODO: Implement actual Solana transaction creation
         // This is a placeholder - real implementation would create proper Solana transactions
         // based on the trading action (swap, arbitrage, MEV, etc.)
@@ -298,22 +1073,49 @@ impl OvermindHFTEngine {
 
     /// Update performance metrics
     fn update_metrics(&mut self, latency_ms: u64, success: bool) {
-        self.metrics.total_executions += 1;
-        
-        if success {
-            self.metrics.successful_executions += 1;
-        } else {
-            self.metrics.failed_executions += 1;
+        self.record_metric(|m| {
+            m.total_executions += 1;
+
+            if success {
+                m.successful_executions += 1;
+            } else {
+                m.failed_executions += 1;
+            }
+
+            // Update rolling average latency
+            m.avg_latency_ms = m.latency_ewma.record(latency_ms as f64);
+        });
+    }
+
+    fn record_inference_wait(&mut self, wait: Duration) {
+        self.record_metric(|m| {
+            m.inferences_admitted += 1;
+            m.avg_inference_wait_ms = m.inference_wait_ewma.record(wait.as_secs_f64() * 1000.0);
+        });
+    }
+
+    /// Apply one update to the shared metrics; the lock is held only for the update
+    fn record_metric(&self, update: impl FnOnce(&mut HFTMetrics)) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            update(&mut metrics);
         }
-        
-        // Update rolling average latency
-        let total_latency = self.metrics.avg_latency_ms * (self.metrics.total_executions - 1) as f64;
-        self.metrics.avg_latency_ms = (total_latency + latency_ms as f64) / self.metrics.total_executions as f64;
     }
 
-    /// Get current performance metrics
-    pub fn get_metrics(&self) -> &HFTMetrics {
-        &self.metrics
+    /// Snapshot of the current performance metrics
+    pub fn get_metrics(&self) -> HFTMetrics {
+        self.metrics
+            .lock()
+            .map(|metrics| metrics.clone())
+            .unwrap_or_default()
+    }
+
+    /// Handle on the live metrics, readable while the engine runs in another task
+    pub fn metrics_handle(&self) -> SharedHFTMetrics {
+        self.metrics.clone()
+    }
+
+    pub fn tensorzero_circuit_state(&self) -> CircuitState {
+        self.tensorzero_breaker.state()
     }
 }
 
@@ -324,8 +1126,18 @@ pub enum ExecutionResult {
         signal_id: Uuid,
         bundle_id: String,
         latency_ms: u64,
+        /// SOL, as on `AITradingSignal`
         estimated_profit: f64,
         ai_confidence: f64,
+        priority_fee_lamports: u64,
+        tip: JitoTipDecision,
+        /// Keeps the bundle counted as in flight until dropped; hold it until confirmation
+        bundle_slot: BundleSlot,
+        /// What is needed to rebuild the bundle if it expires unconfirmed
+        rebuild: Box<BundleRebuild>,
+        /// TensorZero inference behind the trade, for reporting its outcome as feedback
+        inference_id: Option<Uuid>,
+        episode_id: Option<Uuid>,
     },
     Skipped {
         reason: String,
@@ -334,9 +1146,61 @@ pub enum ExecutionResult {
     Failed {
         error: String,
         latency_ms: u64,
+        /// Set when the block engine rejected the bundle
+        rejection: Option<JitoRejection>,
     },
 }
 
+/// An executed AI decision kept so its bundle can be rebuilt and resubmitted
+#[derive(Debug, Clone)]
+pub struct BundleRebuild {
+    pub signal: AITradingSignal,
+    /// Tip paid by the most recent submission
+    pub tip_lamports: u64,
+    /// Rebuilds performed so far
+    pub attempts: u32,
+}
+
+impl BundleRebuild {
+    /// Tip for the next submission; never lowered, and never raised past `max_tip_lamports`
+    pub fn escalated_tip(&self, multiplier: f64, max_tip_lamports: u64) -> u64 {
+        let escalated = (self.tip_lamports as f64 * multiplier.max(1.0)).round() as u64;
+        escalated.min(max_tip_lamports.max(self.tip_lamports))
+    }
+
+    /// Priority fee for a replacement; never lowered, and never raised past `max_priority_fee_lamports`
+    pub fn escalated_priority_fee(&self, multiplier: f64, max_priority_fee_lamports: u64) -> u64 {
+        let current = self.signal.action.priority_fee;
+        let escalated = (current as f64 * multiplier.max(1.0)).round() as u64;
+        escalated.min(max_priority_fee_lamports.max(current))
+    }
+}
+
+/// One Jito bundle's share of the in-flight limit, released on drop
+#[derive(Debug)]
+pub struct BundleSlot {
+    _permit: OwnedSemaphorePermit,
+    in_flight: Arc<AtomicU64>,
+}
+
+impl Drop for BundleSlot {
+    fn drop(&mut self) {
+        self.in_flight.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// Jito tip chosen for a bundle together with the inputs that produced it,
+/// kept for post-hoc tuning of the tip policy
+#[derive(Debug, Clone, Serialize)]
+pub struct JitoTipDecision {
+    pub tip_lamports: u64,
+    pub confidence: f64,
+    /// SOL, as on `AITradingSignal`
+    pub estimated_profit: f64,
+    pub scale: f64,
+    pub profit_cap_lamports: u64,
+}
+
 /// Jito bundle execution result
 #[derive(Debug)]
 pub struct JitoBundleResult {
@@ -347,8 +1211,13 @@ pub struct JitoBundleResult {
 impl TensorZeroClient {
     /// Create new TensorZero HTTP client
     pub fn new(gateway_url: String) -> Result<Self> {
+        Self::with_timeout(gateway_url, Duration::from_millis(100)) // Ultra-low timeout for HFT
+    }
+
+    /// Client with a custom request timeout, for calls off the trading hot path
+    pub fn with_timeout(gateway_url: String, request_timeout: Duration) -> Result<Self> {
         let client = Client::builder()
-            .timeout(Duration::from_millis(100)) // Ultra-low timeout for HFT
+            .timeout(request_timeout)
             .build()
             .context("Failed to create HTTP client")?;
         
@@ -358,6 +1227,26 @@ impl TensorZeroClient {
         })
     }
 
+    /// Send feedback on an earlier inference to TensorZero Gateway
+    pub async fn feedback(&self, request: TensorZeroFeedbackRequest) -> Result<()> {
+        let url = format!("{}/feedback", self.gateway_url);
+
+        let response = self.client
+            .post(&url)
+            .json(&request)
+            .send()
+            .await
+            .context("Failed to send TensorZero feedback")?;
+
+        if !response.status().is_success() {
+            return Err(anyhow::anyhow!(
+                "TensorZero feedback failed with status: {}",
+                response.status()
+            ));
+        }
+        Ok(())
+    }
+
     /// Send inference request to TensorZero Gateway
     pub async fn inference(&self, request: TensorZeroRequest) -> Result<TensorZeroResponse> {
         let url = format!("{}/inference", self.gateway_url);
@@ -402,10 +1291,477 @@ mod tests {
         assert!(client.is_ok());
     }
 
+    fn block(content_type: &str, text: Option<&str>, arguments: Option<serde_json::Value>) -> TensorZeroContent {
+        TensorZeroContent {
+            content_type: content_type.to_string(),
+            text: text.map(str::to_string),
+            arguments,
+            raw_arguments: None,
+        }
+    }
+
+    #[test]
+    fn test_ai_decision_payload_from_text_blocks() {
+        let single = [block("text", Some(r#"{"action_type": "buy", "confidence": 0.9}"#), None)];
+        assert_eq!(ai_decision_payload(&single).unwrap()["action_type"], "buy");
+
+        // A decision split across blocks is joined in order
+        let split = [
+            block("text", Some(r#"{"action_type": "sell","#), None),
+            block("text", Some(r#" "confidence": 0.8}"#), None),
+        ];
+        let payload = ai_decision_payload(&split).unwrap();
+        assert_eq!(payload["action_type"], "sell");
+        assert_eq!(payload["confidence"], 0.8);
+
+        // A self-contained JSON block wins over surrounding commentary
+        let commented = [
+            block("text", Some("Momentum looks strong."), None),
+            block("text", Some(r#"{"action_type": "buy"}"#), None),
+        ];
+        assert_eq!(ai_decision_payload(&commented).unwrap()["action_type"], "buy");
+
+        assert!(ai_decision_payload(&[block("text", Some("no decision"), None)]).is_err());
+        assert!(ai_decision_payload(&[]).is_err());
+    }
+
+    #[test]
+    fn test_ai_decision_payload_prefers_tool_call_arguments() {
+        let content = [
+            block("text", Some(r#"{"action_type": "hold"}"#), None),
+            block("tool_call", None, Some(serde_json::json!({"action_type": "buy", "amount_in": 5}))),
+        ];
+        let payload = ai_decision_payload(&content).unwrap();
+        assert_eq!(payload["action_type"], "buy");
+        assert_eq!(payload["amount_in"], 5);
+
+        // Tool calls the gateway could not parse fall back to their raw arguments
+        let raw: TensorZeroContent = serde_json::from_value(serde_json::json!({
+            "type": "tool_call",
+            "name": "trade_decision",
+            "raw_arguments": "{\"action_type\": \"sell\"}"
+        }))
+        .unwrap();
+        assert_eq!(ai_decision_payload(&[raw]).unwrap()["action_type"], "sell");
+    }
+
     #[test]
     fn test_hft_config_default() {
         let config = HFTConfig::default();
         assert_eq!(config.max_execution_latency_ms, 25);
         assert_eq!(config.ai_confidence_threshold, 0.7);
     }
+
+    fn test_ai_signal(priority_fee: u64) -> AITradingSignal {
+        AITradingSignal {
+            signal_id: Uuid::new_v4(),
+            signal_type: "momentum".to_string(),
+            confidence: 0.9,
+            action: TradingAction {
+                action_type: "buy".to_string(),
+                token_in: "SOL".to_string(),
+                token_out: "USDC".to_string(),
+                amount_in: 1_000,
+                min_amount_out: 990,
+                slippage_tolerance: 0.01,
+                priority_fee,
+            },
+            estimated_profit: 10.0,
+            time_window_ms: 1000,
+            ai_reasoning: String::new(),
+            target_price: None,
+            inference_id: None,
+            episode_id: None,
+            timestamp: Instant::now(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_open_breaker_skips_ai_call() {
+        let config = HFTConfig {
+            // Nothing listens here; a real call would fail rather than skip
+            tensorzero_gateway_url: "http://127.0.0.1:9".to_string(),
+            tensorzero_breaker: CircuitBreakerConfig {
+                failure_threshold: 1,
+                open_duration: Duration::from_secs(60),
+            },
+            ..HFTConfig::default()
+        };
+        // Metrics land in a handle owned outside the engine, as monitoring holds them
+        let shared = HFTMetrics::shared(DEFAULT_EWMA_HALF_LIFE_SAMPLES);
+        let mut engine = OvermindHFTEngine::new(config)
+            .unwrap()
+            .with_shared_metrics(shared.clone());
+
+        assert!(engine.execute_ai_signal("{}").await.is_err());
+        assert_eq!(engine.tensorzero_circuit_state(), CircuitState::Open);
+
+        let result = engine.execute_ai_signal("{}").await.unwrap();
+        assert!(matches!(result, ExecutionResult::Skipped { .. }));
+        assert_eq!(engine.get_metrics().ai_calls_short_circuited, 1);
+        assert_eq!(shared.lock().unwrap().ai_calls_short_circuited, 1);
+    }
+
+    struct StaticQuote(f64);
+
+    impl ArbitrageQuoteSource for StaticQuote {
+        fn quote<'a>(
+            &'a self,
+            _leg: &'a crate::modules::arbitrage::ArbitrageLeg,
+        ) -> futures::future::BoxFuture<'a, Result<f64>> {
+            Box::pin(async move { Ok(self.0) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_arbitrage_skipped_when_edge_closes() {
+        use crate::modules::arbitrage::{ArbitrageLeg, LegDirection};
+        use crate::modules::symbol_resolver::TokenPair;
+
+        let mut engine = OvermindHFTEngine::new(HFTConfig::default()).unwrap();
+        let pair = TokenPair {
+            base_mint: solana_sdk::pubkey::Pubkey::new_unique(),
+            quote_mint: solana_sdk::pubkey::Pubkey::new_unique(),
+        };
+        let leg = |venue: &str, direction, expected_price| ArbitrageLeg {
+            venue: venue.to_string(),
+            pair,
+            direction,
+            expected_price,
+            fee_bps: 0.0,
+        };
+        let signal = ArbitrageSignal {
+            signal_id: Uuid::new_v4(),
+            legs: vec![
+                leg("raydium", LegDirection::Buy, 100.0),
+                leg("orca", LegDirection::Sell, 101.0),
+            ],
+            input_amount: 100.0,
+            notional_sol: 1.0,
+            min_net_edge_bps: 20.0,
+            timestamp: chrono::Utc::now(),
+        };
+
+        // Both venues now quote the same price, so the spread is gone
+        let result = engine.execute_arbitrage(&signal, &StaticQuote(100.0)).await.unwrap();
+        assert!(matches!(result, ArbitrageExecutionResult::Skipped { .. }));
+        assert_eq!(engine.get_metrics().arbitrage_edge_closed_skips, 1);
+        assert_eq!(engine.get_metrics().bundles_submitted, 0);
+
+        let mut long_route = signal.clone();
+        long_route.legs = (0..6)
+            .flat_map(|_| [leg("raydium", LegDirection::Buy, 100.0), leg("orca", LegDirection::Sell, 101.0)])
+            .collect();
+        let result = engine.execute_arbitrage(&long_route, &StaticQuote(100.0)).await.unwrap();
+        assert!(matches!(result, ArbitrageExecutionResult::Skipped { .. }));
+    }
+
+    #[tokio::test]
+    async fn test_inference_concurrency_limit_skips() {
+        let config = HFTConfig {
+            tensorzero_gateway_url: "http://127.0.0.1:9".to_string(),
+            max_concurrent_inferences: 1,
+            inference_queue_timeout_ms: 1,
+            ..HFTConfig::default()
+        };
+        let limiter = Arc::new(Semaphore::new(1));
+        let mut engine = OvermindHFTEngine::new(config).unwrap().with_inference_limiter(limiter.clone());
+
+        // Another engine holds the only slot
+        let _held = limiter.clone().acquire_owned().await.unwrap();
+        assert_eq!(engine.inferences_in_flight(), 1);
+
+        match engine.execute_ai_signal("{}").await.unwrap() {
+            ExecutionResult::Skipped { reason, .. } => assert_eq!(reason, "inference concurrency limit"),
+            other => panic!("expected skip, got {:?}", other),
+        }
+        assert_eq!(engine.get_metrics().inference_concurrency_skips, 1);
+        assert_eq!(engine.get_metrics().inferences_admitted, 0);
+    }
+
+    #[tokio::test]
+    async fn test_bundle_slots_bound_in_flight_bundles() {
+        let config = HFTConfig {
+            max_bundles_in_flight: 2,
+            bundle_queue_timeout_ms: 1,
+            ..HFTConfig::default()
+        };
+        let gauge = Arc::new(AtomicU64::new(0));
+        let mut engine = OvermindHFTEngine::new(config).unwrap().with_bundles_in_flight_gauge(gauge.clone());
+
+        let first = engine.acquire_bundle_slot().await.unwrap();
+        let _second = engine.acquire_bundle_slot().await.unwrap();
+        assert_eq!(gauge.load(Ordering::Relaxed), 2);
+        assert!(engine.acquire_bundle_slot().await.is_none());
+        assert_eq!(engine.get_metrics().bundle_concurrency_skips, 1);
+
+        // A confirmed (released) bundle frees its slot
+        drop(first);
+        assert_eq!(engine.bundles_in_flight(), 1);
+        assert!(engine.acquire_bundle_slot().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_off_market_ai_price_skipped() {
+        use crate::modules::market_prices::MarketPriceCache;
+
+        let prices = MarketPriceCache::shared(chrono::Duration::seconds(30));
+        prices.record("SOL/USDC", 100.0, chrono::Utc::now());
+        let engine = OvermindHFTEngine::new(HFTConfig::default()).unwrap().with_market_prices(prices);
+        let market_data = r#"{"symbol": "SOL/USDC"}"#;
+
+        let mut near = test_ai_signal(1_000);
+        near.target_price = Some(101.0);
+        assert!(engine.check_market_price_deviation(&near, market_data).is_none());
+
+        let mut far = test_ai_signal(1_000);
+        far.target_price = Some(120.0);
+        let reason = engine.check_market_price_deviation(&far, market_data).unwrap();
+        assert!(reason.starts_with("AI price off-market"));
+
+        // Nothing to compare against for unknown symbols
+        assert!(engine.check_market_price_deviation(&far, r#"{"symbol": "JUP/USDC"}"#).is_none());
+    }
+
+    #[tokio::test]
+    async fn test_priority_fee_cap() {
+        let config = HFTConfig {
+            max_priority_fee_lamports: 5_000,
+            ..HFTConfig::default()
+        };
+        let engine = OvermindHFTEngine::new(config).unwrap();
+
+        let mut within_cap = test_ai_signal(4_000);
+        assert!(engine.enforce_priority_fee_cap(&mut within_cap).is_none());
+        assert_eq!(within_cap.action.priority_fee, 4_000);
+
+        let mut over_cap = test_ai_signal(50_000);
+        assert!(engine.enforce_priority_fee_cap(&mut over_cap).is_none());
+        assert_eq!(over_cap.action.priority_fee, 5_000);
+    }
+
+    #[tokio::test]
+    async fn test_priority_fee_cap_skip() {
+        let config = HFTConfig {
+            max_priority_fee_lamports: 5_000,
+            skip_on_priority_fee_cap: true,
+            ..HFTConfig::default()
+        };
+        let engine = OvermindHFTEngine::new(config).unwrap();
+
+        let mut over_cap = test_ai_signal(50_000);
+        assert_eq!(
+            engine.enforce_priority_fee_cap(&mut over_cap).as_deref(),
+            Some("priority fee cap exceeded")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_ai_decision_over_fee_cap_is_skipped() {
+        use wiremock::matchers::{method, path};
+        use wiremock::{Mock, MockServer, ResponseTemplate};
+
+        let gateway = MockServer::start().await;
+        let decision = serde_json::json!({
+            "signal_type": "momentum",
+            "confidence": 0.9,
+            "action_type": "buy",
+            "amount_in": 1_000,
+            "priority_fee": 50_000,
+            "estimated_profit": 1.0,
+        });
+        Mock::given(method("POST"))
+            .and(path("/inference"))
+            .respond_with(ResponseTemplate::new(200).set_body_json(serde_json::json!({
+                "inference_id": Uuid::new_v4(),
+                "episode_id": Uuid::new_v4(),
+                "variant_name": "fast",
+                "content": [{ "type": "text", "text": decision.to_string() }],
+            })))
+            .mount(&gateway)
+            .await;
+
+        let config = HFTConfig {
+            tensorzero_gateway_url: gateway.uri(),
+            max_execution_latency_ms: 1_000,
+            max_priority_fee_lamports: 5_000,
+            skip_on_priority_fee_cap: true,
+            ..HFTConfig::default()
+        };
+        let mut engine = OvermindHFTEngine::new(config).unwrap();
+
+        match engine.execute_ai_signal("{}").await.unwrap() {
+            ExecutionResult::Skipped { reason, .. } => assert_eq!(reason, "priority fee cap exceeded"),
+            other => panic!("expected skip, got {:?}", other),
+        }
+        assert_eq!(engine.get_metrics().bundles_submitted, 0);
+    }
+
+    #[tokio::test]
+    async fn test_jito_tip_scaling() {
+        let engine = OvermindHFTEngine::new(HFTConfig::default()).unwrap();
+
+        let mut strong = test_ai_signal(1_000);
+        strong.confidence = 0.95;
+        strong.estimated_profit = 1.0;
+        let mut weak = test_ai_signal(1_000);
+        weak.confidence = 0.5;
+        weak.estimated_profit = 1.0;
+
+        let strong_tip = engine.compute_jito_tip(&strong);
+        let weak_tip = engine.compute_jito_tip(&weak);
+        assert!(strong_tip.tip_lamports > weak_tip.tip_lamports);
+        assert!(strong_tip.tip_lamports <= 100_000);
+        assert!(weak_tip.tip_lamports >= 1_000);
+
+        // A marginal trade's tip is bounded by its estimated profit
+        let mut marginal = test_ai_signal(1_000);
+        marginal.estimated_profit = 0.000_005; // 5_000 lamports
+        assert_eq!(marginal.estimated_profit_lamports(), 5_000);
+        let marginal_tip = engine.compute_jito_tip(&marginal);
+        assert_eq!(marginal_tip.profit_cap_lamports, 500);
+        assert!(marginal_tip.tip_lamports <= 500);
+    }
+
+    #[tokio::test]
+    async fn test_ai_consistency_rule() {
+        let engine = OvermindHFTEngine::new(HFTConfig::default()).unwrap();
+
+        let consistent = test_ai_signal(1_000);
+        assert!(engine.check_ai_consistency(&consistent).is_none());
+
+        let mut overconfident = test_ai_signal(1_000);
+        overconfident.confidence = 0.95;
+        overconfident.estimated_profit = 0.0;
+        assert!(engine.check_ai_consistency(&overconfident).is_some());
+
+        let mut too_good = test_ai_signal(1_000);
+        too_good.confidence = 0.72;
+        too_good.estimated_profit = 500.0;
+        assert!(engine.check_ai_consistency(&too_good).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_bundle_rebuild_escalates_tip_up_to_cap() {
+        let mut rebuild = BundleRebuild {
+            signal: test_ai_signal(1_000),
+            tip_lamports: 40_000,
+            attempts: 0,
+        };
+        assert_eq!(rebuild.escalated_tip(1.5, 100_000), 60_000);
+        assert_eq!(rebuild.escalated_tip(3.0, 100_000), 100_000);
+        // Multipliers below 1.0 never lower the tip
+        assert_eq!(rebuild.escalated_tip(0.5, 100_000), 40_000);
+
+        // Out of retry attempts: nothing is resubmitted
+        let mut engine = OvermindHFTEngine::new(HFTConfig {
+            retry_attempts: 0,
+            ..HFTConfig::default()
+        }).unwrap();
+        assert!(engine.rebuild_bundle(&mut rebuild).await.unwrap().is_none());
+        assert_eq!(rebuild.tip_lamports, 40_000);
+        assert_eq!(engine.get_metrics().bundles_rebuilt, 0);
+    }
+
+    #[tokio::test]
+    async fn test_bundles_go_through_injected_jito_client() {
+        let jito = Arc::new(LocalJitoClient::new());
+        let mut engine = OvermindHFTEngine::new(HFTConfig {
+            // Would fail if anything reached the network
+            jito_endpoint: "http://127.0.0.1:9".to_string(),
+            ..HFTConfig::default()
+        })
+        .unwrap()
+        .with_jito_client(jito.clone());
+
+        let mut rebuild = BundleRebuild {
+            signal: test_ai_signal(1_000),
+            tip_lamports: 10_000,
+            attempts: 0,
+        };
+        let result = engine.rebuild_bundle(&mut rebuild).await.unwrap().unwrap();
+        assert_ne!(result.bundle_id, "unknown");
+        assert_eq!(jito.submitted(), 1);
+        assert_eq!(jito.last_bundle().unwrap()["transactions"].as_array().unwrap().len(), 1);
+        assert_eq!(engine.get_metrics().bundles_submitted, 1);
+
+        // A replacement bumps both fees up to their ceilings, then stops
+        let mut engine = OvermindHFTEngine::new(HFTConfig {
+            max_priority_fee_lamports: 2_000,
+            max_jito_tip_lamports: 30_000,
+            ..HFTConfig::default()
+        })
+        .unwrap()
+        .with_jito_client(jito.clone());
+        assert!(engine.replace_bundle(&mut rebuild).await.unwrap().is_some());
+        assert_eq!(rebuild.signal.action.priority_fee, 1_500);
+        assert_eq!(rebuild.tip_lamports, 18_750);
+        assert!(engine.replace_bundle(&mut rebuild).await.unwrap().is_some());
+        assert_eq!(rebuild.signal.action.priority_fee, 2_000);
+        assert_eq!(rebuild.tip_lamports, 28_125);
+        assert!(engine.replace_bundle(&mut rebuild).await.unwrap().is_some());
+        assert!(engine.replace_bundle(&mut rebuild).await.unwrap().is_none());
+        assert_eq!(rebuild.tip_lamports, 30_000);
+        assert_eq!(engine.get_metrics().bundles_replaced, 3);
+        assert_eq!(jito.submitted(), 4);
+
+        assert!("local".parse::<JitoBackend>().is_ok());
+        assert!("devnet".parse::<JitoBackend>().is_err());
+    }
+
+    /// Rejects every bundle with a fixed JSON-RPC error
+    struct RejectingJitoClient(serde_json::Value);
+
+    impl JitoClient for RejectingJitoClient {
+        fn send_bundle(&self, _params: serde_json::Value) -> futures::future::BoxFuture<'_, Result<serde_json::Value>> {
+            let response = serde_json::json!({ "jsonrpc": "2.0", "error": self.0.clone(), "id": 1 });
+            Box::pin(async move { Ok(response) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_jito_rejections_are_typed_and_only_endpoint_failures_trip_breaker() {
+        let breaker = CircuitBreakerConfig {
+            failure_threshold: 2,
+            ..CircuitBreakerConfig::default()
+        };
+        let mut rebuild = BundleRebuild {
+            signal: test_ai_signal(1_000),
+            tip_lamports: 10_000,
+            attempts: 0,
+        };
+
+        let mut engine = OvermindHFTEngine::new(HFTConfig {
+            jito_breaker: breaker.clone(),
+            ..HFTConfig::default()
+        })
+        .unwrap()
+        .with_jito_client(Arc::new(RejectingJitoClient(serde_json::json!({
+            "code": -32602,
+            "message": "bundle simulation failed: Transaction simulation failed: Error processing Instruction 0: custom program error: 0x1771"
+        }))));
+        for _ in 0..3 {
+            let error = engine.rebuild_bundle(&mut rebuild).await.unwrap_err();
+            let rejection = error.downcast_ref::<JitoRejection>().unwrap();
+            assert_eq!(rejection.reason, JitoRejectReason::SimulationFailed);
+            rebuild.attempts = 0;
+        }
+        assert_eq!(engine.get_metrics().jito_rejections[&JitoRejectReason::SimulationFailed], 3);
+        assert_eq!(engine.jito_circuit_state(), CircuitState::Closed);
+
+        let mut engine = OvermindHFTEngine::new(HFTConfig {
+            jito_breaker: breaker,
+            ..HFTConfig::default()
+        })
+        .unwrap()
+        .with_jito_client(Arc::new(RejectingJitoClient(serde_json::json!({
+            "code": -32097,
+            "message": "Network congested. Endpoint is globally rate limited."
+        }))));
+        // Rate limiting is retried within one rebuild until the breaker opens
+        assert!(engine.rebuild_bundle(&mut rebuild).await.is_err());
+        assert_eq!(rebuild.attempts, 2);
+        assert_eq!(engine.get_metrics().jito_rejections[&JitoRejectReason::RateLimited], 2);
+        assert_eq!(engine.jito_circuit_state(), CircuitState::Open);
+    }
 }
@@ -0,0 +1,339 @@
+// Honeypot Detection Module
+// Screens a token's mint before a sniping buy and rejects tokens that cannot be sold
+
+use crate::modules::strategy::{StrategyType, TradeAction, TradingSignal};
+use anyhow::{anyhow, Context, Result};
+use futures::future::BoxFuture;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+const TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Length of the base SPL mint layout
+const MINT_LEN: usize = 82;
+/// Token-2022 pads mints to the token-account length before the account-type byte
+const TOKEN_2022_ACCOUNT_TYPE_OFFSET: usize = 165;
+const TOKEN_2022_MINT_ACCOUNT_TYPE: u8 = 1;
+
+// Token-2022 extension type discriminators
+const EXT_TRANSFER_FEE_CONFIG: u16 = 1;
+const EXT_DEFAULT_ACCOUNT_STATE: u16 = 6;
+const EXT_NON_TRANSFERABLE: u16 = 9;
+const EXT_PERMANENT_DELEGATE: u16 = 12;
+const EXT_TRANSFER_HOOK: u16 = 14;
+
+#[derive(Debug, Clone)]
+pub struct HoneypotConfig {
+    /// Strategies whose buys are screened
+    pub strategies: HashSet<StrategyType>,
+    /// How long a verdict for a mint is reused
+    pub cache_ttl: Duration,
+    pub check_timeout: Duration,
+    /// Allow the buy when the check itself fails (RPC down); rejects by default
+    pub fail_open: bool,
+    /// Treat a mint whose freeze authority is still set as a honeypot
+    pub reject_freeze_authority: bool,
+    /// Transfer fees above this (Token-2022) make selling uneconomic
+    pub max_transfer_fee_bps: u16,
+}
+
+impl Default for HoneypotConfig {
+    fn default() -> Self {
+        Self {
+            strategies: [
+                StrategyType::TokenSniping,
+                StrategyType::SoulMeteorSniping,
+                StrategyType::AxiomMemeCoin,
+            ]
+            .into_iter()
+            .collect(),
+            cache_ttl: Duration::from_secs(60),
+            check_timeout: Duration::from_secs(2),
+            fail_open: false,
+            reject_freeze_authority: true,
+            max_transfer_fee_bps: 1_000,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum HoneypotVerdict {
+    Sellable,
+    Honeypot(String),
+}
+
+/// Pluggable pre-trade check deciding whether a token can be sold after buying
+pub trait HoneypotCheck: Send + Sync {
+    fn check<'a>(&'a self, mint: &'a Pubkey) -> BoxFuture<'a, Result<HoneypotVerdict>>;
+}
+
+fn read_u16(data: &[u8], offset: usize) -> Option<u16> {
+    data.get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+/// Inspect a mint account's authorities and Token-2022 extensions for flags
+/// that let the issuer block or tax sells
+pub fn inspect_mint_account(
+    owner: &Pubkey,
+    data: &[u8],
+    config: &HoneypotConfig,
+) -> Result<HoneypotVerdict> {
+    if *owner != TOKEN_PROGRAM_ID && *owner != TOKEN_2022_PROGRAM_ID {
+        return Err(anyhow!("Account is not a token mint (owner {})", owner));
+    }
+    if data.len() < MINT_LEN {
+        return Err(anyhow!("Mint account too short ({} bytes)", data.len()));
+    }
+
+    // COption<Pubkey> freeze authority: u32 tag followed by the key
+    let freeze_authority_set = data[46..50] != [0, 0, 0, 0];
+    if config.reject_freeze_authority && freeze_authority_set {
+        return Ok(HoneypotVerdict::Honeypot(
+            "freeze authority is set".to_string(),
+        ));
+    }
+
+    if *owner != TOKEN_2022_PROGRAM_ID || data.len() <= TOKEN_2022_ACCOUNT_TYPE_OFFSET {
+        return Ok(HoneypotVerdict::Sellable);
+    }
+    if data[TOKEN_2022_ACCOUNT_TYPE_OFFSET] != TOKEN_2022_MINT_ACCOUNT_TYPE {
+        return Err(anyhow!("Token-2022 account is not a mint"));
+    }
+
+    let mut offset = TOKEN_2022_ACCOUNT_TYPE_OFFSET + 1;
+    while let (Some(extension), Some(length)) = (read_u16(data, offset), read_u16(data, offset + 2))
+    {
+        let value = data
+            .get(offset + 4..offset + 4 + length as usize)
+            .context("Truncated Token-2022 extension")?;
+
+        let flagged = match extension {
+            EXT_NON_TRANSFERABLE => Some("token is non-transferable".to_string()),
+            // New token accounts start frozen; holders cannot move what they buy
+            EXT_DEFAULT_ACCOUNT_STATE if value.first() == Some(&2) => {
+                Some("token accounts default to frozen".to_string())
+            }
+            EXT_PERMANENT_DELEGATE if value.iter().any(|b| *b != 0) => {
+                Some("permanent delegate can move holder balances".to_string())
+            }
+            // Hook program id follows the 32-byte authority
+            EXT_TRANSFER_HOOK if value.get(32..64).is_some_and(|p| p.iter().any(|b| *b != 0)) => {
+                Some("transfer hook program can block transfers".to_string())
+            }
+            EXT_TRANSFER_FEE_CONFIG => {
+                // Older and newer fee schedules each end in their basis points
+                let fee_bps = read_u16(value, 88)
+                    .into_iter()
+                    .chain(read_u16(value, 106))
+                    .max()
+                    .unwrap_or(0);
+                (fee_bps > config.max_transfer_fee_bps)
+                    .then(|| format!("transfer fee of {} bps", fee_bps))
+            }
+            _ => None,
+        };
+        if let Some(reason) = flagged {
+            return Ok(HoneypotVerdict::Honeypot(reason));
+        }
+
+        offset += 4 + length as usize;
+    }
+
+    Ok(HoneypotVerdict::Sellable)
+}
+
+/// Honeypot check that fetches the mint account over RPC and inspects its flags
+pub struct MintAuthorityCheck {
+    rpc_client: Arc<RpcClient>,
+    config: HoneypotConfig,
+}
+
+impl MintAuthorityCheck {
+    pub fn new(rpc_client: Arc<RpcClient>, config: HoneypotConfig) -> Self {
+        Self { rpc_client, config }
+    }
+}
+
+impl HoneypotCheck for MintAuthorityCheck {
+    fn check<'a>(&'a self, mint: &'a Pubkey) -> BoxFuture<'a, Result<HoneypotVerdict>> {
+        Box::pin(async move {
+            let account = self
+                .rpc_client
+                .get_account(mint)
+                .await
+                .with_context(|| format!("Failed to fetch mint account {}", mint))?;
+            inspect_mint_account(&account.owner, &account.data, &self.config)
+        })
+    }
+}
+
+/// Runs the configured check before sniping buys, caching verdicts per mint
+pub struct HoneypotGuard {
+    config: HoneypotConfig,
+    check: Arc<dyn HoneypotCheck>,
+    verdicts: Mutex<HashMap<Pubkey, (HoneypotVerdict, Instant)>>,
+    blocked: Arc<AtomicU64>,
+}
+
+#[allow(dead_code)]
+impl HoneypotGuard {
+    pub fn new(config: HoneypotConfig, check: Arc<dyn HoneypotCheck>) -> Self {
+        Self {
+            config,
+            check,
+            verdicts: Mutex::new(HashMap::new()),
+            blocked: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Only buys from the configured sniping strategies are screened
+    pub fn applies_to(&self, signal: &TradingSignal) -> bool {
+        matches!(signal.action, TradeAction::Buy)
+            && self.config.strategies.contains(&signal.strategy_type)
+    }
+
+    pub fn fail_open(&self) -> bool {
+        self.config.fail_open
+    }
+
+    /// Verdict for `mint`, from the cache when still fresh; check failures are not cached
+    pub async fn screen(&self, mint: &Pubkey) -> Result<HoneypotVerdict> {
+        if let Some(verdict) = self.cached(mint) {
+            debug!("🍯 Cached honeypot verdict for {}: {:?}", mint, verdict);
+            return self.count(verdict);
+        }
+
+        let verdict = tokio::time::timeout(self.config.check_timeout, self.check.check(mint))
+            .await
+            .map_err(|_| anyhow!("Honeypot check for {} timed out", mint))??;
+
+        if let Ok(mut verdicts) = self.verdicts.lock() {
+            verdicts.insert(*mint, (verdict.clone(), Instant::now()));
+        }
+        self.count(verdict)
+    }
+
+    fn cached(&self, mint: &Pubkey) -> Option<HoneypotVerdict> {
+        let mut verdicts = self.verdicts.lock().ok()?;
+        verdicts.retain(|_, (_, checked_at)| checked_at.elapsed() < self.config.cache_ttl);
+        verdicts.get(mint).map(|(verdict, _)| verdict.clone())
+    }
+
+    fn count(&self, verdict: HoneypotVerdict) -> Result<HoneypotVerdict> {
+        if matches!(verdict, HoneypotVerdict::Honeypot(_)) {
+            self.blocked.fetch_add(1, Ordering::Relaxed);
+        }
+        Ok(verdict)
+    }
+
+    /// Number of buys rejected as honeypots
+    pub fn blocked_count(&self) -> u64 {
+        self.blocked.load(Ordering::Relaxed)
+    }
+
+    /// Shared handle on the blocked counter for metrics export
+    pub fn blocked_counter(&self) -> Arc<AtomicU64> {
+        self.blocked.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mint_data(freeze_authority: bool) -> Vec<u8> {
+        let mut data = vec![0u8; MINT_LEN];
+        data[45] = 1; // is_initialized
+        if freeze_authority {
+            data[46] = 1;
+            data[50..82].copy_from_slice(Pubkey::new_unique().as_ref());
+        }
+        data
+    }
+
+    fn token_2022_mint(extensions: &[(u16, Vec<u8>)]) -> Vec<u8> {
+        let mut data = mint_data(false);
+        data.resize(TOKEN_2022_ACCOUNT_TYPE_OFFSET, 0);
+        data.push(TOKEN_2022_MINT_ACCOUNT_TYPE);
+        for (extension, value) in extensions {
+            data.extend_from_slice(&extension.to_le_bytes());
+            data.extend_from_slice(&(value.len() as u16).to_le_bytes());
+            data.extend_from_slice(value);
+        }
+        data
+    }
+
+    #[test]
+    fn test_mint_inspection_flags_blocked_sells() {
+        let config = HoneypotConfig::default();
+        let sellable = inspect_mint_account(&TOKEN_PROGRAM_ID, &mint_data(false), &config);
+        assert_eq!(sellable.unwrap(), HoneypotVerdict::Sellable);
+
+        let frozen = inspect_mint_account(&TOKEN_PROGRAM_ID, &mint_data(true), &config);
+        assert!(matches!(frozen.unwrap(), HoneypotVerdict::Honeypot(_)));
+
+        let mut hook = vec![0u8; 64];
+        hook[32..].copy_from_slice(Pubkey::new_unique().as_ref());
+        let hooked = token_2022_mint(&[(EXT_TRANSFER_HOOK, hook)]);
+        assert!(matches!(
+            inspect_mint_account(&TOKEN_2022_PROGRAM_ID, &hooked, &config).unwrap(),
+            HoneypotVerdict::Honeypot(_)
+        ));
+
+        let mut fee = vec![0u8; 108];
+        fee[106..108].copy_from_slice(&5_000u16.to_le_bytes());
+        let taxed = token_2022_mint(&[(EXT_TRANSFER_FEE_CONFIG, fee)]);
+        assert!(matches!(
+            inspect_mint_account(&TOKEN_2022_PROGRAM_ID, &taxed, &config).unwrap(),
+            HoneypotVerdict::Honeypot(_)
+        ));
+
+        let plain = token_2022_mint(&[(EXT_TRANSFER_FEE_CONFIG, vec![0u8; 108])]);
+        assert_eq!(
+            inspect_mint_account(&TOKEN_2022_PROGRAM_ID, &plain, &config).unwrap(),
+            HoneypotVerdict::Sellable
+        );
+    }
+
+    struct CountingCheck(AtomicU64);
+
+    impl HoneypotCheck for CountingCheck {
+        fn check<'a>(&'a self, _mint: &'a Pubkey) -> BoxFuture<'a, Result<HoneypotVerdict>> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            Box::pin(async { Ok(HoneypotVerdict::Honeypot("test".to_string())) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_guard_caches_verdicts_and_counts_blocks() {
+        let check = Arc::new(CountingCheck(AtomicU64::new(0)));
+        let guard = HoneypotGuard::new(HoneypotConfig::default(), check.clone());
+        let mint = Pubkey::new_unique();
+
+        for _ in 0..3 {
+            let verdict = guard.screen(&mint).await.unwrap();
+            assert!(matches!(verdict, HoneypotVerdict::Honeypot(_)));
+        }
+        assert_eq!(check.0.load(Ordering::Relaxed), 1);
+        assert_eq!(guard.blocked_count(), 3);
+
+        let expired = HoneypotGuard::new(
+            HoneypotConfig {
+                cache_ttl: Duration::ZERO,
+                ..HoneypotConfig::default()
+            },
+            check.clone(),
+        );
+        expired.screen(&mint).await.unwrap();
+        expired.screen(&mint).await.unwrap();
+        assert_eq!(check.0.load(Ordering::Relaxed), 3);
+    }
+}
@@ -0,0 +1,195 @@
+// Idempotency Module
+// Durable record of executed signal IDs in DragonflyDB, so a signal replayed after a
+// restart, or seen by another instance, is not executed twice
+
+use anyhow::{Context, Result};
+use futures::future::BoxFuture;
+use redis::aio::ConnectionManager;
+use redis::AsyncCommands;
+use std::sync::Arc;
+use std::time::Duration;
+use tracing::{error, warn};
+
+#[derive(Debug, Clone)]
+pub struct IdempotencyConfig {
+    /// Prefix of the DragonflyDB sets holding executed signal IDs
+    pub key_prefix: String,
+    /// How long an executed signal ID is remembered
+    pub ttl: Duration,
+    /// Execute when the store is unreachable (risking a rare duplicate) instead of
+    /// rejecting every signal until it is back
+    pub fail_open: bool,
+}
+
+impl Default for IdempotencyConfig {
+    fn default() -> Self {
+        Self {
+            key_prefix: "snipercor:executed_signals".to_string(),
+            ttl: Duration::from_secs(24 * 60 * 60),
+            fail_open: true,
+        }
+    }
+}
+
+/// Shared set of executed signal IDs
+pub trait IdempotencyStore: Send + Sync {
+    /// Record `signal_id` as executed; false when it was already recorded
+    fn claim<'a>(&'a self, signal_id: &'a str) -> BoxFuture<'a, Result<bool>>;
+}
+
+/// Signal IDs in DragonflyDB sets, one per TTL-long bucket. A claim checks the previous
+/// bucket with SISMEMBER and adds to the current one with SADD, so an ID is remembered
+/// for at least `ttl` and each set expires once it can no longer be consulted.
+pub struct DragonflyIdempotencyStore {
+    connection: ConnectionManager,
+    key_prefix: String,
+    ttl: Duration,
+}
+
+#[allow(dead_code)]
+impl DragonflyIdempotencyStore {
+    pub async fn connect(url: &str, config: &IdempotencyConfig) -> Result<Self> {
+        let client = redis::Client::open(url).context("Invalid idempotency store URL")?;
+        let connection = ConnectionManager::new(client)
+            .await
+            .context("Failed to connect to idempotency store")?;
+        Ok(Self {
+            connection,
+            key_prefix: config.key_prefix.clone(),
+            ttl: config.ttl,
+        })
+    }
+
+    fn bucket_key(&self, bucket: u64) -> String {
+        format!("{}:{}", self.key_prefix, bucket)
+    }
+}
+
+impl IdempotencyStore for DragonflyIdempotencyStore {
+    fn claim<'a>(&'a self, signal_id: &'a str) -> BoxFuture<'a, Result<bool>> {
+        Box::pin(async move {
+            let ttl_secs = self.ttl.as_secs().max(1);
+            let bucket = chrono::Utc::now().timestamp().max(0) as u64 / ttl_secs;
+            let current = self.bucket_key(bucket);
+            let previous = self.bucket_key(bucket.saturating_sub(1));
+            let mut conn = self.connection.clone();
+
+            let seen_before: bool = conn.sismember(&previous, signal_id).await?;
+            if seen_before {
+                return Ok(false);
+            }
+            let (added,): (i64,) = redis::pipe()
+                .atomic()
+                .sadd(&current, signal_id)
+                .expire(&current, (ttl_secs * 2) as i64)
+                .ignore()
+                .query_async(&mut conn)
+                .await?;
+            Ok(added > 0)
+        })
+    }
+}
+
+/// Outcome of checking a signal against the durable idempotency set
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdempotencyCheck {
+    /// First time this signal is seen; it is now recorded
+    Fresh,
+    /// Already executed, possibly before a restart or by another instance
+    Duplicate,
+    /// The store failed and the guard fails closed
+    Unavailable(String),
+}
+
+pub struct IdempotencyGuard {
+    store: Arc<dyn IdempotencyStore>,
+    fail_open: bool,
+}
+
+#[allow(dead_code)]
+impl IdempotencyGuard {
+    pub fn new(store: Arc<dyn IdempotencyStore>, config: &IdempotencyConfig) -> Self {
+        Self {
+            store,
+            fail_open: config.fail_open,
+        }
+    }
+
+    /// Claim `signal_id` before executing it. The ID is recorded up front, so a crash
+    /// mid-execution drops the signal rather than executing it twice.
+    pub async fn check(&self, signal_id: &str) -> IdempotencyCheck {
+        match self.store.claim(signal_id).await {
+            Ok(true) => IdempotencyCheck::Fresh,
+            Ok(false) => IdempotencyCheck::Duplicate,
+            Err(e) if self.fail_open => {
+                warn!(
+                    "Idempotency store unavailable, executing {} unchecked: {}",
+                    signal_id, e
+                );
+                IdempotencyCheck::Fresh
+            }
+            Err(e) => {
+                error!(
+                    "Idempotency store unavailable, rejecting {}: {}",
+                    signal_id, e
+                );
+                IdempotencyCheck::Unavailable(e.to_string())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+
+    /// Stands in for DragonflyDB; `down` makes every claim fail
+    #[derive(Default)]
+    struct MemoryStore {
+        claimed: Mutex<HashSet<String>>,
+        down: bool,
+    }
+
+    impl IdempotencyStore for MemoryStore {
+        fn claim<'a>(&'a self, signal_id: &'a str) -> BoxFuture<'a, Result<bool>> {
+            Box::pin(async move {
+                if self.down {
+                    return Err(anyhow::anyhow!("connection refused"));
+                }
+                Ok(self.claimed.lock().unwrap().insert(signal_id.to_string()))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_duplicates_rejected_and_outages_follow_fail_mode() {
+        // Two guards over one store behave like a restarted process or an HA peer
+        let store = Arc::new(MemoryStore::default());
+        let config = IdempotencyConfig::default();
+        let first = IdempotencyGuard::new(store.clone(), &config);
+        let second = IdempotencyGuard::new(store, &config);
+        assert_eq!(first.check("sig-1").await, IdempotencyCheck::Fresh);
+        assert_eq!(second.check("sig-1").await, IdempotencyCheck::Duplicate);
+        assert_eq!(second.check("sig-2").await, IdempotencyCheck::Fresh);
+
+        let down = Arc::new(MemoryStore {
+            down: true,
+            ..MemoryStore::default()
+        });
+        let open = IdempotencyGuard::new(down.clone(), &config);
+        assert_eq!(open.check("sig-3").await, IdempotencyCheck::Fresh);
+        let closed = IdempotencyGuard::new(
+            down,
+            &IdempotencyConfig {
+                fail_open: false,
+                ..IdempotencyConfig::default()
+            },
+        );
+        assert!(matches!(
+            closed.check("sig-3").await,
+            IdempotencyCheck::Unavailable(_)
+        ));
+    }
+}
@@ -0,0 +1,241 @@
+// Jito Client Module
+// Bundle submission behind a trait, so the HFT engine can run against the Jito block
+// engine, a local cluster without one, or a test double
+
+use anyhow::Result;
+use futures::future::BoxFuture;
+use jito_sdk_rust::JitoJsonRpcSDK;
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use uuid::Uuid;
+
+/// Where the HFT engine sends its bundles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum JitoBackend {
+    /// The configured Jito block engine (`jito_endpoint`)
+    #[default]
+    BlockEngine,
+    /// Accept bundles locally without sending them, e.g. against solana-test-validator
+    Local,
+}
+
+impl std::str::FromStr for JitoBackend {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "block_engine" | "jito" => Ok(JitoBackend::BlockEngine),
+            "local" => Ok(JitoBackend::Local),
+            other => Err(anyhow::anyhow!("Unknown Jito backend: {}", other)),
+        }
+    }
+}
+
+/// Why the block engine refused a bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum JitoRejectReason {
+    /// A transaction in the bundle failed simulation
+    SimulationFailed,
+    /// The bundle contains a transaction that already landed
+    AlreadyProcessed,
+    /// The endpoint is rate limited or congested
+    RateLimited,
+    /// The bundle does not pay a Jito tip account
+    MissingTip,
+    /// Malformed bundle, e.g. too many or undecodable transactions
+    InvalidBundle,
+    /// An error Jito does not document, or a response without a bundle ID
+    Unknown,
+}
+
+impl JitoRejectReason {
+    /// Whether the block engine itself is struggling, as opposed to the bundle being bad;
+    /// only these count towards opening the Jito circuit breaker
+    pub fn is_endpoint_failure(&self) -> bool {
+        matches!(
+            self,
+            JitoRejectReason::RateLimited | JitoRejectReason::Unknown
+        )
+    }
+
+    /// Whether resubmitting the same decision can succeed
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            JitoRejectReason::RateLimited | JitoRejectReason::Unknown
+        )
+    }
+
+    /// Whether executing the trade another way is safe; an already-processed bundle may
+    /// have landed, so falling back risks executing the trade twice
+    pub fn allows_fallback(&self) -> bool {
+        !matches!(self, JitoRejectReason::AlreadyProcessed)
+    }
+}
+
+/// A `sendBundle` response that carried an error instead of a bundle ID
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JitoRejection {
+    pub reason: JitoRejectReason,
+    /// JSON-RPC error code, when the response had one
+    pub code: Option<i64>,
+    pub message: String,
+}
+
+impl JitoRejection {
+    /// JSON-RPC code Jito returns when the endpoint is rate limited
+    pub const RATE_LIMITED_CODE: i64 = -32097;
+    /// JSON-RPC code for invalid params, which Jito also uses for bundle-level failures
+    pub const INVALID_PARAMS_CODE: i64 = -32602;
+
+    /// The bundle ID of an accepted bundle, or the rejection parsed from the error object
+    pub fn bundle_id_from_response(response: &serde_json::Value) -> Result<String, JitoRejection> {
+        if let Some(bundle_id) = response["result"].as_str() {
+            return Ok(bundle_id.to_string());
+        }
+        let error = &response["error"];
+        let code = error["code"].as_i64();
+        let message = match error["message"].as_str() {
+            Some(message) => message.to_string(),
+            None if error.is_null() => "response has no bundle ID".to_string(),
+            None => error.to_string(),
+        };
+        Err(JitoRejection {
+            reason: Self::classify(code, &message),
+            code,
+            message,
+        })
+    }
+
+    fn classify(code: Option<i64>, message: &str) -> JitoRejectReason {
+        let message = message.to_lowercase();
+        if message.contains("already processed") || message.contains("alreadyprocessed") {
+            JitoRejectReason::AlreadyProcessed
+        } else if message.contains("simulation failed") {
+            JitoRejectReason::SimulationFailed
+        } else if message.contains("tip account") {
+            JitoRejectReason::MissingTip
+        } else if code == Some(Self::RATE_LIMITED_CODE)
+            || message.contains("rate limit")
+            || message.contains("congested")
+        {
+            JitoRejectReason::RateLimited
+        } else if code == Some(Self::INVALID_PARAMS_CODE) {
+            JitoRejectReason::InvalidBundle
+        } else {
+            JitoRejectReason::Unknown
+        }
+    }
+}
+
+impl std::fmt::Display for JitoRejection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Jito rejected bundle ({:?}): {}",
+            self.reason, self.message
+        )
+    }
+}
+
+impl std::error::Error for JitoRejection {}
+
+/// Submits bundles (`sendBundle`) and returns the JSON-RPC response
+pub trait JitoClient: Send + Sync {
+    fn send_bundle(&self, params: serde_json::Value) -> BoxFuture<'_, Result<serde_json::Value>>;
+}
+
+impl JitoClient for JitoJsonRpcSDK {
+    fn send_bundle(&self, params: serde_json::Value) -> BoxFuture<'_, Result<serde_json::Value>> {
+        Box::pin(JitoJsonRpcSDK::send_bundle(self, Some(params), None))
+    }
+}
+
+/// Accepts every bundle with a generated ID and keeps the last one submitted
+#[derive(Debug, Default)]
+pub struct LocalJitoClient {
+    submitted: AtomicU64,
+    last_bundle: Mutex<Option<serde_json::Value>>,
+}
+
+#[allow(dead_code)]
+impl LocalJitoClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn submitted(&self) -> u64 {
+        self.submitted.load(Ordering::Relaxed)
+    }
+
+    pub fn last_bundle(&self) -> Option<serde_json::Value> {
+        self.last_bundle
+            .lock()
+            .ok()
+            .and_then(|bundle| bundle.clone())
+    }
+}
+
+impl JitoClient for LocalJitoClient {
+    fn send_bundle(&self, params: serde_json::Value) -> BoxFuture<'_, Result<serde_json::Value>> {
+        self.submitted.fetch_add(1, Ordering::Relaxed);
+        if let Ok(mut last_bundle) = self.last_bundle.lock() {
+            *last_bundle = Some(params);
+        }
+        let bundle_id = Uuid::new_v4().to_string();
+        Box::pin(async move { Ok(serde_json::json!({ "result": bundle_id })) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn rejection(response: serde_json::Value) -> JitoRejection {
+        JitoRejection::bundle_id_from_response(&response).unwrap_err()
+    }
+
+    #[test]
+    fn test_rejection_reasons_parsed_from_jito_errors() {
+        let accepted = json!({ "jsonrpc": "2.0", "result": "2id3YC2jK9G5Wo2phDx4gJVAew8DcY5NAojnVuao8rkxwPYPe8cSwE5GzhEgJA2y8fVjDEo6iR6ykBvDxrTQrtpb", "id": 1 });
+        assert!(JitoRejection::bundle_id_from_response(&accepted)
+            .unwrap()
+            .starts_with("2id3"));
+
+        let simulation = rejection(
+            json!({ "jsonrpc": "2.0", "error": { "code": -32602, "message": "bundle simulation failed: Transaction simulation failed: Error processing Instruction 2: custom program error: 0x1" }, "id": 1 }),
+        );
+        assert_eq!(simulation.reason, JitoRejectReason::SimulationFailed);
+        assert_eq!(simulation.code, Some(-32602));
+        assert!(!simulation.reason.is_endpoint_failure());
+
+        let processed = rejection(
+            json!({ "jsonrpc": "2.0", "error": { "code": -32602, "message": "bundle contains an already processed transaction" }, "id": 1 }),
+        );
+        assert_eq!(processed.reason, JitoRejectReason::AlreadyProcessed);
+        assert!(!processed.reason.allows_fallback());
+
+        let rate_limited = rejection(
+            json!({ "jsonrpc": "2.0", "error": { "code": -32097, "message": "Network congested. Endpoint is globally rate limited." }, "id": 1 }),
+        );
+        assert_eq!(rate_limited.reason, JitoRejectReason::RateLimited);
+        assert!(rate_limited.reason.is_endpoint_failure() && rate_limited.reason.is_retryable());
+
+        let tip = rejection(
+            json!({ "jsonrpc": "2.0", "error": { "code": -32602, "message": "Bundles must write lock at least one tip account to be eligible for the auction." }, "id": 1 }),
+        );
+        assert_eq!(tip.reason, JitoRejectReason::MissingTip);
+
+        let invalid = rejection(
+            json!({ "jsonrpc": "2.0", "error": { "code": -32602, "message": "bundle exceeds max transaction count of 5" }, "id": 1 }),
+        );
+        assert_eq!(invalid.reason, JitoRejectReason::InvalidBundle);
+
+        let empty = rejection(json!({ "jsonrpc": "2.0", "id": 1 }));
+        assert_eq!(empty.reason, JitoRejectReason::Unknown);
+        assert_eq!(empty.code, None);
+        assert_eq!(empty.message, "response has no bundle ID");
+    }
+}
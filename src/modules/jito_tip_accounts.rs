@@ -0,0 +1,338 @@
+// Jito Tip Account Check Module
+// Validates the configured tip account against Jito's published list before live trading,
+// and picks the tip account each bundle pays
+
+use anyhow::{anyhow, Context, Result};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::{info, warn};
+
+#[derive(Debug, Clone)]
+pub struct TipAccountCheckConfig {
+    pub jito_endpoint: String,
+    /// Tip account bundles will pay; None fails the check
+    pub tip_account: Option<String>,
+    /// Known tip accounts to use instead of fetching (air-gapped setups)
+    pub accounts_override: Option<Vec<String>>,
+    /// File the fetched list is written to, and read from when the fetch fails
+    pub cache_path: Option<PathBuf>,
+    pub fetch_timeout: Duration,
+}
+
+impl Default for TipAccountCheckConfig {
+    fn default() -> Self {
+        Self {
+            jito_endpoint: "https://mainnet.block-engine.jito.wtf".to_string(),
+            tip_account: None,
+            accounts_override: None,
+            cache_path: None,
+            fetch_timeout: Duration::from_secs(5),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TipAccountSource {
+    Override,
+    Fetched,
+    Cache,
+}
+
+#[derive(Debug, Clone)]
+pub struct TipAccountList {
+    pub accounts: HashSet<Pubkey>,
+    pub source: TipAccountSource,
+}
+
+/// How the tip account is chosen for each bundle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TipAccountRotation {
+    /// Always the configured `tip_account`
+    #[default]
+    Fixed,
+    /// Cycle through the tip-account list in order
+    RoundRobin,
+    /// Draw uniformly from the tip-account list
+    Random,
+}
+
+impl std::str::FromStr for TipAccountRotation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "fixed" => Ok(TipAccountRotation::Fixed),
+            "round_robin" | "round-robin" => Ok(TipAccountRotation::RoundRobin),
+            "random" => Ok(TipAccountRotation::Random),
+            other => Err(anyhow!("Unknown tip account rotation: {}", other)),
+        }
+    }
+}
+
+/// Picks the tip account each bundle pays from a validated list
+#[derive(Debug, Clone)]
+pub struct TipAccountSelector {
+    accounts: Vec<Pubkey>,
+    rotation: TipAccountRotation,
+    next: usize,
+    rng: StdRng,
+}
+
+impl TipAccountSelector {
+    /// Accounts are sorted so round-robin order and seeded draws are reproducible
+    pub fn new(accounts: impl IntoIterator<Item = Pubkey>, rotation: TipAccountRotation) -> Result<Self> {
+        let mut accounts: Vec<Pubkey> = accounts.into_iter().collect();
+        accounts.sort();
+        accounts.dedup();
+        if accounts.is_empty() {
+            return Err(anyhow!("Tip account list is empty"));
+        }
+        Ok(Self {
+            accounts,
+            rotation,
+            next: 0,
+            rng: StdRng::from_entropy(),
+        })
+    }
+
+    /// Make random selection deterministic (tests, replays)
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng = StdRng::seed_from_u64(seed);
+        self
+    }
+
+    pub fn accounts(&self) -> &[Pubkey] {
+        &self.accounts
+    }
+
+    /// Tip account for the next bundle
+    pub fn next_account(&mut self) -> Pubkey {
+        match self.rotation {
+            TipAccountRotation::Fixed => self.accounts[0],
+            TipAccountRotation::RoundRobin => {
+                let account = self.accounts[self.next % self.accounts.len()];
+                self.next = (self.next + 1) % self.accounts.len();
+                account
+            }
+            TipAccountRotation::Random => self.accounts[self.rng.gen_range(0..self.accounts.len())],
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct TipAccountsResponse {
+    result: Vec<String>,
+}
+
+/// Parse tip accounts, rejecting the whole list if any entry is malformed
+fn parse_accounts(accounts: &[String]) -> Result<HashSet<Pubkey>> {
+    let parsed = accounts
+        .iter()
+        .map(|a| Pubkey::from_str(a.trim()).with_context(|| format!("Invalid tip account {}", a)))
+        .collect::<Result<HashSet<_>>>()?;
+    if parsed.is_empty() {
+        return Err(anyhow!("Tip account list is empty"));
+    }
+    Ok(parsed)
+}
+
+/// Fetch Jito's published tip accounts via `getTipAccounts`
+pub async fn fetch_tip_accounts(endpoint: &str, fetch_timeout: Duration) -> Result<Vec<String>> {
+    let client = reqwest::Client::builder()
+        .timeout(fetch_timeout)
+        .build()
+        .context("Failed to create HTTP client")?;
+
+    let response = client
+        .post(format!(
+            "{}/api/v1/bundles",
+            endpoint.trim_end_matches('/')
+        ))
+        .json(&serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "getTipAccounts", "params": []}))
+        .send()
+        .await
+        .context("getTipAccounts request failed")?;
+    if !response.status().is_success() {
+        return Err(anyhow!(
+            "getTipAccounts returned HTTP {}",
+            response.status()
+        ));
+    }
+
+    let body: TipAccountsResponse = response
+        .json()
+        .await
+        .context("Invalid getTipAccounts response")?;
+    Ok(body.result)
+}
+
+/// Load the tip-account list from the override, a fresh fetch, or the cache, in that order
+pub async fn load_tip_accounts(config: &TipAccountCheckConfig) -> Result<TipAccountList> {
+    if let Some(accounts) = &config.accounts_override {
+        return Ok(TipAccountList {
+            accounts: parse_accounts(accounts)?,
+            source: TipAccountSource::Override,
+        });
+    }
+
+    let fetch_error = match fetch_tip_accounts(&config.jito_endpoint, config.fetch_timeout).await {
+        Ok(accounts) => {
+            let parsed = parse_accounts(&accounts)?;
+            if let Some(path) = &config.cache_path {
+                if let Err(e) = std::fs::write(path, serde_json::to_string(&accounts)?) {
+                    warn!("Failed to cache Jito tip accounts to {:?}: {}", path, e);
+                }
+            }
+            return Ok(TipAccountList {
+                accounts: parsed,
+                source: TipAccountSource::Fetched,
+            });
+        }
+        Err(e) => e,
+    };
+
+    let path = config
+        .cache_path
+        .as_ref()
+        .ok_or_else(|| fetch_error.context("No tip account cache configured"))?;
+    warn!("Using cached Jito tip accounts from {:?}", path);
+    let cached = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read tip account cache {:?}", path))?;
+    let accounts: Vec<String> =
+        serde_json::from_str(&cached).context("Invalid tip account cache")?;
+
+    Ok(TipAccountList {
+        accounts: parse_accounts(&accounts)?,
+        source: TipAccountSource::Cache,
+    })
+}
+
+/// Ensure `tip_account` is one of the recognized Jito tip accounts
+pub fn validate_tip_account(tip_account: Option<&str>, list: &TipAccountList) -> Result<Pubkey> {
+    let tip_account = tip_account.ok_or_else(|| anyhow!("No Jito tip account configured"))?;
+    let pubkey = Pubkey::from_str(tip_account.trim())
+        .with_context(|| format!("Invalid Jito tip account {}", tip_account))?;
+
+    if !list.accounts.contains(&pubkey) {
+        return Err(anyhow!(
+            "{} is not a recognized Jito tip account ({} known, source: {:?})",
+            pubkey,
+            list.accounts.len(),
+            list.source
+        ));
+    }
+    Ok(pubkey)
+}
+
+/// Startup self-test: fails if the configured tip account would not be honored by Jito
+pub async fn check_tip_account(config: &TipAccountCheckConfig) -> Result<Pubkey> {
+    let list = load_tip_accounts(config).await?;
+    let pubkey = validate_tip_account(config.tip_account.as_deref(), &list)?;
+    info!(
+        "⚡ Jito tip account {} verified ({} known, source: {:?})",
+        pubkey,
+        list.accounts.len(),
+        list.source
+    );
+    Ok(pubkey)
+}
+
+/// Startup self-test for the tip accounts bundles will pay. Fixed rotation verifies the
+/// configured account; rotating modes draw from the whole list and need no single account.
+pub async fn check_tip_accounts(
+    config: &TipAccountCheckConfig,
+    rotation: TipAccountRotation,
+) -> Result<TipAccountSelector> {
+    if rotation == TipAccountRotation::Fixed {
+        let pubkey = check_tip_account(config).await?;
+        return TipAccountSelector::new([pubkey], rotation);
+    }
+
+    let list = load_tip_accounts(config).await?;
+    let selector = TipAccountSelector::new(list.accounts, rotation)?;
+    info!(
+        "⚡ Rotating Jito tips ({:?}) across {} tip accounts",
+        rotation,
+        selector.accounts().len()
+    );
+    Ok(selector)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_override_list_validates_tip_account() {
+        let known = Pubkey::new_unique().to_string();
+        let config = TipAccountCheckConfig {
+            // Unreachable; the override must be used without fetching
+            jito_endpoint: "http://127.0.0.1:9".to_string(),
+            tip_account: Some(known.clone()),
+            accounts_override: Some(vec![known, Pubkey::new_unique().to_string()]),
+            ..TipAccountCheckConfig::default()
+        };
+        assert!(check_tip_account(&config).await.is_ok());
+
+        let list = load_tip_accounts(&config).await.unwrap();
+        assert_eq!(list.source, TipAccountSource::Override);
+        let unknown = Pubkey::new_unique().to_string();
+        assert!(validate_tip_account(Some(&unknown), &list).is_err());
+        assert!(validate_tip_account(None, &list).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_falls_back_to_cache_when_fetch_fails() {
+        let known = Pubkey::new_unique().to_string();
+        let cache_path =
+            std::env::temp_dir().join(format!("jito_tip_accounts_{}.json", uuid::Uuid::new_v4()));
+        std::fs::write(&cache_path, serde_json::to_string(&vec![&known]).unwrap()).unwrap();
+
+        let config = TipAccountCheckConfig {
+            jito_endpoint: "http://127.0.0.1:9".to_string(),
+            tip_account: Some(known),
+            cache_path: Some(cache_path.clone()),
+            fetch_timeout: Duration::from_millis(200),
+            ..TipAccountCheckConfig::default()
+        };
+        let list = load_tip_accounts(&config).await.unwrap();
+        std::fs::remove_file(&cache_path).ok();
+
+        assert_eq!(list.source, TipAccountSource::Cache);
+        assert!(validate_tip_account(config.tip_account.as_deref(), &list).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_tip_rotation_spreads_across_list() {
+        let accounts: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let config = TipAccountCheckConfig {
+            jito_endpoint: "http://127.0.0.1:9".to_string(),
+            accounts_override: Some(accounts.iter().map(Pubkey::to_string).collect()),
+            ..TipAccountCheckConfig::default()
+        };
+
+        // Rotation needs only the list, not a single configured account
+        let mut round_robin = check_tip_accounts(&config, TipAccountRotation::RoundRobin)
+            .await
+            .unwrap();
+        let picks: Vec<Pubkey> = (0..6).map(|_| round_robin.next_account()).collect();
+        assert_eq!(picks[..3], round_robin.accounts()[..]);
+        assert_eq!(picks[..3], picks[3..]);
+        assert!(check_tip_accounts(&config, TipAccountRotation::Fixed).await.is_err());
+
+        let draws = |seed| {
+            let mut selector = TipAccountSelector::new(accounts.clone(), TipAccountRotation::Random)
+                .unwrap()
+                .with_seed(seed);
+            (0..20).map(|_| selector.next_account()).collect::<Vec<_>>()
+        };
+        assert_eq!(draws(7), draws(7));
+        assert!(draws(7).iter().all(|account| accounts.contains(account)));
+    }
+}
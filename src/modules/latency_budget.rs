@@ -0,0 +1,118 @@
+// Latency Budget Module
+// Splits the per-execution latency budget between the AI decision leg and the bundle
+// submission leg, shifting room towards whichever leg has recently been slower
+
+use crate::modules::ewma::Ewma;
+
+/// How `max_execution_latency_ms` is divided between the two legs
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LatencySplit {
+    pub ai_leg_ms: u64,
+    pub bundle_leg_ms: u64,
+}
+
+impl LatencySplit {
+    /// The fixed one-third / two-thirds split
+    pub fn fixed(total_ms: u64) -> Self {
+        Self {
+            ai_leg_ms: total_ms / 3,
+            bundle_leg_ms: total_ms * 2 / 3,
+        }
+    }
+}
+
+/// Observed leg timings and the split derived from them
+#[derive(Debug, Clone)]
+pub struct LatencyBudget {
+    /// Keep the fixed split regardless of observed timings
+    adaptive: bool,
+    /// Smallest share of the budget either leg is given (0.0 - 0.5)
+    min_leg_fraction: f64,
+    ai_leg: Ewma,
+    bundle_leg: Ewma,
+    ai_samples: u64,
+    bundle_samples: u64,
+}
+
+#[allow(dead_code)]
+impl LatencyBudget {
+    pub fn new(adaptive: bool, min_leg_fraction: f64, half_life: u32) -> Self {
+        Self {
+            adaptive,
+            min_leg_fraction: min_leg_fraction.clamp(0.0, 0.5),
+            ai_leg: Ewma::new(half_life),
+            bundle_leg: Ewma::new(half_life),
+            ai_samples: 0,
+            bundle_samples: 0,
+        }
+    }
+
+    pub fn record_ai_leg(&mut self, elapsed_ms: f64) {
+        self.ai_leg.record(elapsed_ms);
+        self.ai_samples += 1;
+    }
+
+    pub fn record_bundle_leg(&mut self, elapsed_ms: f64) {
+        self.bundle_leg.record(elapsed_ms);
+        self.bundle_samples += 1;
+    }
+
+    /// Split `total_ms` in proportion to the average leg timings. Falls back to the
+    /// fixed split until both legs have been observed or when adaptation is off.
+    pub fn split(&self, total_ms: u64) -> LatencySplit {
+        let (ai, bundle) = (self.ai_leg.value(), self.bundle_leg.value());
+        if !self.adaptive || self.ai_samples == 0 || self.bundle_samples == 0 || ai + bundle <= 0.0
+        {
+            return LatencySplit::fixed(total_ms);
+        }
+
+        let ai_fraction =
+            (ai / (ai + bundle)).clamp(self.min_leg_fraction, 1.0 - self.min_leg_fraction);
+        let ai_leg_ms = ((total_ms as f64) * ai_fraction).round() as u64;
+        LatencySplit {
+            ai_leg_ms,
+            bundle_leg_ms: total_ms - ai_leg_ms,
+        }
+    }
+
+    pub fn avg_ai_leg_ms(&self) -> f64 {
+        self.ai_leg.value()
+    }
+
+    pub fn avg_bundle_leg_ms(&self) -> f64 {
+        self.bundle_leg.value()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_faster_leg_gives_up_budget_within_bounds() {
+        let mut budget = LatencyBudget::new(true, 0.2, 0);
+        // Nothing observed yet
+        assert_eq!(budget.split(30), LatencySplit::fixed(30));
+
+        budget.record_ai_leg(2.0);
+        budget.record_bundle_leg(8.0);
+        assert_eq!(
+            budget.split(30),
+            LatencySplit {
+                ai_leg_ms: 6,
+                bundle_leg_ms: 24
+            }
+        );
+
+        // A leg is never squeezed below the minimum share
+        budget.record_ai_leg(0.1);
+        assert_eq!(budget.split(30).ai_leg_ms, 6);
+        budget.record_ai_leg(80.0);
+        assert_eq!(budget.split(30).bundle_leg_ms, 6);
+
+        let mut fixed = LatencyBudget::new(false, 0.2, 0);
+        fixed.record_ai_leg(1.0);
+        fixed.record_bundle_leg(20.0);
+        assert_eq!(fixed.split(25), LatencySplit::fixed(25));
+    }
+}
@@ -0,0 +1,207 @@
+// THE OVERMIND PROTOCOL - Order & Position Lifecycle
+// Explicit state machines with validated transitions for orders and positions
+
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt::Debug;
+
+use crate::modules::executor::ExecutionStatus;
+
+/// A lifecycle state with a fixed set of legal transitions
+pub trait LifecycleState: Copy + Debug + PartialEq {
+    /// Whether moving from `self` to `next` is a legal transition
+    fn can_transition_to(&self, next: Self) -> bool;
+
+    /// Whether no further transitions are possible
+    fn is_terminal(&self) -> bool;
+}
+
+/// Order lifecycle: New → Submitted → {Confirmed, Failed, Cancelled}
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrderState {
+    New,
+    Submitted,
+    Confirmed,
+    Failed,
+    Cancelled,
+}
+
+impl LifecycleState for OrderState {
+    fn can_transition_to(&self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (OrderState::New, OrderState::Submitted)
+                | (OrderState::Submitted, OrderState::Confirmed)
+                | (OrderState::Submitted, OrderState::Failed)
+                | (OrderState::Submitted, OrderState::Cancelled)
+        )
+    }
+
+    fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            OrderState::Confirmed | OrderState::Failed | OrderState::Cancelled
+        )
+    }
+}
+
+impl From<&ExecutionStatus> for OrderState {
+    fn from(status: &ExecutionStatus) -> Self {
+        match status {
+            ExecutionStatus::Pending => OrderState::Submitted,
+            ExecutionStatus::Confirmed => OrderState::Confirmed,
+            ExecutionStatus::Failed => OrderState::Failed,
+            ExecutionStatus::Cancelled => OrderState::Cancelled,
+        }
+    }
+}
+
+/// Position lifecycle: Opening → Open → Closing → Closed, kept on each tracked `Position`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PositionState {
+    Opening,
+    Open,
+    Closing,
+    Closed,
+}
+
+impl LifecycleState for PositionState {
+    fn can_transition_to(&self, next: Self) -> bool {
+        matches!(
+            (self, next),
+            (PositionState::Opening, PositionState::Open)
+                | (PositionState::Open, PositionState::Closing)
+                | (PositionState::Closing, PositionState::Closed)
+        )
+    }
+
+    fn is_terminal(&self) -> bool {
+        matches!(self, PositionState::Closed)
+    }
+}
+
+/// Current state of a tracked entity and when it last changed
+#[derive(Debug, Clone)]
+pub struct LifecycleEntry<S> {
+    pub state: S,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// In-memory tracker that rejects illegal lifecycle transitions
+#[derive(Debug)]
+pub struct LifecycleTracker<S: LifecycleState> {
+    kind: &'static str,
+    initial: S,
+    entries: HashMap<String, LifecycleEntry<S>>,
+}
+
+pub type OrderTracker = LifecycleTracker<OrderState>;
+
+impl OrderTracker {
+    pub fn for_orders() -> Self {
+        Self::new("order", OrderState::New)
+    }
+}
+
+impl<S: LifecycleState> LifecycleTracker<S> {
+    fn new(kind: &'static str, initial: S) -> Self {
+        Self {
+            kind,
+            initial,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Start tracking a new entity in its initial state
+    pub fn create(&mut self, id: &str) -> Result<()> {
+        if self.entries.contains_key(id) {
+            return Err(anyhow!("{} {} already exists", self.kind, id));
+        }
+
+        self.entries.insert(
+            id.to_string(),
+            LifecycleEntry {
+                state: self.initial,
+                updated_at: Utc::now(),
+            },
+        );
+        Ok(())
+    }
+
+    /// Move an entity to `next`, rejecting illegal transitions
+    pub fn transition(&mut self, id: &str, next: S) -> Result<()> {
+        let entry = self
+            .entries
+            .get_mut(id)
+            .ok_or_else(|| anyhow!("Unknown {}: {}", self.kind, id))?;
+
+        if !entry.state.can_transition_to(next) {
+            return Err(anyhow!(
+                "Illegal {} transition for {}: {:?} -> {:?}",
+                self.kind,
+                id,
+                entry.state,
+                next
+            ));
+        }
+
+        entry.state = next;
+        entry.updated_at = Utc::now();
+        Ok(())
+    }
+
+    pub fn state(&self, id: &str) -> Option<S> {
+        self.entries.get(id).map(|e| e.state)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Drop entities that reached a terminal state before `cutoff`
+    pub fn prune_terminal_before(&mut self, cutoff: DateTime<Utc>) {
+        self.entries
+            .retain(|_, e| !(e.state.is_terminal() && e.updated_at < cutoff));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_order_lifecycle() {
+        let mut orders = OrderTracker::for_orders();
+        orders.create("order-1").unwrap();
+        orders.transition("order-1", OrderState::Submitted).unwrap();
+        orders.transition("order-1", OrderState::Cancelled).unwrap();
+
+        // A cancelled order can never be confirmed
+        assert!(orders.transition("order-1", OrderState::Confirmed).is_err());
+        assert_eq!(orders.state("order-1"), Some(OrderState::Cancelled));
+
+        // Orders must be submitted before they can be confirmed
+        orders.create("order-2").unwrap();
+        assert!(orders.transition("order-2", OrderState::Confirmed).is_err());
+        assert!(orders.create("order-2").is_err());
+    }
+
+    #[test]
+    fn test_position_lifecycle() {
+        // A position must be open before it can close
+        assert!(!PositionState::Opening.can_transition_to(PositionState::Closing));
+        assert!(PositionState::Opening.can_transition_to(PositionState::Open));
+        assert!(PositionState::Open.can_transition_to(PositionState::Closing));
+        assert!(PositionState::Closing.can_transition_to(PositionState::Closed));
+
+        // A closed position never reopens
+        assert!(PositionState::Closed.is_terminal());
+        assert!(!PositionState::Closed.can_transition_to(PositionState::Open));
+    }
+}
@@ -0,0 +1,223 @@
+// Market Price Cache Module
+// Latest observed price per symbol, shared between the strategy engine and execution-time checks,
+// and the per-strategy policy for signals that arrive before any price for their symbol
+
+use crate::modules::data_ingestor::MarketData;
+use crate::modules::strategy::StrategyType;
+use crate::modules::strategy_cooldown::parse_strategy_type;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::Notify;
+
+pub type SharedMarketPrices = Arc<MarketPriceCache>;
+
+/// What to do with a signal whose symbol has no fresh market price yet, so spread,
+/// staleness and price-deviation checks have nothing to compare against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingPricePolicy {
+    /// Trade on the signal's own target price (pure sniping)
+    UseSignalPrice,
+    /// Wait up to the timeout for a first price, then trade on the signal's price
+    WaitThenProceed(Duration),
+    /// Wait up to the timeout for a first price, then reject
+    WaitThenReject(Duration),
+}
+
+impl std::str::FromStr for MissingPricePolicy {
+    type Err = anyhow::Error;
+
+    /// Parse "signal_price", "reject", "wait_proceed:<ms>" or "wait_reject:<ms>"
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim().to_lowercase().replace('-', "_");
+        let (name, timeout_ms) = match s.split_once(':') {
+            Some((name, ms)) => (
+                name,
+                Some(
+                    ms.trim()
+                        .parse::<u64>()
+                        .with_context(|| format!("Invalid missing price wait: {}", s))?,
+                ),
+            ),
+            None => (s.as_str(), None),
+        };
+        match (name, timeout_ms) {
+            ("signal_price", None) => Ok(MissingPricePolicy::UseSignalPrice),
+            ("reject", None) => Ok(MissingPricePolicy::WaitThenReject(Duration::ZERO)),
+            ("wait_proceed", Some(ms)) => {
+                Ok(MissingPricePolicy::WaitThenProceed(Duration::from_millis(ms)))
+            }
+            ("wait_reject", Some(ms)) => {
+                Ok(MissingPricePolicy::WaitThenReject(Duration::from_millis(ms)))
+            }
+            _ => Err(anyhow!("Unknown missing price policy: {}", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MissingPriceConfig {
+    pub default_policy: MissingPricePolicy,
+    /// Overrides of `default_policy` for individual strategies
+    pub per_strategy: HashMap<StrategyType, MissingPricePolicy>,
+}
+
+impl Default for MissingPriceConfig {
+    fn default() -> Self {
+        Self {
+            default_policy: MissingPricePolicy::UseSignalPrice,
+            per_strategy: HashMap::new(),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl MissingPriceConfig {
+    /// Parse per-strategy policies such as "TokenSniping=signal_price,Arbitrage=wait_reject:500"
+    pub fn with_strategy_policies(mut self, spec: &str) -> Result<Self> {
+        for entry in spec.split(',').filter(|e| !e.trim().is_empty()) {
+            let (strategy, policy) = entry
+                .split_once('=')
+                .with_context(|| format!("Invalid missing price policy entry: {}", entry))?;
+            self.per_strategy
+                .insert(parse_strategy_type(strategy.trim())?, policy.parse()?);
+        }
+        Ok(self)
+    }
+
+    pub fn policy_for(&self, strategy: &StrategyType) -> MissingPricePolicy {
+        self.per_strategy
+            .get(strategy)
+            .copied()
+            .unwrap_or(self.default_policy)
+    }
+}
+
+pub struct MarketPriceCache {
+    /// Prices older than this are treated as unknown
+    max_age: chrono::Duration,
+    prices: Mutex<HashMap<String, (f64, DateTime<Utc>)>>,
+    /// Wakes callers waiting for a symbol's first price
+    recorded: Notify,
+}
+
+#[allow(dead_code)]
+impl MarketPriceCache {
+    pub fn new(max_age: chrono::Duration) -> Self {
+        Self {
+            max_age,
+            prices: Mutex::new(HashMap::new()),
+            recorded: Notify::new(),
+        }
+    }
+
+    pub fn shared(max_age: chrono::Duration) -> SharedMarketPrices {
+        Arc::new(Self::new(max_age))
+    }
+
+    pub fn record(&self, symbol: &str, price: f64, observed_at: DateTime<Utc>) {
+        if !price.is_finite() || price <= 0.0 {
+            return;
+        }
+        if let Ok(mut prices) = self.prices.lock() {
+            let entry = prices
+                .entry(symbol.to_string())
+                .or_insert((price, observed_at));
+            // Out-of-order ticks never overwrite a newer price
+            if observed_at >= entry.1 {
+                *entry = (price, observed_at);
+            }
+        }
+        self.recorded.notify_waiters();
+    }
+
+    pub fn record_market_data(&self, data: &MarketData) {
+        self.record(&data.symbol, data.price, data.received_at);
+    }
+
+    /// Latest price for `symbol`, if one was observed within `max_age`
+    pub fn latest(&self, symbol: &str) -> Option<f64> {
+        let prices = self.prices.lock().ok()?;
+        let (price, observed_at) = prices.get(symbol)?;
+        (Utc::now() - *observed_at <= self.max_age).then_some(*price)
+    }
+
+    /// Latest price for `symbol`, waiting up to `timeout` for one to be recorded
+    /// Convert an amount in `symbol`'s quote currency to SOL through the latest SOL/<quote>
+    /// price; SOL-quoted symbols and bare mints pass through unchanged
+    pub fn quote_to_sol(&self, symbol: &str, amount: f64) -> Option<f64> {
+        match symbol.split_once('/') {
+            None => Some(amount),
+            Some((_, "SOL" | "WSOL")) => Some(amount),
+            Some((_, quote)) => self
+                .latest(&format!("SOL/{}", quote))
+                .filter(|sol_price| *sol_price > 0.0)
+                .map(|sol_price| amount / sol_price),
+        }
+    }
+
+    pub async fn wait_for_price(&self, symbol: &str, timeout: Duration) -> Option<f64> {
+        let deadline = tokio::time::Instant::now() + timeout;
+        loop {
+            // Registered before the check so a price recorded in between is not missed
+            let recorded = self.recorded.notified();
+            if let Some(price) = self.latest(symbol) {
+                return Some(price);
+            }
+            tokio::time::timeout_at(deadline, recorded).await.ok()?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latest_price_ignores_stale_and_out_of_order_ticks() {
+        let cache = MarketPriceCache::new(chrono::Duration::seconds(30));
+        let now = Utc::now();
+
+        cache.record("SOL/USDC", 150.0, now);
+        cache.record("SOL/USDC", 140.0, now - chrono::Duration::seconds(5));
+        assert_eq!(cache.latest("SOL/USDC"), Some(150.0));
+
+        cache.record("BONK/USDC", 0.00002, now - chrono::Duration::minutes(5));
+        assert_eq!(cache.latest("BONK/USDC"), None);
+        assert_eq!(cache.latest("JUP/USDC"), None);
+    }
+
+    #[tokio::test]
+    async fn test_missing_price_policy_waits_for_first_price() {
+        let config = MissingPriceConfig::default()
+            .with_strategy_policies("Arbitrage=wait_reject:200,TokenSniping=signal_price")
+            .unwrap();
+        assert_eq!(
+            config.policy_for(&StrategyType::Arbitrage),
+            MissingPricePolicy::WaitThenReject(Duration::from_millis(200))
+        );
+        assert_eq!(
+            config.policy_for(&StrategyType::MomentumTrading),
+            MissingPricePolicy::UseSignalPrice
+        );
+        assert!("wait_proceed".parse::<MissingPricePolicy>().is_err());
+
+        let cache = MarketPriceCache::shared(chrono::Duration::seconds(30));
+        assert_eq!(
+            cache.wait_for_price("NEW/SOL", Duration::from_millis(20)).await,
+            None
+        );
+
+        let recorder = cache.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            recorder.record("NEW/SOL", 0.5, Utc::now());
+        });
+        assert_eq!(
+            cache.wait_for_price("NEW/SOL", Duration::from_secs(5)).await,
+            Some(0.5)
+        );
+    }
+}
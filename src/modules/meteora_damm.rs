@@ -3,7 +3,8 @@
 
 #![allow(dead_code)]
 
-use anyhow::Result;
+use crate::modules::target_price::{PoolReserves, TargetPriceConfig};
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use tracing::{error, info, warn};
@@ -13,6 +14,9 @@ pub struct DAMMOpportunity {
     pub token_address: String,
     pub token_symbol: String,
     pub pool_address: Option<String>,
+    /// Current pool state; entries are priced off it and skipped without it
+    #[serde(default)]
+    pub pool_reserves: Option<PoolReserves>,
     pub launch_platform: LaunchPlatform,
     pub estimated_sniper_activity: SniperActivity,
     pub recommended_position_size: f64,
@@ -174,6 +178,11 @@ impl MeteoraDAMMStrategy {
                 token_address: format!("token_address_{}", i),
                 token_symbol: format!("EARLY{}", i),
                 pool_address: None, // Will be created
+                // Seed liquidity of the launch pool
+                pool_reserves: Some(PoolReserves {
+                    base_reserve: 1_000_000_000.0,
+                    quote_reserve: 30.0 + (i as f64 * 10.0),
+                }),
                 launch_platform: platform,
                 estimated_sniper_activity: sniper_activity,
                 recommended_position_size: self.calculate_position_size(&sniper_activity),
@@ -297,20 +306,32 @@ impl Default for DAMMConfig {
 
 // Integration with main strategy engine
 impl DAMMOpportunity {
-    pub fn to_trading_signal(&self) -> crate::modules::strategy::TradingSignal {
+    /// Signal priced at the average fill of the recommended SOL size against the pool
+    pub fn to_trading_signal(
+        &self,
+        prices: &TargetPriceConfig,
+    ) -> Result<crate::modules::strategy::TradingSignal> {
         use crate::modules::strategy::{StrategyType, TradeAction, TradingSignal};
         use uuid::Uuid;
 
-        TradingSignal {
+        let target_price = self
+            .pool_reserves
+            .and_then(|pool| pool.buy_quote(self.recommended_position_size))
+            .with_context(|| format!("No pool state to price {}", self.token_symbol))?;
+        let target_price = prices.validate(&StrategyType::MeteoraDAMM, target_price)?;
+
+        Ok(TradingSignal {
             signal_id: Uuid::new_v4().to_string(),
             symbol: self.token_symbol.clone(),
             action: TradeAction::Buy,
             quantity: self.recommended_position_size,
-            target_price: 0.001, // Very early entry price
+            target_price,
             confidence: self.calculate_confidence(),
             timestamp: chrono::Utc::now(),
             strategy_type: StrategyType::MeteoraDAMM,
-        }
+            tick_received_at: None,
+            pool_liquidity: self.pool_reserves.map(|pool| pool.quote_reserve),
+        })
     }
 
     fn calculate_confidence(&self) -> f64 {
@@ -340,6 +361,7 @@ mod tests {
             token_address: "test".to_string(),
             token_symbol: "TEST".to_string(),
             pool_address: None,
+            pool_reserves: None,
             launch_platform: LaunchPlatform::Launchcoin,
             estimated_sniper_activity: SniperActivity::VeryHigh,
             recommended_position_size: 5.0,
@@ -348,5 +370,20 @@ mod tests {
         };
 
         assert!(strategy.evaluate_opportunity(&high_opportunity));
+
+        // No pool state means no price, so no signal
+        let prices = TargetPriceConfig::default();
+        assert!(high_opportunity.to_trading_signal(&prices).is_err());
+
+        let priced = DAMMOpportunity {
+            pool_reserves: Some(PoolReserves {
+                base_reserve: 1_000_000_000.0,
+                quote_reserve: 45.0,
+            }),
+            ..high_opportunity
+        };
+        let signal = priced.to_trading_signal(&prices).unwrap();
+        // 5 SOL into a 45 SOL pool fills at 5 / 100M tokens
+        assert!((signal.target_price - 5e-8).abs() < 1e-15);
     }
 }
@@ -1,13 +1,51 @@
 // Module declarations for THE OVERMIND PROTOCOL
 // Each module handles a specific aspect of the AI-enhanced HFT trading system
 
+pub mod ai_feedback;
+pub mod ai_funnel;
+pub mod allocation;
+pub mod arbitrage;
+pub mod circuit_breaker;
+pub mod clock_skew;
+pub mod confirmation;
 pub mod data_ingestor;
+pub mod ewma;
+#[cfg(feature = "event-bus")]
+pub mod event_bus;
 pub mod executor;
+pub mod fee_budget;
+pub mod fees;
+pub mod honeypot;
+pub mod idempotency;
+pub mod latency_budget;
+pub mod lifecycle;
+pub mod market_prices;
+pub mod notional_rate;
+pub mod pending_replacement;
+pub mod pipeline_capture;
 pub mod persistence;
+pub mod portfolio_exposure;
+pub mod reconciliation;
 pub mod risk;
+pub mod rpc_pool;
+pub mod shadow_divergence;
+pub mod shutdown_report;
+pub mod signer;
+pub mod simulation_guard;
+pub mod slippage_curve;
+pub mod slippage_model;
 pub mod strategy;
+pub mod strategy_cooldown;
+pub mod strategy_routing;
+pub mod strategy_sandbox;
+pub mod symbol_resolver;
+pub mod target_price;
+pub mod token_metadata;
+pub mod wallet_submission;
 // THE OVERMIND PROTOCOL - Core Components
 pub mod hft_engine;
+pub mod jito_client;
+pub mod jito_tip_accounts;
 pub mod ai_connector;
 // THE OVERMIND PROTOCOL - Multi-Wallet Support
 pub mod wallet_manager;
@@ -15,6 +53,7 @@ pub mod multi_wallet_config;
 pub mod multi_wallet_executor;
 
 // Advanced strategy modules based on Solana knowledge
+pub mod axiom_memecoin;
 pub mod dev_tracker;
 pub mod meteora_damm;
 pub mod soul_meteor;
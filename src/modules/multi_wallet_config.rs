@@ -3,14 +3,17 @@
 
 use anyhow::{anyhow, Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::path::Path;
-use tracing::info;
+use tracing::{info, warn};
 
+use crate::modules::signer::SignerConfig;
 use crate::modules::strategy::StrategyType;
+use crate::modules::wallet_submission::DEFAULT_PER_WALLET_CONCURRENCY;
 use crate::modules::wallet_manager::{
-    WalletConfig, WalletConfigBuilder, WalletRiskLimits, WalletType,
+    AllocationEnforcement, CashBufferConfig, CompromiseContainmentConfig, WalletConfig, WalletConfigBuilder, WalletManager,
+    WalletRiskLimits, WalletType,
 };
 
 /// Multi-wallet configuration for THE OVERMIND PROTOCOL
@@ -31,6 +34,73 @@ pub struct GlobalWalletSettings {
     pub emergency_stop_threshold: f64,
     pub auto_rebalance_enabled: bool,
     pub risk_aggregation_enabled: bool,
+    /// Fetch balances of wallets without metrics during selection instead of scoring them as empty
+    #[serde(default)]
+    pub jit_balance_fetch_enabled: bool,
+    /// Re-read affected wallets' on-chain balances after a failed transfer, rebalance or top-up
+    #[serde(default = "default_reconcile_on_balance_failure")]
+    pub reconcile_on_balance_failure: bool,
+    /// How executions are kept within each wallet's per-strategy allocation
+    #[serde(default)]
+    pub allocation_enforcement: AllocationEnforcement,
+    /// Reassign the default wallet to the best active wallet once it is suspended or stopped
+    #[serde(default = "default_auto_recover_default_wallet")]
+    pub auto_recover_default_wallet: bool,
+    /// Largest share of the selected wallet's SOL balance one AI decision may buy with (0 disables)
+    #[serde(default = "default_max_ai_trade_fraction")]
+    pub max_ai_trade_fraction: f64,
+    /// Submissions one wallet may have in flight at once (1 serializes each wallet)
+    #[serde(default = "default_per_wallet_concurrency")]
+    pub per_wallet_concurrency: usize,
+    /// SOL a wallet may lose beyond what our trades explain before it is treated as compromised (0 disables)
+    #[serde(default)]
+    pub compromise_delta_threshold_sol: f64,
+    /// Public key that a compromised wallet's remaining SOL is swept to; no sweep when unset
+    #[serde(default)]
+    pub compromise_safe_wallet: Option<String>,
+    /// Keep each wallet's unallocated share idle instead of treating it as slack
+    #[serde(default)]
+    pub enforce_cash_buffer: bool,
+    /// Warn when a wallet leaves more than this percentage unallocated
+    #[serde(default = "default_cash_buffer_warning_percentage")]
+    pub cash_buffer_warning_percentage: f64,
+    /// Wallet metrics older than this are refreshed or distrusted during selection (0 disables)
+    #[serde(default = "default_max_wallet_metrics_age_sec")]
+    pub max_wallet_metrics_age_sec: u64,
+}
+
+/// Default cap on a single AI-originated buy, as a fraction of the wallet's SOL balance
+pub const DEFAULT_MAX_AI_TRADE_FRACTION: f64 = 0.25;
+
+#[allow(dead_code)]
+fn default_reconcile_on_balance_failure() -> bool {
+    true
+}
+
+#[allow(dead_code)]
+fn default_auto_recover_default_wallet() -> bool {
+    true
+}
+
+#[allow(dead_code)]
+fn default_max_ai_trade_fraction() -> f64 {
+    DEFAULT_MAX_AI_TRADE_FRACTION
+}
+
+#[allow(dead_code)]
+fn default_cash_buffer_warning_percentage() -> f64 {
+    CashBufferConfig::default().warning_percentage
+}
+
+#[allow(dead_code)]
+fn default_max_wallet_metrics_age_sec() -> u64 {
+    // Three missed balance refreshes at the default interval
+    900
+}
+
+#[allow(dead_code)]
+fn default_per_wallet_concurrency() -> usize {
+    DEFAULT_PER_WALLET_CONCURRENCY
 }
 
 /// Wallet configuration from environment variables
@@ -93,10 +163,62 @@ impl MultiWalletConfig {
         })
     }
 
+    /// Build a wallet manager for these wallets, with JIT balance fetching, reconciliation
+    /// after failed transfers and compromise containment if enabled
+    pub async fn create_wallet_manager(&self, rpc_url: &str) -> Result<WalletManager> {
+        let mut wallet_manager = WalletManager::new()
+            .with_default_wallet_recovery(self.global_settings.auto_recover_default_wallet)
+            .with_cash_buffer(CashBufferConfig {
+                enforced: self.global_settings.enforce_cash_buffer,
+                warning_percentage: self.global_settings.cash_buffer_warning_percentage,
+            });
+        let rpc_client = std::sync::Arc::new(solana_client::nonblocking::rpc_client::RpcClient::new(rpc_url.to_string()));
+        if self.global_settings.jit_balance_fetch_enabled {
+            // Leave half of the selection budget for scoring the remaining candidates
+            let jit_timeout =
+                std::time::Duration::from_millis(self.global_settings.wallet_selection_timeout_ms / 2);
+            wallet_manager = wallet_manager.with_jit_balance_fetch(rpc_client.clone(), jit_timeout);
+        }
+        if self.global_settings.max_wallet_metrics_age_sec > 0 {
+            wallet_manager = wallet_manager.with_max_metrics_age(std::time::Duration::from_secs(
+                self.global_settings.max_wallet_metrics_age_sec,
+            ));
+        }
+        if self.global_settings.reconcile_on_balance_failure {
+            // Reconciliation is off the trading hot path, so it gets the full selection timeout
+            let reconcile_timeout = std::time::Duration::from_millis(self.global_settings.wallet_selection_timeout_ms);
+            wallet_manager = wallet_manager.with_balance_reconciliation(rpc_client.clone(), reconcile_timeout);
+        }
+        if self.global_settings.compromise_delta_threshold_sol > 0.0 {
+            let safe_wallet = self
+                .global_settings
+                .compromise_safe_wallet
+                .as_deref()
+                .map(std::str::FromStr::from_str)
+                .transpose()
+                .context("Invalid OVERMIND_COMPROMISE_SAFE_WALLET")?;
+            wallet_manager = wallet_manager.with_compromise_containment(
+                CompromiseContainmentConfig {
+                    unexplained_delta_threshold: self.global_settings.compromise_delta_threshold_sol,
+                    safe_wallet,
+                },
+                rpc_client,
+            );
+        }
+
+        wallet_manager
+            .initialize(self.wallets.values().cloned().collect())
+            .await?;
+        if let Err(e) = wallet_manager.set_default_wallet(&self.default_wallet_id).await {
+            warn!("Configured default wallet not used: {}", e);
+        }
+        Ok(wallet_manager)
+    }
+
     /// Parse managed wallets string from environment
     /// Format: "wallet_id:path:type:risk:allocation,wallet_id2:path2:type2:risk2:allocation2"
     fn parse_managed_wallets(managed_wallets: &str) -> Result<Vec<EnvWalletConfig>> {
-        let mut configs = Vec::new();
+        let mut configs: Vec<EnvWalletConfig> = Vec::new();
 
         for wallet_def in managed_wallets.split(',') {
             let parts: Vec<&str> = wallet_def.split(':').collect();
@@ -127,6 +249,16 @@ impl MultiWalletConfig {
                 return Err(anyhow!("Allocation must be between 0.0 and 1.0, got: {}", max_allocation));
             }
 
+            // Later entries would otherwise silently replace earlier, possibly funded, wallets
+            if let Some(position) = configs.iter().position(|c| c.wallet_id == parts[0]) {
+                return Err(anyhow!(
+                    "Duplicate wallet ID '{}' in OVERMIND_MANAGED_WALLETS (entries {} and {})",
+                    parts[0],
+                    position + 1,
+                    configs.len() + 1
+                ));
+            }
+
             configs.push(EnvWalletConfig {
                 wallet_id: parts[0].to_string(),
                 name: parts[0].replace('_', " ").to_title_case(),
@@ -147,31 +279,28 @@ impl MultiWalletConfig {
 
     /// Build wallet configuration from environment config
     fn build_wallet_config(env_config: EnvWalletConfig) -> Result<WalletConfig> {
-        // Load private key from file or environment
-        let private_key = if env_config.private_key_path.starts_with("env:") {
-            let env_var = &env_config.private_key_path[4..];
-            env::var(env_var)
-                .context(format!("Environment variable {} not found", env_var))?
-        } else if Path::new(&env_config.private_key_path).exists() {
-            std::fs::read_to_string(&env_config.private_key_path)
-                .context("Failed to read private key file")?
-                .trim()
-                .to_string()
-        } else {
-            return Err(anyhow!("Private key path not found: {}", env_config.private_key_path));
-        };
-
         // Create risk limits based on risk profile
         let risk_limits = Self::create_risk_limits(&env_config.risk_profile, env_config.max_allocation)?;
 
         // Create strategy allocations based on wallet type
         let strategy_allocations = Self::create_strategy_allocations(&env_config.wallet_type, env_config.max_allocation);
 
-        let mut builder = WalletConfigBuilder::new(
-            env_config.wallet_id.clone(),
-            env_config.name,
-            private_key,
-        )?;
+        let mut builder = if env_config.private_key_path == "remote" {
+            Self::remote_wallet_builder(&env_config)?
+        } else {
+            let private_key = Self::load_private_key(&env_config.private_key_path)?;
+            WalletConfigBuilder::new(
+                env_config.wallet_id.clone(),
+                env_config.name,
+                private_key,
+            )?
+        };
+
+        // Optional dedicated endpoint, e.g. a low-latency node for HFT wallets
+        let rpc_url_var = format!("OVERMIND_WALLET_{}_RPC_URL", env_config.wallet_id.to_uppercase());
+        if let Ok(rpc_url) = env::var(&rpc_url_var) {
+            builder = builder.rpc_url(rpc_url);
+        }
 
         builder = builder
             .wallet_type(env_config.wallet_type)
@@ -186,6 +315,46 @@ impl MultiWalletConfig {
         Ok(builder.build())
     }
 
+    /// Remote-signer wallet; its settings come from OVERMIND_WALLET_<ID>_SIGNER_URL,
+    /// _SIGNER_KEY_ID, _PUBLIC_KEY and optionally _SIGNER_TOKEN_ENV
+    fn remote_wallet_builder(env_config: &EnvWalletConfig) -> Result<WalletConfigBuilder> {
+        let prefix = format!("OVERMIND_WALLET_{}", env_config.wallet_id.to_uppercase());
+        let var = |suffix: &str| {
+            let name = format!("{}_{}", prefix, suffix);
+            env::var(&name).context(format!("Environment variable {} not found", name))
+        };
+
+        let signer = SignerConfig::Remote {
+            endpoint: var("SIGNER_URL")?,
+            key_id: var("SIGNER_KEY_ID")?,
+            auth_token_env: var("SIGNER_TOKEN_ENV").ok(),
+        };
+
+        WalletConfigBuilder::new_remote(
+            env_config.wallet_id.clone(),
+            env_config.name.clone(),
+            var("PUBLIC_KEY")?,
+            signer,
+        )
+    }
+
+    /// Load private key from file or environment
+    fn load_private_key(private_key_path: &str) -> Result<String> {
+        let private_key = if let Some(env_var) = private_key_path.strip_prefix("env:") {
+            env::var(env_var)
+                .context(format!("Environment variable {} not found", env_var))?
+        } else if Path::new(private_key_path).exists() {
+            std::fs::read_to_string(private_key_path)
+                .context("Failed to read private key file")?
+                .trim()
+                .to_string()
+        } else {
+            return Err(anyhow!("Private key path not found: {}", private_key_path));
+        };
+
+        Ok(private_key)
+    }
+
     /// Create risk limits based on risk profile
     fn create_risk_limits(risk_profile: &str, max_allocation: f64) -> Result<WalletRiskLimits> {
         let base_limits = match risk_profile.to_lowercase().as_str() {
@@ -290,6 +459,17 @@ impl MultiWalletConfig {
         
         let config: Self = serde_json::from_str(&content)
             .context("Failed to parse configuration file")?;
+
+        // Entries are keyed by name, so two keys can still carry the same wallet ID
+        let mut wallet_ids = HashSet::new();
+        for (key, wallet) in &config.wallets {
+            if !wallet_ids.insert(wallet.wallet_id.as_str()) {
+                return Err(anyhow!(
+                    "Duplicate wallet ID '{}' in {} (entry '{}' reuses it)",
+                    wallet.wallet_id, path, key
+                ));
+            }
+        }
         
         info!("📂 Loaded multi-wallet configuration from {}", path);
         Ok(config)
@@ -328,6 +508,58 @@ impl GlobalWalletSettings {
                 .unwrap_or_else(|_| "true".to_string())
                 .parse()
                 .context("Invalid OVERMIND_RISK_AGGREGATION_ENABLED")?,
+            
+            jit_balance_fetch_enabled: env::var("OVERMIND_JIT_BALANCE_FETCH_ENABLED")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .context("Invalid OVERMIND_JIT_BALANCE_FETCH_ENABLED")?,
+            
+            reconcile_on_balance_failure: env::var("OVERMIND_RECONCILE_ON_BALANCE_FAILURE")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .context("Invalid OVERMIND_RECONCILE_ON_BALANCE_FAILURE")?,
+
+            allocation_enforcement: env::var("OVERMIND_ALLOCATION_ENFORCEMENT")
+                .unwrap_or_else(|_| "downsize".to_string())
+                .parse()
+                .context("Invalid OVERMIND_ALLOCATION_ENFORCEMENT")?,
+
+            auto_recover_default_wallet: env::var("OVERMIND_AUTO_RECOVER_DEFAULT_WALLET")
+                .unwrap_or_else(|_| "true".to_string())
+                .parse()
+                .context("Invalid OVERMIND_AUTO_RECOVER_DEFAULT_WALLET")?,
+
+            max_ai_trade_fraction: env::var("OVERMIND_MAX_AI_TRADE_FRACTION")
+                .unwrap_or_else(|_| DEFAULT_MAX_AI_TRADE_FRACTION.to_string())
+                .parse()
+                .context("Invalid OVERMIND_MAX_AI_TRADE_FRACTION")?,
+
+            per_wallet_concurrency: env::var("OVERMIND_PER_WALLET_CONCURRENCY")
+                .unwrap_or_else(|_| DEFAULT_PER_WALLET_CONCURRENCY.to_string())
+                .parse()
+                .context("Invalid OVERMIND_PER_WALLET_CONCURRENCY")?,
+
+            compromise_delta_threshold_sol: env::var("OVERMIND_COMPROMISE_DELTA_THRESHOLD_SOL")
+                .unwrap_or_else(|_| "0".to_string())
+                .parse()
+                .context("Invalid OVERMIND_COMPROMISE_DELTA_THRESHOLD_SOL")?,
+
+            compromise_safe_wallet: env::var("OVERMIND_COMPROMISE_SAFE_WALLET").ok(),
+
+            enforce_cash_buffer: env::var("OVERMIND_ENFORCE_CASH_BUFFER")
+                .unwrap_or_else(|_| "false".to_string())
+                .parse()
+                .context("Invalid OVERMIND_ENFORCE_CASH_BUFFER")?,
+
+            cash_buffer_warning_percentage: env::var("OVERMIND_CASH_BUFFER_WARNING_PERCENTAGE")
+                .unwrap_or_else(|_| "50".to_string())
+                .parse()
+                .context("Invalid OVERMIND_CASH_BUFFER_WARNING_PERCENTAGE")?,
+
+            max_wallet_metrics_age_sec: env::var("OVERMIND_MAX_WALLET_METRICS_AGE_SEC")
+                .unwrap_or_else(|_| default_max_wallet_metrics_age_sec().to_string())
+                .parse()
+                .context("Invalid OVERMIND_MAX_WALLET_METRICS_AGE_SEC")?,
         })
     }
 }
@@ -341,6 +573,17 @@ impl Default for GlobalWalletSettings {
             emergency_stop_threshold: 0.1,
             auto_rebalance_enabled: true,
             risk_aggregation_enabled: true,
+            jit_balance_fetch_enabled: false,
+            reconcile_on_balance_failure: true,
+            allocation_enforcement: AllocationEnforcement::default(),
+            auto_recover_default_wallet: true,
+            max_ai_trade_fraction: DEFAULT_MAX_AI_TRADE_FRACTION,
+            per_wallet_concurrency: DEFAULT_PER_WALLET_CONCURRENCY,
+            compromise_delta_threshold_sol: 0.0,
+            compromise_safe_wallet: None,
+            enforce_cash_buffer: false,
+            cash_buffer_warning_percentage: default_cash_buffer_warning_percentage(),
+            max_wallet_metrics_age_sec: default_max_wallet_metrics_age_sec(),
         }
     }
 }
@@ -364,3 +607,22 @@ impl ToTitleCase for str {
             .join(" ")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_duplicate_wallet_ids_are_rejected() {
+        let error = MultiWalletConfig::parse_managed_wallets(
+            "primary:/keys/a.json:primary:low:0.5,hft:/keys/b.json:hft:high:0.3,primary:/keys/c.json:secondary:low:0.2",
+        )
+        .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Duplicate wallet ID 'primary' in OVERMIND_MANAGED_WALLETS (entries 1 and 3)"
+        );
+        assert!(MultiWalletConfig::parse_managed_wallets("primary:/keys/a.json:primary:low:0.5").is_ok());
+    }
+}
@@ -3,15 +3,23 @@
 
 use anyhow::{anyhow, Result};
 use std::sync::Arc;
+use solana_client::nonblocking::rpc_client::RpcClient;
 use tokio::sync::{mpsc, RwLock};
 use tracing::{debug, error, info, warn};
 
 use crate::config::TradingMode;
 use crate::modules::executor::{ExecutionResult, ExecutionStatus};
+use crate::modules::fees::{FeeBreakdown, FeeModel};
 use crate::modules::hft_engine::{HFTConfig, ExecutionResult as HFTExecutionResult, OvermindHFTEngine};
 use crate::modules::risk::ApprovedSignal;
-use crate::modules::strategy::StrategyType;
-use crate::modules::wallet_manager::{WalletManager, WalletSelectionCriteria, WalletType};
+use crate::modules::rpc_pool::RpcClientPool;
+use crate::modules::signer::TransactionSigner;
+use crate::modules::lifecycle::PositionState;
+use crate::modules::strategy::{StrategyType, TradeAction};
+use crate::modules::multi_wallet_config::DEFAULT_MAX_AI_TRADE_FRACTION;
+use crate::modules::pipeline_capture::{CaptureArtifact, SharedPipelineCapture};
+use crate::modules::wallet_submission::{WalletQueueDepth, WalletSubmissionLanes};
+use crate::modules::wallet_manager::{fill_sol_delta, AllocationEnforcement, Position, WalletManager, WalletSelectionCriteria, WalletStatus, WalletType};
 
 /// Enhanced signal with wallet routing information
 #[derive(Debug, Clone)]
@@ -28,8 +36,8 @@ pub struct MultiWalletExecutor {
     persistence_sender: mpsc::UnboundedSender<ExecutionResult>,
     wallet_manager: Arc<RwLock<WalletManager>>,
     trading_mode: TradingMode,
-    #[allow(dead_code)]
-    solana_rpc_url: String,
+    /// Clients per RPC endpoint; wallets without their own endpoint use `solana_rpc_url`
+    rpc_pool: RpcClientPool,
     is_running: bool,
     hft_engine: Option<OvermindHFTEngine>,
     hft_mode_enabled: bool,
@@ -37,6 +45,14 @@ pub struct MultiWalletExecutor {
     wallet_selection_timeout_ms: u64,
     fallback_wallet_id: Option<String>,
     execution_stats: Arc<RwLock<ExecutionStats>>,
+    fee_model: FeeModel,
+    allocation_enforcement: AllocationEnforcement,
+    /// Largest share of the selected wallet's SOL balance one AI decision may buy with (0 disables)
+    max_ai_trade_fraction: f64,
+    /// Serializes submissions per wallet while different wallets proceed in parallel
+    submission_lanes: Arc<WalletSubmissionLanes>,
+    /// Adds wallet selection decisions to captured pipeline traces
+    capture: Option<SharedPipelineCapture>,
 }
 
 /// Execution statistics per wallet
@@ -47,6 +63,11 @@ pub struct ExecutionStats {
     pub failed_executions: u64,
     pub wallet_usage: std::collections::HashMap<String, u64>,
     pub strategy_routing: std::collections::HashMap<StrategyType, std::collections::HashMap<String, u64>>,
+    /// AI decisions downsized by `max_ai_trade_fraction`, and the quantity trimmed from them
+    pub ai_trades_capped: u64,
+    pub ai_quantity_trimmed: f64,
+    /// Submissions waiting for or holding each wallet's lane
+    pub wallet_queue_depth: std::collections::HashMap<String, WalletQueueDepth>,
 }
 
 impl MultiWalletExecutor {
@@ -65,13 +86,18 @@ impl MultiWalletExecutor {
             persistence_sender,
             wallet_manager,
             trading_mode,
-            solana_rpc_url,
+            rpc_pool: RpcClientPool::new(solana_rpc_url),
             is_running: false,
             hft_engine: None,
             hft_mode_enabled: false,
             wallet_selection_timeout_ms,
             fallback_wallet_id,
             execution_stats: Arc::new(RwLock::new(ExecutionStats::default())),
+            fee_model: FeeModel::default(),
+            allocation_enforcement: AllocationEnforcement::default(),
+            max_ai_trade_fraction: DEFAULT_MAX_AI_TRADE_FRACTION,
+            submission_lanes: Arc::new(WalletSubmissionLanes::default()),
+            capture: None,
         }
     }
 
@@ -94,16 +120,78 @@ impl MultiWalletExecutor {
             persistence_sender,
             wallet_manager,
             trading_mode,
-            solana_rpc_url,
+            rpc_pool: RpcClientPool::new(solana_rpc_url),
             is_running: false,
             hft_engine: Some(hft_engine),
             hft_mode_enabled: true,
             wallet_selection_timeout_ms,
             fallback_wallet_id,
             execution_stats: Arc::new(RwLock::new(ExecutionStats::default())),
+            fee_model: FeeModel::default(),
+            allocation_enforcement: AllocationEnforcement::default(),
+            max_ai_trade_fraction: DEFAULT_MAX_AI_TRADE_FRACTION,
+            submission_lanes: Arc::new(WalletSubmissionLanes::default()),
+            capture: None,
         })
     }
 
+    /// Fee accounting used for every wallet's execution results
+    pub fn with_fee_model(mut self, fee_model: FeeModel) -> Self {
+        self.fee_model = fee_model;
+        self
+    }
+
+    /// Whether buys are downsized or rejected when they would exceed the wallet's strategy allocation
+    pub fn with_allocation_enforcement(mut self, allocation_enforcement: AllocationEnforcement) -> Self {
+        self.allocation_enforcement = allocation_enforcement;
+        self
+    }
+
+    /// The configured fallback wallet while it is active, otherwise the wallet manager's default
+    async fn fallback_wallet(&self) -> Result<String> {
+        let wallet_manager = self.wallet_manager.read().await;
+        if let Some(fallback_id) = &self.fallback_wallet_id {
+            match wallet_manager.get_wallet(fallback_id).await {
+                Ok(wallet) if wallet.status == WalletStatus::Active => return Ok(fallback_id.clone()),
+                Ok(wallet) => warn!("Fallback wallet {} is {:?}, using the default wallet", fallback_id, wallet.status),
+                Err(e) => warn!("Fallback wallet unavailable, using the default wallet: {}", e),
+            }
+        }
+        wallet_manager.default_wallet_id().await
+    }
+
+    /// Cap each AI-originated buy at this fraction of the selected wallet's SOL balance
+    pub fn with_max_ai_trade_fraction(mut self, max_ai_trade_fraction: f64) -> Self {
+        self.max_ai_trade_fraction = max_ai_trade_fraction;
+        self
+    }
+
+    /// Submissions one wallet may have in flight at once (1 keeps each wallet strictly ordered)
+    pub fn with_per_wallet_concurrency(self, per_wallet_concurrency: usize) -> Self {
+        self.with_submission_lanes(Arc::new(WalletSubmissionLanes::new(per_wallet_concurrency)))
+    }
+
+    /// Record the wallet selection of targeted signals into their captured pipeline traces
+    pub fn with_pipeline_capture(mut self, capture: SharedPipelineCapture) -> Self {
+        self.capture = Some(capture);
+        self
+    }
+
+    /// Share wallet lanes with other executors submitting from the same wallets
+    pub fn with_submission_lanes(mut self, submission_lanes: Arc<WalletSubmissionLanes>) -> Self {
+        self.submission_lanes = submission_lanes;
+        self
+    }
+
+    /// Fees for the standard paths, which pay the default priority fee and no tip
+    fn standard_fees(&self, routed_signal: &RoutedSignal) -> FeeBreakdown {
+        self.fee_model.for_signal(
+            &routed_signal.original_signal,
+            self.fee_model.config().default_priority_fee_lamports,
+            0,
+        )
+    }
+
     /// Start the multi-wallet executor
     pub async fn start(&mut self) -> Result<()> {
         info!("🏦 THE OVERMIND PROTOCOL Multi-Wallet Executor starting in {:?} mode", self.trading_mode);
@@ -143,28 +231,45 @@ impl MultiWalletExecutor {
         info!("🏦 Processing signal {} with multi-wallet routing", signal_id);
 
         // Step 1: Select optimal wallet for this signal
-        let routed_signal = match self.select_wallet_for_signal(&signal).await {
+        let mut routed_signal = match self.select_wallet_for_signal(&signal).await {
             Ok(routed) => routed,
             Err(e) => {
                 error!("Failed to select wallet for signal {}: {}", signal_id, e);
                 
                 // Try fallback wallet if available
-                if let Some(fallback_id) = &self.fallback_wallet_id {
-                    warn!("Using fallback wallet {} for signal {}", fallback_id, signal_id);
-                    RoutedSignal {
-                        original_signal: signal,
-                        selected_wallet_id: fallback_id.clone(),
-                        wallet_selection_reason: "Fallback due to selection failure".to_string(),
-                        routing_timestamp: chrono::Utc::now(),
-                    }
-                } else {
-                    return Err(anyhow!("No suitable wallet found and no fallback configured"));
+                let fallback_id = self.fallback_wallet().await
+                    .map_err(|e| anyhow!("No suitable wallet found and no usable fallback: {}", e))?;
+                warn!("Using fallback wallet {} for signal {}", fallback_id, signal_id);
+                RoutedSignal {
+                    original_signal: signal,
+                    selected_wallet_id: fallback_id,
+                    wallet_selection_reason: "Fallback due to selection failure".to_string(),
+                    routing_timestamp: chrono::Utc::now(),
                 }
             }
         };
 
-        // Step 2: Execute the trade with selected wallet
-        let result = self.execute_routed_signal(routed_signal).await?;
+        // Step 2: Never let one AI decision deploy more than its share of the wallet
+        self.cap_ai_trade_size(&mut routed_signal).await;
+
+        // Keep the trade within the wallet's allocation for its strategy and outside its cash buffer
+        let rejection = match self.enforce_strategy_allocation(&mut routed_signal).await? {
+            Some(reason) => Some(reason),
+            None => self.enforce_cash_buffer(&mut routed_signal).await,
+        };
+        let result = match rejection {
+            Some(reason) => {
+                warn!("🚫 Signal {} rejected for wallet {}: {}", signal_id, routed_signal.selected_wallet_id, reason);
+                self.rejected_result(&routed_signal, reason)
+            }
+            // Execute the trade with selected wallet
+            None => {
+                let result = self.execute_routed_signal(routed_signal.clone()).await?;
+                self.track_strategy_exposure(&routed_signal, &result).await;
+                self.record_balance_effect(&routed_signal, &result).await;
+                result
+            }
+        };
 
         // Step 3: Update statistics
         self.update_execution_stats(&result).await;
@@ -179,6 +284,199 @@ impl MultiWalletExecutor {
         Ok(())
     }
 
+    /// Check a buy against the wallet's remaining allocation for its strategy, downsizing it
+    /// when configured to. Returns the rejection reason when the trade cannot go ahead.
+    async fn enforce_strategy_allocation(&self, routed_signal: &mut RoutedSignal) -> Result<Option<String>> {
+        let signal = &routed_signal.original_signal;
+        if self.allocation_enforcement == AllocationEnforcement::Off
+            || signal.original_signal.action != TradeAction::Buy
+        {
+            return Ok(None);
+        }
+
+        let strategy = &signal.original_signal.strategy_type;
+        let wallet_manager = self.wallet_manager.read().await;
+        let Some(remaining) = wallet_manager
+            .remaining_strategy_allocation(&routed_signal.selected_wallet_id, strategy)
+            .await?
+        else {
+            return Ok(None);
+        };
+
+        let price = signal.original_signal.target_price;
+        let notional = signal.approved_quantity * price;
+        if notional <= remaining {
+            return Ok(None);
+        }
+
+        let reason = format!(
+            "{:?} allocation exceeded ({:.4} requested, {:.4} remaining)",
+            strategy, notional, remaining
+        );
+        if self.allocation_enforcement == AllocationEnforcement::Reject || remaining <= 0.0 || price <= 0.0 {
+            return Ok(Some(reason));
+        }
+
+        let downsized = remaining / price;
+        info!(
+            "📉 Downsizing signal {} from {} to {}: {}",
+            signal.original_signal.signal_id, signal.approved_quantity, downsized, reason
+        );
+        routed_signal.original_signal.approved_quantity = downsized;
+        Ok(None)
+    }
+
+    /// Shrink a buy to what the wallet may deploy without touching its cash buffer, rejecting
+    /// it when nothing is deployable. A no-op unless the wallet manager enforces the buffer.
+    async fn enforce_cash_buffer(&self, routed_signal: &mut RoutedSignal) -> Option<String> {
+        let signal = &routed_signal.original_signal;
+        let price = signal.original_signal.target_price;
+        if signal.original_signal.action != TradeAction::Buy || price <= 0.0 {
+            return None;
+        }
+
+        let wallet_id = &routed_signal.selected_wallet_id;
+        let deployable = match self.wallet_manager.read().await.deployable_balance(wallet_id).await {
+            Ok(deployable) => deployable,
+            Err(e) => {
+                debug!("No deployable balance for wallet {}: {}", wallet_id, e);
+                return None;
+            }
+        };
+        let notional = signal.approved_quantity * price;
+        if notional <= deployable {
+            return None;
+        }
+        if deployable <= 0.0 {
+            return Some(format!("wallet {} has nothing deployable outside its cash buffer", wallet_id));
+        }
+
+        let downsized = deployable / price;
+        info!(
+            "💤 Downsizing signal {} from {} to {} to keep wallet {}'s cash buffer idle ({:.4} deployable)",
+            signal.original_signal.signal_id, signal.approved_quantity, downsized, wallet_id, deployable
+        );
+        routed_signal.original_signal.approved_quantity = downsized;
+        None
+    }
+
+    /// Downsize an AI decision's buy to `max_ai_trade_fraction` of the wallet's SOL balance
+    async fn cap_ai_trade_size(&self, routed_signal: &mut RoutedSignal) {
+        let signal = &routed_signal.original_signal.original_signal;
+        if self.max_ai_trade_fraction <= 0.0
+            || signal.strategy_type != StrategyType::AIDecision
+            || signal.action != TradeAction::Buy
+            || signal.target_price <= 0.0
+        {
+            return;
+        }
+
+        let wallet_id = &routed_signal.selected_wallet_id;
+        let sol_balance = match self.wallet_manager.read().await.get_wallet_metrics(wallet_id).await {
+            Ok(metrics) => metrics.sol_balance,
+            Err(e) => {
+                warn!("Cannot cap AI trade {} without a balance for wallet {}: {}", signal.signal_id, wallet_id, e);
+                return;
+            }
+        };
+
+        let requested = routed_signal.original_signal.approved_quantity;
+        let capped = (sol_balance * self.max_ai_trade_fraction).max(0.0) / signal.target_price;
+        if requested <= capped {
+            return;
+        }
+
+        info!(
+            "🧠 Capping AI trade {} on wallet {} from {} to {} ({:.0}% of {:.4} SOL)",
+            signal.signal_id, wallet_id, requested, capped, self.max_ai_trade_fraction * 100.0, sol_balance
+        );
+        routed_signal.original_signal.approved_quantity = capped;
+
+        let mut stats = self.execution_stats.write().await;
+        stats.ai_trades_capped += 1;
+        stats.ai_quantity_trimmed += requested - capped;
+    }
+
+    /// Track confirmed buys as open strategy exposure on their wallet and release it on sells
+    async fn track_strategy_exposure(&self, routed_signal: &RoutedSignal, result: &ExecutionResult) {
+        if !matches!(result.status, ExecutionStatus::Confirmed) {
+            return;
+        }
+
+        let signal = &routed_signal.original_signal;
+        let wallet_id = &routed_signal.selected_wallet_id;
+        let wallet_manager = self.wallet_manager.read().await;
+        match signal.original_signal.action {
+            TradeAction::Buy => {
+                let mut position = Position::opening(
+                    signal.original_signal.signal_id.clone(),
+                    wallet_id.clone(),
+                    signal.original_signal.symbol.clone(),
+                    signal.original_signal.strategy_type.clone(),
+                    result.executed_quantity,
+                    result.executed_price,
+                );
+                position.token_pair = Some(signal.token_pair);
+                match position.transition(PositionState::Open) {
+                    Ok(()) => wallet_manager.record_open_position(position).await,
+                    Err(e) => warn!("{}", e),
+                }
+            }
+            TradeAction::Sell => {
+                // Fees are paid in SOL while positions are priced in the quote currency
+                let sol_per_quote = self.fee_model.sol_per_quote(&signal.original_signal.symbol);
+                wallet_manager.release_position_quantity(
+                    wallet_id,
+                    &signal.original_signal.strategy_type,
+                    &signal.original_signal.symbol,
+                    result.executed_quantity,
+                    result.executed_price,
+                    result.fees / sol_per_quote,
+                ).await;
+            }
+            TradeAction::Hold => {}
+        }
+    }
+
+    /// Tell the wallet manager how a confirmed trade moved the wallet's SOL, so balance drops
+    /// our own trades explain are not mistaken for a compromised key
+    async fn record_balance_effect(&self, routed_signal: &RoutedSignal, result: &ExecutionResult) {
+        if !matches!(result.status, ExecutionStatus::Confirmed) {
+            return;
+        }
+
+        let signal = &routed_signal.original_signal;
+        let sol_delta = fill_sol_delta(
+            &signal.original_signal.action,
+            &signal.token_pair,
+            result.executed_quantity,
+            result.executed_price,
+            result.fees,
+        );
+        self.wallet_manager
+            .read()
+            .await
+            .record_own_balance_change(&routed_signal.selected_wallet_id, sol_delta)
+            .await;
+    }
+
+    /// Result reported for a signal that was not executed
+    fn rejected_result(&self, routed_signal: &RoutedSignal, reason: String) -> ExecutionResult {
+        ExecutionResult {
+            signal_id: routed_signal.original_signal.original_signal.signal_id.clone(),
+            transaction_id: format!("{}_rejected", routed_signal.selected_wallet_id),
+            status: ExecutionStatus::Cancelled,
+            executed_quantity: 0.0,
+            executed_price: 0.0,
+            fees: 0.0,
+            fee_breakdown: FeeBreakdown::default(),
+            timestamp: chrono::Utc::now(),
+            error_message: Some(reason),
+            strategy_type: Some(routed_signal.original_signal.original_signal.strategy_type.clone()),
+            wallet_id: Some(routed_signal.selected_wallet_id.clone()),
+        }
+    }
+
     /// Select optimal wallet for the given signal
     async fn select_wallet_for_signal(&self, signal: &ApprovedSignal) -> Result<RoutedSignal> {
         let wallet_manager = self.wallet_manager.read().await;
@@ -193,11 +491,17 @@ impl MultiWalletExecutor {
         };
 
         // Select wallet with timeout
-        let selection_future = wallet_manager.select_wallet(criteria);
+        let selection_future = wallet_manager.select_wallet_with_trace(criteria);
         let selection_result = tokio::time::timeout(
             std::time::Duration::from_millis(self.wallet_selection_timeout_ms),
             selection_future,
         ).await;
+        let selection_result = selection_result.map(|(result, trace)| {
+            if let Some(capture) = &self.capture {
+                capture.record(&signal.original_signal.signal_id, CaptureArtifact::WalletSelection(trace));
+            }
+            result
+        });
 
         match selection_result {
             Ok(Ok(selection)) => {
@@ -244,17 +548,27 @@ impl MultiWalletExecutor {
             signal_id, wallet_id, routed_signal.wallet_selection_reason
         );
 
-        // Get wallet keypair for signing
+        // Get wallet signer (local keypair or remote KMS/HSM) and its RPC endpoint
         let wallet_manager = self.wallet_manager.read().await;
-        let wallet_keypair = wallet_manager.get_wallet_keypair(&wallet_id).await?;
+        let wallet_signer = wallet_manager.get_wallet_signer(&wallet_id).await?;
+        let wallet_rpc_url = wallet_manager.get_wallet(&wallet_id).await?.rpc_url;
         drop(wallet_manager); // Release lock
+        let rpc_client = self.rpc_pool.client_for(wallet_rpc_url.as_deref());
+
+        // Held until the submission completes, so the wallet's next one cannot overtake it
+        let submission_lanes = self.submission_lanes.clone();
+        let _wallet_permit = submission_lanes.acquire(&wallet_id).await;
+        let queue_depth = submission_lanes.queue_depth(&wallet_id);
+        if queue_depth.current > 1 {
+            debug!("🚦 Wallet {} has {} submissions queued", wallet_id, queue_depth.current);
+        }
 
         // Execute based on trading mode and HFT settings
         let mut result = match (&self.trading_mode, self.hft_mode_enabled) {
             (&TradingMode::Paper, false) => self.execute_paper_trade_with_wallet(&routed_signal, &wallet_id).await?,
             (&TradingMode::Paper, true) => self.execute_ai_paper_trade_with_wallet(&routed_signal, &wallet_id).await?,
-            (&TradingMode::Live, false) => self.execute_live_trade_with_wallet(&routed_signal, &wallet_id, &wallet_keypair).await?,
-            (&TradingMode::Live, true) => self.execute_ai_live_trade_with_wallet(&routed_signal, &wallet_id, &wallet_keypair).await?,
+            (&TradingMode::Live, false) => self.execute_live_trade_with_wallet(&routed_signal, &wallet_id, wallet_signer.as_ref(), &rpc_client).await?,
+            (&TradingMode::Live, true) => self.execute_ai_live_trade_with_wallet(&routed_signal, &wallet_id, wallet_signer.as_ref(), &rpc_client).await?,
         };
 
         // Add wallet information to result
@@ -274,15 +588,19 @@ impl MultiWalletExecutor {
         // Simulate execution delay
         tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
 
+        let fee_breakdown = self.standard_fees(routed_signal);
         Ok(ExecutionResult {
             signal_id: routed_signal.original_signal.original_signal.signal_id.clone(),
             transaction_id: format!("paper_{}", uuid::Uuid::new_v4()),
             status: ExecutionStatus::Confirmed,
             executed_quantity: routed_signal.original_signal.approved_quantity,
             executed_price: routed_signal.original_signal.original_signal.target_price,
-            fees: routed_signal.original_signal.approved_quantity * routed_signal.original_signal.original_signal.target_price * 0.001,
+            fees: fee_breakdown.total_sol(),
+            fee_breakdown,
             timestamp: chrono::Utc::now(),
             error_message: None,
+            strategy_type: Some(routed_signal.original_signal.original_signal.strategy_type.clone()),
+            wallet_id: Some(routed_signal.selected_wallet_id.clone()),
         })
     }
 
@@ -301,21 +619,29 @@ impl MultiWalletExecutor {
             match hft_engine.execute_ai_signal(&market_data).await {
                 Ok(hft_result) => {
                     match hft_result {
-                        HFTExecutionResult::Executed { latency_ms, estimated_profit, ai_confidence, .. } => {
+                        HFTExecutionResult::Executed { latency_ms, estimated_profit, ai_confidence, priority_fee_lamports, tip, .. } => {
                             info!(
-                                "🧠 AI paper trade executed with wallet {} - Latency: {}ms, Confidence: {:.2}, Profit: ${:.2}",
+                                "🧠 AI paper trade executed with wallet {} - Latency: {}ms, Confidence: {:.2}, Profit: {:.6} SOL",
                                 wallet_id, latency_ms, ai_confidence, estimated_profit
                             );
 
+                            let fee_breakdown = self.fee_model.for_signal(
+                                &routed_signal.original_signal,
+                                priority_fee_lamports,
+                                tip.tip_lamports,
+                            );
                             Ok(ExecutionResult {
                                 signal_id: routed_signal.original_signal.original_signal.signal_id.clone(),
                                 transaction_id: format!("ai_paper_{}", uuid::Uuid::new_v4()),
                                 status: ExecutionStatus::Confirmed,
                                 executed_quantity: routed_signal.original_signal.approved_quantity,
                                 executed_price: routed_signal.original_signal.original_signal.target_price,
-                                fees: routed_signal.original_signal.approved_quantity * routed_signal.original_signal.original_signal.target_price * 0.0005,
+                                fees: fee_breakdown.total_sol(),
+                                fee_breakdown,
                                 timestamp: chrono::Utc::now(),
                                 error_message: None,
+                                strategy_type: Some(routed_signal.original_signal.original_signal.strategy_type.clone()),
+                                wallet_id: Some(routed_signal.selected_wallet_id.clone()),
                             })
                         },
                         _ => self.execute_paper_trade_with_wallet(routed_signal, wallet_id).await,
@@ -333,28 +659,33 @@ impl MultiWalletExecutor {
         &self,
         routed_signal: &RoutedSignal,
         wallet_id: &str,
-        _wallet_keypair: &solana_sdk::signature::Keypair,
+        _wallet_signer: &dyn TransactionSigner,
+        rpc_client: &RpcClient,
     ) -> Result<ExecutionResult> {
-        warn!("🔴 EXECUTING LIVE TRADE with wallet {}", wallet_id);
+        warn!("🔴 EXECUTING LIVE TRADE with wallet {} via {}", wallet_id, rpc_client.url());
 
         // TODO: Implement actual Solana transaction execution with specific wallet
         // This would involve:
         // 1. Building the transaction with Solana SDK
-        // 2. Signing with the provided wallet keypair
-        // 3. Sending with HFT optimizations
+        // 2. Signing via signer::sign_transaction with the wallet's signer
+        // 3. Sending through `rpc_client` (the wallet's endpoint) with HFT optimizations
         // 4. Monitoring transaction status
 
         tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
 
+        let fee_breakdown = self.standard_fees(routed_signal);
         Ok(ExecutionResult {
             signal_id: routed_signal.original_signal.original_signal.signal_id.clone(),
             transaction_id: uuid::Uuid::new_v4().to_string(),
             status: ExecutionStatus::Confirmed,
             executed_quantity: routed_signal.original_signal.approved_quantity,
             executed_price: routed_signal.original_signal.original_signal.target_price * 1.005,
-            fees: routed_signal.original_signal.approved_quantity * routed_signal.original_signal.original_signal.target_price * 0.0025,
+            fees: fee_breakdown.total_sol(),
+            fee_breakdown,
             timestamp: chrono::Utc::now(),
             error_message: None,
+            strategy_type: Some(routed_signal.original_signal.original_signal.strategy_type.clone()),
+            wallet_id: Some(routed_signal.selected_wallet_id.clone()),
         })
     }
 
@@ -363,7 +694,8 @@ impl MultiWalletExecutor {
         &mut self,
         routed_signal: &RoutedSignal,
         wallet_id: &str,
-        wallet_keypair: &solana_sdk::signature::Keypair,
+        wallet_signer: &dyn TransactionSigner,
+        rpc_client: &RpcClient,
     ) -> Result<ExecutionResult> {
         warn!("🧠 EXECUTING AI-ENHANCED LIVE TRADE with wallet {}", wallet_id);
 
@@ -374,30 +706,38 @@ impl MultiWalletExecutor {
             match hft_engine.execute_ai_signal(&market_data).await {
                 Ok(hft_result) => {
                     match hft_result {
-                        HFTExecutionResult::Executed { bundle_id, latency_ms, estimated_profit, ai_confidence, signal_id: _ } => {
+                        HFTExecutionResult::Executed { bundle_id, latency_ms, estimated_profit, ai_confidence, priority_fee_lamports, tip, .. } => {
                             info!(
-                                "🧠 AI live trade executed with wallet {} - Bundle: {}, Latency: {}ms, Confidence: {:.2}, Profit: ${:.2}",
+                                "🧠 AI live trade executed with wallet {} - Bundle: {}, Latency: {}ms, Confidence: {:.2}, Profit: {:.6} SOL",
                                 wallet_id, bundle_id, latency_ms, ai_confidence, estimated_profit
                             );
 
+                            let fee_breakdown = self.fee_model.for_signal(
+                                &routed_signal.original_signal,
+                                priority_fee_lamports,
+                                tip.tip_lamports,
+                            );
                             Ok(ExecutionResult {
                                 signal_id: routed_signal.original_signal.original_signal.signal_id.clone(),
                                 transaction_id: bundle_id,
                                 status: ExecutionStatus::Confirmed,
                                 executed_quantity: routed_signal.original_signal.approved_quantity,
                                 executed_price: routed_signal.original_signal.original_signal.target_price * 1.002,
-                                fees: routed_signal.original_signal.approved_quantity * routed_signal.original_signal.original_signal.target_price * 0.0015,
+                                fees: fee_breakdown.total_sol(),
+                                fee_breakdown,
                                 timestamp: chrono::Utc::now(),
                                 error_message: None,
+                                strategy_type: Some(routed_signal.original_signal.original_signal.strategy_type.clone()),
+                                wallet_id: Some(routed_signal.selected_wallet_id.clone()),
                             })
                         },
-                        _ => self.execute_live_trade_with_wallet(routed_signal, wallet_id, wallet_keypair).await,
+                        _ => self.execute_live_trade_with_wallet(routed_signal, wallet_id, wallet_signer, rpc_client).await,
                     }
                 },
-                Err(_) => self.execute_live_trade_with_wallet(routed_signal, wallet_id, wallet_keypair).await,
+                Err(_) => self.execute_live_trade_with_wallet(routed_signal, wallet_id, wallet_signer, rpc_client).await,
             }
         } else {
-            self.execute_live_trade_with_wallet(routed_signal, wallet_id, wallet_keypair).await
+            self.execute_live_trade_with_wallet(routed_signal, wallet_id, wallet_signer, rpc_client).await
         }
     }
 
@@ -410,6 +750,8 @@ impl MultiWalletExecutor {
             "strategy_type": format!("{:?}", routed_signal.original_signal.original_signal.strategy_type),
             "action": format!("{:?}", routed_signal.original_signal.original_signal.action),
             "symbol": routed_signal.original_signal.original_signal.symbol,
+            "base_mint": routed_signal.original_signal.token_pair.base_mint.to_string(),
+            "quote_mint": routed_signal.original_signal.token_pair.quote_mint.to_string(),
             "quantity": routed_signal.original_signal.original_signal.quantity,
             "target_price": routed_signal.original_signal.original_signal.target_price,
             "approved_quantity": routed_signal.original_signal.approved_quantity,
@@ -431,15 +773,30 @@ impl MultiWalletExecutor {
             _ => {}
         }
 
-        // Extract wallet ID from transaction ID
-        if let Some(wallet_id) = result.transaction_id.split('_').next() {
-            *stats.wallet_usage.entry(wallet_id.to_string()).or_insert(0) += 1;
+        let wallet_id = Self::result_wallet_id(result).to_string();
+        *stats.wallet_usage.entry(wallet_id.clone()).or_insert(0) += 1;
+        if let Some(strategy_type) = &result.strategy_type {
+            *stats
+                .strategy_routing
+                .entry(strategy_type.clone())
+                .or_default()
+                .entry(wallet_id)
+                .or_insert(0) += 1;
         }
     }
 
+    /// Wallet recorded on the result, else the prefix of its transaction ID
+    fn result_wallet_id(result: &ExecutionResult) -> &str {
+        result
+            .wallet_id
+            .as_deref()
+            .or_else(|| result.transaction_id.split('_').next())
+            .unwrap_or("unknown")
+    }
+
     /// Log execution result with wallet information
     fn log_execution_result(&self, result: &ExecutionResult) {
-        let wallet_id = result.transaction_id.split('_').next().unwrap_or("unknown");
+        let wallet_id = Self::result_wallet_id(result);
         
         match result.status {
             ExecutionStatus::Confirmed => {
@@ -465,6 +822,73 @@ impl MultiWalletExecutor {
 
     /// Get execution statistics
     pub async fn get_execution_stats(&self) -> ExecutionStats {
-        self.execution_stats.read().await.clone()
+        let mut stats = self.execution_stats.read().await.clone();
+        stats.wallet_queue_depth = self.submission_lanes.queue_depths();
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::strategy::TradingSignal;
+    use crate::modules::symbol_resolver::TokenPair;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[tokio::test]
+    async fn test_stats_track_wallet_usage_and_strategy_routing() {
+        let (_signal_tx, signal_rx) = mpsc::unbounded_channel();
+        let (persistence_tx, _persistence_rx) = mpsc::unbounded_channel();
+        let executor = MultiWalletExecutor::new(
+            signal_rx,
+            persistence_tx,
+            Arc::new(RwLock::new(WalletManager::new())),
+            TradingMode::Paper,
+            "http://127.0.0.1:8899".to_string(),
+            100,
+            None,
+        );
+
+        let routed_signal = RoutedSignal {
+            original_signal: ApprovedSignal {
+                original_signal: TradingSignal {
+                    signal_id: "sig-1".to_string(),
+                    symbol: "SOL/USDC".to_string(),
+                    action: TradeAction::Buy,
+                    quantity: 1.0,
+                    target_price: 150.0,
+                    confidence: 0.9,
+                    timestamp: chrono::Utc::now(),
+                    strategy_type: StrategyType::Arbitrage,
+                    tick_received_at: None,
+                    pool_liquidity: None,
+                },
+                token_pair: TokenPair {
+                    base_mint: Pubkey::new_unique(),
+                    quote_mint: Pubkey::new_unique(),
+                },
+                approved_quantity: 1.0,
+                risk_score: 0.1,
+                approval_timestamp: chrono::Utc::now(),
+                base_metadata: None,
+                allowed_slippage_bps: None,
+            },
+            selected_wallet_id: "arb-wallet".to_string(),
+            wallet_selection_reason: "test".to_string(),
+            routing_timestamp: chrono::Utc::now(),
+        };
+        let result = executor
+            .execute_paper_trade_with_wallet(&routed_signal, "arb-wallet")
+            .await
+            .unwrap();
+        executor.update_execution_stats(&result).await;
+
+        let stats = executor.get_execution_stats().await;
+        assert_eq!(stats.successful_executions, 1);
+        assert_eq!(stats.wallet_usage.get("arb-wallet"), Some(&1));
+        assert_eq!(
+            stats.strategy_routing[&StrategyType::Arbitrage].get("arb-wallet"),
+            Some(&1)
+        );
     }
 }
@@ -0,0 +1,147 @@
+// Notional Rate Limit Module
+// Caps how much new exposure each strategy may open per minute, so a strategy stuck in a
+// feedback loop cannot deploy capital faster than intended
+
+use crate::modules::strategy::StrategyType;
+use crate::modules::strategy_cooldown::parse_strategy_type;
+use anyhow::{anyhow, Context, Result};
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, VecDeque};
+
+#[derive(Debug, Clone)]
+pub struct NotionalRateConfig {
+    /// Notional (approved quantity x target price) a strategy may open per window (0 disables)
+    pub max_notional_per_window: f64,
+    /// Overrides of `max_notional_per_window` for individual strategies
+    pub per_strategy: HashMap<StrategyType, f64>,
+    pub window: chrono::Duration,
+}
+
+impl Default for NotionalRateConfig {
+    fn default() -> Self {
+        Self {
+            max_notional_per_window: 0.0,
+            per_strategy: HashMap::new(),
+            window: chrono::Duration::minutes(1),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl NotionalRateConfig {
+    /// Parse per-strategy limits such as "TokenSniping=50,MeteoraDAMM=5"
+    pub fn with_strategy_limits(mut self, spec: &str) -> Result<Self> {
+        for entry in spec.split(',').filter(|e| !e.trim().is_empty()) {
+            let (strategy, limit) = entry
+                .split_once('=')
+                .with_context(|| format!("Invalid notional rate limit entry: {}", entry))?;
+            let limit: f64 = limit
+                .trim()
+                .parse()
+                .with_context(|| format!("Invalid notional rate limit: {}", entry))?;
+            if !limit.is_finite() || limit < 0.0 {
+                return Err(anyhow!("Invalid notional rate limit: {}", entry));
+            }
+            self.per_strategy
+                .insert(parse_strategy_type(strategy.trim())?, limit);
+        }
+        Ok(self)
+    }
+
+    pub fn limit_for(&self, strategy: &StrategyType) -> f64 {
+        self.per_strategy
+            .get(strategy)
+            .copied()
+            .unwrap_or(self.max_notional_per_window)
+    }
+}
+
+/// Sliding window of notional opened per strategy
+#[derive(Debug)]
+pub struct NotionalRateLimiter {
+    config: NotionalRateConfig,
+    opened: HashMap<StrategyType, VecDeque<(DateTime<Utc>, f64)>>,
+}
+
+#[allow(dead_code)]
+impl NotionalRateLimiter {
+    pub fn new(config: NotionalRateConfig) -> Self {
+        Self {
+            config,
+            opened: HashMap::new(),
+        }
+    }
+
+    /// Notional the strategy opened within the window ending at `now`
+    pub fn opened_in_window(&mut self, strategy: &StrategyType, now: DateTime<Utc>) -> f64 {
+        let window_start = now - self.config.window;
+        match self.opened.get_mut(strategy) {
+            Some(entries) => {
+                while entries.front().is_some_and(|(at, _)| *at <= window_start) {
+                    entries.pop_front();
+                }
+                entries.iter().map(|(_, notional)| notional).sum()
+            }
+            None => 0.0,
+        }
+    }
+
+    /// Record `notional` as opened if it fits the strategy's remaining budget.
+    /// Returns the notional already opened in the window when it does not.
+    pub fn try_open(
+        &mut self,
+        strategy: &StrategyType,
+        notional: f64,
+        now: DateTime<Utc>,
+    ) -> std::result::Result<(), f64> {
+        let limit = self.config.limit_for(strategy);
+        if limit <= 0.0 {
+            return Ok(());
+        }
+
+        let opened = self.opened_in_window(strategy, now);
+        if opened + notional > limit {
+            return Err(opened);
+        }
+        self.opened
+            .entry(strategy.clone())
+            .or_default()
+            .push_back((now, notional));
+        Ok(())
+    }
+
+    pub fn limit_for(&self, strategy: &StrategyType) -> f64 {
+        self.config.limit_for(strategy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_notional_window_rejects_then_recovers() {
+        let config = NotionalRateConfig {
+            max_notional_per_window: 10.0,
+            ..NotionalRateConfig::default()
+        }
+        .with_strategy_limits("MeteoraDAMM=2")
+        .unwrap();
+        let mut limiter = NotionalRateLimiter::new(config);
+        let strategy = StrategyType::TokenSniping;
+        let now = Utc::now();
+
+        assert!(limiter.try_open(&strategy, 6.0, now).is_ok());
+        assert_eq!(limiter.try_open(&strategy, 5.0, now), Err(6.0));
+        // Rejected entries use no budget; a smaller one still fits
+        assert!(limiter.try_open(&strategy, 4.0, now).is_ok());
+        assert!(limiter
+            .try_open(&StrategyType::MeteoraDAMM, 3.0, now)
+            .is_err());
+
+        // Once the first entries leave the window the budget is available again
+        let later = now + chrono::Duration::seconds(61);
+        assert_eq!(limiter.opened_in_window(&strategy, later), 0.0);
+        assert!(limiter.try_open(&strategy, 10.0, later).is_ok());
+    }
+}
@@ -0,0 +1,93 @@
+// Pending Replacement Module
+// Registry of pending trades that can be replaced with a fee-bumped rebuild, so a trade
+// stuck on an insufficient priority fee need not wait out its expiry
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tokio::sync::oneshot;
+
+/// One replacement request channel per pending trade, keyed by signal ID
+#[derive(Debug, Default)]
+pub struct PendingReplacements {
+    pending: Mutex<HashMap<String, oneshot::Sender<()>>>,
+}
+
+pub type SharedPendingReplacements = Arc<PendingReplacements>;
+
+#[allow(dead_code)]
+impl PendingReplacements {
+    pub fn shared() -> SharedPendingReplacements {
+        Arc::new(Self::default())
+    }
+
+    /// Make a pending trade replaceable; the receiver fires once a replacement is requested
+    pub fn register(&self, signal_id: &str) -> oneshot::Receiver<()> {
+        let (sender, receiver) = oneshot::channel();
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.insert(signal_id.to_string(), sender);
+        }
+        receiver
+    }
+
+    /// Forget a trade once it reached a verdict
+    pub fn unregister(&self, signal_id: &str) {
+        if let Ok(mut pending) = self.pending.lock() {
+            pending.remove(signal_id);
+        }
+    }
+
+    /// Signal IDs of the trades that can currently be replaced
+    pub fn pending_signals(&self) -> Vec<String> {
+        let mut signals: Vec<String> = self
+            .pending
+            .lock()
+            .map(|pending| pending.keys().cloned().collect())
+            .unwrap_or_default();
+        signals.sort();
+        signals
+    }
+
+    /// Ask the executor to replace `signal_id`'s pending trade with a fee-bumped rebuild
+    pub fn replace_pending(&self, signal_id: &str) -> Result<()> {
+        let sender = self
+            .pending
+            .lock()
+            .map_err(|_| anyhow!("Pending replacement registry poisoned"))?
+            .remove(signal_id)
+            .ok_or_else(|| anyhow!("No replaceable pending trade for signal {}", signal_id))?;
+        sender.send(()).map_err(|_| {
+            anyhow!(
+                "Signal {} reached a verdict before it could be replaced",
+                signal_id
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_replacement_reaches_only_pending_trades() {
+        let replacements = PendingReplacements::shared();
+        let receiver = replacements.register("sig-1");
+        let finished = replacements.register("sig-2");
+        assert_eq!(replacements.pending_signals(), vec!["sig-1", "sig-2"]);
+
+        replacements.replace_pending("sig-1").unwrap();
+        assert!(receiver.await.is_ok());
+        // A request is delivered once; the trade must be registered again when resubmitted
+        assert!(replacements.replace_pending("sig-1").is_err());
+
+        // The watcher already gave its verdict and dropped its receiver
+        drop(finished);
+        assert!(replacements.replace_pending("sig-2").is_err());
+
+        replacements.register("sig-3");
+        replacements.unregister("sig-3");
+        assert!(replacements.replace_pending("sig-3").is_err());
+        assert!(replacements.pending_signals().is_empty());
+    }
+}
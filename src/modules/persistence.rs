@@ -2,15 +2,178 @@
 // Handles data storage and retrieval
 
 use crate::modules::executor::ExecutionResult;
+use crate::modules::pipeline_capture::PipelineTrace;
+use crate::modules::risk::RiskParameters;
+use crate::modules::shutdown_report::ShutdownReport;
+use crate::modules::strategy::{StrategyType, TradingSignal};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use tokio::sync::mpsc;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
+
+#[derive(Debug, Clone)]
+pub struct PersistenceBatchConfig {
+    /// Buffered records that trigger a flush (1 writes every record through)
+    pub max_batch_size: usize,
+    /// Longest a record stays buffered when the batch does not fill up
+    pub flush_interval: Duration,
+}
+
+impl Default for PersistenceBatchConfig {
+    fn default() -> Self {
+        Self {
+            max_batch_size: 100,
+            flush_interval: Duration::from_secs(1),
+        }
+    }
+}
+
+/// Result of the final flush, recorded in the stored shutdown report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PersistenceFlushStatus {
+    /// Buffered records written by the final flush
+    pub records_flushed: usize,
+    pub completed: bool,
+    pub error: Option<String>,
+    pub flushed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// A serialized record waiting for the next batch insert
+#[derive(Debug, Clone)]
+struct BufferedRecord {
+    table: &'static str,
+    key: String,
+    payload: String,
+}
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PersistenceMessage {
     ExecutionResult(ExecutionResult),
+    /// Signal emitted by the strategy engine
+    TradingSignal(TradingSignal),
+    /// Signal of an observe-only strategy, recorded instead of routed to risk
+    ObservedSignal(TradingSignal),
+    /// Risk manager approval or rejection of a signal
+    RiskDecision(RiskDecision),
+    /// Rejected signal with the risk state at the time, for replaying thresholds
+    RejectedSignal(Box<RejectedSignal>),
+    WalletStateChange(WalletStateChange),
+    /// Realized outcome of an AI decision and whether it reached TensorZero as feedback
+    AIDecisionOutcome(AIDecisionOutcome),
+    /// Every captured artifact of one targeted signal's pipeline
+    PipelineTrace(Box<PipelineTrace>),
     HealthCheck,
+    /// Final run summary; the manager stops after storing it
+    ShutdownReport(Box<ShutdownReport>),
+    /// Flush and stop without a report
+    Shutdown,
+}
+
+impl PersistenceMessage {
+    /// Table each message variant is stored in; None for messages that are not stored
+    pub fn table(&self) -> Option<&'static str> {
+        match self {
+            PersistenceMessage::ExecutionResult(_) => Some("execution_results"),
+            PersistenceMessage::TradingSignal(_) => Some("trading_signals"),
+            PersistenceMessage::ObservedSignal(_) => Some("observed_signals"),
+            PersistenceMessage::RiskDecision(_) => Some("risk_decisions"),
+            PersistenceMessage::RejectedSignal(_) => Some("rejected_signals"),
+            PersistenceMessage::WalletStateChange(_) => Some("wallet_events"),
+            PersistenceMessage::AIDecisionOutcome(_) => Some("ai_decision_outcomes"),
+            PersistenceMessage::PipelineTrace(_) => Some("pipeline_traces"),
+            PersistenceMessage::ShutdownReport(_) => Some("shutdown_reports"),
+            PersistenceMessage::HealthCheck | PersistenceMessage::Shutdown => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RiskDecision {
+    pub signal_id: String,
+    pub strategy_type: StrategyType,
+    pub symbol: String,
+    pub approved: bool,
+    pub approved_quantity: f64,
+    pub risk_score: Option<f64>,
+    pub rejection_reason: Option<String>,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+#[allow(dead_code)]
+impl RiskDecision {
+    pub fn approved(signal: &TradingSignal, approved_quantity: f64, risk_score: f64) -> Self {
+        Self {
+            signal_id: signal.signal_id.clone(),
+            strategy_type: signal.strategy_type.clone(),
+            symbol: signal.symbol.clone(),
+            approved: true,
+            approved_quantity,
+            risk_score: Some(risk_score),
+            rejection_reason: None,
+            timestamp: chrono::Utc::now(),
+        }
+    }
+
+    pub fn rejected(signal: &TradingSignal, reason: String) -> Self {
+        Self {
+            signal_id: signal.signal_id.clone(),
+            strategy_type: signal.strategy_type.clone(),
+            symbol: signal.symbol.clone(),
+            approved: false,
+            approved_quantity: 0.0,
+            risk_score: None,
+            rejection_reason: Some(reason),
+            timestamp: chrono::Utc::now(),
+        }
+    }
+}
+
+/// Everything needed to replay a rejection against realized market moves
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RejectedSignal {
+    pub signal: TradingSignal,
+    pub rejection_reason: String,
+    pub risk_params: RiskParameters,
+    /// Position limit after the strategy's allocation multiplier
+    pub effective_max_position_size: f64,
+    pub daily_pnl: f64,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletStateChange {
+    pub wallet_id: String,
+    /// What changed, e.g. "status Active -> Emergency"
+    pub event: String,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+#[allow(dead_code)]
+impl WalletStateChange {
+    pub fn new(wallet_id: &str, event: impl Into<String>) -> Self {
+        Self {
+            wallet_id: wallet_id.to_string(),
+            event: event.into(),
+            timestamp: chrono::Utc::now(),
+        }
+    }
+}
+
+/// Realized P&L of a trade correlated with the TensorZero inference that decided it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AIDecisionOutcome {
+    pub signal_id: String,
+    pub inference_id: uuid::Uuid,
+    pub episode_id: Option<uuid::Uuid>,
+    pub metric_name: String,
+    pub realized_pnl: f64,
+    /// Profit the AI estimated when it made the decision
+    #[serde(default)]
+    pub estimated_profit: Option<f64>,
+    pub executed_at: chrono::DateTime<chrono::Utc>,
+    pub settled_at: chrono::DateTime<chrono::Utc>,
+    pub feedback_sent: bool,
 }
 
 #[allow(dead_code)]
@@ -19,6 +182,9 @@ pub struct PersistenceManager {
     execution_result_receiver: mpsc::UnboundedReceiver<ExecutionResult>,
     database_url: String,
     is_running: bool,
+    batch_config: PersistenceBatchConfig,
+    batch: Vec<BufferedRecord>,
+    final_flush: Option<PersistenceFlushStatus>,
 }
 
 #[allow(dead_code)]
@@ -33,9 +199,28 @@ impl PersistenceManager {
             execution_result_receiver,
             database_url,
             is_running: false,
+            batch_config: PersistenceBatchConfig::default(),
+            batch: Vec::new(),
+            final_flush: None,
         }
     }
 
+    /// Batch inserts by size and age instead of using the defaults
+    pub fn with_batching(mut self, batch_config: PersistenceBatchConfig) -> Self {
+        self.batch_config = batch_config;
+        self
+    }
+
+    /// Records buffered and not yet written
+    pub fn buffered_records(&self) -> usize {
+        self.batch.len()
+    }
+
+    /// Outcome of the shutdown flush, once `start` has returned
+    pub fn final_flush(&self) -> Option<&PersistenceFlushStatus> {
+        self.final_flush.as_ref()
+    }
+
     pub async fn start(&mut self) -> Result<()> {
         info!("💾 PersistenceManager starting...");
         self.is_running = true;
@@ -43,19 +228,64 @@ impl PersistenceManager {
         // TODO: Initialize database connection
         // let pool = sqlx::PgPool::connect(&self.database_url).await?;
 
+        // A quiet period must not leave the latest records buffered
+        let mut flush_timer = tokio::time::interval(self.batch_config.flush_interval);
+        flush_timer.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+
         while self.is_running {
             tokio::select! {
                 Some(message) = self.message_receiver.recv() => {
+                    let is_final = matches!(
+                        message,
+                        PersistenceMessage::ShutdownReport(_) | PersistenceMessage::Shutdown
+                    );
                     self.handle_message(message).await?;
+                    if is_final {
+                        return Ok(());
+                    }
                 }
                 Some(execution_result) = self.execution_result_receiver.recv() => {
                     self.store_execution_result(execution_result).await?;
                 }
+                _ = flush_timer.tick() => {
+                    if let Err(e) = self.flush().await {
+                        warn!("💾 Timed persistence flush failed, retrying next tick: {}", e);
+                    }
+                }
                 else => break,
             }
         }
 
-        Ok(())
+        // Stopped or every sender gone: nothing buffered may be lost on the way out
+        let status = self.flush_for_shutdown().await;
+        match status.error {
+            Some(e) => Err(anyhow::anyhow!("Final persistence flush failed: {}", e)),
+            None => Ok(()),
+        }
+    }
+
+    /// Force-flush before returning from shutdown, recording how it went
+    async fn flush_for_shutdown(&mut self) -> PersistenceFlushStatus {
+        let records_buffered = self.batch.len();
+        let flushed = self.flush().await;
+        let status = PersistenceFlushStatus {
+            records_flushed: *flushed.as_ref().unwrap_or(&0),
+            completed: flushed.is_ok(),
+            error: flushed.err().map(|e| e.to_string()),
+            flushed_at: chrono::Utc::now(),
+        };
+        match &status.error {
+            None => info!(
+                "💾 Shutdown flush complete: {} buffered records written",
+                status.records_flushed
+            ),
+            Some(e) => warn!(
+                "💾 Shutdown flush failed with {} records buffered: {}",
+                records_buffered, e
+            ),
+        }
+        self.final_flush = Some(status.clone());
+        status
     }
 
     pub async fn stop(&mut self) {
@@ -63,45 +293,135 @@ impl PersistenceManager {
         self.is_running = false;
     }
 
-    async fn handle_message(&self, message: PersistenceMessage) -> Result<()> {
+    async fn handle_message(&mut self, message: PersistenceMessage) -> Result<()> {
+        let table = message.table();
         match message {
             PersistenceMessage::ExecutionResult(result) => {
                 self.store_execution_result(result).await?;
             }
+            PersistenceMessage::TradingSignal(signal) | PersistenceMessage::ObservedSignal(signal) => {
+                self.store_record(table, &signal.signal_id, &signal).await?;
+            }
+            PersistenceMessage::RiskDecision(decision) => {
+                self.store_record(table, &decision.signal_id, &decision)
+                    .await?;
+            }
+            PersistenceMessage::RejectedSignal(rejected) => {
+                self.store_record(table, &rejected.signal.signal_id, &rejected)
+                    .await?;
+            }
+            PersistenceMessage::WalletStateChange(change) => {
+                self.store_record(table, &change.wallet_id, &change).await?;
+            }
+            PersistenceMessage::AIDecisionOutcome(outcome) => {
+                self.store_record(table, &outcome.signal_id, &outcome)
+                    .await?;
+            }
+            PersistenceMessage::PipelineTrace(trace) => {
+                self.store_record(table, &trace.signal_id, &trace).await?;
+            }
             PersistenceMessage::HealthCheck => {
                 debug!("💓 Persistence health check");
             }
+            PersistenceMessage::Shutdown => {
+                self.flush_for_shutdown().await;
+            }
+            PersistenceMessage::ShutdownReport(mut report) => {
+                // The most recent trades are still buffered; write them before the report
+                let status = self.flush_for_shutdown().await;
+                report.persistence_flush = Some(status);
+                let key = report.generated_at.to_rfc3339();
+                self.store_record(table, &key, &report).await?;
+                self.flush().await?;
+                info!(
+                    "📊 Stored shutdown report ({} trades, realized P&L {:.4})",
+                    report.executed_trades, report.realized_pnl
+                );
+            }
         }
         Ok(())
     }
 
-    async fn store_execution_result(&self, result: ExecutionResult) -> Result<()> {
-        debug!("💾 Storing execution result: {}", result.transaction_id);
-
-        // TODO: Implement actual database storage
-        // sqlx::query!(
-        //     "INSERT INTO execution_results (signal_id, transaction_id, status, executed_quantity, executed_price, fees, timestamp, error_message)
-        //      VALUES ($1, $2, $3, $4, $5, $6, $7, $8)",
-        //     result.signal_id,
-        //     result.transaction_id,
-        //     serde_json::to_string(&result.status)?,
-        //     result.executed_quantity,
-        //     result.executed_price,
-        //     result.fees,
-        //     result.timestamp,
-        //     result.error_message
-        // )
-        // .execute(&pool)
-        // .await?;
-
-        // For now, just log the storage
-        info!(
-            "📊 Stored execution result: {} ({})",
-            result.transaction_id, result.signal_id
-        );
+    /// Buffer a record for the next batch insert, flushing once the batch is full
+    async fn store_record<T: Serialize>(
+        &mut self,
+        table: Option<&'static str>,
+        key: &str,
+        record: &T,
+    ) -> Result<()> {
+        self.batch.push(BufferedRecord {
+            table: table.unwrap_or("events"),
+            key: key.to_string(),
+            payload: serde_json::to_string(record)?,
+        });
+        if self.batch.len() >= self.batch_config.max_batch_size.max(1) {
+            // Failed records stay buffered for the next timed flush
+            if let Err(e) = self.flush().await {
+                warn!("💾 Persistence batch flush failed: {}", e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Write every buffered record, one batch insert per table. Returns the number written.
+    pub async fn flush(&mut self) -> Result<usize> {
+        if self.batch.is_empty() {
+            return Ok(0);
+        }
+        let mut by_table: Vec<(&'static str, Vec<BufferedRecord>)> = Vec::new();
+        for record in std::mem::take(&mut self.batch) {
+            match by_table
+                .iter_mut()
+                .find(|(table, _)| *table == record.table)
+            {
+                Some((_, records)) => records.push(record),
+                None => by_table.push((record.table, vec![record])),
+            }
+        }
+
+        let mut flushed = 0;
+        let mut pending = by_table.into_iter();
+        while let Some((table, records)) = pending.next() {
+            if let Err(e) = self.write_batch(table, &records).await {
+                // Keep what was not written so the next flush retries it
+                self.batch = records
+                    .into_iter()
+                    .chain(pending.flat_map(|(_, records)| records))
+                    .collect();
+                return Err(e);
+            }
+            flushed += records.len();
+        }
+        debug!("💾 Flushed {} buffered records", flushed);
+        Ok(flushed)
+    }
 
+    async fn write_batch(&self, table: &str, records: &[BufferedRecord]) -> Result<()> {
+        // TODO: Implement actual database storage as one multi-row insert
+        // let mut query = sqlx::QueryBuilder::new(format!("INSERT INTO {} (key, payload) ", table));
+        // query.push_values(records, |mut row, record| {
+        //     row.push_bind(&record.key).push_bind(&record.payload);
+        // });
+        // query.build().execute(&pool).await?;
+
+        for record in records {
+            debug!(
+                "📊 Stored {} record {}: {}",
+                table, record.key, record.payload
+            );
+        }
         Ok(())
     }
+
+    async fn store_execution_result(&mut self, result: ExecutionResult) -> Result<()> {
+        debug!(
+            "💾 Buffering execution result: {} ({})",
+            result.transaction_id, result.signal_id
+        );
+        let key = result.transaction_id.clone();
+        self.store_record(Some("execution_results"), &key, &result)
+            .await
+    }
 }
 
 #[cfg(test)]
@@ -117,4 +437,117 @@ mod tests {
 
         assert!(!manager.is_running);
     }
+
+    #[test]
+    fn test_messages_route_to_tables() {
+        let signal = TradingSignal {
+            signal_id: "sig".to_string(),
+            symbol: "BONK/USDC".to_string(),
+            action: crate::modules::strategy::TradeAction::Buy,
+            quantity: 1.0,
+            target_price: 0.00002,
+            confidence: 0.8,
+            timestamp: chrono::Utc::now(),
+            strategy_type: StrategyType::TokenSniping,
+            tick_received_at: None,
+            pool_liquidity: None,
+        };
+        let rejection = RiskDecision::rejected(&signal, "daily loss limits exceeded".to_string());
+        assert!(!rejection.approved);
+
+        assert_eq!(
+            PersistenceMessage::RiskDecision(rejection).table(),
+            Some("risk_decisions")
+        );
+        assert_eq!(
+            PersistenceMessage::TradingSignal(signal).table(),
+            Some("trading_signals")
+        );
+        assert_eq!(
+            PersistenceMessage::WalletStateChange(WalletStateChange::new("primary", "added"))
+                .table(),
+            Some("wallet_events")
+        );
+        assert_eq!(PersistenceMessage::HealthCheck.table(), None);
+        assert_eq!(PersistenceMessage::Shutdown.table(), None);
+    }
+
+    #[tokio::test]
+    async fn test_batches_flush_by_size_timer_and_shutdown() {
+        use crate::modules::shutdown_report::ShutdownReport;
+        use std::time::Duration;
+
+        let change =
+            || PersistenceMessage::WalletStateChange(WalletStateChange::new("primary", "added"));
+        let (_tx, rx) = mpsc::unbounded_channel();
+        let (_exec_tx, exec_rx) = mpsc::unbounded_channel();
+        let mut manager = PersistenceManager::new(rx, exec_rx, "postgresql://test".to_string())
+            .with_batching(PersistenceBatchConfig {
+                max_batch_size: 3,
+                flush_interval: Duration::from_secs(60),
+            });
+        manager.handle_message(change()).await.unwrap();
+        manager.handle_message(change()).await.unwrap();
+        assert_eq!(manager.buffered_records(), 2);
+        manager.handle_message(change()).await.unwrap();
+        assert_eq!(manager.buffered_records(), 0);
+
+        // The report forces out what the size threshold has not reached yet
+        manager.handle_message(change()).await.unwrap();
+        let report = ShutdownReport::new("Paper".to_string(), 1);
+        manager
+            .handle_message(PersistenceMessage::ShutdownReport(Box::new(report)))
+            .await
+            .unwrap();
+        assert_eq!(manager.buffered_records(), 0);
+        let flush = manager.final_flush().unwrap();
+        assert!(flush.completed);
+        assert_eq!(flush.records_flushed, 1);
+
+        // A quiet period is flushed by the timer, leaving nothing for shutdown
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (_exec_tx, exec_rx) = mpsc::unbounded_channel();
+        let mut manager = PersistenceManager::new(rx, exec_rx, "postgresql://test".to_string())
+            .with_batching(PersistenceBatchConfig {
+                max_batch_size: 100,
+                flush_interval: Duration::from_millis(20),
+            });
+        let task = tokio::spawn(async move {
+            manager.start().await.unwrap();
+            manager
+        });
+        tx.send(change()).unwrap();
+        tx.send(change()).unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let report = ShutdownReport::new("Paper".to_string(), 1);
+        tx.send(PersistenceMessage::ShutdownReport(Box::new(report)))
+            .unwrap();
+        let manager = task.await.unwrap();
+        assert_eq!(manager.final_flush().unwrap().records_flushed, 0);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_without_report_flushes_and_stops() {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (_exec_tx, exec_rx) = mpsc::unbounded_channel();
+        let mut manager = PersistenceManager::new(rx, exec_rx, "postgresql://test".to_string());
+        let task = tokio::spawn(async move {
+            manager.start().await.unwrap();
+            manager
+        });
+        tx.send(PersistenceMessage::WalletStateChange(WalletStateChange::new(
+            "primary", "added",
+        )))
+        .unwrap();
+        tx.send(PersistenceMessage::Shutdown).unwrap();
+
+        // Other senders are still alive; the manager stops on the message alone
+        let manager = tokio::time::timeout(std::time::Duration::from_secs(1), task)
+            .await
+            .expect("persistence did not stop")
+            .unwrap();
+        let flush = manager.final_flush().unwrap();
+        assert!(flush.completed);
+        assert_eq!(flush.records_flushed, 1);
+    }
 }
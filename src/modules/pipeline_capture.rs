@@ -0,0 +1,328 @@
+// Pipeline Capture Module
+// Records every intermediate artifact of the signal→execution pipeline for targeted symbols
+// or signal IDs into one correlated trace, for forensics on a specific bad trade. Heavier than
+// normal logging, so only targets named in the configuration are captured.
+
+use crate::modules::data_ingestor::MarketData;
+use crate::modules::executor::ExecutionResult;
+use crate::modules::persistence::{PersistenceMessage, RiskDecision};
+use crate::modules::risk::RiskParameters;
+use crate::modules::strategy::TradingSignal;
+use crate::modules::wallet_manager::WalletSelectionTrace;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex};
+use tokio::sync::mpsc;
+use tracing::{debug, info};
+
+#[derive(Debug, Clone)]
+pub struct PipelineCaptureConfig {
+    /// Symbols whose signals are captured
+    pub symbols: HashSet<String>,
+    /// Signal IDs captured regardless of symbol
+    pub signal_ids: HashSet<String>,
+    /// Traces kept in memory for the debug endpoint; the oldest is evicted first
+    pub max_traces: usize,
+    /// Artifacts kept per trace; later ones are counted as dropped
+    pub max_artifacts_per_trace: usize,
+}
+
+impl Default for PipelineCaptureConfig {
+    fn default() -> Self {
+        Self {
+            symbols: HashSet::new(),
+            signal_ids: HashSet::new(),
+            max_traces: 100,
+            max_artifacts_per_trace: 64,
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl PipelineCaptureConfig {
+    /// Capture targets from comma-separated symbol and signal ID lists
+    pub fn with_targets(mut self, symbols: &str, signal_ids: &str) -> Self {
+        let parse = |list: &str| {
+            list.split(',')
+                .map(str::trim)
+                .filter(|t| !t.is_empty())
+                .map(str::to_string)
+                .collect::<Vec<_>>()
+        };
+        self.symbols.extend(parse(symbols));
+        self.signal_ids.extend(parse(signal_ids));
+        self
+    }
+
+    pub fn has_targets(&self) -> bool {
+        !self.symbols.is_empty() || !self.signal_ids.is_empty()
+    }
+}
+
+/// One intermediate artifact of a captured signal's pipeline
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "stage", rename_all = "snake_case")]
+pub enum CaptureArtifact {
+    /// Latest tick for the symbol when the signal was generated
+    MarketData(MarketData),
+    Signal(TradingSignal),
+    /// Risk verdict with the limits it was judged against
+    RiskDecision {
+        decision: RiskDecision,
+        risk_params: RiskParameters,
+        effective_max_position_size: f64,
+        daily_pnl: f64,
+    },
+    WalletSelection(WalletSelectionTrace),
+    /// Market context handed to the AI for a decision
+    AiRequest { market_data: serde_json::Value },
+    AiResponse {
+        outcome: String,
+        latency_ms: u64,
+        ai_confidence: Option<f64>,
+        estimated_profit: Option<f64>,
+        inference_id: Option<uuid::Uuid>,
+        detail: Option<String>,
+    },
+    BundleSubmission {
+        bundle_id: String,
+        tip_lamports: u64,
+        priority_fee_lamports: u64,
+    },
+    /// Commitment verdict of a live submission
+    Confirmation {
+        transaction_id: String,
+        outcome: String,
+    },
+    Execution(ExecutionResult),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CapturedArtifact {
+    pub recorded_at: DateTime<Utc>,
+    pub artifact: CaptureArtifact,
+}
+
+/// Everything captured for one signal, from its market tick to its final execution result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineTrace {
+    pub signal_id: String,
+    pub symbol: String,
+    pub started_at: DateTime<Utc>,
+    /// Set once the signal is rejected or its execution is final
+    pub completed_at: Option<DateTime<Utc>>,
+    pub artifacts: Vec<CapturedArtifact>,
+    /// Artifacts beyond `max_artifacts_per_trace`
+    pub artifacts_dropped: usize,
+}
+
+#[derive(Debug, Default)]
+struct CaptureState {
+    latest_ticks: HashMap<String, MarketData>,
+    traces: HashMap<String, PipelineTrace>,
+    order: VecDeque<String>,
+}
+
+/// Captured traces, shared by every pipeline stage and the debug endpoint
+#[derive(Debug)]
+pub struct PipelineCapture {
+    config: PipelineCaptureConfig,
+    state: Mutex<CaptureState>,
+    persistence_sender: Option<mpsc::UnboundedSender<PersistenceMessage>>,
+}
+
+pub type SharedPipelineCapture = Arc<PipelineCapture>;
+
+#[allow(dead_code)]
+impl PipelineCapture {
+    pub fn new(config: PipelineCaptureConfig) -> Self {
+        Self {
+            config,
+            state: Mutex::new(CaptureState::default()),
+            persistence_sender: None,
+        }
+    }
+
+    /// Persist each trace as one record once it completes
+    pub fn with_persistence(mut self, sender: mpsc::UnboundedSender<PersistenceMessage>) -> Self {
+        self.persistence_sender = Some(sender);
+        self
+    }
+
+    pub fn config(&self) -> &PipelineCaptureConfig {
+        &self.config
+    }
+
+    fn is_target(&self, signal: &TradingSignal) -> bool {
+        self.config.symbols.contains(&signal.symbol)
+            || self.config.signal_ids.contains(&signal.signal_id)
+    }
+
+    /// Keep the latest tick of targeted symbols, to attach to their next signal
+    pub fn record_market_data(&self, data: &MarketData) {
+        if !self.config.symbols.contains(&data.symbol) {
+            return;
+        }
+        if let Ok(mut state) = self.state.lock() {
+            state.latest_ticks.insert(data.symbol.clone(), data.clone());
+        }
+    }
+
+    /// Start a trace for a targeted signal; later artifacts are only kept for started traces
+    pub fn record_signal(&self, signal: &TradingSignal) {
+        if !self.is_target(signal) {
+            return;
+        }
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if state.traces.contains_key(&signal.signal_id) {
+            return;
+        }
+        while state.order.len() >= self.config.max_traces.max(1) {
+            if let Some(evicted) = state.order.pop_front() {
+                state.traces.remove(&evicted);
+            }
+        }
+
+        let now = Utc::now();
+        let mut artifacts = Vec::new();
+        if let Some(tick) = state.latest_ticks.get(&signal.symbol) {
+            artifacts.push(CapturedArtifact {
+                recorded_at: tick.received_at,
+                artifact: CaptureArtifact::MarketData(tick.clone()),
+            });
+        }
+        artifacts.push(CapturedArtifact {
+            recorded_at: now,
+            artifact: CaptureArtifact::Signal(signal.clone()),
+        });
+        info!("🔬 Capturing pipeline of signal {} ({})", signal.signal_id, signal.symbol);
+        state.traces.insert(
+            signal.signal_id.clone(),
+            PipelineTrace {
+                signal_id: signal.signal_id.clone(),
+                symbol: signal.symbol.clone(),
+                started_at: now,
+                completed_at: None,
+                artifacts,
+                artifacts_dropped: 0,
+            },
+        );
+        state.order.push_back(signal.signal_id.clone());
+    }
+
+    /// Append an artifact to the signal's trace, if it is being captured
+    pub fn record(&self, signal_id: &str, artifact: CaptureArtifact) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let Some(trace) = state.traces.get_mut(signal_id) else {
+            return;
+        };
+        if trace.artifacts.len() >= self.config.max_artifacts_per_trace {
+            trace.artifacts_dropped += 1;
+            return;
+        }
+        trace.artifacts.push(CapturedArtifact {
+            recorded_at: Utc::now(),
+            artifact,
+        });
+    }
+
+    /// Mark the trace final and persist it as one correlated record
+    pub fn complete(&self, signal_id: &str) {
+        let trace = {
+            let Ok(mut state) = self.state.lock() else {
+                return;
+            };
+            let Some(trace) = state.traces.get_mut(signal_id) else {
+                return;
+            };
+            trace.completed_at = Some(Utc::now());
+            trace.clone()
+        };
+        if let Some(sender) = &self.persistence_sender {
+            if let Err(e) = sender.send(PersistenceMessage::PipelineTrace(Box::new(trace))) {
+                debug!("Pipeline trace not persisted: {}", e);
+            }
+        }
+    }
+
+    pub fn trace(&self, signal_id: &str) -> Option<PipelineTrace> {
+        self.state.lock().ok()?.traces.get(signal_id).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::data_ingestor::DataSource;
+    use crate::modules::strategy::{StrategyType, TradeAction};
+
+    fn signal(signal_id: &str, symbol: &str) -> TradingSignal {
+        TradingSignal {
+            signal_id: signal_id.to_string(),
+            symbol: symbol.to_string(),
+            action: TradeAction::Buy,
+            quantity: 1.0,
+            target_price: 0.5,
+            confidence: 0.9,
+            timestamp: Utc::now(),
+            strategy_type: StrategyType::TokenSniping,
+            tick_received_at: None,
+            pool_liquidity: None,
+        }
+    }
+
+    #[test]
+    fn test_captures_targeted_signals_into_one_persisted_trace() {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let config = PipelineCaptureConfig {
+            max_traces: 1,
+            ..PipelineCaptureConfig::default()
+        }
+        .with_targets("BONK/SOL", "sig-x");
+        let capture = PipelineCapture::new(config).with_persistence(tx);
+
+        let now = Utc::now();
+        capture.record_market_data(&MarketData {
+            symbol: "BONK/SOL".to_string(),
+            price: 0.5,
+            volume: 10.0,
+            timestamp: now,
+            source: DataSource::Helius,
+            received_at: now,
+        });
+        capture.record_signal(&signal("sig-1", "BONK/SOL"));
+        capture.record_signal(&signal("sig-2", "JUP/SOL"));
+        capture.record(
+            "sig-1",
+            CaptureArtifact::Confirmation {
+                transaction_id: "tx".to_string(),
+                outcome: "Confirmed".to_string(),
+            },
+        );
+        // Untargeted signals are never traced
+        capture.record("sig-2", CaptureArtifact::AiRequest { market_data: serde_json::Value::Null });
+        assert!(capture.trace("sig-2").is_none());
+
+        capture.complete("sig-1");
+        let PersistenceMessage::PipelineTrace(trace) = rx.try_recv().unwrap() else {
+            panic!("expected a pipeline trace");
+        };
+        assert!(trace.completed_at.is_some());
+        let stages: Vec<_> = trace
+            .artifacts
+            .iter()
+            .map(|a| serde_json::to_value(&a.artifact).unwrap()["stage"].clone())
+            .collect();
+        assert_eq!(stages, vec!["market_data", "signal", "confirmation"]);
+
+        // A targeted signal ID is captured on any symbol, evicting the oldest trace
+        capture.record_signal(&signal("sig-x", "JUP/SOL"));
+        assert!(capture.trace("sig-1").is_none());
+        assert_eq!(capture.trace("sig-x").unwrap().artifacts.len(), 1);
+    }
+}
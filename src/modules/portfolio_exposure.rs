@@ -0,0 +1,183 @@
+// Portfolio Exposure Module
+// Caps the total SOL deployed in open positions across all wallets, as a final safety net
+// independent of per-wallet and per-strategy limits
+
+use crate::modules::market_prices::SharedMarketPrices;
+use crate::modules::wallet_manager::WalletManager;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+pub const PORTFOLIO_EXPOSURE_CAP_REACHED: &str = "portfolio exposure cap reached";
+
+/// SOL deployed in open positions against the portfolio cap
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PortfolioExposure {
+    pub deployed_sol: f64,
+    /// Notional of approved buys that have not settled yet
+    pub reserved_sol: f64,
+    pub max_deployed_sol: f64,
+    /// SOL new entries may still deploy before the cap is reached
+    pub headroom_sol: f64,
+}
+
+/// Global cap on open-position notional, read from the wallet manager's tracked positions
+#[derive(Clone)]
+pub struct PortfolioExposureCap {
+    max_deployed_sol: f64,
+    wallet_manager: Arc<WalletManager>,
+    // Converts notionals of non-SOL-quoted symbols to SOL
+    market_prices: Option<SharedMarketPrices>,
+    // Approved buy notional by signal ID, held until the executor settles the signal
+    reserved: Arc<Mutex<HashMap<String, f64>>>,
+}
+
+impl std::fmt::Debug for PortfolioExposureCap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PortfolioExposureCap")
+            .field("max_deployed_sol", &self.max_deployed_sol)
+            .finish_non_exhaustive()
+    }
+}
+
+#[allow(dead_code)]
+impl PortfolioExposureCap {
+    pub fn new(max_deployed_sol: f64, wallet_manager: Arc<WalletManager>) -> Self {
+        Self {
+            max_deployed_sol,
+            wallet_manager,
+            market_prices: None,
+            reserved: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    pub fn with_market_prices(mut self, market_prices: SharedMarketPrices) -> Self {
+        self.market_prices = Some(market_prices);
+        self
+    }
+
+    /// `notional` in `symbol`'s quote currency as SOL; left unconverted while the quote's
+    /// SOL price is unknown, which overstates stablecoin notionals rather than understating them
+    pub fn notional_sol(&self, symbol: &str, notional: f64) -> f64 {
+        self.market_prices
+            .as_ref()
+            .and_then(|prices| prices.quote_to_sol(symbol, notional))
+            .unwrap_or(notional)
+    }
+
+    pub fn max_deployed_sol(&self) -> f64 {
+        self.max_deployed_sol
+    }
+
+    /// Hold an approved buy's notional against the cap until `release`
+    pub fn reserve(&self, signal_id: &str, notional: f64) {
+        if let Ok(mut reserved) = self.reserved.lock() {
+            reserved.insert(signal_id.to_string(), notional);
+        }
+    }
+
+    /// Drop the signal's reservation once it has settled or will never execute
+    pub fn release(&self, signal_id: &str) {
+        if let Ok(mut reserved) = self.reserved.lock() {
+            reserved.remove(signal_id);
+        }
+    }
+
+    pub fn reserved_sol(&self) -> f64 {
+        self.reserved
+            .lock()
+            .map(|reserved| reserved.values().sum())
+            .unwrap_or(0.0)
+    }
+
+    /// Current deployed and reserved totals and remaining headroom
+    pub async fn exposure(&self) -> PortfolioExposure {
+        let deployed_sol = self
+            .wallet_manager
+            .total_open_notional(|symbol, notional| self.notional_sol(symbol, notional))
+            .await;
+        let reserved_sol = self.reserved_sol();
+        PortfolioExposure {
+            deployed_sol,
+            reserved_sol,
+            max_deployed_sol: self.max_deployed_sol,
+            headroom_sol: (self.max_deployed_sol - deployed_sol - reserved_sol).max(0.0),
+        }
+    }
+
+    /// Returns the current exposure when opening `notional` more would exceed the cap
+    pub async fn check(&self, notional: f64) -> Option<PortfolioExposure> {
+        let exposure = self.exposure().await;
+        (exposure.deployed_sol + exposure.reserved_sol + notional > self.max_deployed_sol).then_some(exposure)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::lifecycle::PositionState;
+    use crate::modules::strategy::StrategyType;
+    use crate::modules::wallet_manager::Position;
+
+    #[tokio::test]
+    async fn test_cap_sums_open_positions_across_wallets() {
+        let wallet_manager = Arc::new(WalletManager::new());
+        for (wallet_id, quantity, closed) in [("a", 3.0, false), ("b", 4.0, false), ("b", 10.0, true)] {
+            let mut position = Position::opening(
+                format!("{}-{}", wallet_id, quantity),
+                wallet_id.to_string(),
+                "BONK/SOL".to_string(),
+                StrategyType::TokenSniping,
+                quantity,
+                1.0,
+            );
+            position.transition(PositionState::Open).unwrap();
+            if closed {
+                position.close().unwrap();
+            }
+            wallet_manager.record_open_position(position).await;
+        }
+
+        let cap = PortfolioExposureCap::new(10.0, wallet_manager);
+        let exposure = cap.exposure().await;
+        assert_eq!(exposure.deployed_sol, 7.0);
+        assert_eq!(exposure.headroom_sol, 3.0);
+
+        assert!(cap.check(3.0).await.is_none());
+        let rejected = cap.check(3.5).await.unwrap();
+        assert_eq!(rejected.deployed_sol, 7.0);
+
+        // Approved buys count against the cap until they settle
+        cap.reserve("sig-1", 2.0);
+        assert_eq!(cap.exposure().await.headroom_sol, 1.0);
+        assert!(cap.check(1.5).await.is_some());
+        cap.release("sig-1");
+        assert!(cap.check(1.5).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_cap_converts_stablecoin_notional_to_sol() {
+        let wallet_manager = Arc::new(WalletManager::new());
+        let mut position = Position::opening(
+            "usdc-1".to_string(),
+            "a".to_string(),
+            "JUP/USDC".to_string(),
+            StrategyType::TokenSniping,
+            300.0,
+            1.0,
+        );
+        position.transition(PositionState::Open).unwrap();
+        wallet_manager.record_open_position(position).await;
+
+        let market_prices = crate::modules::market_prices::MarketPriceCache::shared(chrono::Duration::seconds(30));
+        let cap = PortfolioExposureCap::new(10.0, wallet_manager).with_market_prices(market_prices.clone());
+        // Without a SOL/USDC price the notional counts unconverted
+        assert_eq!(cap.exposure().await.deployed_sol, 300.0);
+
+        market_prices.record("SOL/USDC", 150.0, chrono::Utc::now());
+        assert_eq!(cap.exposure().await.deployed_sol, 2.0);
+        assert_eq!(cap.notional_sol("BONK/SOL", 4.0), 4.0);
+        assert_eq!(cap.notional_sol("JUP/USDC", 1500.0), 10.0);
+        assert!(cap.check(cap.notional_sol("JUP/USDC", 1350.0)).await.is_some());
+    }
+}
@@ -0,0 +1,761 @@
+// Position Reconciliation Module
+// Compares persisted positions against on-chain wallet balances at startup and optionally rebuilds them
+
+use crate::modules::lifecycle::PositionState;
+use crate::modules::strategy::{StrategyType, TradeAction};
+use crate::modules::symbol_resolver::TokenPair;
+use crate::modules::wallet_manager::{Position, WalletManager};
+use anyhow::{Context, Result};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_request::RpcRequest;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+const TOKEN_PROGRAM_ID: &str = "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA";
+const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+const WSOL_MINT: &str = "So11111111111111111111111111111111111111112";
+
+#[derive(Debug, Clone)]
+pub struct ReconciliationConfig {
+    /// Last persisted positions and SOL balances
+    pub snapshot_path: PathBuf,
+    /// JSON-lines trade log used to attribute untracked holdings when rebuilding
+    pub trade_log_path: Option<PathBuf>,
+    /// Replace the tracked positions with the reconciled view instead of only reporting
+    pub rebuild_positions: bool,
+    /// Relative token quantity difference tolerated before flagging (0.01 = 1%)
+    pub quantity_tolerance: f64,
+    /// SOL balance drift tolerated before flagging; covers fees and rent
+    pub sol_tolerance: f64,
+    /// Token balances at or below this are treated as empty
+    pub dust_threshold: f64,
+}
+
+impl Default for ReconciliationConfig {
+    fn default() -> Self {
+        Self {
+            snapshot_path: PathBuf::from("positions.json"),
+            trade_log_path: None,
+            rebuild_positions: false,
+            quantity_tolerance: 0.01,
+            sol_tolerance: 0.05,
+            dust_threshold: 0.0,
+        }
+    }
+}
+
+/// SOL and SPL token balances of a wallet, token balances keyed by mint
+#[derive(Debug, Clone, Default)]
+pub struct OnChainHoldings {
+    pub sol_balance: f64,
+    pub token_balances: HashMap<Pubkey, f64>,
+}
+
+/// Source of on-chain wallet balances (`getBalance` + `getTokenAccountsByOwner`)
+pub trait HoldingsSource: Send + Sync {
+    fn holdings<'a>(&'a self, owner: &'a Pubkey) -> BoxFuture<'a, Result<OnChainHoldings>>;
+}
+
+impl HoldingsSource for RpcClient {
+    fn holdings<'a>(&'a self, owner: &'a Pubkey) -> BoxFuture<'a, Result<OnChainHoldings>> {
+        Box::pin(async move {
+            let lamports = self.get_balance(owner).await.context("getBalance failed")?;
+            let mut holdings = OnChainHoldings {
+                sol_balance: lamports as f64 / LAMPORTS_PER_SOL as f64,
+                token_balances: HashMap::new(),
+            };
+
+            for program_id in [TOKEN_PROGRAM_ID, TOKEN_2022_PROGRAM_ID] {
+                let response: serde_json::Value = self
+                    .send(
+                        RpcRequest::GetTokenAccountsByOwner,
+                        serde_json::json!([
+                            owner.to_string(),
+                            { "programId": program_id },
+                            { "encoding": "jsonParsed" }
+                        ]),
+                    )
+                    .await
+                    .context("getTokenAccountsByOwner failed")?;
+
+                for account in response["value"].as_array().into_iter().flatten() {
+                    let info = &account["account"]["data"]["parsed"]["info"];
+                    let mint = info["mint"].as_str().and_then(|m| Pubkey::from_str(m).ok());
+                    let amount = info["tokenAmount"]["uiAmountString"]
+                        .as_str()
+                        .and_then(|a| a.parse::<f64>().ok());
+                    if let (Some(mint), Some(amount)) = (mint, amount) {
+                        *holdings.token_balances.entry(mint).or_insert(0.0) += amount;
+                    }
+                }
+            }
+            Ok(holdings)
+        })
+    }
+}
+
+/// Positions and SOL balances as last persisted by the bot
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionSnapshot {
+    pub saved_at: chrono::DateTime<chrono::Utc>,
+    /// Keyed by wallet ID
+    #[serde(default)]
+    pub sol_balances: HashMap<String, f64>,
+    pub positions: Vec<Position>,
+}
+
+impl PositionSnapshot {
+    pub fn new(positions: Vec<Position>, sol_balances: HashMap<String, f64>) -> Self {
+        Self {
+            saved_at: chrono::Utc::now(),
+            sol_balances,
+            positions,
+        }
+    }
+
+    /// None when no snapshot has been written yet
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read position snapshot from {:?}", path))?;
+        let snapshot = serde_json::from_str(&json).context("Invalid position snapshot file")?;
+        Ok(Some(snapshot))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write position snapshot to {:?}", path))
+    }
+}
+
+/// One executed trade as recorded in the trade log
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TradeLogEntry {
+    pub wallet_id: String,
+    pub symbol: String,
+    /// Base mint of the traded token
+    pub mint: String,
+    pub strategy_type: StrategyType,
+    pub action: TradeAction,
+    pub quantity: f64,
+    pub price: f64,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+pub fn append_trade_log(path: &Path, entry: &TradeLogEntry) -> Result<()> {
+    let mut file = std::fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Failed to open trade log {:?}", path))?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)
+        .with_context(|| format!("Failed to append to trade log {:?}", path))
+}
+
+/// Read the trade log, skipping unparseable lines; a missing log is empty
+pub fn load_trade_log(path: &Path) -> Result<Vec<TradeLogEntry>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let contents = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read trade log {:?}", path))?;
+
+    let mut entries = Vec::new();
+    for (line_number, line) in contents.lines().enumerate() {
+        if line.trim().is_empty() {
+            continue;
+        }
+        match serde_json::from_str(line) {
+            Ok(entry) => entries.push(entry),
+            Err(e) => warn!(
+                "Skipping trade log line {} in {:?}: {}",
+                line_number + 1,
+                path,
+                e
+            ),
+        }
+    }
+    Ok(entries)
+}
+
+/// Snapshot and trade log kept up to date as fills confirm, for the next startup's reconciliation
+#[derive(Debug, Clone)]
+pub struct PositionJournal {
+    pub snapshot_path: PathBuf,
+    pub trade_log_path: Option<PathBuf>,
+}
+
+impl PositionJournal {
+    /// Append a confirmed fill to the trade log, if one is configured
+    pub fn record_fill(&self, entry: &TradeLogEntry) -> Result<()> {
+        match &self.trade_log_path {
+            Some(path) => append_trade_log(path, entry),
+            None => Ok(()),
+        }
+    }
+
+    /// Save the manager's tracked positions and last known SOL balances
+    pub async fn save_snapshot(&self, wallet_manager: &WalletManager) -> Result<()> {
+        let mut sol_balances = HashMap::new();
+        for wallet in wallet_manager.get_active_wallets().await? {
+            if let Ok(metrics) = wallet_manager.get_wallet_metrics(&wallet.wallet_id).await {
+                sol_balances.insert(wallet.wallet_id, metrics.sol_balance);
+            }
+        }
+        PositionSnapshot::new(wallet_manager.get_all_positions().await, sol_balances)
+            .save(&self.snapshot_path)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Discrepancy {
+    SolBalance {
+        wallet_id: String,
+        recorded: f64,
+        on_chain: f64,
+    },
+    /// A tracked position whose token the wallet no longer holds
+    MissingOnChain {
+        wallet_id: String,
+        symbol: String,
+        recorded_quantity: f64,
+    },
+    /// A token the wallet holds without a tracked position
+    UntrackedHolding {
+        wallet_id: String,
+        mint: Pubkey,
+        on_chain_quantity: f64,
+    },
+    QuantityMismatch {
+        wallet_id: String,
+        symbol: String,
+        recorded: f64,
+        on_chain: f64,
+    },
+    /// A tracked position whose mint could not be determined, so it was not checked
+    UnresolvedMint { wallet_id: String, symbol: String },
+}
+
+impl fmt::Display for Discrepancy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Discrepancy::SolBalance {
+                wallet_id,
+                recorded,
+                on_chain,
+            } => write!(
+                f,
+                "wallet {}: SOL balance recorded {:.4}, on-chain {:.4}",
+                wallet_id, recorded, on_chain
+            ),
+            Discrepancy::MissingOnChain {
+                wallet_id,
+                symbol,
+                recorded_quantity,
+            } => write!(
+                f,
+                "wallet {}: position {} ({}) not held on-chain",
+                wallet_id, symbol, recorded_quantity
+            ),
+            Discrepancy::UntrackedHolding {
+                wallet_id,
+                mint,
+                on_chain_quantity,
+            } => write!(
+                f,
+                "wallet {}: holds {} of {} without a tracked position",
+                wallet_id, on_chain_quantity, mint
+            ),
+            Discrepancy::QuantityMismatch {
+                wallet_id,
+                symbol,
+                recorded,
+                on_chain,
+            } => write!(
+                f,
+                "wallet {}: position {} recorded {}, on-chain {}",
+                wallet_id, symbol, recorded, on_chain
+            ),
+            Discrepancy::UnresolvedMint { wallet_id, symbol } => write!(
+                f,
+                "wallet {}: position {} has no resolvable mint",
+                wallet_id, symbol
+            ),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ReconciliationReport {
+    pub wallets_checked: usize,
+    /// Wallets whose balances could not be fetched, with the error
+    pub failed_wallets: Vec<(String, String)>,
+    pub discrepancies: Vec<Discrepancy>,
+    /// SOL balances read on-chain, keyed by wallet ID
+    pub sol_balances: HashMap<String, f64>,
+    /// Reconciled positions of the checked wallets; set when rebuilding is enabled
+    pub rebuilt_positions: Option<Vec<Position>>,
+}
+
+impl ReconciliationReport {
+    pub fn is_consistent(&self) -> bool {
+        self.discrepancies.is_empty() && self.failed_wallets.is_empty()
+    }
+
+    pub fn log_summary(&self) {
+        if self.is_consistent() {
+            info!(
+                "🧮 Reconciliation: {} wallets match persisted positions",
+                self.wallets_checked
+            );
+            return;
+        }
+        warn!(
+            "🧮 Reconciliation: {} discrepancies across {} wallets",
+            self.discrepancies.len(),
+            self.wallets_checked
+        );
+        for discrepancy in &self.discrepancies {
+            warn!("🧮   {}", discrepancy);
+        }
+        for (wallet_id, error) in &self.failed_wallets {
+            warn!(
+                "🧮   wallet {}: balances unavailable ({})",
+                wallet_id, error
+            );
+        }
+    }
+}
+
+pub struct PositionReconciler {
+    config: ReconciliationConfig,
+    source: Arc<dyn HoldingsSource>,
+}
+
+#[allow(dead_code)]
+impl PositionReconciler {
+    pub fn new(config: ReconciliationConfig, source: Arc<dyn HoldingsSource>) -> Self {
+        Self { config, source }
+    }
+
+    /// Compare each wallet's on-chain holdings with the snapshot's positions
+    pub async fn reconcile(
+        &self,
+        wallets: &[(String, Pubkey)],
+        snapshot: &PositionSnapshot,
+        trade_log: &[TradeLogEntry],
+    ) -> ReconciliationReport {
+        let mut report = ReconciliationReport::default();
+        let mut rebuilt = Vec::new();
+
+        for (wallet_id, owner) in wallets {
+            let holdings = match self.source.holdings(owner).await {
+                Ok(holdings) => holdings,
+                Err(e) => {
+                    report
+                        .failed_wallets
+                        .push((wallet_id.clone(), e.to_string()));
+                    // Keep what we knew rather than dropping positions we could not check
+                    rebuilt.extend(
+                        snapshot
+                            .positions
+                            .iter()
+                            .filter(|p| p.wallet_id == *wallet_id)
+                            .cloned(),
+                    );
+                    continue;
+                }
+            };
+            report.wallets_checked += 1;
+            report
+                .sol_balances
+                .insert(wallet_id.clone(), holdings.sol_balance);
+            self.reconcile_wallet(
+                wallet_id,
+                &holdings,
+                snapshot,
+                trade_log,
+                &mut report.discrepancies,
+                &mut rebuilt,
+            );
+        }
+
+        if self.config.rebuild_positions {
+            report.rebuilt_positions = Some(rebuilt);
+        }
+        report
+    }
+
+    fn reconcile_wallet(
+        &self,
+        wallet_id: &str,
+        holdings: &OnChainHoldings,
+        snapshot: &PositionSnapshot,
+        trade_log: &[TradeLogEntry],
+        discrepancies: &mut Vec<Discrepancy>,
+        rebuilt: &mut Vec<Position>,
+    ) {
+        if let Some(recorded) = snapshot.sol_balances.get(wallet_id) {
+            if (recorded - holdings.sol_balance).abs() > self.config.sol_tolerance {
+                discrepancies.push(Discrepancy::SolBalance {
+                    wallet_id: wallet_id.to_string(),
+                    recorded: *recorded,
+                    on_chain: holdings.sol_balance,
+                });
+            }
+        }
+
+        let wsol = Pubkey::from_str(WSOL_MINT).ok();
+        let mut seen_mints = HashSet::new();
+        for position in snapshot
+            .positions
+            .iter()
+            .filter(|p| p.wallet_id == wallet_id && p.state() != PositionState::Closed)
+        {
+            let Some(mint) = position_mint(position) else {
+                discrepancies.push(Discrepancy::UnresolvedMint {
+                    wallet_id: wallet_id.to_string(),
+                    symbol: position.symbol.clone(),
+                });
+                rebuilt.push(position.clone());
+                continue;
+            };
+            seen_mints.insert(mint);
+
+            let on_chain = self.held_quantity(holdings, &mint);
+            if on_chain <= 0.0 {
+                discrepancies.push(Discrepancy::MissingOnChain {
+                    wallet_id: wallet_id.to_string(),
+                    symbol: position.symbol.clone(),
+                    recorded_quantity: position.quantity,
+                });
+                continue;
+            }
+
+            let drift =
+                (on_chain - position.quantity).abs() / position.quantity.abs().max(f64::EPSILON);
+            if drift > self.config.quantity_tolerance {
+                discrepancies.push(Discrepancy::QuantityMismatch {
+                    wallet_id: wallet_id.to_string(),
+                    symbol: position.symbol.clone(),
+                    recorded: position.quantity,
+                    on_chain,
+                });
+            }
+            let mut position = position.clone();
+            position.quantity = on_chain;
+            position.updated_at = chrono::Utc::now();
+            rebuilt.push(position);
+        }
+
+        let mut untracked: Vec<_> = holdings
+            .token_balances
+            .keys()
+            .filter(|mint| !seen_mints.contains(*mint) && Some(**mint) != wsol)
+            .copied()
+            .collect();
+        untracked.sort();
+        for mint in untracked {
+            let on_chain = self.held_quantity(holdings, &mint);
+            if on_chain <= 0.0 {
+                continue;
+            }
+            discrepancies.push(Discrepancy::UntrackedHolding {
+                wallet_id: wallet_id.to_string(),
+                mint,
+                on_chain_quantity: on_chain,
+            });
+            match position_from_trade_log(wallet_id, &mint, on_chain, trade_log) {
+                Some(position) => rebuilt.push(position),
+                None => warn!(
+                    "🧮 wallet {}: no trade history for {}, leaving it untracked",
+                    wallet_id, mint
+                ),
+            }
+        }
+    }
+
+    fn held_quantity(&self, holdings: &OnChainHoldings, mint: &Pubkey) -> f64 {
+        holdings
+            .token_balances
+            .get(mint)
+            .copied()
+            .filter(|q| *q > self.config.dust_threshold)
+            .unwrap_or(0.0)
+    }
+
+    /// Reconcile the manager's active wallets against the persisted snapshot and,
+    /// when rebuilding, replace their tracked positions and the snapshot with the reconciled view
+    pub async fn reconcile_wallet_manager(
+        &self,
+        wallet_manager: &WalletManager,
+    ) -> Result<ReconciliationReport> {
+        let snapshot = PositionSnapshot::load(&self.config.snapshot_path)?
+            .unwrap_or_else(|| PositionSnapshot::new(Vec::new(), HashMap::new()));
+        let trade_log = match &self.config.trade_log_path {
+            Some(path) => load_trade_log(path)?,
+            None => Vec::new(),
+        };
+
+        let mut wallets = Vec::new();
+        for wallet in wallet_manager.get_active_wallets().await? {
+            match Pubkey::from_str(&wallet.public_key) {
+                Ok(pubkey) => wallets.push((wallet.wallet_id, pubkey)),
+                Err(e) => warn!(
+                    "Skipping wallet {} in reconciliation: {}",
+                    wallet.wallet_id, e
+                ),
+            }
+        }
+        wallets.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let report = self.reconcile(&wallets, &snapshot, &trade_log).await;
+        if let Some(positions) = &report.rebuilt_positions {
+            for (wallet_id, _) in &wallets {
+                let wallet_positions = positions
+                    .iter()
+                    .filter(|p| p.wallet_id == *wallet_id)
+                    .cloned()
+                    .collect();
+                wallet_manager
+                    .replace_positions(wallet_id, wallet_positions)
+                    .await;
+            }
+            PositionSnapshot::new(positions.clone(), report.sol_balances.clone())
+                .save(&self.config.snapshot_path)?;
+            info!(
+                "🧮 Rebuilt {} positions from on-chain state",
+                positions.len()
+            );
+        }
+        Ok(report)
+    }
+}
+
+fn position_mint(position: &Position) -> Option<Pubkey> {
+    position
+        .token_pair
+        .map(|pair| pair.base_mint)
+        .or_else(|| Pubkey::from_str(&position.symbol).ok())
+}
+
+/// Open position for an untracked holding, priced at the average of its logged buys
+fn position_from_trade_log(
+    wallet_id: &str,
+    mint: &Pubkey,
+    quantity: f64,
+    trade_log: &[TradeLogEntry],
+) -> Option<Position> {
+    let mint_str = mint.to_string();
+    let buys: Vec<_> = trade_log
+        .iter()
+        .filter(|e| {
+            e.wallet_id == wallet_id && e.mint == mint_str && matches!(e.action, TradeAction::Buy)
+        })
+        .collect();
+    let latest = buys.iter().max_by_key(|e| e.timestamp)?;
+    let opened_at = buys.iter().map(|e| e.timestamp).min()?;
+
+    let bought: f64 = buys.iter().map(|e| e.quantity).sum();
+    let entry_price = if bought > 0.0 {
+        buys.iter().map(|e| e.quantity * e.price).sum::<f64>() / bought
+    } else {
+        latest.price
+    };
+
+    let mut position = Position::opening(
+        uuid::Uuid::new_v4().to_string(),
+        wallet_id.to_string(),
+        latest.symbol.clone(),
+        latest.strategy_type.clone(),
+        quantity,
+        entry_price,
+    );
+    position.token_pair = Pubkey::from_str(WSOL_MINT)
+        .ok()
+        .map(|quote_mint| TokenPair {
+            base_mint: *mint,
+            quote_mint,
+        });
+    position.opened_at = opened_at;
+    position.transition(PositionState::Open).ok()?;
+    Some(position)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedHoldings(HashMap<Pubkey, OnChainHoldings>);
+
+    impl HoldingsSource for FixedHoldings {
+        fn holdings<'a>(&'a self, owner: &'a Pubkey) -> BoxFuture<'a, Result<OnChainHoldings>> {
+            let holdings = self
+                .0
+                .get(owner)
+                .cloned()
+                .ok_or_else(|| anyhow::anyhow!("RPC unavailable"));
+            Box::pin(async move { holdings })
+        }
+    }
+
+    fn position(wallet_id: &str, mint: Pubkey, quantity: f64) -> Position {
+        let mut position = Position::opening(
+            uuid::Uuid::new_v4().to_string(),
+            wallet_id.to_string(),
+            mint.to_string(),
+            StrategyType::TokenSniping,
+            quantity,
+            1.0,
+        );
+        position.transition(PositionState::Open).unwrap();
+        position
+    }
+
+    #[tokio::test]
+    async fn test_discrepancies_and_rebuild() {
+        let (owner, offline) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (held, sold, resized, airdropped, bought) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        let holdings = OnChainHoldings {
+            sol_balance: 2.0,
+            token_balances: HashMap::from([
+                (held, 100.0),
+                (resized, 40.0),
+                (airdropped, 5.0),
+                (bought, 30.0),
+            ]),
+        };
+        let source = FixedHoldings(HashMap::from([(owner, holdings)]));
+
+        let snapshot = PositionSnapshot::new(
+            vec![
+                position("main", held, 100.0),
+                position("main", sold, 10.0),
+                position("main", resized, 50.0),
+                position("backup", held, 7.0),
+            ],
+            HashMap::from([("main".to_string(), 3.0)]),
+        );
+        let trade_log = vec![
+            TradeLogEntry {
+                wallet_id: "main".to_string(),
+                symbol: "BONK".to_string(),
+                mint: bought.to_string(),
+                strategy_type: StrategyType::AxiomMemeCoin,
+                action: TradeAction::Buy,
+                quantity: 10.0,
+                price: 1.0,
+                timestamp: chrono::Utc::now() - chrono::Duration::minutes(5),
+            },
+            TradeLogEntry {
+                wallet_id: "main".to_string(),
+                symbol: "BONK".to_string(),
+                mint: bought.to_string(),
+                strategy_type: StrategyType::AxiomMemeCoin,
+                action: TradeAction::Buy,
+                quantity: 20.0,
+                price: 4.0,
+                timestamp: chrono::Utc::now(),
+            },
+        ];
+
+        let reconciler = PositionReconciler::new(
+            ReconciliationConfig {
+                rebuild_positions: true,
+                ..ReconciliationConfig::default()
+            },
+            Arc::new(source),
+        );
+        let wallets = vec![("main".to_string(), owner), ("backup".to_string(), offline)];
+        let report = reconciler.reconcile(&wallets, &snapshot, &trade_log).await;
+
+        assert_eq!(report.wallets_checked, 1);
+        assert_eq!(report.failed_wallets.len(), 1);
+        let d = &report.discrepancies;
+        assert_eq!(d.len(), 5, "{:?}", d);
+        assert!(matches!(d[0], Discrepancy::SolBalance { .. }));
+        assert!(d.contains(&Discrepancy::MissingOnChain {
+            wallet_id: "main".to_string(),
+            symbol: sold.to_string(),
+            recorded_quantity: 10.0,
+        }));
+        assert!(d.contains(&Discrepancy::QuantityMismatch {
+            wallet_id: "main".to_string(),
+            symbol: resized.to_string(),
+            recorded: 50.0,
+            on_chain: 40.0,
+        }));
+        assert!(d.iter().any(
+            |d| matches!(d, Discrepancy::UntrackedHolding { mint, .. } if *mint == airdropped)
+        ));
+        assert!(d
+            .iter()
+            .any(|d| matches!(d, Discrepancy::UntrackedHolding { mint, .. } if *mint == bought)));
+
+        // held + resized + BONK from the trade log, plus the unchecked backup position
+        let rebuilt = report.rebuilt_positions.unwrap();
+        assert_eq!(rebuilt.len(), 4);
+        let bonk = rebuilt.iter().find(|p| p.symbol == "BONK").unwrap();
+        assert_eq!(bonk.quantity, 30.0);
+        assert_eq!(bonk.entry_price, 3.0);
+        assert_eq!(bonk.strategy_type, StrategyType::AxiomMemeCoin);
+        let resized_position = rebuilt
+            .iter()
+            .find(|p| p.symbol == resized.to_string())
+            .unwrap();
+        assert_eq!(resized_position.quantity, 40.0);
+    }
+
+    #[tokio::test]
+    async fn test_journal_round_trips_fills_and_positions() {
+        let dir = std::env::temp_dir().join(format!("position_journal_{}", uuid::Uuid::new_v4()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let journal = PositionJournal {
+            snapshot_path: dir.join("positions.json"),
+            trade_log_path: Some(dir.join("trades.jsonl")),
+        };
+        let mint = Pubkey::new_unique();
+        let fill = TradeLogEntry {
+            wallet_id: "main".to_string(),
+            symbol: "BONK".to_string(),
+            mint: mint.to_string(),
+            strategy_type: StrategyType::TokenSniping,
+            action: TradeAction::Buy,
+            quantity: 5.0,
+            price: 2.0,
+            timestamp: chrono::Utc::now(),
+        };
+        journal.record_fill(&fill).unwrap();
+        journal.record_fill(&fill).unwrap();
+
+        let manager = WalletManager::new();
+        manager.record_open_position(position("main", mint, 10.0)).await;
+        journal.save_snapshot(&manager).await.unwrap();
+
+        let trade_log = load_trade_log(journal.trade_log_path.as_ref().unwrap()).unwrap();
+        assert_eq!(trade_log.len(), 2);
+        let snapshot = PositionSnapshot::load(&journal.snapshot_path).unwrap().unwrap();
+        assert_eq!(snapshot.positions.len(), 1);
+        assert_eq!(snapshot.positions[0].quantity, 10.0);
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
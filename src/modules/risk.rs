@@ -1,9 +1,31 @@
 // Risk Manager Module
 // Evaluates trading signals against risk parameters
 
-use crate::modules::strategy::TradingSignal;
+use crate::modules::ai_funnel::{AIFunnelStage, SharedAIDecisionFunnel};
+use crate::modules::allocation::{
+    RebalancerConfig, SharedStrategyAllocations, StrategyAllocations,
+};
+use crate::modules::fee_budget::{SharedFeeBudgets, FEE_BUDGET_EXHAUSTED};
+use crate::modules::honeypot::{HoneypotGuard, HoneypotVerdict};
+use crate::modules::market_prices::{MissingPriceConfig, MissingPricePolicy, SharedMarketPrices};
+use crate::modules::notional_rate::{NotionalRateConfig, NotionalRateLimiter};
+use crate::modules::persistence::{PersistenceMessage, RejectedSignal, RiskDecision};
+use crate::modules::pipeline_capture::{CaptureArtifact, SharedPipelineCapture};
+use crate::modules::portfolio_exposure::{
+    PortfolioExposure, PortfolioExposureCap, PORTFOLIO_EXPOSURE_CAP_REACHED,
+};
+use crate::modules::slippage_curve::SlippageCurveConfig;
+use crate::modules::strategy::{StrategyType, TradeAction, TradingSignal};
+use crate::modules::strategy_cooldown::{
+    ExecutionCooldowns, LosingStreakConfig, SharedExecutionCooldowns, SharedStrategyCooldowns,
+    StrategyCooldowns,
+};
+use crate::modules::symbol_resolver::{SymbolResolver, TokenPair};
+use crate::modules::target_price::TargetPriceConfig;
+use crate::modules::token_metadata::{SharedTokenMetadataCache, TokenMetadata};
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 use tokio::sync::mpsc;
 use tracing::{debug, error, info, warn};
 
@@ -14,12 +36,28 @@ pub struct RiskParameters {
     pub min_confidence_threshold: f64,
 }
 
+/// Realized P&L of a confirmed exit, reported back by the executor
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SettledTrade {
+    pub signal_id: String,
+    pub strategy_type: StrategyType,
+    pub realized_pnl: f64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ApprovedSignal {
     pub original_signal: TradingSignal,
+    /// Canonical mint pair resolved from `original_signal.symbol`
+    pub token_pair: TokenPair,
     pub approved_quantity: f64,
     pub risk_score: f64,
     pub approval_timestamp: chrono::DateTime<chrono::Utc>,
+    /// Base mint metadata, when the token metadata cache could supply it in time
+    #[serde(default)]
+    pub base_metadata: Option<TokenMetadata>,
+    /// Largest adverse fill the executor accepts, from the strategy's slippage curve
+    #[serde(default)]
+    pub allowed_slippage_bps: Option<f64>,
 }
 
 pub struct RiskManager {
@@ -27,6 +65,25 @@ pub struct RiskManager {
     execution_sender: mpsc::UnboundedSender<ApprovedSignal>,
     risk_params: RiskParameters,
     daily_pnl: f64,
+    strategy_cooldowns: SharedStrategyCooldowns,
+    execution_cooldowns: SharedExecutionCooldowns,
+    strategy_allocations: SharedStrategyAllocations,
+    honeypot_guard: Option<Arc<HoneypotGuard>>,
+    notional_rate: Option<NotionalRateLimiter>,
+    fee_budgets: Option<SharedFeeBudgets>,
+    symbol_resolver: SymbolResolver,
+    target_prices: TargetPriceConfig,
+    slippage_curves: SlippageCurveConfig,
+    token_metadata: Option<SharedTokenMetadataCache>,
+    persistence_sender: Option<mpsc::UnboundedSender<PersistenceMessage>>,
+    rejected_signal_sender: Option<mpsc::UnboundedSender<PersistenceMessage>>,
+    ai_funnel: Option<SharedAIDecisionFunnel>,
+    exposure_cap: Option<Arc<PortfolioExposureCap>>,
+    market_prices: Option<SharedMarketPrices>,
+    missing_price: MissingPriceConfig,
+    capture: Option<SharedPipelineCapture>,
+    trade_result_sender: mpsc::UnboundedSender<SettledTrade>,
+    trade_result_receiver: mpsc::UnboundedReceiver<SettledTrade>,
     is_running: bool,
 }
 
@@ -37,15 +94,102 @@ impl RiskManager {
         execution_sender: mpsc::UnboundedSender<ApprovedSignal>,
         risk_params: RiskParameters,
     ) -> Self {
+        let (trade_result_sender, trade_result_receiver) = mpsc::unbounded_channel();
         Self {
             signal_receiver,
             execution_sender,
             risk_params,
             daily_pnl: 0.0,
+            strategy_cooldowns: StrategyCooldowns::shared(LosingStreakConfig::default()),
+            execution_cooldowns: ExecutionCooldowns::shared(0),
+            strategy_allocations: StrategyAllocations::shared(RebalancerConfig::default()),
+            honeypot_guard: None,
+            notional_rate: None,
+            fee_budgets: None,
+            symbol_resolver: SymbolResolver::default(),
+            target_prices: TargetPriceConfig::default(),
+            slippage_curves: SlippageCurveConfig::default(),
+            token_metadata: None,
+            persistence_sender: None,
+            rejected_signal_sender: None,
+            ai_funnel: None,
+            exposure_cap: None,
+            market_prices: None,
+            missing_price: MissingPriceConfig::default(),
+            capture: None,
+            trade_result_sender,
+            trade_result_receiver,
             is_running: false,
         }
     }
 
+    /// Share losing-streak cool-down state with other components (e.g. the HTTP API)
+    pub fn with_strategy_cooldowns(mut self, strategy_cooldowns: SharedStrategyCooldowns) -> Self {
+        self.strategy_cooldowns = strategy_cooldowns;
+        self
+    }
+
+    /// Share post-execution cool-downs with the executor that records fills
+    pub fn with_execution_cooldowns(
+        mut self,
+        execution_cooldowns: SharedExecutionCooldowns,
+    ) -> Self {
+        self.execution_cooldowns = execution_cooldowns;
+        self
+    }
+
+    /// Scale each strategy's position limit by the rebalancer's allocation multipliers
+    pub fn with_strategy_allocations(
+        mut self,
+        strategy_allocations: SharedStrategyAllocations,
+    ) -> Self {
+        self.strategy_allocations = strategy_allocations;
+        self
+    }
+
+    /// Screen sniping buys for tokens that cannot be sold
+    pub fn with_honeypot_guard(mut self, honeypot_guard: Arc<HoneypotGuard>) -> Self {
+        self.honeypot_guard = Some(honeypot_guard);
+        self
+    }
+
+    /// Cap the notional each strategy may open per window
+    pub fn with_notional_rate_limit(mut self, config: NotionalRateConfig) -> Self {
+        self.notional_rate = Some(NotionalRateLimiter::new(config));
+        self
+    }
+
+    /// Reject a strategy's trades once the fees it paid today reach its budget; the
+    /// executor records the fees
+    pub fn with_fee_budgets(mut self, fee_budgets: SharedFeeBudgets) -> Self {
+        self.fee_budgets = Some(fee_budgets);
+        self
+    }
+
+    /// Attach base mint metadata to approved signals from the shared cache
+    pub fn with_token_metadata(mut self, token_metadata: SharedTokenMetadataCache) -> Self {
+        self.token_metadata = Some(token_metadata);
+        self
+    }
+
+    /// Persist every approval and rejection as a risk decision
+    pub fn with_persistence(
+        mut self,
+        persistence_sender: mpsc::UnboundedSender<PersistenceMessage>,
+    ) -> Self {
+        self.persistence_sender = Some(persistence_sender);
+        self
+    }
+
+    /// Publish every rejected signal with the risk state it was judged against
+    pub fn with_rejected_signal_log(
+        mut self,
+        rejected_signal_sender: mpsc::UnboundedSender<PersistenceMessage>,
+    ) -> Self {
+        self.rejected_signal_sender = Some(rejected_signal_sender);
+        self
+    }
+
     pub async fn start(&mut self) -> Result<()> {
         info!(
             "🛡️ RiskManager starting with params: {:?}",
@@ -54,62 +198,274 @@ impl RiskManager {
         self.is_running = true;
 
         while self.is_running {
-            if let Some(signal) = self.signal_receiver.recv().await {
-                self.evaluate_signal(signal).await?;
+            tokio::select! {
+                Some(signal) = self.signal_receiver.recv() => {
+                    self.evaluate_signal(signal).await?;
+                }
+                Some(trade) = self.trade_result_receiver.recv() => {
+                    self.record_settled_trade(trade);
+                }
             }
         }
 
         Ok(())
     }
 
+    /// Sender through which the executor reports realized P&L of confirmed exits
+    pub fn trade_result_sender(&self) -> mpsc::UnboundedSender<SettledTrade> {
+        self.trade_result_sender.clone()
+    }
+
     pub async fn stop(&mut self) {
         info!("🛑 RiskManager stopping...");
         self.is_running = false;
     }
 
+    pub fn with_symbol_resolver(mut self, symbol_resolver: SymbolResolver) -> Self {
+        self.symbol_resolver = symbol_resolver;
+        self
+    }
+
+    /// Per-strategy bands a signal's target price must fall in
+    pub fn with_target_prices(mut self, target_prices: TargetPriceConfig) -> Self {
+        self.target_prices = target_prices;
+        self
+    }
+
+    /// Per-strategy curves bounding the slippage an approved signal may execute at
+    pub fn with_slippage_curves(mut self, slippage_curves: SlippageCurveConfig) -> Self {
+        self.slippage_curves = slippage_curves;
+        self
+    }
+
+    /// Count approved AI decisions into the AI acceptance funnel
+    pub fn with_ai_funnel(mut self, ai_funnel: SharedAIDecisionFunnel) -> Self {
+        self.ai_funnel = Some(ai_funnel);
+        self
+    }
+
+    /// Reject entries that would push the SOL deployed across all wallets over the cap
+    pub fn with_portfolio_exposure_cap(mut self, exposure_cap: Arc<PortfolioExposureCap>) -> Self {
+        self.exposure_cap = Some(exposure_cap);
+        self
+    }
+
+    /// Apply each strategy's missing-price policy to signals whose symbol has no fresh price
+    /// in `market_prices`. Waiting holds the risk manager's queue, so waits should stay short.
+    pub fn with_missing_price_policy(
+        mut self,
+        market_prices: SharedMarketPrices,
+        missing_price: MissingPriceConfig,
+    ) -> Self {
+        self.market_prices = Some(market_prices);
+        self.missing_price = missing_price;
+        self
+    }
+
+    /// Add each decision, with the limits it was judged against, to captured pipeline traces
+    pub fn with_pipeline_capture(mut self, capture: SharedPipelineCapture) -> Self {
+        self.capture = Some(capture);
+        self
+    }
+
     async fn evaluate_signal(&mut self, signal: TradingSignal) -> Result<()> {
         debug!("Evaluating signal: {}", signal.signal_id);
 
+        // Zero or negative quantities and prices would become zero-notional fills and
+        // negative fees downstream, so nothing malformed gets past this point
+        if let Err(e) = signal.validate() {
+            return self.reject(&signal, e.to_string());
+        }
+
+        // Reject symbols that cannot be mapped to on-chain mints before any other check
+        let token_pair = match self.symbol_resolver.resolve(&signal.symbol) {
+            Ok(pair) => pair,
+            Err(e) => {
+                return self.reject(
+                    &signal,
+                    format!("unresolvable symbol {}: {}", signal.symbol, e),
+                );
+            }
+        };
+
+        // Placeholder or nonsensical prices would defeat every downstream price check
+        if let Err(e) = self.target_prices.validate_signal(&signal) {
+            return self.reject(&signal, format!("invalid target price ({})", e));
+        }
+
         // Check confidence threshold
         if signal.confidence < self.risk_params.min_confidence_threshold {
-            warn!(
-                "Signal {} rejected: confidence {} below threshold {}",
-                signal.signal_id, signal.confidence, self.risk_params.min_confidence_threshold
+            return self.reject(
+                &signal,
+                format!(
+                    "confidence {} below threshold {}",
+                    signal.confidence, self.risk_params.min_confidence_threshold
+                ),
+            );
+        }
+
+        // Check losing-streak cool-down for the strategy
+        if !self.is_strategy_enabled(&signal.strategy_type) {
+            return self.reject(
+                &signal,
+                format!(
+                    "strategy {:?} is cooling down after a losing streak",
+                    signal.strategy_type
+                ),
+            );
+        }
+
+        // Check the fees the strategy already paid today
+        if self.is_fee_budget_exhausted(&signal.strategy_type) {
+            return self.reject(&signal, FEE_BUDGET_EXHAUSTED.to_string());
+        }
+
+        // Check post-execution cool-down for this strategy on this symbol
+        if self.is_cooling_down_after_execution(&signal.strategy_type, &token_pair) {
+            return self.reject(
+                &signal,
+                format!(
+                    "strategy {:?} recently executed on {}",
+                    signal.strategy_type, signal.symbol
+                ),
             );
-            return Ok(());
+        }
+
+        // Check sniping entries for tokens whose sells are blocked
+        if let Some(guard) = self
+            .honeypot_guard
+            .as_ref()
+            .filter(|g| g.applies_to(&signal))
+        {
+            match guard.screen(&token_pair.base_mint).await {
+                Ok(HoneypotVerdict::Sellable) => {}
+                Ok(HoneypotVerdict::Honeypot(reason)) => {
+                    return self.reject(
+                        &signal,
+                        format!("honeypot detected on {} ({})", signal.symbol, reason),
+                    );
+                }
+                Err(e) if guard.fail_open() => {
+                    warn!(
+                        "Honeypot check for {} failed, allowing signal {}: {}",
+                        signal.symbol, signal.signal_id, e
+                    );
+                }
+                Err(e) => {
+                    return self.reject(
+                        &signal,
+                        format!("honeypot check for {} failed: {}", signal.symbol, e),
+                    );
+                }
+            }
+        }
+
+        // Check signals that arrived before any market data for their symbol
+        if let Some(reason) = self.check_missing_price(&signal).await {
+            return self.reject(&signal, reason);
         }
 
         // Check position size limits
         let approved_quantity = self.check_position_limits(&signal)?;
         if approved_quantity <= 0.0 {
-            warn!(
-                "Signal {} rejected: position size limits exceeded",
-                signal.signal_id
-            );
-            return Ok(());
+            return self.reject(&signal, "position size limits exceeded".to_string());
         }
 
         // Check daily loss limits
         if !self.check_daily_loss_limits()? {
-            warn!(
-                "Signal {} rejected: daily loss limits exceeded",
-                signal.signal_id
+            return self.reject(&signal, "daily loss limits exceeded".to_string());
+        }
+
+        // Check the SOL already deployed across all wallets
+        if let Some(exposure) = self.check_portfolio_exposure(&signal, approved_quantity).await {
+            return self.reject(
+                &signal,
+                format!(
+                    "{} ({:.4} deployed and {:.4} reserved of {:.4} SOL)",
+                    PORTFOLIO_EXPOSURE_CAP_REACHED,
+                    exposure.deployed_sol,
+                    exposure.reserved_sol,
+                    exposure.max_deployed_sol
+                ),
+            );
+        }
+
+        // Check how much new exposure the strategy opened recently
+        if let Some(opened) = self.check_notional_rate(&signal, approved_quantity) {
+            return self.reject(
+                &signal,
+                format!(
+                    "strategy notional rate limit ({:.4} of {:.4} opened in window)",
+                    opened,
+                    self.notional_rate
+                        .as_ref()
+                        .map_or(0.0, |limiter| limiter.limit_for(&signal.strategy_type))
+                ),
             );
-            return Ok(());
         }
 
         // Calculate risk score
         let risk_score = self.calculate_risk_score(&signal)?;
 
+        // Metadata is informational here; a miss is bounded by the cache and never blocks approval
+        let base_metadata = match &self.token_metadata {
+            Some(cache) => match cache.get(&token_pair.base_mint).await {
+                Ok(metadata) => Some(metadata.as_ref().clone()),
+                Err(e) => {
+                    warn!("Signal {}: {}", signal.signal_id, e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        let allowed_slippage_bps = self
+            .slippage_curves
+            .allowed_slippage_bps(&signal, approved_quantity);
+        if let Some(allowed) = allowed_slippage_bps {
+            debug!(
+                "Signal {}: allowed slippage {:.1} bps",
+                signal.signal_id, allowed
+            );
+        }
+
         // Approve signal
         let approved_signal = ApprovedSignal {
             original_signal: signal.clone(),
+            token_pair,
             approved_quantity,
             risk_score,
             approval_timestamp: chrono::Utc::now(),
+            base_metadata,
+            allowed_slippage_bps,
         };
 
-        self.send_approved_signal(approved_signal).await?;
+        // Captured first so the decision precedes the executor's artifacts in the trace
+        let decision = RiskDecision::approved(&signal, approved_quantity, risk_score);
+        self.capture_decision(&signal, &decision);
+        // Approved buys hold their notional against the portfolio cap until the executor settles them
+        let reserved_cap = self
+            .exposure_cap
+            .as_ref()
+            .filter(|_| matches!(signal.action, TradeAction::Buy));
+        if let Some(exposure_cap) = reserved_cap {
+            exposure_cap.reserve(
+                &signal.signal_id,
+                exposure_cap.notional_sol(&signal.symbol, approved_quantity * signal.target_price),
+            );
+        }
+        if let Err(e) = self.send_approved_signal(approved_signal).await {
+            if let Some(exposure_cap) = reserved_cap {
+                exposure_cap.release(&signal.signal_id);
+            }
+            return Err(e);
+        }
+        if signal.strategy_type == StrategyType::AIDecision {
+            if let Some(ai_funnel) = &self.ai_funnel {
+                ai_funnel.record(AIFunnelStage::Approved);
+            }
+        }
+        self.persist(PersistenceMessage::RiskDecision(decision));
         info!(
             "✅ Signal {} approved with quantity {}",
             signal.signal_id, approved_quantity
@@ -118,13 +474,193 @@ impl RiskManager {
         Ok(())
     }
 
+    /// Log a rejection and persist it as a risk decision
+    fn reject(&self, signal: &TradingSignal, reason: String) -> Result<()> {
+        warn!("Signal {} rejected: {}", signal.signal_id, reason);
+        if let Some(sender) = &self.rejected_signal_sender {
+            // Unbounded send never waits, so the approval path is not slowed down
+            let rejected = RejectedSignal {
+                signal: signal.clone(),
+                rejection_reason: reason.clone(),
+                risk_params: self.risk_params.clone(),
+                effective_max_position_size: self
+                    .effective_max_position_size(&signal.strategy_type),
+                daily_pnl: self.daily_pnl,
+                timestamp: chrono::Utc::now(),
+            };
+            if let Err(e) = sender.send(PersistenceMessage::RejectedSignal(Box::new(rejected))) {
+                debug!("Rejected signal not recorded: {}", e);
+            }
+        }
+        let decision = RiskDecision::rejected(signal, reason);
+        self.capture_decision(signal, &decision);
+        if let Some(capture) = &self.capture {
+            capture.complete(&signal.signal_id);
+        }
+        self.persist(PersistenceMessage::RiskDecision(decision));
+        Ok(())
+    }
+
+    fn capture_decision(&self, signal: &TradingSignal, decision: &RiskDecision) {
+        if let Some(capture) = &self.capture {
+            capture.record(
+                &signal.signal_id,
+                CaptureArtifact::RiskDecision {
+                    decision: decision.clone(),
+                    risk_params: self.risk_params.clone(),
+                    effective_max_position_size: self
+                        .effective_max_position_size(&signal.strategy_type),
+                    daily_pnl: self.daily_pnl,
+                },
+            );
+        }
+    }
+
+    fn persist(&self, message: PersistenceMessage) {
+        if let Some(sender) = &self.persistence_sender {
+            if let Err(e) = sender.send(message) {
+                debug!("Risk decision not persisted: {}", e);
+            }
+        }
+    }
+
     fn check_position_limits(&self, signal: &TradingSignal) -> Result<f64> {
-        if signal.quantity > self.risk_params.max_position_size {
-            return Ok(self.risk_params.max_position_size);
+        let max_position_size = self.effective_max_position_size(&signal.strategy_type);
+        if signal.quantity > max_position_size {
+            return Ok(max_position_size);
         }
         Ok(signal.quantity)
     }
 
+    /// `max_position_size` scaled by the strategy's current allocation
+    pub fn effective_max_position_size(&self, strategy: &StrategyType) -> f64 {
+        let multiplier = match self.strategy_allocations.lock() {
+            Ok(allocations) => allocations.multiplier(strategy),
+            Err(_) => {
+                error!(
+                    "Strategy allocation state poisoned, using base limit for {:?}",
+                    strategy
+                );
+                1.0
+            }
+        };
+        self.risk_params.max_position_size * multiplier
+    }
+
+    fn is_strategy_enabled(&self, strategy: &StrategyType) -> bool {
+        match self.strategy_cooldowns.lock() {
+            Ok(mut cooldowns) => cooldowns.is_enabled(strategy),
+            Err(_) => {
+                error!("Strategy cool-down state poisoned, allowing {:?}", strategy);
+                true
+            }
+        }
+    }
+
+    fn is_cooling_down_after_execution(
+        &self,
+        strategy: &StrategyType,
+        token_pair: &TokenPair,
+    ) -> bool {
+        match self.execution_cooldowns.lock() {
+            Ok(mut cooldowns) => cooldowns.is_cooling_down(strategy, token_pair),
+            Err(_) => {
+                error!(
+                    "Execution cool-down state poisoned, allowing {:?}",
+                    strategy
+                );
+                false
+            }
+        }
+    }
+
+    fn is_fee_budget_exhausted(&self, strategy: &StrategyType) -> bool {
+        let Some(fee_budgets) = &self.fee_budgets else {
+            return false;
+        };
+        match fee_budgets.lock() {
+            Ok(mut budgets) => budgets.is_exhausted(strategy),
+            Err(_) => {
+                error!("Fee budget state poisoned, allowing {:?}", strategy);
+                false
+            }
+        }
+    }
+
+    /// Record a buy's notional against its strategy's window; returns the notional
+    /// already opened when this entry would exceed the rate
+    fn check_notional_rate(
+        &mut self,
+        signal: &TradingSignal,
+        approved_quantity: f64,
+    ) -> Option<f64> {
+        if !matches!(signal.action, TradeAction::Buy) {
+            return None;
+        }
+        let limiter = self.notional_rate.as_mut()?;
+        let notional = approved_quantity * signal.target_price;
+        limiter
+            .try_open(&signal.strategy_type, notional, chrono::Utc::now())
+            .err()
+    }
+
+    /// Returns a rejection reason when the symbol has no fresh market price and the
+    /// strategy's policy rejects after waiting for one
+    async fn check_missing_price(&self, signal: &TradingSignal) -> Option<String> {
+        let market_prices = self.market_prices.as_ref()?;
+        if market_prices.latest(&signal.symbol).is_some() {
+            return None;
+        }
+
+        match self.missing_price.policy_for(&signal.strategy_type) {
+            MissingPricePolicy::UseSignalPrice => {
+                debug!(
+                    "Signal {}: no market data for {}, using target price {}",
+                    signal.signal_id, signal.symbol, signal.target_price
+                );
+                None
+            }
+            MissingPricePolicy::WaitThenProceed(timeout) => {
+                if market_prices.wait_for_price(&signal.symbol, timeout).await.is_none() {
+                    debug!(
+                        "Signal {}: no market data for {} after {} ms, using target price {}",
+                        signal.signal_id,
+                        signal.symbol,
+                        timeout.as_millis(),
+                        signal.target_price
+                    );
+                }
+                None
+            }
+            MissingPricePolicy::WaitThenReject(timeout) => market_prices
+                .wait_for_price(&signal.symbol, timeout)
+                .await
+                .is_none()
+                .then(|| {
+                    format!(
+                        "no market data for {} after {} ms",
+                        signal.symbol,
+                        timeout.as_millis()
+                    )
+                }),
+        }
+    }
+
+    /// Returns the portfolio exposure when a buy's notional would exceed the global cap
+    async fn check_portfolio_exposure(
+        &self,
+        signal: &TradingSignal,
+        approved_quantity: f64,
+    ) -> Option<PortfolioExposure> {
+        if !matches!(signal.action, TradeAction::Buy) {
+            return None;
+        }
+        let exposure_cap = self.exposure_cap.as_ref()?;
+        exposure_cap
+            .check(exposure_cap.notional_sol(&signal.symbol, approved_quantity * signal.target_price))
+            .await
+    }
+
     fn check_daily_loss_limits(&self) -> Result<bool> {
         Ok(self.daily_pnl > -self.risk_params.max_daily_loss)
     }
@@ -166,6 +702,25 @@ impl RiskManager {
         self.daily_pnl += pnl_change;
     }
 
+    /// Record the realized P&L of a closed trade against its strategy
+    pub fn record_trade_result(&mut self, strategy: &StrategyType, realized_pnl: f64) {
+        self.update_daily_pnl(realized_pnl);
+        if let Ok(mut cooldowns) = self.strategy_cooldowns.lock() {
+            cooldowns.record_trade(strategy, realized_pnl);
+        }
+        if let Ok(mut allocations) = self.strategy_allocations.lock() {
+            allocations.record_trade(strategy, realized_pnl);
+        }
+    }
+
+    fn record_settled_trade(&mut self, trade: SettledTrade) {
+        info!(
+            "📒 Signal {} realized {:.6} SOL for {:?}",
+            trade.signal_id, trade.realized_pnl, trade.strategy_type
+        );
+        self.record_trade_result(&trade.strategy_type, trade.realized_pnl);
+    }
+
     pub fn get_daily_pnl(&self) -> f64 {
         self.daily_pnl
     }
@@ -191,4 +746,91 @@ mod tests {
         assert!(!manager.is_running);
         assert_eq!(manager.daily_pnl, 0.0);
     }
+
+    #[tokio::test]
+    async fn test_rejections_are_recorded_with_risk_state() {
+        let (_signal_tx, signal_rx) = mpsc::unbounded_channel();
+        let (execution_tx, _execution_rx) = mpsc::unbounded_channel();
+        let (rejected_tx, mut rejected_rx) = mpsc::unbounded_channel();
+
+        let risk_params = RiskParameters {
+            max_position_size: 1000.0,
+            max_daily_loss: 500.0,
+            min_confidence_threshold: 0.6,
+        };
+        let manager = RiskManager::new(signal_rx, execution_tx, risk_params)
+            .with_rejected_signal_log(rejected_tx);
+
+        let signal = TradingSignal {
+            signal_id: "sig".to_string(),
+            symbol: "SOL/USDC".to_string(),
+            action: crate::modules::strategy::TradeAction::Buy,
+            quantity: 10.0,
+            target_price: 150.0,
+            confidence: 0.4,
+            timestamp: chrono::Utc::now(),
+            strategy_type: StrategyType::TokenSniping,
+            tick_received_at: None,
+            pool_liquidity: None,
+        };
+        manager
+            .reject(&signal, "confidence below threshold".to_string())
+            .unwrap();
+
+        let message = rejected_rx.try_recv().unwrap();
+        assert_eq!(message.table(), Some("rejected_signals"));
+        let PersistenceMessage::RejectedSignal(rejected) = message else {
+            panic!("expected a rejected signal");
+        };
+        assert_eq!(rejected.signal.signal_id, "sig");
+        assert_eq!(rejected.rejection_reason, "confidence below threshold");
+        assert_eq!(rejected.risk_params.min_confidence_threshold, 0.6);
+        assert_eq!(rejected.effective_max_position_size, 1000.0);
+    }
+
+    #[tokio::test]
+    async fn test_non_positive_quantity_or_price_never_reaches_executor() {
+        let (_signal_tx, signal_rx) = mpsc::unbounded_channel();
+        let (execution_tx, mut execution_rx) = mpsc::unbounded_channel();
+        let (rejected_tx, mut rejected_rx) = mpsc::unbounded_channel();
+        let risk_params = RiskParameters {
+            max_position_size: 1000.0,
+            max_daily_loss: 500.0,
+            min_confidence_threshold: 0.6,
+        };
+        let mut manager = RiskManager::new(signal_rx, execution_tx, risk_params)
+            .with_rejected_signal_log(rejected_tx);
+
+        let signal = TradingSignal {
+            signal_id: "sig".to_string(),
+            symbol: "SOL/USDC".to_string(),
+            action: crate::modules::strategy::TradeAction::Buy,
+            quantity: 10.0,
+            target_price: 150.0,
+            confidence: 0.9,
+            timestamp: chrono::Utc::now(),
+            strategy_type: StrategyType::TokenSniping,
+            tick_received_at: None,
+            pool_liquidity: None,
+        };
+        assert!(signal.validate().is_ok());
+
+        for (quantity, target_price) in [(0.0, 150.0), (-1.0, 150.0), (10.0, 0.0), (10.0, -5.0)] {
+            let invalid = TradingSignal {
+                quantity,
+                target_price,
+                ..signal.clone()
+            };
+            manager.evaluate_signal(invalid).await.unwrap();
+            let PersistenceMessage::RejectedSignal(rejected) = rejected_rx.try_recv().unwrap()
+            else {
+                panic!("expected a rejected signal");
+            };
+            assert_eq!(
+                rejected.rejection_reason,
+                crate::modules::strategy::INVALID_QUANTITY_OR_PRICE
+            );
+        }
+        assert!(execution_rx.try_recv().is_err());
+    }
 }
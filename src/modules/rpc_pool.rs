@@ -0,0 +1,86 @@
+// RPC Client Pool Module
+// One shared RPC client per endpoint so per-wallet endpoints don't rebuild clients per trade
+
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::commitment_config::CommitmentConfig;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::debug;
+
+pub struct RpcClientPool {
+    default_url: String,
+    commitment: CommitmentConfig,
+    clients: Mutex<HashMap<String, Arc<RpcClient>>>,
+}
+
+#[allow(dead_code)]
+impl RpcClientPool {
+    /// `default_url` serves every wallet without its own endpoint (`solana.rpc_url`)
+    pub fn new(default_url: String) -> Self {
+        Self {
+            default_url,
+            commitment: CommitmentConfig::confirmed(),
+            clients: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn with_commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.commitment = commitment;
+        self
+    }
+
+    pub fn default_url(&self) -> &str {
+        &self.default_url
+    }
+
+    /// Client for the wallet's endpoint override, or the default endpoint when unset
+    pub fn client_for(&self, rpc_url: Option<&str>) -> Arc<RpcClient> {
+        let url = rpc_url
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .unwrap_or(&self.default_url);
+
+        let mut clients = self.clients.lock().unwrap_or_else(|e| e.into_inner());
+        clients
+            .entry(url.to_string())
+            .or_insert_with(|| {
+                debug!("🔌 Creating RPC client for {}", url);
+                Arc::new(RpcClient::new_with_commitment(
+                    url.to_string(),
+                    self.commitment,
+                ))
+            })
+            .clone()
+    }
+
+    pub fn default_client(&self) -> Arc<RpcClient> {
+        self.client_for(None)
+    }
+
+    /// Number of distinct endpoints with a live client
+    pub fn endpoint_count(&self) -> usize {
+        self.clients.lock().map(|c| c.len()).unwrap_or(0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clients_are_reused_per_endpoint() {
+        let pool = RpcClientPool::new("http://default:8899".to_string());
+
+        let default = pool.client_for(None);
+        assert_eq!(default.url(), "http://default:8899");
+        assert!(Arc::ptr_eq(&default, &pool.client_for(Some(" "))));
+
+        let hft = pool.client_for(Some("http://hft-node:8899"));
+        assert_eq!(hft.url(), "http://hft-node:8899");
+        assert!(Arc::ptr_eq(
+            &hft,
+            &pool.client_for(Some("http://hft-node:8899"))
+        ));
+        assert_eq!(pool.endpoint_count(), 2);
+    }
+}
@@ -0,0 +1,252 @@
+// Shadow Divergence Module
+// Compares a shadow (candidate) strategy's per-tick decisions with the live ones over a
+// rolling window and alerts when they disagree too often, which usually means a config or
+// data bug in the candidate rather than a better strategy
+
+use crate::modules::data_ingestor::MarketData;
+use crate::modules::strategy::{
+    token_sniping_signal, StrategyType, TokenSnipingParams, TradeAction, TradingSignal,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tracing::{error, info};
+
+#[derive(Debug, Clone)]
+pub struct ShadowDivergenceConfig {
+    /// Most recent compared ticks the disagreement rate is computed over
+    pub window: usize,
+    /// Compared ticks needed in the window before an alert can fire
+    pub min_samples: usize,
+    /// Disagreement rate above which the shadow strategy is flagged (0.0 - 1.0)
+    pub max_disagreement_rate: f64,
+}
+
+impl Default for ShadowDivergenceConfig {
+    fn default() -> Self {
+        Self {
+            window: 200,
+            min_samples: 50,
+            max_disagreement_rate: 0.2,
+        }
+    }
+}
+
+/// Candidate strategy run on the live ticks; its signals are compared, never executed
+pub trait ShadowStrategy: Send + Sync {
+    fn signal(&self, strategy: &StrategyType, data: &MarketData) -> Option<TradingSignal>;
+}
+
+/// Token sniping with candidate parameters; other strategies hold
+pub struct TokenSnipingCandidate {
+    params: TokenSnipingParams,
+}
+
+impl TokenSnipingCandidate {
+    pub fn new(params: TokenSnipingParams) -> Self {
+        Self { params }
+    }
+}
+
+impl ShadowStrategy for TokenSnipingCandidate {
+    fn signal(&self, strategy: &StrategyType, data: &MarketData) -> Option<TradingSignal> {
+        match strategy {
+            StrategyType::TokenSniping => token_sniping_signal(&self.params, data),
+            _ => None,
+        }
+    }
+}
+
+/// Action a strategy took on a tick; emitting nothing counts as holding
+pub fn decided_action(signal: Option<&TradingSignal>) -> TradeAction {
+    signal.map_or(TradeAction::Hold, |signal| signal.action.clone())
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowDisagreement {
+    pub symbol: String,
+    pub strategy_type: StrategyType,
+    pub live: TradeAction,
+    pub shadow: TradeAction,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+}
+
+/// Comparator state exposed through the HTTP API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShadowDivergenceStatus {
+    pub alerting: bool,
+    pub disagreement_rate: f64,
+    pub samples: usize,
+    pub ticks_compared: u64,
+    pub alerts: u64,
+    pub last_disagreement: Option<ShadowDisagreement>,
+}
+
+#[derive(Debug)]
+pub struct ShadowDivergence {
+    config: ShadowDivergenceConfig,
+    /// Comparisons in the window, true where shadow and live disagreed
+    disagreements: VecDeque<bool>,
+    ticks_compared: u64,
+    alerting: bool,
+    alerts: u64,
+    last_disagreement: Option<ShadowDisagreement>,
+}
+
+pub type SharedShadowDivergence = Arc<Mutex<ShadowDivergence>>;
+
+#[allow(dead_code)]
+impl ShadowDivergence {
+    pub fn new(config: ShadowDivergenceConfig) -> Self {
+        Self {
+            config,
+            disagreements: VecDeque::new(),
+            ticks_compared: 0,
+            alerting: false,
+            alerts: 0,
+            last_disagreement: None,
+        }
+    }
+
+    pub fn shared(config: ShadowDivergenceConfig) -> SharedShadowDivergence {
+        Arc::new(Mutex::new(Self::new(config)))
+    }
+
+    pub fn disagreement_rate(&self) -> f64 {
+        if self.disagreements.is_empty() {
+            return 0.0;
+        }
+        let disagreed = self.disagreements.iter().filter(|d| **d).count();
+        disagreed as f64 / self.disagreements.len() as f64
+    }
+
+    /// Whether the shadow strategy currently diverges beyond the threshold
+    pub fn is_alerting(&self) -> bool {
+        self.alerting
+    }
+
+    /// Record the live and shadow decisions for one tick; returns whether they disagreed
+    pub fn record(
+        &mut self,
+        symbol: &str,
+        strategy: &StrategyType,
+        live: &TradeAction,
+        shadow: &TradeAction,
+    ) -> bool {
+        let disagreed = live != shadow;
+        self.ticks_compared += 1;
+        if disagreed {
+            self.last_disagreement = Some(ShadowDisagreement {
+                symbol: symbol.to_string(),
+                strategy_type: strategy.clone(),
+                live: live.clone(),
+                shadow: shadow.clone(),
+                timestamp: chrono::Utc::now(),
+            });
+        }
+
+        self.disagreements.push_back(disagreed);
+        while self.disagreements.len() > self.config.window.max(1) {
+            self.disagreements.pop_front();
+        }
+
+        let rate = self.disagreement_rate();
+        let diverging = self.disagreements.len() >= self.config.min_samples
+            && rate > self.config.max_disagreement_rate;
+        if diverging && !self.alerting {
+            error!(
+                "🚨 ALERT: shadow strategy disagrees with live on {:.0}% of the last {} ticks (threshold {:.0}%), do not promote it",
+                rate * 100.0,
+                self.disagreements.len(),
+                self.config.max_disagreement_rate * 100.0
+            );
+            self.alerts += 1;
+        } else if !diverging && self.alerting {
+            info!(
+                "✅ Shadow strategy back within divergence threshold ({:.0}% disagreement)",
+                rate * 100.0
+            );
+        }
+        self.alerting = diverging;
+        disagreed
+    }
+
+    pub fn status(&self) -> ShadowDivergenceStatus {
+        ShadowDivergenceStatus {
+            alerting: self.alerting,
+            disagreement_rate: self.disagreement_rate(),
+            samples: self.disagreements.len(),
+            ticks_compared: self.ticks_compared,
+            alerts: self.alerts,
+            last_disagreement: self.last_disagreement.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_alerts_when_disagreement_exceeds_threshold() {
+        let mut divergence = ShadowDivergence::new(ShadowDivergenceConfig {
+            window: 4,
+            min_samples: 4,
+            max_disagreement_rate: 0.25,
+        });
+        let mut record = |live: TradeAction, shadow: TradeAction| {
+            divergence.record("SOL/USDC", &StrategyType::TokenSniping, &live, &shadow);
+            divergence.status()
+        };
+
+        record(TradeAction::Buy, TradeAction::Buy);
+        record(TradeAction::Hold, TradeAction::Buy);
+        // Too few samples to judge
+        assert!(!record(TradeAction::Hold, TradeAction::Buy).alerting);
+        // 2 of 4 disagree, above 25%
+        let status = record(TradeAction::Sell, TradeAction::Sell);
+        assert!(status.alerting);
+        assert_eq!(status.alerts, 1);
+        assert_eq!(status.disagreement_rate, 0.5);
+
+        // Still diverging: no second alert
+        assert_eq!(record(TradeAction::Buy, TradeAction::Buy).alerts, 1);
+        // The disagreements roll out of the window
+        let status = record(TradeAction::Buy, TradeAction::Buy);
+        assert!(!status.alerting);
+        assert_eq!(status.samples, 4);
+        assert_eq!(status.ticks_compared, 6);
+        let last = status.last_disagreement.unwrap();
+        assert_eq!(
+            (last.live, last.shadow),
+            (TradeAction::Hold, TradeAction::Buy)
+        );
+
+        assert_eq!(decided_action(None), TradeAction::Hold);
+    }
+
+    #[test]
+    fn test_sniping_candidate_uses_its_own_entry_price() {
+        use crate::modules::data_ingestor::DataSource;
+
+        let candidate = TokenSnipingCandidate::new(TokenSnipingParams {
+            entry_price: 100.0,
+            ..TokenSnipingParams::default()
+        });
+        let tick = MarketData {
+            symbol: "SOL/USDC".to_string(),
+            price: 102.0,
+            volume: 1_000_000.0,
+            timestamp: chrono::Utc::now(),
+            source: DataSource::Helius,
+            received_at: chrono::Utc::now(),
+        };
+
+        // Live holds below its 105 entry price; the candidate buys
+        let live = token_sniping_signal(&TokenSnipingParams::default(), &tick);
+        assert_eq!(decided_action(live.as_ref()), TradeAction::Hold);
+        let shadow = candidate.signal(&StrategyType::TokenSniping, &tick);
+        assert_eq!(decided_action(shadow.as_ref()), TradeAction::Buy);
+        assert!(candidate.signal(&StrategyType::Arbitrage, &tick).is_none());
+    }
+}
@@ -0,0 +1,288 @@
+// Shutdown Report Module
+// Final run summary written to the logs, a local file and persistence on graceful shutdown
+
+use crate::modules::allocation::{SharedStrategyAllocations, StrategyPnlTotal};
+use crate::modules::persistence::{PersistenceFlushStatus, PersistenceMessage};
+use crate::modules::strategy_cooldown::{SharedStrategyCooldowns, StrategyCooldownStatus};
+use crate::modules::wallet_manager::WalletManager;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+use tokio::sync::mpsc;
+use tracing::{info, warn};
+
+#[derive(Debug, Clone)]
+pub struct ShutdownReportConfig {
+    /// Always written, so the report survives a degraded persistence layer
+    pub file_path: PathBuf,
+}
+
+impl Default for ShutdownReportConfig {
+    fn default() -> Self {
+        Self {
+            file_path: PathBuf::from("shutdown_report.json"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletBalance {
+    pub wallet_id: String,
+    pub sol_balance: f64,
+    pub total_value_usd: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShutdownReport {
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    pub uptime_seconds: u64,
+    pub trading_mode: String,
+    pub total_signals: u64,
+    pub executed_trades: u64,
+    /// Sum of the per-strategy realized P&L
+    pub realized_pnl: f64,
+    pub strategy_attribution: Vec<StrategyPnlTotal>,
+    pub wallet_balances: Vec<WalletBalance>,
+    /// Strategies still in a losing-streak cool-down at exit
+    pub disabled_strategies: Vec<StrategyCooldownStatus>,
+    /// Final flush of buffered persistence records, filled in by the PersistenceManager
+    #[serde(default)]
+    pub persistence_flush: Option<PersistenceFlushStatus>,
+}
+
+#[allow(dead_code)]
+impl ShutdownReport {
+    pub fn new(trading_mode: String, uptime_seconds: u64) -> Self {
+        Self {
+            generated_at: chrono::Utc::now(),
+            uptime_seconds,
+            trading_mode,
+            total_signals: 0,
+            executed_trades: 0,
+            realized_pnl: 0.0,
+            strategy_attribution: Vec::new(),
+            wallet_balances: Vec::new(),
+            disabled_strategies: Vec::new(),
+            persistence_flush: None,
+        }
+    }
+
+    pub fn with_trade_counts(mut self, total_signals: u64, executed_trades: u64) -> Self {
+        self.total_signals = total_signals;
+        self.executed_trades = executed_trades;
+        self
+    }
+
+    pub fn with_strategy_allocations(mut self, allocations: &SharedStrategyAllocations) -> Self {
+        match allocations.lock() {
+            Ok(allocations) => self.strategy_attribution = allocations.pnl_totals(),
+            Err(_) => warn!("Strategy allocation state poisoned, omitting P&L attribution"),
+        }
+        self.realized_pnl = self
+            .strategy_attribution
+            .iter()
+            .map(|t| t.realized_pnl)
+            .sum();
+        self
+    }
+
+    pub fn with_strategy_cooldowns(mut self, cooldowns: &SharedStrategyCooldowns) -> Self {
+        match cooldowns.lock() {
+            Ok(cooldowns) => {
+                self.disabled_strategies = cooldowns
+                    .all_status()
+                    .into_iter()
+                    .filter(|s| s.disabled_until.is_some())
+                    .collect();
+            }
+            Err(_) => warn!("Strategy cool-down state poisoned, omitting disabled strategies"),
+        }
+        self
+    }
+
+    /// Latest known balance of every active wallet
+    pub async fn with_wallet_manager(mut self, wallet_manager: &WalletManager) -> Self {
+        let wallets = match wallet_manager.get_active_wallets().await {
+            Ok(wallets) => wallets,
+            Err(e) => {
+                warn!("Failed to list wallets for shutdown report: {}", e);
+                return self;
+            }
+        };
+        for wallet in wallets {
+            if let Ok(metrics) = wallet_manager.get_wallet_metrics(&wallet.wallet_id).await {
+                self.wallet_balances.push(WalletBalance {
+                    wallet_id: wallet.wallet_id,
+                    sol_balance: metrics.sol_balance,
+                    total_value_usd: metrics.total_value_usd,
+                });
+            }
+        }
+        self.wallet_balances
+            .sort_by(|a, b| a.wallet_id.cmp(&b.wallet_id));
+        self
+    }
+
+    pub fn log_summary(&self) {
+        info!(
+            "📋 Shutdown report: uptime {}s, mode {}, {} signals, {} trades, realized P&L {:.4}",
+            self.uptime_seconds,
+            self.trading_mode,
+            self.total_signals,
+            self.executed_trades,
+            self.realized_pnl
+        );
+        for total in &self.strategy_attribution {
+            info!(
+                "📋   {:?}: {} trades, realized P&L {:.4}",
+                total.strategy, total.trades, total.realized_pnl
+            );
+        }
+        for wallet in &self.wallet_balances {
+            info!(
+                "📋   wallet {}: {:.4} SOL (${:.2})",
+                wallet.wallet_id, wallet.sol_balance, wallet.total_value_usd
+            );
+        }
+        for status in &self.disabled_strategies {
+            info!(
+                "📋   {:?} ended disabled ({} consecutive losses)",
+                status.strategy, status.consecutive_losses
+            );
+        }
+    }
+
+    pub fn write_to_file(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write shutdown report to {:?}", path))
+    }
+
+    /// Record that persistence flushed its buffered records and rewrite the local file,
+    /// so the report on disk confirms nothing was left unwritten
+    pub fn confirm_persistence_flush(
+        &mut self,
+        status: PersistenceFlushStatus,
+        config: &ShutdownReportConfig,
+    ) {
+        if status.completed {
+            info!(
+                "📋 Persistence flushed {} buffered records before exit",
+                status.records_flushed
+            );
+        } else {
+            warn!(
+                "📋 Persistence flush incomplete: {}",
+                status.error.as_deref().unwrap_or("unknown error")
+            );
+        }
+        self.persistence_flush = Some(status);
+        if let Err(e) = self.write_to_file(&config.file_path) {
+            warn!("{}", e);
+        }
+    }
+
+    /// Log the report, write the local file, then hand it to persistence;
+    /// returns whether persistence accepted it
+    pub fn persist(
+        &self,
+        config: &ShutdownReportConfig,
+        persistence: &mpsc::UnboundedSender<PersistenceMessage>,
+    ) -> bool {
+        self.log_summary();
+
+        match self.write_to_file(&config.file_path) {
+            Ok(()) => info!("📋 Shutdown report written to {:?}", config.file_path),
+            Err(e) => warn!("{}", e),
+        }
+
+        let accepted = persistence
+            .send(PersistenceMessage::ShutdownReport(Box::new(self.clone())))
+            .is_ok();
+        if !accepted {
+            warn!(
+                "📋 Persistence unavailable, shutdown report kept only in {:?}",
+                config.file_path
+            );
+        }
+        accepted
+    }
+}
+
+/// Resolves on Ctrl-C or, on Unix, SIGTERM
+pub async fn wait_for_shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            warn!("Failed to listen for Ctrl-C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                warn!("Failed to listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::modules::allocation::{RebalancerConfig, StrategyAllocations};
+    use crate::modules::strategy::StrategyType;
+    use crate::modules::strategy_cooldown::{LosingStreakConfig, StrategyCooldowns};
+
+    #[test]
+    fn test_report_written_when_persistence_is_down() {
+        let allocations = StrategyAllocations::shared(RebalancerConfig::default());
+        let cooldowns = StrategyCooldowns::shared(LosingStreakConfig {
+            max_consecutive_losses: 1,
+            cooldown_secs: 600,
+        });
+        {
+            let mut allocations = allocations.lock().unwrap();
+            allocations.record_trade(&StrategyType::Arbitrage, 3.0);
+            allocations.record_trade(&StrategyType::TokenSniping, -1.0);
+            cooldowns
+                .lock()
+                .unwrap()
+                .record_trade(&StrategyType::TokenSniping, -1.0);
+        }
+
+        let report = ShutdownReport::new("Paper".to_string(), 42)
+            .with_trade_counts(10, 2)
+            .with_strategy_allocations(&allocations)
+            .with_strategy_cooldowns(&cooldowns);
+        assert_eq!(report.realized_pnl, 2.0);
+        assert_eq!(report.strategy_attribution.len(), 2);
+        assert_eq!(report.disabled_strategies.len(), 1);
+
+        let config = ShutdownReportConfig {
+            file_path: std::env::temp_dir()
+                .join(format!("shutdown_report_{}.json", uuid::Uuid::new_v4())),
+        };
+        let (persistence_tx, persistence_rx) = mpsc::unbounded_channel();
+        drop(persistence_rx);
+
+        assert!(!report.persist(&config, &persistence_tx));
+        let written: ShutdownReport =
+            serde_json::from_str(&std::fs::read_to_string(&config.file_path).unwrap()).unwrap();
+        std::fs::remove_file(&config.file_path).ok();
+        assert_eq!(written.executed_trades, 2);
+        assert_eq!(written.uptime_seconds, 42);
+    }
+}
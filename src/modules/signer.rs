@@ -0,0 +1,202 @@
+// THE OVERMIND PROTOCOL - Transaction Signing
+// Abstracts over local keypairs and external signers (KMS/HSM) so keys need not live on the host
+
+use anyhow::{anyhow, Context, Result};
+use base64::Engine;
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    transaction::Transaction,
+};
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Signs transaction messages on behalf of a single wallet
+pub trait TransactionSigner: Send + Sync {
+    fn pubkey(&self) -> Pubkey;
+
+    /// Sign a serialized transaction message
+    fn sign_message<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<Signature>>;
+}
+
+/// How a wallet's transactions are signed
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum SignerConfig {
+    /// Sign with `WalletConfig::private_key` on this host
+    #[default]
+    Local,
+    /// Send messages to an external signer; no private key is stored
+    Remote {
+        endpoint: String,
+        key_id: String,
+        /// Environment variable holding the bearer token for the signer, if any
+        auth_token_env: Option<String>,
+    },
+}
+
+/// Signer backed by an in-memory keypair (the original behaviour)
+pub struct LocalKeypairSigner {
+    keypair: Keypair,
+}
+
+impl LocalKeypairSigner {
+    pub fn new(keypair: Keypair) -> Self {
+        Self { keypair }
+    }
+}
+
+impl TransactionSigner for LocalKeypairSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.keypair.pubkey()
+    }
+
+    fn sign_message<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<Signature>> {
+        Box::pin(async move { Ok(self.keypair.sign_message(message)) })
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RemoteSignRequest<'a> {
+    key_id: &'a str,
+    pubkey: String,
+    /// Base64-encoded message bytes
+    message: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RemoteSignResponse {
+    /// Base58-encoded ed25519 signature
+    signature: String,
+}
+
+/// Signer that delegates to an external HTTP signing service.
+///
+/// The service receives `POST {endpoint}/sign` with `{key_id, pubkey, message}`
+/// and must answer `{signature}`; signatures are verified before use.
+pub struct RemoteSigner {
+    client: reqwest::Client,
+    endpoint: String,
+    key_id: String,
+    pubkey: Pubkey,
+    auth_token: Option<String>,
+}
+
+impl RemoteSigner {
+    pub fn new(
+        endpoint: String,
+        key_id: String,
+        pubkey: Pubkey,
+        auth_token: Option<String>,
+    ) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(2))
+            .build()
+            .context("Failed to create remote signer HTTP client")?;
+
+        Ok(Self {
+            client,
+            endpoint: endpoint.trim_end_matches('/').to_string(),
+            key_id,
+            pubkey,
+            auth_token,
+        })
+    }
+
+    async fn request_signature(&self, message: &[u8]) -> Result<Signature> {
+        let request = RemoteSignRequest {
+            key_id: &self.key_id,
+            pubkey: self.pubkey.to_string(),
+            message: base64::engine::general_purpose::STANDARD.encode(message),
+        };
+
+        let mut http_request = self
+            .client
+            .post(format!("{}/sign", self.endpoint))
+            .json(&request);
+        if let Some(token) = &self.auth_token {
+            http_request = http_request.bearer_auth(token);
+        }
+
+        let response = http_request
+            .send()
+            .await
+            .context("Remote signer request failed")?;
+        if !response.status().is_success() {
+            return Err(anyhow!("Remote signer returned HTTP {}", response.status()));
+        }
+
+        let body: RemoteSignResponse = response
+            .json()
+            .await
+            .context("Invalid remote signer response")?;
+        let signature =
+            Signature::from_str(&body.signature).context("Invalid signature from remote signer")?;
+
+        if !signature.verify(self.pubkey.as_ref(), message) {
+            return Err(anyhow!(
+                "Remote signer returned a signature that does not match {}",
+                self.pubkey
+            ));
+        }
+        Ok(signature)
+    }
+}
+
+impl TransactionSigner for RemoteSigner {
+    fn pubkey(&self) -> Pubkey {
+        self.pubkey
+    }
+
+    fn sign_message<'a>(&'a self, message: &'a [u8]) -> BoxFuture<'a, Result<Signature>> {
+        Box::pin(self.request_signature(message))
+    }
+}
+
+/// Add the signer's signature to a transaction that lists it as a required signer
+pub async fn sign_transaction(
+    signer: &dyn TransactionSigner,
+    transaction: &mut Transaction,
+) -> Result<()> {
+    let pubkey = signer.pubkey();
+    let required = transaction.message.header.num_required_signatures as usize;
+    let index = transaction.message.account_keys[..required]
+        .iter()
+        .position(|key| *key == pubkey)
+        .ok_or_else(|| anyhow!("{} is not a required signer of this transaction", pubkey))?;
+
+    let signature = signer.sign_message(&transaction.message_data()).await?;
+    if transaction.signatures.len() != required {
+        transaction.signatures = vec![Signature::default(); required];
+    }
+    transaction.signatures[index] = signature;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::{hash::Hash, message::Message, system_instruction};
+
+    #[tokio::test]
+    async fn test_local_signer_signs_transaction() {
+        let keypair = Keypair::new();
+        let payer = keypair.pubkey();
+        let signer = LocalKeypairSigner::new(keypair);
+
+        let instruction = system_instruction::transfer(&payer, &Pubkey::new_unique(), 1);
+        let mut transaction = Transaction::new_unsigned(Message::new_with_blockhash(
+            &[instruction],
+            Some(&payer),
+            &Hash::default(),
+        ));
+
+        sign_transaction(&signer, &mut transaction).await.unwrap();
+        assert!(transaction.verify().is_ok());
+
+        // A signer that is not part of the transaction is rejected
+        let outsider = LocalKeypairSigner::new(Keypair::new());
+        assert!(sign_transaction(&outsider, &mut transaction).await.is_err());
+    }
+}
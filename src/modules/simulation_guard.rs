@@ -0,0 +1,320 @@
+// Simulation Guard Module
+// Halts live trading when pre-flight simulations fail at a high rate over a rolling
+// window, which usually means a systemic problem (bad RPC, broken route, program upgrade)
+
+use crate::modules::risk::ApprovedSignal;
+use crate::modules::strategy::TradeAction;
+use crate::modules::symbol_resolver::SOL_MINT;
+use anyhow::{anyhow, Result};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::RpcSimulateTransactionConfig;
+use solana_sdk::message::Message;
+use solana_sdk::native_token::sol_to_lamports;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_instruction;
+use solana_sdk::transaction::Transaction;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tracing::{error, info};
+
+#[derive(Debug, Clone)]
+pub struct SimulationGuardConfig {
+    /// Most recent simulations the failure rate is computed over
+    pub window: usize,
+    /// Simulations needed in the window before the guard can halt
+    pub min_samples: usize,
+    /// Failure rate above which live trading is halted (0.0 - 1.0)
+    pub max_failure_rate: f64,
+    /// Consecutive successful simulations that lift a halt (0 requires a manual re-enable)
+    pub recovery_streak: u32,
+}
+
+impl Default for SimulationGuardConfig {
+    fn default() -> Self {
+        Self {
+            window: 50,
+            min_samples: 10,
+            max_failure_rate: 0.5,
+            recovery_streak: 20,
+        }
+    }
+}
+
+/// Runs a signal's transaction against the cluster without submitting it
+pub trait PreflightSimulator: Send + Sync {
+    fn simulate<'a>(&'a self, signal: &'a ApprovedSignal) -> BoxFuture<'a, Result<()>>;
+}
+
+/// Simulates a transfer of each SOL-quoted buy's cost from the trading wallet to itself.
+/// Until live trades build swap transactions this catches an unreachable RPC or an
+/// underfunded wallet, which is what the guard halts on.
+pub struct RpcPreflightSimulator {
+    rpc_client: Arc<RpcClient>,
+    payer: Pubkey,
+}
+
+impl RpcPreflightSimulator {
+    pub fn new(rpc_client: Arc<RpcClient>, payer: Pubkey) -> Self {
+        Self { rpc_client, payer }
+    }
+
+    fn transaction(&self, signal: &ApprovedSignal) -> Transaction {
+        let spends_sol = signal.original_signal.action == TradeAction::Buy
+            && signal.token_pair.quote_mint.to_string() == SOL_MINT;
+        let lamports = if spends_sol {
+            sol_to_lamports(signal.approved_quantity * signal.original_signal.target_price)
+        } else {
+            0
+        };
+        let instruction = system_instruction::transfer(&self.payer, &self.payer, lamports);
+        Transaction::new_unsigned(Message::new(&[instruction], Some(&self.payer)))
+    }
+}
+
+impl PreflightSimulator for RpcPreflightSimulator {
+    fn simulate<'a>(&'a self, signal: &'a ApprovedSignal) -> BoxFuture<'a, Result<()>> {
+        Box::pin(async move {
+            let config = RpcSimulateTransactionConfig {
+                sig_verify: false,
+                replace_recent_blockhash: true,
+                ..RpcSimulateTransactionConfig::default()
+            };
+            let response = self
+                .rpc_client
+                .simulate_transaction_with_config(&self.transaction(signal), config)
+                .await?;
+            match response.value.err {
+                Some(e) => Err(anyhow!("{}", e)),
+                None => Ok(()),
+            }
+        })
+    }
+}
+
+/// Guard state exposed through the HTTP API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SimulationGuardStatus {
+    pub halted: bool,
+    pub failure_rate: f64,
+    pub samples: usize,
+    pub healthy_streak: u32,
+    pub halts: u64,
+    pub halted_at: Option<chrono::DateTime<chrono::Utc>>,
+    pub last_failure: Option<String>,
+}
+
+#[derive(Debug)]
+pub struct SimulationGuard {
+    config: SimulationGuardConfig,
+    /// Outcomes in the window, true for a successful simulation
+    outcomes: VecDeque<bool>,
+    halted_at: Option<chrono::DateTime<chrono::Utc>>,
+    healthy_streak: u32,
+    halts: u64,
+    last_failure: Option<String>,
+}
+
+pub type SharedSimulationGuard = Arc<Mutex<SimulationGuard>>;
+
+#[allow(dead_code)]
+impl SimulationGuard {
+    pub fn new(config: SimulationGuardConfig) -> Self {
+        Self {
+            config,
+            outcomes: VecDeque::new(),
+            halted_at: None,
+            healthy_streak: 0,
+            halts: 0,
+            last_failure: None,
+        }
+    }
+
+    pub fn shared(config: SimulationGuardConfig) -> SharedSimulationGuard {
+        Arc::new(Mutex::new(Self::new(config)))
+    }
+
+    /// No new live trades may be submitted while halted
+    pub fn is_halted(&self) -> bool {
+        self.halted_at.is_some()
+    }
+
+    pub fn failure_rate(&self) -> f64 {
+        if self.outcomes.is_empty() {
+            return 0.0;
+        }
+        let failures = self.outcomes.iter().filter(|ok| !**ok).count();
+        failures as f64 / self.outcomes.len() as f64
+    }
+
+    /// Record a simulation outcome. Simulations keep running while halted, so a
+    /// sustained healthy streak can lift the halt.
+    pub fn record(&mut self, outcome: &Result<()>) {
+        match outcome {
+            Ok(()) => self.healthy_streak += 1,
+            Err(e) => {
+                self.healthy_streak = 0;
+                self.last_failure = Some(e.to_string());
+            }
+        }
+
+        if self.is_halted() {
+            if self.config.recovery_streak > 0 && self.healthy_streak >= self.config.recovery_streak
+            {
+                info!(
+                    "✅ Live trading re-enabled after {} consecutive successful simulations",
+                    self.healthy_streak
+                );
+                self.resume();
+            }
+            return;
+        }
+
+        self.outcomes.push_back(outcome.is_ok());
+        while self.outcomes.len() > self.config.window.max(1) {
+            self.outcomes.pop_front();
+        }
+        let failure_rate = self.failure_rate();
+        if self.outcomes.len() >= self.config.min_samples
+            && failure_rate > self.config.max_failure_rate
+        {
+            error!(
+                "🚨 ALERT: live trading halted, {:.0}% of the last {} pre-flight simulations failed (last: {})",
+                failure_rate * 100.0,
+                self.outcomes.len(),
+                self.last_failure.as_deref().unwrap_or("unknown")
+            );
+            self.halted_at = Some(chrono::Utc::now());
+            self.halts += 1;
+        }
+    }
+
+    /// Manually lift a halt, e.g. after fixing the RPC endpoint
+    pub fn reenable(&mut self) {
+        if self.is_halted() {
+            info!("✅ Live trading manually re-enabled");
+        }
+        self.resume();
+    }
+
+    fn resume(&mut self) {
+        self.halted_at = None;
+        self.healthy_streak = 0;
+        // Failures from before the halt must not immediately halt again
+        self.outcomes.clear();
+    }
+
+    pub fn status(&self) -> SimulationGuardStatus {
+        SimulationGuardStatus {
+            halted: self.is_halted(),
+            failure_rate: self.failure_rate(),
+            samples: self.outcomes.len(),
+            healthy_streak: self.healthy_streak,
+            halts: self.halts,
+            halted_at: self.halted_at,
+            last_failure: self.last_failure.clone(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_failure_rate_halts_until_streak_or_manual_reenable() {
+        let mut guard = SimulationGuard::new(SimulationGuardConfig {
+            window: 4,
+            min_samples: 4,
+            max_failure_rate: 0.5,
+            recovery_streak: 2,
+        });
+        let failed = || Err(anyhow::anyhow!("route not found"));
+
+        guard.record(&Ok(()));
+        guard.record(&failed());
+        guard.record(&failed());
+        // Too few samples to judge
+        assert!(!guard.is_halted());
+        guard.record(&Ok(()));
+        // 50% is not above the threshold
+        assert!(!guard.is_halted());
+        guard.record(&failed());
+        assert!(guard.is_halted());
+        assert_eq!(guard.status().halts, 1);
+
+        // A healthy streak that gets interrupted does not recover
+        guard.record(&Ok(()));
+        guard.record(&failed());
+        guard.record(&Ok(()));
+        assert!(guard.is_halted());
+        guard.record(&Ok(()));
+        assert!(!guard.is_halted());
+        assert_eq!(guard.status().samples, 0);
+
+        let mut manual = SimulationGuard::new(SimulationGuardConfig {
+            window: 2,
+            min_samples: 1,
+            max_failure_rate: 0.0,
+            recovery_streak: 0,
+        });
+        manual.record(&failed());
+        assert!(manual.is_halted());
+        for _ in 0..10 {
+            manual.record(&Ok(()));
+        }
+        assert!(manual.is_halted());
+        manual.reenable();
+        assert!(!manual.is_halted());
+    }
+
+    #[test]
+    fn test_preflight_transfers_the_sol_cost_of_buys() {
+        use crate::modules::strategy::{StrategyType, TradingSignal};
+        use crate::modules::symbol_resolver::TokenPair;
+        use std::str::FromStr;
+
+        let payer = Pubkey::new_unique();
+        let simulator = RpcPreflightSimulator::new(
+            Arc::new(RpcClient::new("http://127.0.0.1:8899".to_string())),
+            payer,
+        );
+        let signal = |action, quote_mint| ApprovedSignal {
+            original_signal: TradingSignal {
+                signal_id: "sig".to_string(),
+                symbol: "BONK/SOL".to_string(),
+                action,
+                quantity: 4.0,
+                target_price: 0.5,
+                confidence: 0.9,
+                timestamp: chrono::Utc::now(),
+                strategy_type: StrategyType::TokenSniping,
+                tick_received_at: None,
+                pool_liquidity: None,
+            },
+            token_pair: TokenPair {
+                base_mint: Pubkey::new_unique(),
+                quote_mint,
+            },
+            approved_quantity: 4.0,
+            risk_score: 0.1,
+            approval_timestamp: chrono::Utc::now(),
+            base_metadata: None,
+            allowed_slippage_bps: None,
+        };
+        let lamports = |transaction: Transaction| {
+            u64::from_le_bytes(transaction.message.instructions[0].data[4..12].try_into().unwrap())
+        };
+        let sol = Pubkey::from_str(SOL_MINT).unwrap();
+
+        let buy = simulator.transaction(&signal(TradeAction::Buy, sol));
+        assert_eq!(buy.message.account_keys[0], payer);
+        assert_eq!(lamports(buy), 2_000_000_000);
+        assert_eq!(lamports(simulator.transaction(&signal(TradeAction::Sell, sol))), 0);
+        assert_eq!(
+            lamports(simulator.transaction(&signal(TradeAction::Buy, Pubkey::new_unique()))),
+            0
+        );
+    }
+}
@@ -0,0 +1,204 @@
+// Slippage Curve Module
+// Per-strategy allowed slippage as a function of order size relative to pool liquidity,
+// so small orders are held tight and large orders get a bounded, size-appropriate allowance
+
+use crate::modules::strategy::{StrategyType, TradeAction, TradingSignal};
+use crate::modules::strategy_cooldown::parse_strategy_type;
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashMap;
+
+/// Allowed slippage in bps at increasing order sizes (fraction of pool liquidity),
+/// interpolated linearly between points and flat beyond either end
+#[derive(Debug, Clone, PartialEq)]
+pub struct SlippageCurve {
+    points: Vec<(f64, f64)>,
+}
+
+#[allow(dead_code)]
+impl SlippageCurve {
+    pub fn new(mut points: Vec<(f64, f64)>) -> Result<Self> {
+        if points.is_empty() {
+            return Err(anyhow!("Slippage curve needs at least one point"));
+        }
+        if points
+            .iter()
+            .any(|&(size, bps)| !(size.is_finite() && size >= 0.0 && bps.is_finite() && bps >= 0.0))
+        {
+            return Err(anyhow!("Invalid slippage curve point in {:?}", points));
+        }
+        points.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if points.windows(2).any(|pair| pair[1].1 < pair[0].1) {
+            return Err(anyhow!(
+                "Slippage allowance must not shrink as size grows: {:?}",
+                points
+            ));
+        }
+        Ok(Self { points })
+    }
+
+    /// Parse points of the form "0.001:30|0.01:100|0.05:300" (size fraction:bps)
+    pub fn parse(spec: &str) -> Result<Self> {
+        let points = spec
+            .split('|')
+            .filter(|p| !p.trim().is_empty())
+            .map(|point| {
+                let (size, bps) = point
+                    .split_once(':')
+                    .with_context(|| format!("Invalid slippage curve point: {}", point))?;
+                Ok((
+                    size.trim().parse().context("Invalid slippage curve size")?,
+                    bps.trim().parse().context("Invalid slippage curve bps")?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        Self::new(points)
+    }
+
+    pub fn allowed_bps(&self, size_fraction: f64) -> f64 {
+        let (first, last) = (self.points[0], self.points[self.points.len() - 1]);
+        if size_fraction.is_nan() || size_fraction <= first.0 {
+            return first.1;
+        }
+        if size_fraction >= last.0 {
+            return last.1;
+        }
+        self.points
+            .windows(2)
+            .find(|pair| size_fraction <= pair[1].0)
+            .map(|pair| {
+                let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+                y0 + (y1 - y0) * (size_fraction - x0) / (x1 - x0)
+            })
+            .unwrap_or(last.1)
+    }
+
+    /// The allowance for the smallest orders
+    pub fn tightest_bps(&self) -> f64 {
+        self.points[0].1
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SlippageCurveConfig {
+    /// Applies to strategies without their own curve; None leaves them unbounded
+    pub default_curve: Option<SlippageCurve>,
+    pub per_strategy: HashMap<StrategyType, SlippageCurve>,
+}
+
+#[allow(dead_code)]
+impl SlippageCurveConfig {
+    /// Parse curves of the form "TokenSniping=0.001:30|0.05:300,default=0.01:100";
+    /// the `default` entry applies to every other strategy
+    pub fn with_strategy_curves(mut self, spec: &str) -> Result<Self> {
+        for entry in spec.split(',').filter(|e| !e.trim().is_empty()) {
+            let (strategy, curve) = entry
+                .split_once('=')
+                .with_context(|| format!("Invalid slippage curve entry: {}", entry))?;
+            let curve = SlippageCurve::parse(curve)?;
+            match strategy.trim() {
+                "default" => self.default_curve = Some(curve),
+                strategy => {
+                    self.per_strategy
+                        .insert(parse_strategy_type(strategy)?, curve);
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.default_curve.is_none() && self.per_strategy.is_empty()
+    }
+
+    pub fn curve_for(&self, strategy: &StrategyType) -> Option<&SlippageCurve> {
+        self.per_strategy
+            .get(strategy)
+            .or(self.default_curve.as_ref())
+    }
+
+    /// Allowed slippage for executing `quantity` of `signal`. Without known pool
+    /// liquidity the order cannot be sized against the pool, so it gets the tightest
+    /// allowance. None when no curve covers the strategy.
+    pub fn allowed_slippage_bps(&self, signal: &TradingSignal, quantity: f64) -> Option<f64> {
+        let curve = self.curve_for(&signal.strategy_type)?;
+        let allowed = match signal.pool_liquidity.filter(|l| *l > 0.0) {
+            Some(liquidity) => curve.allowed_bps(quantity * signal.target_price / liquidity),
+            None => curve.tightest_bps(),
+        };
+        Some(allowed)
+    }
+}
+
+/// Adverse price move of a fill against the requested price, in bps; negative when
+/// the fill was better than requested
+pub fn realized_slippage_bps(
+    action: &TradeAction,
+    requested_price: f64,
+    executed_price: f64,
+) -> f64 {
+    if requested_price <= 0.0 {
+        return 0.0;
+    }
+    let adverse = match action {
+        TradeAction::Sell => requested_price - executed_price,
+        _ => executed_price - requested_price,
+    };
+    adverse / requested_price * 10_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn signal(strategy_type: StrategyType, pool_liquidity: Option<f64>) -> TradingSignal {
+        TradingSignal {
+            signal_id: "sig".to_string(),
+            symbol: "BONK/USDC".to_string(),
+            action: TradeAction::Buy,
+            quantity: 10.0,
+            target_price: 1.0,
+            confidence: 0.8,
+            timestamp: chrono::Utc::now(),
+            strategy_type,
+            tick_received_at: None,
+            pool_liquidity,
+        }
+    }
+
+    #[test]
+    fn test_allowance_grows_with_size_within_bounds() {
+        let config = SlippageCurveConfig::default()
+            .with_strategy_curves("TokenSniping=0.05:300|0.01:100,default=0:50")
+            .unwrap();
+        let curve = config.curve_for(&StrategyType::TokenSniping).unwrap();
+        assert_eq!(curve.allowed_bps(0.001), 100.0);
+        assert!((curve.allowed_bps(0.03) - 200.0).abs() < 1e-9);
+        // Capped beyond the largest configured size
+        assert_eq!(curve.allowed_bps(0.5), 300.0);
+
+        // 10 units against 1000 of liquidity is a 1% order
+        let sniping = signal(StrategyType::TokenSniping, Some(1_000.0));
+        assert_eq!(config.allowed_slippage_bps(&sniping, 10.0), Some(100.0));
+        assert_eq!(config.allowed_slippage_bps(&sniping, 50.0), Some(300.0));
+        // Unknown liquidity gets the tightest allowance
+        let unknown = signal(StrategyType::TokenSniping, None);
+        assert_eq!(config.allowed_slippage_bps(&unknown, 50.0), Some(100.0));
+        let arbitrage = signal(StrategyType::Arbitrage, Some(1_000.0));
+        assert_eq!(config.allowed_slippage_bps(&arbitrage, 500.0), Some(50.0));
+        assert_eq!(
+            SlippageCurveConfig::default().allowed_slippage_bps(&arbitrage, 1.0),
+            None
+        );
+
+        assert_eq!(
+            realized_slippage_bps(&TradeAction::Buy, 100.0, 101.0),
+            100.0
+        );
+        assert_eq!(
+            realized_slippage_bps(&TradeAction::Sell, 100.0, 101.0),
+            -100.0
+        );
+        assert!(SlippageCurve::parse("0.01:300|0.05:100").is_err());
+        assert!(SlippageCurve::parse("").is_err());
+    }
+}
@@ -0,0 +1,303 @@
+// Slippage Model Module
+// Paper fill model whose slippage is calibrated from observed live fills per symbol and size bucket
+
+use crate::modules::strategy::TradeAction;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tracing::{debug, error, info, warn};
+
+#[derive(Debug, Clone)]
+pub struct SlippageModelConfig {
+    /// Slippage assumed for symbol/size buckets without calibrated parameters
+    pub default_slippage_bps: f64,
+    /// Upper bounds of the order notional (quantity * price) buckets; larger orders fall in a final bucket
+    pub size_bucket_bounds: Vec<f64>,
+    pub calibration_interval: Duration,
+    /// Live fills a bucket needs before its parameters are (re)fitted
+    pub min_samples: usize,
+    /// Weight of a calibration pass's fitted slippage against the current parameters (0.0 - 1.0)
+    pub learning_rate: f64,
+    /// Keep the loaded parameters fixed and ignore new live fills
+    pub frozen: bool,
+    /// Learned parameters are loaded from and saved to this file
+    pub params_path: Option<PathBuf>,
+}
+
+impl Default for SlippageModelConfig {
+    fn default() -> Self {
+        Self {
+            default_slippage_bps: 0.0,
+            size_bucket_bounds: vec![100.0, 1_000.0, 10_000.0],
+            calibration_interval: Duration::from_secs(3600),
+            min_samples: 5,
+            learning_rate: 0.3,
+            frozen: false,
+            params_path: None,
+        }
+    }
+}
+
+/// Fitted slippage for one symbol/size bucket
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SlippageParams {
+    /// Adverse slippage in basis points (positive = worse than requested)
+    pub slippage_bps: f64,
+    /// Live fills the parameters were fitted on, across all passes
+    pub samples: u64,
+    pub updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Live fill observed since the last calibration pass
+#[derive(Debug, Clone)]
+struct SlippageObservation {
+    key: String,
+    slippage_bps: f64,
+}
+
+#[derive(Debug)]
+pub struct SlippageModel {
+    config: SlippageModelConfig,
+    /// Keyed by `symbol|bucket`
+    params: HashMap<String, SlippageParams>,
+    observations: Vec<SlippageObservation>,
+}
+
+pub type SharedSlippageModel = Arc<Mutex<SlippageModel>>;
+
+#[allow(dead_code)]
+impl SlippageModel {
+    pub fn new(config: SlippageModelConfig) -> Self {
+        Self {
+            config,
+            params: HashMap::new(),
+            observations: Vec::new(),
+        }
+    }
+
+    pub fn shared(config: SlippageModelConfig) -> SharedSlippageModel {
+        Arc::new(Mutex::new(Self::new(config)))
+    }
+
+    fn bucket(&self, notional: f64) -> usize {
+        self.config
+            .size_bucket_bounds
+            .iter()
+            .position(|bound| notional <= *bound)
+            .unwrap_or(self.config.size_bucket_bounds.len())
+    }
+
+    fn key(&self, symbol: &str, quantity: f64, price: f64) -> String {
+        format!("{}|{}", symbol, self.bucket(quantity.abs() * price.abs()))
+    }
+
+    /// Slippage applied to a paper fill of this size
+    pub fn slippage_bps(&self, symbol: &str, quantity: f64, price: f64) -> f64 {
+        self.params
+            .get(&self.key(symbol, quantity, price))
+            .map_or(self.config.default_slippage_bps, |p| p.slippage_bps)
+    }
+
+    /// Simulated execution price for a paper order at `requested_price`
+    pub fn paper_fill_price(
+        &self,
+        symbol: &str,
+        action: &TradeAction,
+        quantity: f64,
+        requested_price: f64,
+    ) -> f64 {
+        let slippage = self.slippage_bps(symbol, quantity, requested_price) / 10_000.0;
+        match action {
+            TradeAction::Buy => requested_price * (1.0 + slippage),
+            TradeAction::Sell => requested_price * (1.0 - slippage),
+            TradeAction::Hold => requested_price,
+        }
+    }
+
+    /// Record a confirmed live fill's requested and executed prices
+    pub fn record_live_fill(
+        &mut self,
+        symbol: &str,
+        action: &TradeAction,
+        quantity: f64,
+        requested_price: f64,
+        executed_price: f64,
+    ) {
+        if self.config.frozen || requested_price <= 0.0 || executed_price <= 0.0 {
+            return;
+        }
+        let relative = (executed_price - requested_price) / requested_price;
+        let slippage_bps = match action {
+            TradeAction::Buy => relative * 10_000.0,
+            TradeAction::Sell => -relative * 10_000.0,
+            TradeAction::Hold => return,
+        };
+
+        let key = self.key(symbol, quantity, requested_price);
+        debug!("📐 Live fill slippage for {}: {:.2} bps", key, slippage_bps);
+        self.observations
+            .push(SlippageObservation { key, slippage_bps });
+    }
+
+    /// Fit buckets with enough new live fills and return how many were updated.
+    /// Observations of buckets still short of `min_samples` are kept for the next pass.
+    pub fn calibrate(&mut self) -> usize {
+        if self.config.frozen {
+            return 0;
+        }
+
+        let mut grouped: HashMap<String, Vec<f64>> = HashMap::new();
+        for observation in self.observations.drain(..) {
+            grouped
+                .entry(observation.key)
+                .or_default()
+                .push(observation.slippage_bps);
+        }
+
+        let learning_rate = self.config.learning_rate.clamp(0.0, 1.0);
+        let mut updated = 0;
+        for (key, mut samples) in grouped {
+            if samples.len() < self.config.min_samples.max(1) {
+                self.observations
+                    .extend(samples.into_iter().map(|slippage_bps| SlippageObservation {
+                        key: key.clone(),
+                        slippage_bps,
+                    }));
+                continue;
+            }
+
+            // Median resists the occasional outlier fill
+            samples.sort_by(|a, b| a.total_cmp(b));
+            let fitted = samples[samples.len() / 2];
+            let params = self.params.entry(key).or_insert(SlippageParams {
+                slippage_bps: fitted,
+                samples: 0,
+                updated_at: chrono::Utc::now(),
+            });
+            params.slippage_bps += learning_rate * (fitted - params.slippage_bps);
+            params.samples += samples.len() as u64;
+            params.updated_at = chrono::Utc::now();
+            updated += 1;
+        }
+        updated
+    }
+
+    pub fn params(&self) -> &HashMap<String, SlippageParams> {
+        &self.params
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json = serde_json::to_string_pretty(&self.params)?;
+        std::fs::write(path, json)
+            .with_context(|| format!("Failed to write slippage parameters to {:?}", path))
+    }
+
+    /// Replace the parameters with those saved at `path`
+    pub fn load(&mut self, path: &Path) -> Result<usize> {
+        let json = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read slippage parameters from {:?}", path))?;
+        self.params = serde_json::from_str(&json).context("Invalid slippage parameters file")?;
+        Ok(self.params.len())
+    }
+}
+
+/// Periodically calibrates the shared model and saves the learned parameters
+pub struct SlippageCalibrator {
+    model: SharedSlippageModel,
+    interval: Duration,
+    params_path: Option<PathBuf>,
+}
+
+impl SlippageCalibrator {
+    pub fn new(model: SharedSlippageModel) -> Self {
+        let (interval, params_path) = model
+            .lock()
+            .map(|m| (m.config.calibration_interval, m.config.params_path.clone()))
+            .unwrap_or_else(|_| (SlippageModelConfig::default().calibration_interval, None));
+        Self {
+            model,
+            interval,
+            params_path,
+        }
+    }
+
+    pub async fn run(self) {
+        info!(
+            "📐 Slippage calibrator starting (interval: {:?})",
+            self.interval
+        );
+        let mut interval = tokio::time::interval(self.interval);
+
+        loop {
+            interval.tick().await;
+            let saved = match self.model.lock() {
+                Ok(mut model) => {
+                    let updated = model.calibrate();
+                    if updated == 0 {
+                        debug!("📐 Calibration pass updated no slippage buckets");
+                        continue;
+                    }
+                    info!("📐 Calibrated slippage for {} buckets", updated);
+                    self.params_path.as_ref().map(|path| model.save(path))
+                }
+                Err(_) => {
+                    error!("Slippage model state poisoned, stopping calibrator");
+                    return;
+                }
+            };
+            if let Some(Err(e)) = saved {
+                warn!("{}", e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_calibration_moves_paper_fills_toward_live_slippage() {
+        let mut model = SlippageModel::new(SlippageModelConfig {
+            min_samples: 3,
+            learning_rate: 1.0,
+            ..SlippageModelConfig::default()
+        });
+        assert_eq!(
+            model.paper_fill_price("SOL/USDC", &TradeAction::Buy, 1.0, 100.0),
+            100.0
+        );
+
+        // Small buys fill ~50 bps worse than requested; one large fill is far worse
+        for executed in [100.4, 100.5, 100.6] {
+            model.record_live_fill("SOL/USDC", &TradeAction::Buy, 1.0, 100.0, executed);
+        }
+        model.record_live_fill("SOL/USDC", &TradeAction::Sell, 500.0, 100.0, 97.0);
+
+        assert_eq!(model.calibrate(), 1);
+        let small = model.paper_fill_price("SOL/USDC", &TradeAction::Buy, 1.0, 100.0);
+        assert!((small - 100.5).abs() < 1e-9, "small fill at {}", small);
+        // The large bucket has too few samples and keeps the default
+        assert_eq!(
+            model.paper_fill_price("SOL/USDC", &TradeAction::Sell, 500.0, 100.0),
+            100.0
+        );
+
+        let path = std::env::temp_dir().join(format!("slippage_{}.json", uuid::Uuid::new_v4()));
+        model.save(&path).unwrap();
+        let mut frozen = SlippageModel::new(SlippageModelConfig {
+            frozen: true,
+            min_samples: 1,
+            ..SlippageModelConfig::default()
+        });
+        assert_eq!(frozen.load(&path).unwrap(), 1);
+        std::fs::remove_file(&path).ok();
+
+        frozen.record_live_fill("SOL/USDC", &TradeAction::Buy, 1.0, 100.0, 102.0);
+        assert_eq!(frozen.calibrate(), 0);
+        assert!((frozen.slippage_bps("SOL/USDC", 1.0, 100.0) - 50.0).abs() < 1e-6);
+    }
+}
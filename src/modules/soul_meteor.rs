@@ -39,9 +39,11 @@ pub enum RiskLevel {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct SoulMeteorFilters {
     pub min_liquidity_usd: f64,           // Default: 20_000
     pub max_age_minutes: u32,             // Default: 10
+    pub min_age_seconds: u64,             // Default: 0 (pure sniping)
     pub min_market_cap_usd: f64,          // Default: 800_000
     pub max_market_cap_usd: f64,          // Default: 2_000_000
     pub max_concentrated_percentage: f64, // Default: 30.0
@@ -55,6 +57,7 @@ impl Default for SoulMeteorFilters {
         Self {
             min_liquidity_usd: 20_000.0,
             max_age_minutes: 10,
+            min_age_seconds: 0,
             min_market_cap_usd: 800_000.0,
             max_market_cap_usd: 2_000_000.0,
             max_concentrated_percentage: 30.0,
@@ -155,6 +158,7 @@ impl SoulMeteorAnalyzer {
         // Apply Soul Meteor filters based on the knowledge
         pool.liquidity_usd >= self.filters.min_liquidity_usd
             && pool.age_minutes <= self.filters.max_age_minutes
+            && pool.age_seconds() >= self.filters.min_age_seconds
             && pool.market_cap_usd >= self.filters.min_market_cap_usd
             && pool.market_cap_usd <= self.filters.max_market_cap_usd
             && pool.holder_distribution.total_concentrated
@@ -173,6 +177,11 @@ impl SoulMeteorAnalyzer {
 
 // Integration with existing strategy engine
 impl PoolAnalysis {
+    /// Ages are reported in whole minutes, so a pool in its first minute counts as 0s old
+    pub fn age_seconds(&self) -> u64 {
+        u64::from(self.age_minutes) * 60
+    }
+
     pub fn to_trading_signal(&self) -> crate::modules::strategy::TradingSignal {
         use crate::modules::strategy::{StrategyType, TradeAction, TradingSignal};
         use uuid::Uuid;
@@ -197,6 +206,8 @@ impl PoolAnalysis {
             confidence,
             timestamp: chrono::Utc::now(),
             strategy_type: StrategyType::SoulMeteorSniping,
+            tick_received_at: None,
+            pool_liquidity: None,
         }
     }
 
@@ -268,6 +279,42 @@ mod tests {
         assert!(analyzer.meets_criteria(&pool));
     }
 
+    #[test]
+    fn test_min_pool_age_rejects_young_pools() {
+        let mut pool = PoolAnalysis {
+            pool_address: "test_pool".to_string(),
+            token_symbol: "TEST".to_string(),
+            liquidity_usd: 25_000.0,
+            age_minutes: 0, // Still in the opening bot war
+            market_cap_usd: 1_200_000.0,
+            volume_24h: 80_000.0,
+            holder_distribution: HolderDistribution {
+                top_10_percentage: 18.0,
+                dev_percentage: 6.0,
+                bundler_percentage: 3.0,
+                sniper_percentage: 2.0,
+                total_concentrated: 29.0,
+            },
+            soul_meteor_score: 8.2,
+            risk_assessment: RiskLevel::Low,
+        };
+
+        let (tx, _rx) = mpsc::unbounded_channel();
+        let sniper = SoulMeteorAnalyzer::new(SoulMeteorFilters::default(), tx.clone());
+        assert!(sniper.meets_criteria(&pool));
+
+        let patient = SoulMeteorAnalyzer::new(
+            SoulMeteorFilters {
+                min_age_seconds: 90,
+                ..SoulMeteorFilters::default()
+            },
+            tx,
+        );
+        assert!(!patient.meets_criteria(&pool));
+        pool.age_minutes = 2;
+        assert!(patient.meets_criteria(&pool));
+    }
+
     #[test]
     fn test_confidence_calculation() {
         let pool = PoolAnalysis {
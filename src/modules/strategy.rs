@@ -2,10 +2,22 @@
 // Analyzes market data and generates trading signals
 
 use crate::modules::data_ingestor::MarketData;
-use anyhow::Result;
+use crate::modules::market_prices::SharedMarketPrices;
+use crate::modules::persistence::PersistenceMessage;
+use crate::modules::pipeline_capture::SharedPipelineCapture;
+use crate::modules::shadow_divergence::{decided_action, ShadowStrategy, SharedShadowDivergence};
+use crate::modules::strategy_routing::{MarketDataFilter, StrategyRouter};
+use crate::modules::strategy_sandbox::{run_sandboxed, SharedStrategySandbox};
+use crate::monitoring::MonitoringState;
+use anyhow::{anyhow, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::sync::Arc;
 use tokio::sync::mpsc;
-use tracing::{debug, error, info};
+use tracing::{debug, error, info, warn};
+
+/// Ticks older than this (since ingestion) are skipped by default
+pub const DEFAULT_MAX_TICK_AGE_MS: u64 = 500;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TradingSignal {
@@ -17,12 +29,40 @@ pub struct TradingSignal {
     pub confidence: f64,
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub strategy_type: StrategyType,
+    /// Ingestion time of the market tick that produced this signal, if any
+    #[serde(default)]
+    pub tick_received_at: Option<chrono::DateTime<chrono::Utc>>,
+    /// Quote-side liquidity of the pool the signal trades against, if known
+    #[serde(default)]
+    pub pool_liquidity: Option<f64>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+/// Rejection reason for signals whose quantity or target price is not positive
+pub const INVALID_QUANTITY_OR_PRICE: &str = "invalid quantity/price";
+
+impl TradingSignal {
+    /// Shape every signal must have before it is evaluated: an ID, a positive finite
+    /// quantity and target price, and a confidence within [0, 1]
+    pub fn validate(&self) -> Result<()> {
+        if self.signal_id.is_empty() {
+            return Err(anyhow!("missing signal ID"));
+        }
+        let positive = |value: f64| value.is_finite() && value > 0.0;
+        if !positive(self.quantity) || !positive(self.target_price) {
+            return Err(anyhow!(INVALID_QUANTITY_OR_PRICE));
+        }
+        if !(0.0..=1.0).contains(&self.confidence) {
+            return Err(anyhow!("confidence {} outside [0, 1]", self.confidence));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum TradeAction {
     Buy,
     Sell,
+    /// Observed the market and decided not to trade; recorded, never executed
     Hold,
 }
 
@@ -41,6 +81,25 @@ pub enum StrategyType {
 pub struct StrategyEngine {
     market_data_receiver: mpsc::UnboundedReceiver<MarketData>,
     signal_sender: mpsc::UnboundedSender<TradingSignal>,
+    max_tick_age: chrono::Duration,
+    stale_ticks_skipped: u64,
+    // Consecutive stale ticks in the current backlog, for catch-up logging
+    stale_streak: u64,
+    monitoring: Option<MonitoringState>,
+    persistence_sender: Option<mpsc::UnboundedSender<PersistenceMessage>>,
+    market_prices: Option<SharedMarketPrices>,
+    // Catches strategy panics and disables the offending strategy
+    sandbox: Option<SharedStrategySandbox>,
+    // Which strategies each tick is dispatched to
+    router: StrategyRouter,
+    // Persist and count Hold decisions instead of dropping them
+    record_hold_decisions: bool,
+    // Candidate strategy compared against the live decisions on every tick
+    shadow: Option<(Arc<dyn ShadowStrategy>, SharedShadowDivergence)>,
+    // Strategies whose signals are logged and persisted but never reach risk
+    observe_only: HashSet<StrategyType>,
+    // Starts the forensic trace of signals on targeted symbols
+    capture: Option<SharedPipelineCapture>,
     is_running: bool,
 }
 
@@ -53,16 +112,153 @@ impl StrategyEngine {
         Self {
             market_data_receiver,
             signal_sender,
+            max_tick_age: chrono::Duration::milliseconds(DEFAULT_MAX_TICK_AGE_MS as i64),
+            stale_ticks_skipped: 0,
+            stale_streak: 0,
+            monitoring: None,
+            persistence_sender: None,
+            market_prices: None,
+            sandbox: None,
+            router: StrategyRouter::new()
+                .with_strategy(StrategyType::TokenSniping, MarketDataFilter::default()),
+            record_hold_decisions: true,
+            shadow: None,
+            observe_only: HashSet::new(),
+            capture: None,
             is_running: false,
         }
     }
 
+    pub fn with_max_tick_age_ms(mut self, max_tick_age_ms: u64) -> Self {
+        self.max_tick_age = chrono::Duration::milliseconds(max_tick_age_ms as i64);
+        self
+    }
+
+    /// Report skipped stale ticks to the monitoring metrics
+    pub fn with_monitoring(mut self, monitoring: MonitoringState) -> Self {
+        self.monitoring = Some(monitoring);
+        self
+    }
+
+    /// Persist every emitted signal
+    pub fn with_persistence(
+        mut self,
+        persistence_sender: mpsc::UnboundedSender<PersistenceMessage>,
+    ) -> Self {
+        self.persistence_sender = Some(persistence_sender);
+        self
+    }
+
+    /// Capture the ticks and signals of targeted symbols or signal IDs
+    pub fn with_pipeline_capture(mut self, capture: SharedPipelineCapture) -> Self {
+        self.capture = Some(capture);
+        self
+    }
+
+    /// Record the latest fresh price per symbol for execution-time checks
+    pub fn with_market_prices(mut self, market_prices: SharedMarketPrices) -> Self {
+        self.market_prices = Some(market_prices);
+        self
+    }
+
+    /// Whether Hold decisions are persisted and counted; they never reach the executor
+    pub fn with_hold_decisions(mut self, record_hold_decisions: bool) -> Self {
+        self.record_hold_decisions = record_hold_decisions;
+        self
+    }
+
+    /// Run each strategy invocation inside the panic sandbox
+    pub fn with_sandbox(mut self, sandbox: SharedStrategySandbox) -> Self {
+        self.sandbox = Some(sandbox);
+        self
+    }
+
+    /// Run `shadow` on the same ticks as the live strategies and record how often their
+    /// decisions disagree; shadow signals are never executed
+    pub fn with_shadow_strategy(
+        mut self,
+        shadow: Arc<dyn ShadowStrategy>,
+        divergence: SharedShadowDivergence,
+    ) -> Self {
+        self.shadow = Some((shadow, divergence));
+        self
+    }
+
+    /// Record `strategy`'s signals in full without routing any of them to risk or the
+    /// executor, for evaluating a new strategy before it is even paper traded
+    pub fn with_observe_only(mut self, strategy: StrategyType) -> Self {
+        self.observe_only.insert(strategy);
+        self
+    }
+
+    /// Restrict the ticks dispatched to `strategy` to those matching `filter`
+    pub fn with_strategy_filter(
+        mut self,
+        strategy: StrategyType,
+        filter: MarketDataFilter,
+    ) -> Self {
+        self.router = self.router.with_strategy(strategy, filter);
+        self
+    }
+
+    /// Stop running `strategy`, e.g. when it is disabled in configuration
+    pub fn without_strategy(mut self, strategy: &StrategyType) -> Self {
+        self.router = self.router.without_strategy(strategy);
+        self
+    }
+
+    /// Registered strategies the engine actually generates signals for
+    pub fn runnable_strategies(&self) -> Vec<StrategyType> {
+        self.router
+            .strategies()
+            .filter(|strategy| Self::runs_strategy(strategy))
+            .cloned()
+            .collect()
+    }
+
+    /// Runnable strategies not disabled by the panic sandbox
+    pub fn active_strategy_count(&self) -> usize {
+        let runnable = self.runnable_strategies();
+        match self
+            .sandbox
+            .as_ref()
+            .and_then(|sandbox| sandbox.lock().ok())
+        {
+            Some(sandbox) => runnable
+                .iter()
+                .filter(|strategy| !sandbox.is_disabled(strategy))
+                .count(),
+            None => runnable.len(),
+        }
+    }
+
+    pub fn stale_ticks_skipped(&self) -> u64 {
+        self.stale_ticks_skipped
+    }
+
     pub async fn start(&mut self) -> Result<()> {
         info!("🧠 StrategyEngine starting...");
+        let strategies = self.runnable_strategies();
+        if self.active_strategy_count() == 0 {
+            warn!("⚠️ StrategyEngine has NO active strategies: market data will be consumed but no signals will be emitted");
+        } else {
+            info!("🧠 Active strategies: {:?}", strategies);
+        }
+        if !self.observe_only.is_empty() {
+            info!("👁️ Observe-only strategies (never routed): {:?}", self.observe_only);
+        }
+        if let Some(monitoring) = &self.monitoring {
+            monitoring.set_engine_strategies(strategies);
+        }
         self.is_running = true;
 
         while self.is_running {
             if let Some(market_data) = self.market_data_receiver.recv().await {
+                if self.is_stale(&market_data) {
+                    self.record_stale_tick(&market_data);
+                    continue;
+                }
+                self.finish_stale_streak();
                 self.process_market_data(market_data).await?;
             }
         }
@@ -75,76 +271,244 @@ impl StrategyEngine {
         self.is_running = false;
     }
 
+    fn is_stale(&self, data: &MarketData) -> bool {
+        chrono::Utc::now() - data.received_at > self.max_tick_age
+    }
+
+    fn record_stale_tick(&mut self, data: &MarketData) {
+        self.stale_ticks_skipped += 1;
+        self.stale_streak += 1;
+        if self.stale_streak == 1 {
+            warn!(
+                "⏩ StrategyEngine falling behind feed: skipping stale tick for {} ({}ms old)",
+                data.symbol,
+                (chrono::Utc::now() - data.received_at).num_milliseconds()
+            );
+        }
+        if let Some(monitoring) = &self.monitoring {
+            monitoring.record_stale_tick_skipped();
+        }
+    }
+
+    fn finish_stale_streak(&mut self) {
+        if self.stale_streak > 0 {
+            info!(
+                "✅ StrategyEngine caught up after skipping {} stale ticks",
+                self.stale_streak
+            );
+            self.stale_streak = 0;
+        }
+    }
+
     async fn process_market_data(&self, data: MarketData) -> Result<()> {
         debug!("Processing market data for symbol: {}", data.symbol);
+        if let Some(prices) = &self.market_prices {
+            prices.record_market_data(&data);
+        }
+        if let Some(capture) = &self.capture {
+            capture.record_market_data(&data);
+        }
 
-        // TODO: Implement actual trading strategies
-        // For now, generate a simple signal occasionally
-        if data.price > 105.0 {
-            // Simple condition instead of random
-            let quantity = 100.0;
+        for strategy in self.router.route(&data) {
+            let signal = match &self.sandbox {
+                Some(sandbox) => {
+                    run_sandboxed(sandbox, strategy, || self.strategy_signal(strategy, &data))
+                        .flatten()
+                }
+                None => self.strategy_signal(strategy, &data),
+            };
+            self.compare_shadow(strategy, &data, signal.as_ref());
 
-            // Estimate liquidity (in a real implementation, this would come from market data)
-            let estimated_liquidity = data.volume * 0.1; // Simplified estimation
+            if let Some(signal) = signal {
+                self.dispatch_signal(signal);
+            }
+        }
 
-            // Calculate expected slippage
-            let slippage = self.calculate_slippage(quantity, estimated_liquidity, data.price);
+        Ok(())
+    }
 
-            // Adjust target price based on slippage
-            let target_price = data.price * (1.01 + slippage);
+    fn compare_shadow(
+        &self,
+        strategy: &StrategyType,
+        data: &MarketData,
+        live: Option<&TradingSignal>,
+    ) {
+        let Some((shadow, divergence)) = &self.shadow else {
+            return;
+        };
+        let shadow_action = decided_action(shadow.signal(strategy, data).as_ref());
+        if let Ok(mut divergence) = divergence.lock() {
+            divergence.record(
+                &data.symbol,
+                strategy,
+                &decided_action(live),
+                &shadow_action,
+            );
+        }
+    }
 
-            let signal = TradingSignal {
-                signal_id: uuid::Uuid::new_v4().to_string(),
-                symbol: data.symbol,
-                action: TradeAction::Buy,
-                quantity,
-                target_price,
-                confidence: 0.7 * (1.0 - slippage), // Lower confidence with higher slippage
-                timestamp: chrono::Utc::now(),
-                strategy_type: StrategyType::TokenSniping,
-            };
+    /// Persist a strategy decision and forward it for execution unless it is a Hold
+    fn dispatch_signal(&self, signal: TradingSignal) {
+        let is_hold = signal.action == TradeAction::Hold;
+        if is_hold && !self.record_hold_decisions {
+            return;
+        }
+        if self.observe_only.contains(&signal.strategy_type) {
+            self.observe_signal(signal);
+            return;
+        }
 
-            if let Err(e) = self.signal_sender.send(signal) {
-                error!("Failed to send trading signal: {}", e);
+        if let Some(sender) = &self.persistence_sender {
+            if let Err(e) = sender.send(PersistenceMessage::TradingSignal(signal.clone())) {
+                debug!("Trading signal not persisted: {}", e);
+            }
+        }
+        if let Some(capture) = &self.capture {
+            capture.record_signal(&signal);
+            if is_hold {
+                capture.complete(&signal.signal_id);
             }
         }
 
-        Ok(())
+        if is_hold {
+            debug!(
+                "⏸️ {:?} decided to hold {}",
+                signal.strategy_type, signal.symbol
+            );
+            if let Some(monitoring) = &self.monitoring {
+                monitoring.record_hold_decision();
+            }
+            return;
+        }
+        if let Err(e) = self.signal_sender.send(signal) {
+            error!("Failed to send trading signal: {}", e);
+        }
+    }
+
+    /// Log and persist an observe-only strategy's signal; it is dropped before the risk channel
+    fn observe_signal(&self, signal: TradingSignal) {
+        info!(
+            "👁️ Observed {:?} signal {}: {:?} {} x{} @ {} (confidence {:.2})",
+            signal.strategy_type,
+            signal.signal_id,
+            signal.action,
+            signal.symbol,
+            signal.quantity,
+            signal.target_price,
+            signal.confidence
+        );
+        if let Some(sender) = &self.persistence_sender {
+            if let Err(e) = sender.send(PersistenceMessage::ObservedSignal(signal)) {
+                debug!("Observed signal not persisted: {}", e);
+            }
+        }
+    }
+
+    /// Strategies with a signal implementation in `strategy_signal`
+    fn runs_strategy(strategy: &StrategyType) -> bool {
+        matches!(strategy, StrategyType::TokenSniping)
+    }
+
+    fn strategy_signal(&self, strategy: &StrategyType, data: &MarketData) -> Option<TradingSignal> {
+        match strategy {
+            StrategyType::TokenSniping => self.token_sniping_signal(data),
+            // Other strategies run in their own modules and do not consume engine ticks yet
+            _ => None,
+        }
+    }
+
+    fn token_sniping_signal(&self, data: &MarketData) -> Option<TradingSignal> {
+        token_sniping_signal(&TokenSnipingParams::default(), data)
     }
 
     /// Calculates expected slippage for a given order size and liquidity
     pub fn calculate_slippage(&self, order_size: f64, liquidity: f64, price: f64) -> f64 {
-        // Guard against division by zero
-        if liquidity <= 0.0 {
-            return 1.0; // 100% slippage for zero liquidity
-        }
-
-        // Calculate impact ratio (order size relative to available liquidity)
-        let impact_ratio = order_size / liquidity;
-
-        // Apply non-linear slippage model
-        // Small orders: minimal slippage
-        // Large orders: exponentially increasing slippage
-        let base_slippage = impact_ratio.min(0.5);
-
-        // Apply additional factors based on price volatility
-        // This is a simplified model - can be enhanced with historical volatility
-        let price_factor = if price < 0.01 {
-            // Micro-cap tokens have higher slippage
-            1.5
-        } else if price < 1.0 {
-            // Low-priced tokens
-            1.2
-        } else {
-            // Higher-priced tokens
-            1.0
-        };
+        expected_slippage(order_size, liquidity, price)
+    }
+}
 
-        // Return slippage as a percentage (0.0 to 1.0)
-        (base_slippage * price_factor).min(1.0)
+/// Token sniping parameters; the live engine runs the defaults, a shadow candidate its own
+#[derive(Debug, Clone)]
+pub struct TokenSnipingParams {
+    /// Ticks priced above this trigger a buy
+    pub entry_price: f64,
+    pub quantity: f64,
+}
+
+impl Default for TokenSnipingParams {
+    fn default() -> Self {
+        Self {
+            entry_price: 105.0,
+            quantity: 100.0,
+        }
+    }
+}
+
+pub fn token_sniping_signal(params: &TokenSnipingParams, data: &MarketData) -> Option<TradingSignal> {
+    // TODO: Implement actual trading strategies
+    // For now, generate a simple signal occasionally
+    if data.price > params.entry_price {
+        // Simple condition instead of random
+        let quantity = params.quantity;
+
+        // Estimate liquidity (in a real implementation, this would come from market data)
+        let estimated_liquidity = data.volume * 0.1; // Simplified estimation
+
+        // Calculate expected slippage
+        let slippage = expected_slippage(quantity, estimated_liquidity, data.price);
+
+        // Adjust target price based on slippage
+        let target_price = data.price * (1.01 + slippage);
+
+        Some(TradingSignal {
+            signal_id: uuid::Uuid::new_v4().to_string(),
+            symbol: data.symbol.clone(),
+            action: TradeAction::Buy,
+            quantity,
+            target_price,
+            confidence: 0.7 * (1.0 - slippage), // Lower confidence with higher slippage
+            timestamp: chrono::Utc::now(),
+            strategy_type: StrategyType::TokenSniping,
+            tick_received_at: Some(data.received_at),
+            pool_liquidity: Some(estimated_liquidity * data.price),
+        })
+    } else {
+        None
     }
 }
 
+/// Calculates expected slippage for a given order size and liquidity
+fn expected_slippage(order_size: f64, liquidity: f64, price: f64) -> f64 {
+    // Guard against division by zero
+    if liquidity <= 0.0 {
+        return 1.0; // 100% slippage for zero liquidity
+    }
+
+    // Calculate impact ratio (order size relative to available liquidity)
+    let impact_ratio = order_size / liquidity;
+
+    // Apply non-linear slippage model
+    // Small orders: minimal slippage
+    // Large orders: exponentially increasing slippage
+    let base_slippage = impact_ratio.min(0.5);
+
+    // Apply additional factors based on price volatility
+    // This is a simplified model - can be enhanced with historical volatility
+    let price_factor = if price < 0.01 {
+        // Micro-cap tokens have higher slippage
+        1.5
+    } else if price < 1.0 {
+        // Low-priced tokens
+        1.2
+    } else {
+        // Higher-priced tokens
+        1.0
+    };
+
+    // Return slippage as a percentage (0.0 to 1.0)
+    (base_slippage * price_factor).min(1.0)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -158,6 +522,134 @@ mod tests {
         assert!(!engine.is_running);
     }
 
+    #[tokio::test]
+    async fn test_stale_ticks_skipped() {
+        let (market_tx, market_rx) = mpsc::unbounded_channel();
+        let (signal_tx, mut signal_rx) = mpsc::unbounded_channel();
+        let mut engine = StrategyEngine::new(market_rx, signal_tx).with_max_tick_age_ms(100);
+
+        let tick = |age_ms: i64| MarketData {
+            symbol: "SOL/USDC".to_string(),
+            price: 110.0,
+            volume: 10_000.0,
+            timestamp: chrono::Utc::now(),
+            source: crate::modules::data_ingestor::DataSource::Helius,
+            received_at: chrono::Utc::now() - chrono::Duration::milliseconds(age_ms),
+        };
+        market_tx.send(tick(5_000)).unwrap();
+        market_tx.send(tick(1_000)).unwrap();
+        market_tx.send(tick(0)).unwrap();
+
+        // Run until the backlog is drained and the engine waits for new ticks
+        let _ = tokio::time::timeout(std::time::Duration::from_millis(200), engine.start()).await;
+
+        assert_eq!(engine.stale_ticks_skipped(), 2);
+        assert!(signal_rx.try_recv().is_ok());
+        assert!(signal_rx.try_recv().is_err());
+    }
+
+    #[test]
+    fn test_hold_decisions_are_recorded_but_not_executed() {
+        let (_market_tx, market_rx) = mpsc::unbounded_channel();
+        let (signal_tx, mut signal_rx) = mpsc::unbounded_channel();
+        let (persistence_tx, mut persistence_rx) = mpsc::unbounded_channel();
+        let engine = StrategyEngine::new(market_rx, signal_tx).with_persistence(persistence_tx);
+
+        let decision = |action: TradeAction| TradingSignal {
+            signal_id: uuid::Uuid::new_v4().to_string(),
+            symbol: "SOL/USDC".to_string(),
+            action,
+            quantity: 0.0,
+            target_price: 100.0,
+            confidence: 0.5,
+            timestamp: chrono::Utc::now(),
+            strategy_type: StrategyType::TokenSniping,
+            tick_received_at: None,
+            pool_liquidity: None,
+        };
+
+        engine.dispatch_signal(decision(TradeAction::Hold));
+        assert!(signal_rx.try_recv().is_err());
+        assert!(matches!(
+            persistence_rx.try_recv(),
+            Ok(PersistenceMessage::TradingSignal(signal)) if signal.action == TradeAction::Hold
+        ));
+
+        engine.dispatch_signal(decision(TradeAction::Buy));
+        assert_eq!(signal_rx.try_recv().unwrap().action, TradeAction::Buy);
+
+        // Holds are dropped entirely when not recorded
+        let engine = engine.with_hold_decisions(false);
+        engine.dispatch_signal(decision(TradeAction::Hold));
+        assert!(persistence_rx.try_recv().is_ok()); // the Buy above
+        assert!(persistence_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_observe_only_signals_are_recorded_but_not_routed() {
+        let (_market_tx, market_rx) = mpsc::unbounded_channel();
+        let (signal_tx, mut signal_rx) = mpsc::unbounded_channel();
+        let (persistence_tx, mut persistence_rx) = mpsc::unbounded_channel();
+        let engine = StrategyEngine::new(market_rx, signal_tx)
+            .with_persistence(persistence_tx)
+            .with_observe_only(StrategyType::TokenSniping);
+
+        engine
+            .process_market_data(MarketData {
+                symbol: "SOL/USDC".to_string(),
+                price: 110.0,
+                volume: 10_000.0,
+                timestamp: chrono::Utc::now(),
+                source: crate::modules::data_ingestor::DataSource::Helius,
+                received_at: chrono::Utc::now(),
+            })
+            .await
+            .unwrap();
+
+        assert!(signal_rx.try_recv().is_err());
+        assert!(matches!(
+            persistence_rx.try_recv(),
+            Ok(PersistenceMessage::ObservedSignal(signal)) if signal.action == TradeAction::Buy
+        ));
+        assert!(persistence_rx.try_recv().is_err());
+    }
+
+    #[tokio::test]
+    async fn test_shadow_decisions_are_compared_but_not_executed() {
+        use crate::modules::shadow_divergence::{ShadowDivergence, ShadowDivergenceConfig};
+
+        struct NeverTrades;
+        impl ShadowStrategy for NeverTrades {
+            fn signal(&self, _: &StrategyType, _: &MarketData) -> Option<TradingSignal> {
+                None
+            }
+        }
+
+        let (_market_tx, market_rx) = mpsc::unbounded_channel();
+        let (signal_tx, mut signal_rx) = mpsc::unbounded_channel();
+        let divergence = ShadowDivergence::shared(ShadowDivergenceConfig::default());
+        let engine = StrategyEngine::new(market_rx, signal_tx)
+            .with_shadow_strategy(Arc::new(NeverTrades), divergence.clone());
+
+        let tick = |price: f64| MarketData {
+            symbol: "SOL/USDC".to_string(),
+            price,
+            volume: 10_000.0,
+            timestamp: chrono::Utc::now(),
+            source: crate::modules::data_ingestor::DataSource::Helius,
+            received_at: chrono::Utc::now(),
+        };
+        engine.process_market_data(tick(110.0)).await.unwrap();
+        engine.process_market_data(tick(100.0)).await.unwrap();
+
+        // Only the live Buy is executed
+        assert_eq!(signal_rx.try_recv().unwrap().action, TradeAction::Buy);
+        assert!(signal_rx.try_recv().is_err());
+        let status = divergence.lock().unwrap().status();
+        assert_eq!(status.ticks_compared, 2);
+        assert_eq!(status.disagreement_rate, 0.5);
+    }
+
     #[test]
     fn test_calculate_slippage() {
         // Create a minimal StrategyEngine for testing
@@ -0,0 +1,304 @@
+// Strategy Cool-down Module
+// Pauses a strategy after a streak of losing trades and re-enables it later,
+// and holds it off a symbol for a while after each fill
+
+use crate::modules::strategy::StrategyType;
+use crate::modules::symbol_resolver::TokenPair;
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LosingStreakConfig {
+    /// Consecutive losing trades that trigger a cool-down (0 disables the feature)
+    pub max_consecutive_losses: u32,
+    pub cooldown_secs: u64,
+}
+
+impl Default for LosingStreakConfig {
+    fn default() -> Self {
+        Self {
+            max_consecutive_losses: 5,
+            cooldown_secs: 1800,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+struct StreakState {
+    consecutive_losses: u32,
+    disabled_until: Option<DateTime<Utc>>,
+}
+
+/// Queryable streak and cool-down state for a single strategy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyCooldownStatus {
+    pub strategy: StrategyType,
+    pub consecutive_losses: u32,
+    pub disabled_until: Option<DateTime<Utc>>,
+    pub cooldown_remaining_secs: i64,
+}
+
+/// Per-strategy losing-streak tracker fed with realized P&L per trade
+#[derive(Debug)]
+pub struct StrategyCooldowns {
+    config: LosingStreakConfig,
+    states: HashMap<StrategyType, StreakState>,
+}
+
+pub type SharedStrategyCooldowns = Arc<Mutex<StrategyCooldowns>>;
+
+#[allow(dead_code)]
+impl StrategyCooldowns {
+    pub fn new(config: LosingStreakConfig) -> Self {
+        Self {
+            config,
+            states: HashMap::new(),
+        }
+    }
+
+    pub fn shared(config: LosingStreakConfig) -> SharedStrategyCooldowns {
+        Arc::new(Mutex::new(Self::new(config)))
+    }
+
+    /// Attribute a closed trade's realized P&L to its strategy.
+    /// Returns true when this trade started a cool-down.
+    pub fn record_trade(&mut self, strategy: &StrategyType, realized_pnl: f64) -> bool {
+        let now = Utc::now();
+        self.expire_cooldown(strategy, now);
+
+        let config = self.config.clone();
+        let state = self.states.entry(strategy.clone()).or_default();
+
+        if realized_pnl >= 0.0 {
+            state.consecutive_losses = 0;
+            return false;
+        }
+
+        state.consecutive_losses += 1;
+        if config.max_consecutive_losses == 0
+            || state.consecutive_losses < config.max_consecutive_losses
+            || state.disabled_until.is_some()
+        {
+            return false;
+        }
+
+        let until = now + chrono::Duration::seconds(config.cooldown_secs as i64);
+        state.disabled_until = Some(until);
+        warn!(
+            "⏸️ Strategy {:?} paused after {} consecutive losing trades (until {})",
+            strategy, state.consecutive_losses, until
+        );
+        true
+    }
+
+    /// Whether the strategy may trade, re-enabling it once its cool-down has elapsed
+    pub fn is_enabled(&mut self, strategy: &StrategyType) -> bool {
+        self.expire_cooldown(strategy, Utc::now());
+        self.states
+            .get(strategy)
+            .is_none_or(|s| s.disabled_until.is_none())
+    }
+
+    /// Manually re-enable a strategy and reset its losing streak
+    pub fn reset(&mut self, strategy: &StrategyType) {
+        self.states.remove(strategy);
+        info!("▶️ Strategy {:?} manually re-enabled", strategy);
+    }
+
+    pub fn status(&self, strategy: &StrategyType) -> StrategyCooldownStatus {
+        let now = Utc::now();
+        let state = self.states.get(strategy).cloned().unwrap_or_default();
+        let disabled_until = state.disabled_until.filter(|until| *until > now);
+
+        StrategyCooldownStatus {
+            strategy: strategy.clone(),
+            consecutive_losses: state.consecutive_losses,
+            disabled_until,
+            cooldown_remaining_secs: disabled_until.map_or(0, |until| (until - now).num_seconds()),
+        }
+    }
+
+    pub fn all_status(&self) -> Vec<StrategyCooldownStatus> {
+        self.states.keys().map(|s| self.status(s)).collect()
+    }
+
+    fn expire_cooldown(&mut self, strategy: &StrategyType, now: DateTime<Utc>) {
+        if let Some(state) = self.states.get_mut(strategy) {
+            if matches!(state.disabled_until, Some(until) if until <= now) {
+                state.disabled_until = None;
+                state.consecutive_losses = 0;
+                info!("▶️ Strategy {:?} re-enabled after cool-down", strategy);
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct ExecutionCooldown {
+    symbol: String,
+    until: DateTime<Utc>,
+}
+
+/// An active post-execution cool-down
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionCooldownStatus {
+    pub strategy: StrategyType,
+    pub symbol: String,
+    pub token_pair: TokenPair,
+    pub until: DateTime<Utc>,
+    pub cooldown_remaining_secs: i64,
+}
+
+/// Blocks a strategy from re-trading a symbol for a window after a fill,
+/// so noisy ticks cannot make it flip the same position repeatedly
+#[derive(Debug)]
+pub struct ExecutionCooldowns {
+    window: chrono::Duration,
+    active: HashMap<(StrategyType, TokenPair), ExecutionCooldown>,
+}
+
+pub type SharedExecutionCooldowns = Arc<Mutex<ExecutionCooldowns>>;
+
+#[allow(dead_code)]
+impl ExecutionCooldowns {
+    /// A window of 0 seconds disables post-execution cool-downs
+    pub fn new(window_secs: u64) -> Self {
+        Self {
+            window: chrono::Duration::seconds(window_secs as i64),
+            active: HashMap::new(),
+        }
+    }
+
+    pub fn shared(window_secs: u64) -> SharedExecutionCooldowns {
+        Arc::new(Mutex::new(Self::new(window_secs)))
+    }
+
+    /// Start the cool-down after `strategy` filled a trade on `token_pair`
+    pub fn record_execution(
+        &mut self,
+        strategy: &StrategyType,
+        symbol: &str,
+        token_pair: TokenPair,
+    ) {
+        if self.window <= chrono::Duration::zero() {
+            return;
+        }
+
+        let until = Utc::now() + self.window;
+        self.active.insert(
+            (strategy.clone(), token_pair),
+            ExecutionCooldown {
+                symbol: symbol.to_string(),
+                until,
+            },
+        );
+        info!(
+            "⏳ Strategy {:?} cooling down on {} until {}",
+            strategy, symbol, until
+        );
+    }
+
+    /// Whether `strategy` recently executed on `token_pair`
+    pub fn is_cooling_down(&mut self, strategy: &StrategyType, token_pair: &TokenPair) -> bool {
+        let now = Utc::now();
+        self.active.retain(|_, cooldown| cooldown.until > now);
+        self.active.contains_key(&(strategy.clone(), *token_pair))
+    }
+
+    pub fn active(&self) -> Vec<ExecutionCooldownStatus> {
+        let now = Utc::now();
+        self.active
+            .iter()
+            .filter(|(_, cooldown)| cooldown.until > now)
+            .map(
+                |((strategy, token_pair), cooldown)| ExecutionCooldownStatus {
+                    strategy: strategy.clone(),
+                    symbol: cooldown.symbol.clone(),
+                    token_pair: *token_pair,
+                    until: cooldown.until,
+                    cooldown_remaining_secs: (cooldown.until - now).num_seconds(),
+                },
+            )
+            .collect()
+    }
+}
+
+/// Parse a strategy name as used in HTTP paths (e.g. "TokenSniping")
+pub fn parse_strategy_type(name: &str) -> Result<StrategyType> {
+    serde_json::from_value(serde_json::Value::String(name.to_string()))
+        .map_err(|_| anyhow!("Unknown strategy: {}", name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_losing_streak_triggers_cooldown() {
+        let mut cooldowns = StrategyCooldowns::new(LosingStreakConfig {
+            max_consecutive_losses: 3,
+            cooldown_secs: 600,
+        });
+        let strategy = StrategyType::TokenSniping;
+
+        assert!(!cooldowns.record_trade(&strategy, -1.0));
+        assert!(!cooldowns.record_trade(&strategy, -1.0));
+        // A winning trade breaks the streak
+        assert!(!cooldowns.record_trade(&strategy, 0.5));
+        assert!(!cooldowns.record_trade(&strategy, -1.0));
+        assert!(!cooldowns.record_trade(&strategy, -1.0));
+        assert!(cooldowns.is_enabled(&strategy));
+
+        assert!(cooldowns.record_trade(&strategy, -1.0));
+        assert!(!cooldowns.is_enabled(&strategy));
+        assert!(cooldowns.is_enabled(&StrategyType::Arbitrage));
+
+        let status = cooldowns.status(&strategy);
+        assert_eq!(status.consecutive_losses, 3);
+        assert!(status.cooldown_remaining_secs > 590);
+
+        cooldowns.reset(&strategy);
+        assert!(cooldowns.is_enabled(&strategy));
+        assert_eq!(cooldowns.status(&strategy).consecutive_losses, 0);
+    }
+
+    #[test]
+    fn test_execution_cooldown_per_strategy_and_symbol() {
+        let mut cooldowns = ExecutionCooldowns::new(60);
+        let sol_usdc = TokenPair {
+            base_mint: solana_sdk::pubkey::Pubkey::new_unique(),
+            quote_mint: solana_sdk::pubkey::Pubkey::new_unique(),
+        };
+        let other = TokenPair {
+            base_mint: solana_sdk::pubkey::Pubkey::new_unique(),
+            quote_mint: sol_usdc.quote_mint,
+        };
+
+        cooldowns.record_execution(&StrategyType::MomentumTrading, "SOL/USDC", sol_usdc);
+        assert!(cooldowns.is_cooling_down(&StrategyType::MomentumTrading, &sol_usdc));
+        // Other symbols and other strategies on the same symbol are unaffected
+        assert!(!cooldowns.is_cooling_down(&StrategyType::MomentumTrading, &other));
+        assert!(!cooldowns.is_cooling_down(&StrategyType::Arbitrage, &sol_usdc));
+
+        let active = cooldowns.active();
+        assert_eq!(active.len(), 1);
+        assert_eq!(active[0].symbol, "SOL/USDC");
+
+        let mut disabled = ExecutionCooldowns::new(0);
+        disabled.record_execution(&StrategyType::MomentumTrading, "SOL/USDC", sol_usdc);
+        assert!(!disabled.is_cooling_down(&StrategyType::MomentumTrading, &sol_usdc));
+    }
+
+    #[test]
+    fn test_parse_strategy_type() {
+        assert_eq!(
+            parse_strategy_type("AIDecision").unwrap(),
+            StrategyType::AIDecision
+        );
+        assert!(parse_strategy_type("NotAStrategy").is_err());
+    }
+}
@@ -0,0 +1,186 @@
+// Strategy Routing Module
+// Dispatches each market tick only to the strategies whose symbol and source filter
+// matches, through an index built when filters are registered rather than per tick
+
+use crate::modules::data_ingestor::{DataSource, MarketData};
+use crate::modules::strategy::StrategyType;
+use crate::modules::strategy_cooldown::parse_strategy_type;
+use anyhow::{anyhow, Context, Result};
+use std::collections::{HashMap, HashSet};
+
+/// Market data a strategy wants to see; empty sets match everything
+#[derive(Debug, Clone, Default)]
+pub struct MarketDataFilter {
+    pub symbols: HashSet<String>,
+    pub sources: Vec<DataSource>,
+}
+
+#[allow(dead_code)]
+impl MarketDataFilter {
+    pub fn symbols<S: Into<String>>(symbols: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            symbols: symbols.into_iter().map(Into::into).collect(),
+            sources: Vec::new(),
+        }
+    }
+
+    pub fn with_sources(mut self, sources: Vec<DataSource>) -> Self {
+        self.sources = sources;
+        self
+    }
+
+    fn matches_source(&self, source: &DataSource) -> bool {
+        self.sources.is_empty() || self.sources.contains(source)
+    }
+}
+
+/// Parse filters such as "TokenSniping=BONK/USDC|WIF/USDC|@Helius,Arbitrage=SOL/USDC";
+/// entries starting with `@` name a data source instead of a symbol
+pub fn parse_market_filters(spec: &str) -> Result<Vec<(StrategyType, MarketDataFilter)>> {
+    spec.split(',')
+        .filter(|entry| !entry.trim().is_empty())
+        .map(|entry| {
+            let (strategy, items) = entry
+                .split_once('=')
+                .with_context(|| format!("Invalid market filter entry: {}", entry))?;
+            let mut filter = MarketDataFilter::default();
+            for item in items.split('|').map(str::trim).filter(|i| !i.is_empty()) {
+                match item.strip_prefix('@') {
+                    Some(source) => filter.sources.push(
+                        serde_json::from_value(serde_json::Value::String(source.to_string()))
+                            .map_err(|_| anyhow!("Unknown data source: {}", source))?,
+                    ),
+                    None => {
+                        filter.symbols.insert(item.to_string());
+                    }
+                }
+            }
+            Ok((parse_strategy_type(strategy.trim())?, filter))
+        })
+        .collect()
+}
+
+/// Strategy filters with a precomputed symbol index
+#[derive(Debug, Clone, Default)]
+pub struct StrategyRouter {
+    /// Registration order, which is also dispatch order
+    routes: Vec<(StrategyType, MarketDataFilter)>,
+    by_symbol: HashMap<String, Vec<usize>>,
+    any_symbol: Vec<usize>,
+}
+
+#[allow(dead_code)]
+impl StrategyRouter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a strategy, replacing its previous filter
+    pub fn with_strategy(mut self, strategy: StrategyType, filter: MarketDataFilter) -> Self {
+        match self.routes.iter_mut().find(|(s, _)| *s == strategy) {
+            Some(route) => route.1 = filter,
+            None => self.routes.push((strategy, filter)),
+        }
+        self.rebuild_index();
+        self
+    }
+
+    /// Stop dispatching ticks to `strategy`
+    pub fn without_strategy(mut self, strategy: &StrategyType) -> Self {
+        self.routes.retain(|(s, _)| s != strategy);
+        self.rebuild_index();
+        self
+    }
+
+    fn rebuild_index(&mut self) {
+        self.by_symbol.clear();
+        self.any_symbol.clear();
+        for (index, (_, filter)) in self.routes.iter().enumerate() {
+            if filter.symbols.is_empty() {
+                self.any_symbol.push(index);
+            }
+            for symbol in &filter.symbols {
+                self.by_symbol
+                    .entry(symbol.clone())
+                    .or_default()
+                    .push(index);
+            }
+        }
+    }
+
+    /// Strategies interested in `data`, in registration order
+    pub fn route(&self, data: &MarketData) -> Vec<&StrategyType> {
+        let mut indices: Vec<usize> = self
+            .by_symbol
+            .get(&data.symbol)
+            .into_iter()
+            .flatten()
+            .chain(&self.any_symbol)
+            .copied()
+            .collect();
+        indices.sort_unstable();
+
+        indices
+            .into_iter()
+            .map(|index| &self.routes[index])
+            .filter(|(_, filter)| filter.matches_source(&data.source))
+            .map(|(strategy, _)| strategy)
+            .collect()
+    }
+
+    pub fn strategies(&self) -> impl Iterator<Item = &StrategyType> {
+        self.routes.iter().map(|(strategy, _)| strategy)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tick(symbol: &str, source: DataSource) -> MarketData {
+        MarketData {
+            symbol: symbol.to_string(),
+            price: 1.0,
+            volume: 1.0,
+            timestamp: chrono::Utc::now(),
+            source,
+            received_at: chrono::Utc::now(),
+        }
+    }
+
+    #[test]
+    fn test_ticks_reach_only_matching_strategies() {
+        let mut router =
+            StrategyRouter::new().with_strategy(StrategyType::TokenSniping, Default::default());
+        for (strategy, filter) in
+            parse_market_filters("Arbitrage=SOL/USDC|JUP/USDC,AxiomMemeCoin=BONK/USDC|@Helius")
+                .unwrap()
+        {
+            router = router.with_strategy(strategy, filter);
+        }
+
+        assert_eq!(
+            router.route(&tick("SOL/USDC", DataSource::QuickNode)),
+            vec![&StrategyType::TokenSniping, &StrategyType::Arbitrage]
+        );
+        assert_eq!(
+            router.route(&tick("BONK/USDC", DataSource::Helius)),
+            vec![&StrategyType::TokenSniping, &StrategyType::AxiomMemeCoin]
+        );
+        // Wrong source for the memecoin strategy
+        assert_eq!(
+            router.route(&tick("BONK/USDC", DataSource::QuickNode)),
+            vec![&StrategyType::TokenSniping]
+        );
+
+        // Narrowing a registered strategy replaces its filter
+        router = router.with_strategy(
+            StrategyType::TokenSniping,
+            MarketDataFilter::symbols(["WIF/USDC"]),
+        );
+        assert!(router
+            .route(&tick("JTO/USDC", DataSource::Helius))
+            .is_empty());
+        assert!(parse_market_filters("Arbitrage=SOL/USDC|@Binance").is_err());
+    }
+}
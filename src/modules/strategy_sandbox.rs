@@ -0,0 +1,171 @@
+// Strategy Sandbox Module
+// Catches panics inside individual strategies so one faulty strategy cannot stop the engine
+
+use crate::modules::strategy::StrategyType;
+use serde::{Deserialize, Serialize};
+use std::any::Any;
+use std::collections::HashMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex};
+use tracing::{error, warn};
+
+#[derive(Debug, Clone)]
+pub struct StrategySandboxConfig {
+    /// Panics tolerated before the strategy is disabled (1 disables on the first panic)
+    pub max_panics: u32,
+}
+
+impl Default for StrategySandboxConfig {
+    fn default() -> Self {
+        Self { max_panics: 1 }
+    }
+}
+
+/// Panic history of one strategy, exposed through the HTTP API
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyPanicStatus {
+    pub strategy_type: StrategyType,
+    pub panic_count: u32,
+    pub disabled: bool,
+    pub last_panic: Option<String>,
+    pub last_panic_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[derive(Debug)]
+pub struct StrategySandbox {
+    config: StrategySandboxConfig,
+    strategies: HashMap<StrategyType, StrategyPanicStatus>,
+}
+
+pub type SharedStrategySandbox = Arc<Mutex<StrategySandbox>>;
+
+#[allow(dead_code)]
+impl StrategySandbox {
+    pub fn new(config: StrategySandboxConfig) -> Self {
+        Self {
+            config,
+            strategies: HashMap::new(),
+        }
+    }
+
+    pub fn shared(config: StrategySandboxConfig) -> SharedStrategySandbox {
+        Arc::new(Mutex::new(Self::new(config)))
+    }
+
+    pub fn is_disabled(&self, strategy: &StrategyType) -> bool {
+        self.strategies
+            .get(strategy)
+            .is_some_and(|status| status.disabled)
+    }
+
+    /// Record a caught panic; returns true when it disabled the strategy
+    pub fn record_panic(&mut self, strategy: &StrategyType, message: String) -> bool {
+        let max_panics = self.config.max_panics.max(1);
+        let status =
+            self.strategies
+                .entry(strategy.clone())
+                .or_insert_with(|| StrategyPanicStatus {
+                    strategy_type: strategy.clone(),
+                    panic_count: 0,
+                    disabled: false,
+                    last_panic: None,
+                    last_panic_at: None,
+                });
+
+        status.panic_count += 1;
+        status.last_panic = Some(message);
+        status.last_panic_at = Some(chrono::Utc::now());
+
+        let newly_disabled = !status.disabled && status.panic_count >= max_panics;
+        status.disabled |= newly_disabled;
+        newly_disabled
+    }
+
+    /// Re-enable a disabled strategy; its panic count is kept
+    pub fn enable(&mut self, strategy: &StrategyType) {
+        if let Some(status) = self.strategies.get_mut(strategy) {
+            status.disabled = false;
+        }
+    }
+
+    pub fn all_status(&self) -> Vec<StrategyPanicStatus> {
+        let mut statuses: Vec<_> = self.strategies.values().cloned().collect();
+        statuses.sort_by_key(|status| std::cmp::Reverse(status.panic_count));
+        statuses
+    }
+}
+
+/// Run one strategy invocation, catching a panic instead of unwinding into the engine.
+/// Returns None when the strategy is disabled or panicked.
+pub fn run_sandboxed<T>(
+    sandbox: &SharedStrategySandbox,
+    strategy: &StrategyType,
+    invocation: impl FnOnce() -> T,
+) -> Option<T> {
+    if sandbox
+        .lock()
+        .is_ok_and(|sandbox| sandbox.is_disabled(strategy))
+    {
+        return None;
+    }
+
+    // The lock is not held while the strategy runs
+    match panic::catch_unwind(AssertUnwindSafe(invocation)) {
+        Ok(output) => Some(output),
+        Err(payload) => {
+            let message = panic_message(payload.as_ref());
+            error!("💥 Strategy {:?} panicked: {}", strategy, message);
+            if let Ok(mut sandbox) = sandbox.lock() {
+                if sandbox.record_panic(strategy, message) {
+                    warn!(
+                        "🚫 Strategy {:?} disabled after {} panic(s); re-enable via /strategies/{:?}/enable",
+                        strategy,
+                        sandbox.config.max_panics.max(1),
+                        strategy
+                    );
+                }
+            }
+            None
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "non-string panic payload".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_panicking_strategy_is_caught_and_disabled() {
+        let sandbox = StrategySandbox::shared(StrategySandboxConfig { max_panics: 2 });
+        let strategy = StrategyType::MomentumTrading;
+
+        let first: Option<()> = run_sandboxed(&sandbox, &strategy, || panic!("bad tick"));
+        assert!(first.is_none());
+        assert!(!sandbox.lock().unwrap().is_disabled(&strategy));
+
+        let _: Option<()> = run_sandboxed(&sandbox, &strategy, || panic!("bad tick again"));
+        assert!(sandbox.lock().unwrap().is_disabled(&strategy));
+        // Disabled strategies are not invoked at all
+        assert_eq!(run_sandboxed(&sandbox, &strategy, || 1), None);
+        // Other strategies keep running
+        assert_eq!(
+            run_sandboxed(&sandbox, &StrategyType::TokenSniping, || 1),
+            Some(1)
+        );
+
+        let status = sandbox.lock().unwrap().all_status().remove(0);
+        assert_eq!(status.panic_count, 2);
+        assert_eq!(status.last_panic.as_deref(), Some("bad tick again"));
+
+        sandbox.lock().unwrap().enable(&strategy);
+        assert_eq!(run_sandboxed(&sandbox, &strategy, || 1), Some(1));
+    }
+}
@@ -0,0 +1,167 @@
+// Symbol Resolver Module
+// Canonicalizes human symbols ("SOL/USDC") and mint addresses to on-chain mint pairs
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::str::FromStr;
+
+pub const SOL_MINT: &str = "So11111111111111111111111111111111111111112";
+pub const USDC_MINT: &str = "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v";
+pub const USDT_MINT: &str = "Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB";
+
+/// Canonical on-chain representation of a trading symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct TokenPair {
+    pub base_mint: Pubkey,
+    pub quote_mint: Pubkey,
+}
+
+/// Entry of a Solana token-list JSON document
+#[derive(Debug, Deserialize)]
+struct TokenListEntry {
+    symbol: String,
+    address: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenList {
+    tokens: Vec<TokenListEntry>,
+}
+
+/// Maps the symbol formats used by data sources and strategies to mint pairs
+#[derive(Debug, Clone)]
+pub struct SymbolResolver {
+    tokens: HashMap<String, Pubkey>,
+    pair_aliases: HashMap<String, TokenPair>,
+    default_quote: Pubkey,
+}
+
+impl Default for SymbolResolver {
+    fn default() -> Self {
+        let mut tokens = HashMap::new();
+        for (symbol, mint) in [("SOL", SOL_MINT), ("USDC", USDC_MINT), ("USDT", USDT_MINT)] {
+            tokens.insert(
+                symbol.to_string(),
+                Pubkey::from_str(mint).expect("valid mint"),
+            );
+        }
+
+        Self {
+            tokens,
+            pair_aliases: HashMap::new(),
+            default_quote: Pubkey::from_str(USDC_MINT).expect("valid mint"),
+        }
+    }
+}
+
+#[allow(dead_code)]
+impl SymbolResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a token symbol → mint mapping
+    pub fn with_token(mut self, symbol: &str, mint: &str) -> Result<Self> {
+        let mint =
+            Pubkey::from_str(mint).with_context(|| format!("Invalid mint for {}", symbol))?;
+        self.tokens.insert(symbol.to_uppercase(), mint);
+        Ok(self)
+    }
+
+    /// Register a full symbol that maps directly to a pair (e.g. a venue-specific ticker)
+    pub fn with_pair_alias(mut self, symbol: &str, pair: TokenPair) -> Self {
+        self.pair_aliases.insert(symbol.to_uppercase(), pair);
+        self
+    }
+
+    /// Quote mint used when a symbol names only a base token
+    pub fn with_default_quote(mut self, quote: &str) -> Result<Self> {
+        self.default_quote = self.resolve_token(quote)?;
+        Ok(self)
+    }
+
+    /// Merge the tokens of a Solana token-list JSON document (`{"tokens": [...]}`)
+    pub fn with_token_list_json(mut self, json: &str) -> Result<Self> {
+        let list: TokenList = serde_json::from_str(json).context("Invalid token list")?;
+        for entry in list.tokens {
+            // Keep existing mappings so well-known symbols cannot be shadowed
+            if let Ok(mint) = Pubkey::from_str(&entry.address) {
+                self.tokens
+                    .entry(entry.symbol.to_uppercase())
+                    .or_insert(mint);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Resolve "BASE/QUOTE", "BASE-QUOTE", "BASE_QUOTE", a lone base token or mint
+    /// addresses to a canonical mint pair
+    pub fn resolve(&self, symbol: &str) -> Result<TokenPair> {
+        let symbol = symbol.trim();
+        if let Some(pair) = self.pair_aliases.get(&symbol.to_uppercase()) {
+            return Ok(*pair);
+        }
+
+        let legs: Vec<&str> = symbol.split(['/', '-', '_']).collect();
+        let pair = match legs.as_slice() {
+            [base] => TokenPair {
+                base_mint: self.resolve_token(base)?,
+                quote_mint: self.default_quote,
+            },
+            [base, quote] => TokenPair {
+                base_mint: self.resolve_token(base)?,
+                quote_mint: self.resolve_token(quote)?,
+            },
+            _ => return Err(anyhow!("Unrecognized symbol format: {}", symbol)),
+        };
+
+        if pair.base_mint == pair.quote_mint {
+            return Err(anyhow!("Symbol {} has identical base and quote", symbol));
+        }
+        Ok(pair)
+    }
+
+    fn resolve_token(&self, token: &str) -> Result<Pubkey> {
+        let token = token.trim();
+        if let Some(mint) = self.tokens.get(&token.to_uppercase()) {
+            return Ok(*mint);
+        }
+        Pubkey::from_str(token).map_err(|_| anyhow!("Unknown token: {}", token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_symbol_formats_unify() {
+        let resolver = SymbolResolver::new();
+        let expected = resolver.resolve("SOL/USDC").unwrap();
+
+        assert_eq!(resolver.resolve("sol-usdc").unwrap(), expected);
+        assert_eq!(resolver.resolve("SOL_USDC").unwrap(), expected);
+        assert_eq!(resolver.resolve("SOL").unwrap(), expected);
+        assert_eq!(
+            resolver
+                .resolve(&format!("{}/{}", SOL_MINT, USDC_MINT))
+                .unwrap(),
+            expected
+        );
+        assert_eq!(expected.base_mint.to_string(), SOL_MINT);
+    }
+
+    #[test]
+    fn test_unresolvable_symbols_rejected() {
+        let resolver = SymbolResolver::new()
+            .with_token_list_json(r#"{"tokens": [{"symbol": "BONK", "address": "DezXAZ8z7PnrnRJjz3wXBoRgixCa6xjnB7YaB1pPB263"}]}"#)
+            .unwrap();
+
+        assert!(resolver.resolve("BONK/SOL").is_ok());
+        assert!(resolver.resolve("NOTATOKEN/USDC").is_err());
+        assert!(resolver.resolve("SOL/SOL").is_err());
+        assert!(resolver.resolve("SOL/USDC/USDT").is_err());
+    }
+}
@@ -0,0 +1,151 @@
+// Target Price Module
+// Derives signal target prices from pool reserves and validates them against per-strategy bands
+
+use crate::modules::strategy::{StrategyType, TradingSignal};
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Constant-product pool reserves in UI units; prices are quote per base token
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PoolReserves {
+    pub base_reserve: f64,
+    pub quote_reserve: f64,
+}
+
+impl PoolReserves {
+    /// None for an empty or malformed pool
+    pub fn spot_price(&self) -> Option<f64> {
+        (self.base_reserve > 0.0 && self.quote_reserve > 0.0)
+            .then(|| self.quote_reserve / self.base_reserve)
+            .filter(|price| price.is_finite())
+    }
+
+    /// Average price paid when buying with `quote_in` against the pool (x * y = k)
+    pub fn buy_quote(&self, quote_in: f64) -> Option<f64> {
+        let spot = self.spot_price()?;
+        if quote_in <= 0.0 {
+            return Some(spot);
+        }
+        let base_out = self.base_reserve * quote_in / (self.quote_reserve + quote_in);
+        (base_out > 0.0).then(|| quote_in / base_out)
+    }
+}
+
+/// Inclusive range of target prices considered sane for a strategy
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TargetPriceBand {
+    pub min_price: f64,
+    pub max_price: f64,
+}
+
+impl Default for TargetPriceBand {
+    fn default() -> Self {
+        Self {
+            min_price: 1e-12,
+            max_price: 1e7,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TargetPriceConfig {
+    pub default_band: TargetPriceBand,
+    pub per_strategy: HashMap<StrategyType, TargetPriceBand>,
+}
+
+#[allow(dead_code)]
+impl TargetPriceConfig {
+    /// Parse per-strategy bands of the form "MeteoraDAMM=0.000000001:0.01,DeveloperTracking=0.0000001:1"
+    pub fn with_strategy_bands(mut self, spec: &str) -> Result<Self> {
+        for entry in spec.split(',').filter(|e| !e.trim().is_empty()) {
+            let (strategy, band) = entry
+                .split_once('=')
+                .with_context(|| format!("Invalid target price band entry: {}", entry))?;
+            let (min_price, max_price) = band
+                .split_once(':')
+                .with_context(|| format!("Invalid target price band: {}", band))?;
+            let band = TargetPriceBand {
+                min_price: min_price
+                    .trim()
+                    .parse()
+                    .context("Invalid minimum target price")?,
+                max_price: max_price
+                    .trim()
+                    .parse()
+                    .context("Invalid maximum target price")?,
+            };
+            if !(band.min_price > 0.0 && band.min_price <= band.max_price) {
+                return Err(anyhow!("Invalid target price band: {}", entry));
+            }
+            let strategy = crate::modules::strategy_cooldown::parse_strategy_type(strategy.trim())?;
+            self.per_strategy.insert(strategy, band);
+        }
+        Ok(self)
+    }
+
+    pub fn band_for(&self, strategy: &StrategyType) -> TargetPriceBand {
+        self.per_strategy
+            .get(strategy)
+            .copied()
+            .unwrap_or(self.default_band)
+    }
+
+    /// Ok when the price is positive, finite and inside the strategy's band
+    pub fn validate(&self, strategy: &StrategyType, price: f64) -> Result<f64> {
+        if !price.is_finite() || price <= 0.0 {
+            return Err(anyhow!("target price {} is not positive", price));
+        }
+        let band = self.band_for(strategy);
+        if price < band.min_price || price > band.max_price {
+            return Err(anyhow!(
+                "target price {} outside [{}, {}] for {:?}",
+                price,
+                band.min_price,
+                band.max_price,
+                strategy
+            ));
+        }
+        Ok(price)
+    }
+
+    pub fn validate_signal(&self, signal: &TradingSignal) -> Result<f64> {
+        self.validate(&signal.strategy_type, signal.target_price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pool_pricing_and_bands() {
+        let pool = PoolReserves {
+            base_reserve: 1_000_000.0,
+            quote_reserve: 100.0,
+        };
+        assert_eq!(pool.spot_price(), Some(0.0001));
+        // Buying moves the price against us
+        assert!(pool.buy_quote(10.0).unwrap() > 0.0001);
+        assert_eq!(
+            PoolReserves {
+                base_reserve: 0.0,
+                quote_reserve: 100.0
+            }
+            .spot_price(),
+            None
+        );
+
+        let config = TargetPriceConfig::default()
+            .with_strategy_bands("MeteoraDAMM=0.00000001:0.01")
+            .unwrap();
+        assert!(config.validate(&StrategyType::MeteoraDAMM, 0.0001).is_ok());
+        assert!(config.validate(&StrategyType::MeteoraDAMM, 5.0).is_err());
+        assert!(config.validate(&StrategyType::Arbitrage, 5.0).is_ok());
+        assert!(config.validate(&StrategyType::Arbitrage, 0.0).is_err());
+        assert!(config.validate(&StrategyType::Arbitrage, f64::NAN).is_err());
+        assert!(TargetPriceConfig::default()
+            .with_strategy_bands("MeteoraDAMM=1:0.5")
+            .is_err());
+    }
+}
@@ -0,0 +1,329 @@
+// Token Metadata Module
+// Warm, TTL-bounded cache of mint metadata (decimals, symbol, authorities, owning program)
+
+use anyhow::{anyhow, Context, Result};
+use futures::future::BoxFuture;
+use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+const TOKEN_PROGRAM_ID: Pubkey = solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+const TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+const METAPLEX_METADATA_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Length of the base SPL mint layout
+const MINT_LEN: usize = 82;
+/// Metaplex metadata: key byte, update authority and mint precede the name
+const METADATA_NAME_OFFSET: usize = 1 + 32 + 32;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TokenMetadata {
+    pub mint: Pubkey,
+    pub decimals: u8,
+    pub supply: u64,
+    /// From the Metaplex metadata account, when the mint has one
+    pub symbol: Option<String>,
+    pub mint_authority: Option<Pubkey>,
+    pub freeze_authority: Option<Pubkey>,
+    /// SPL Token or Token-2022
+    pub program_owner: Pubkey,
+}
+
+#[allow(dead_code)]
+impl TokenMetadata {
+    /// Convert raw base units into UI units
+    pub fn ui_amount(&self, raw_amount: u64) -> f64 {
+        raw_amount as f64 / 10f64.powi(self.decimals as i32)
+    }
+
+    pub fn is_token_2022(&self) -> bool {
+        self.program_owner == TOKEN_2022_PROGRAM_ID
+    }
+}
+
+fn read_coption_pubkey(data: &[u8], offset: usize) -> Option<Pubkey> {
+    let tag = data.get(offset..offset + 4)?;
+    if tag == [0, 0, 0, 0] {
+        return None;
+    }
+    data.get(offset + 4..offset + 36)
+        .and_then(|key| Pubkey::try_from(key).ok())
+}
+
+/// Decode the base SPL mint layout shared by both token programs
+pub fn parse_mint_account(mint: &Pubkey, owner: &Pubkey, data: &[u8]) -> Result<TokenMetadata> {
+    if *owner != TOKEN_PROGRAM_ID && *owner != TOKEN_2022_PROGRAM_ID {
+        return Err(anyhow!("Account is not a token mint (owner {})", owner));
+    }
+    if data.len() < MINT_LEN {
+        return Err(anyhow!("Mint account too short ({} bytes)", data.len()));
+    }
+
+    Ok(TokenMetadata {
+        mint: *mint,
+        mint_authority: read_coption_pubkey(data, 0),
+        supply: u64::from_le_bytes(data[36..44].try_into()?),
+        decimals: data[44],
+        freeze_authority: read_coption_pubkey(data, 46),
+        symbol: None,
+        program_owner: *owner,
+    })
+}
+
+/// Symbol from a Metaplex metadata account (borsh strings, NUL-padded)
+pub fn parse_metadata_symbol(data: &[u8]) -> Option<String> {
+    let read_string = |offset: usize| -> Option<(String, usize)> {
+        let len = u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?) as usize;
+        let bytes = data.get(offset + 4..offset + 4 + len)?;
+        let value = String::from_utf8_lossy(bytes)
+            .trim_end_matches('\0')
+            .trim()
+            .to_string();
+        Some((value, offset + 4 + len))
+    };
+
+    let (_name, symbol_offset) = read_string(METADATA_NAME_OFFSET)?;
+    let (symbol, _) = read_string(symbol_offset)?;
+    (!symbol.is_empty()).then_some(symbol)
+}
+
+/// Where mint metadata is fetched from on a cache miss
+pub trait TokenMetadataSource: Send + Sync {
+    fn fetch<'a>(&'a self, mint: &'a Pubkey) -> BoxFuture<'a, Result<TokenMetadata>>;
+}
+
+impl TokenMetadataSource for RpcClient {
+    fn fetch<'a>(&'a self, mint: &'a Pubkey) -> BoxFuture<'a, Result<TokenMetadata>> {
+        Box::pin(async move {
+            let (metadata_address, _) = Pubkey::find_program_address(
+                &[
+                    b"metadata",
+                    METAPLEX_METADATA_PROGRAM_ID.as_ref(),
+                    mint.as_ref(),
+                ],
+                &METAPLEX_METADATA_PROGRAM_ID,
+            );
+
+            // One round trip for the mint and its metadata account
+            let mut accounts = self
+                .get_multiple_accounts(&[*mint, metadata_address])
+                .await
+                .with_context(|| format!("Failed to fetch mint account {}", mint))?;
+            let metadata_account = accounts.pop().flatten();
+            let mint_account = accounts
+                .pop()
+                .flatten()
+                .with_context(|| format!("Mint account {} not found", mint))?;
+
+            let mut metadata = parse_mint_account(mint, &mint_account.owner, &mint_account.data)?;
+            metadata.symbol = metadata_account.and_then(|a| parse_metadata_symbol(&a.data));
+            Ok(metadata)
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TokenMetadataConfig {
+    /// How long fetched metadata is reused; authorities can be revoked, so keep it finite
+    pub ttl: Duration,
+    /// Longest a cache miss may wait for the fetch before reporting metadata unavailable
+    pub fetch_timeout: Duration,
+}
+
+impl Default for TokenMetadataConfig {
+    fn default() -> Self {
+        Self {
+            ttl: Duration::from_secs(300),
+            fetch_timeout: Duration::from_millis(250),
+        }
+    }
+}
+
+/// Metadata for a mint was neither cached nor fetchable within the deadline
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetadataUnavailable {
+    pub mint: Pubkey,
+    pub reason: String,
+}
+
+impl fmt::Display for MetadataUnavailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "metadata unavailable for {}: {}", self.mint, self.reason)
+    }
+}
+
+impl std::error::Error for MetadataUnavailable {}
+
+/// One cache serves every component that needs mint details
+pub type SharedTokenMetadataCache = Arc<TokenMetadataCache>;
+
+pub struct TokenMetadataCache {
+    config: TokenMetadataConfig,
+    source: Arc<dyn TokenMetadataSource>,
+    entries: Mutex<HashMap<Pubkey, (Arc<TokenMetadata>, Instant)>>,
+}
+
+#[allow(dead_code)]
+impl TokenMetadataCache {
+    pub fn new(config: TokenMetadataConfig, source: Arc<dyn TokenMetadataSource>) -> Self {
+        Self {
+            config,
+            source,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn shared(
+        config: TokenMetadataConfig,
+        source: Arc<dyn TokenMetadataSource>,
+    ) -> SharedTokenMetadataCache {
+        Arc::new(Self::new(config, source))
+    }
+
+    /// Fresh cached metadata only; never touches the network
+    pub fn get_cached(&self, mint: &Pubkey) -> Option<Arc<TokenMetadata>> {
+        let mut entries = self.entries.lock().ok()?;
+        entries.retain(|_, (_, fetched_at)| fetched_at.elapsed() < self.config.ttl);
+        entries.get(mint).map(|(metadata, _)| metadata.clone())
+    }
+
+    /// Cached metadata, or fetched within `fetch_timeout`; failures are not cached
+    pub async fn get(&self, mint: &Pubkey) -> Result<Arc<TokenMetadata>, MetadataUnavailable> {
+        if let Some(metadata) = self.get_cached(mint) {
+            return Ok(metadata);
+        }
+
+        let unavailable = |reason: String| MetadataUnavailable {
+            mint: *mint,
+            reason,
+        };
+        let metadata = tokio::time::timeout(self.config.fetch_timeout, self.source.fetch(mint))
+            .await
+            .map_err(|_| {
+                unavailable(format!(
+                    "fetch timed out after {:?}",
+                    self.config.fetch_timeout
+                ))
+            })?
+            .map_err(|e| unavailable(e.to_string()))?;
+
+        debug!(
+            "🪙 Cached metadata for {}: {} decimals, symbol {:?}",
+            mint, metadata.decimals, metadata.symbol
+        );
+        let metadata = Arc::new(metadata);
+        if let Ok(mut entries) = self.entries.lock() {
+            entries.insert(*mint, (metadata.clone(), Instant::now()));
+        }
+        Ok(metadata)
+    }
+
+    /// Pre-fetch metadata for known mints so the first trade does not pay for it
+    pub async fn warm(&self, mints: &[Pubkey]) -> usize {
+        let results = futures::future::join_all(mints.iter().map(|mint| self.get(mint))).await;
+        let mut warmed = 0;
+        for result in results {
+            match result {
+                Ok(_) => warmed += 1,
+                Err(e) => warn!("🪙 Could not warm token metadata: {}", e),
+            }
+        }
+        info!(
+            "🪙 Warmed token metadata for {}/{} mints",
+            warmed,
+            mints.len()
+        );
+        warmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    fn mint_data(decimals: u8, freeze_authority: Option<Pubkey>) -> Vec<u8> {
+        let mut data = vec![0u8; MINT_LEN];
+        data[36..44].copy_from_slice(&1_000_000u64.to_le_bytes());
+        data[44] = decimals;
+        data[45] = 1; // is_initialized
+        if let Some(authority) = freeze_authority {
+            data[46] = 1;
+            data[50..82].copy_from_slice(authority.as_ref());
+        }
+        data
+    }
+
+    #[test]
+    fn test_parse_mint_and_metadata_symbol() {
+        let mint = Pubkey::new_unique();
+        let freeze = Pubkey::new_unique();
+        let metadata =
+            parse_mint_account(&mint, &TOKEN_PROGRAM_ID, &mint_data(6, Some(freeze))).unwrap();
+        assert_eq!(metadata.decimals, 6);
+        assert_eq!(metadata.mint_authority, None);
+        assert_eq!(metadata.freeze_authority, Some(freeze));
+        assert_eq!(metadata.ui_amount(2_500_000), 2.5);
+        assert!(parse_mint_account(&mint, &Pubkey::new_unique(), &mint_data(6, None)).is_err());
+
+        let mut account = vec![4u8; METADATA_NAME_OFFSET];
+        for (value, padded) in [("Bonk", 32), ("BONK", 10)] {
+            let mut bytes = value.as_bytes().to_vec();
+            bytes.resize(padded, 0);
+            account.extend_from_slice(&(padded as u32).to_le_bytes());
+            account.extend_from_slice(&bytes);
+        }
+        assert_eq!(parse_metadata_symbol(&account), Some("BONK".to_string()));
+    }
+
+    struct SlowSource {
+        fetches: AtomicU64,
+        delay: Duration,
+    }
+
+    impl TokenMetadataSource for SlowSource {
+        fn fetch<'a>(&'a self, mint: &'a Pubkey) -> BoxFuture<'a, Result<TokenMetadata>> {
+            self.fetches.fetch_add(1, Ordering::Relaxed);
+            Box::pin(async move {
+                tokio::time::sleep(self.delay).await;
+                parse_mint_account(mint, &TOKEN_PROGRAM_ID, &mint_data(9, None))
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cache_reuses_entries_and_bounds_misses() {
+        let mint = Pubkey::new_unique();
+        let fast = Arc::new(SlowSource {
+            fetches: AtomicU64::new(0),
+            delay: Duration::ZERO,
+        });
+        let cache = TokenMetadataCache::new(TokenMetadataConfig::default(), fast.clone());
+        assert_eq!(cache.get(&mint).await.unwrap().decimals, 9);
+        assert_eq!(cache.get(&mint).await.unwrap().decimals, 9);
+        assert_eq!(fast.fetches.load(Ordering::Relaxed), 1);
+
+        let slow = Arc::new(SlowSource {
+            fetches: AtomicU64::new(0),
+            delay: Duration::from_secs(5),
+        });
+        let cache = TokenMetadataCache::new(
+            TokenMetadataConfig {
+                fetch_timeout: Duration::from_millis(10),
+                ..TokenMetadataConfig::default()
+            },
+            slow,
+        );
+        let error = cache.get(&mint).await.unwrap_err();
+        assert_eq!(error.mint, mint);
+        assert!(error.to_string().starts_with("metadata unavailable"));
+        assert!(cache.get_cached(&mint).is_none());
+    }
+}
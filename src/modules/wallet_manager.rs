@@ -3,17 +3,30 @@
 
 use anyhow::{anyhow, Context, Result};
 use chrono::{DateTime, Utc};
+use futures::future::BoxFuture;
 use serde::{Deserialize, Serialize};
+use solana_client::nonblocking::rpc_client::RpcClient;
 use solana_sdk::{
-    signature::{Keypair, Signer},
+    native_token::LAMPORTS_PER_SOL,
+    message::Message,
+    pubkey::Pubkey,
+    signature::{Keypair, Signature, Signer},
+    system_instruction,
     transaction::Transaction,
 };
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::RwLock;
-use tracing::{info, warn};
-
+use std::str::FromStr;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::future::Future;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{mpsc, RwLock};
+use tracing::{error, info, warn};
+
+use crate::modules::lifecycle::{LifecycleState, PositionState};
+use crate::modules::persistence::{PersistenceMessage, WalletStateChange};
+use crate::modules::signer::{sign_transaction, LocalKeypairSigner, RemoteSigner, SignerConfig, TransactionSigner};
 use crate::modules::strategy::{StrategyType, TradeAction};
+use crate::modules::symbol_resolver::{TokenPair, SOL_MINT};
 
 /// Wallet configuration and metadata
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -21,8 +34,13 @@ pub struct WalletConfig {
     pub wallet_id: String,
     pub name: String,
     pub description: String,
-    pub private_key: String, // Base58 encoded or JSON array format
+    pub private_key: String, // Base58 encoded or JSON array format; empty with a remote signer
     pub public_key: String,
+    #[serde(default)]
+    pub signer: SignerConfig,
+    /// Dedicated RPC endpoint for this wallet; falls back to `solana.rpc_url` when unset
+    #[serde(default)]
+    pub rpc_url: Option<String>,
     pub wallet_type: WalletType,
     pub strategy_allocation: Vec<StrategyAllocation>,
     pub risk_limits: WalletRiskLimits,
@@ -31,6 +49,20 @@ pub struct WalletConfig {
     pub last_used: Option<DateTime<Utc>>,
 }
 
+impl WalletConfig {
+    /// Share of the wallet no enabled strategy is allocated, in percent; kept idle as a
+    /// cash buffer when the buffer is enforced
+    pub fn unallocated_percentage(&self) -> f64 {
+        let allocated: f64 = self
+            .strategy_allocation
+            .iter()
+            .filter(|a| a.enabled)
+            .map(|a| a.allocation_percentage)
+            .sum();
+        (100.0 - allocated).max(0.0)
+    }
+}
+
 /// Types of wallets for different purposes
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub enum WalletType {
@@ -61,6 +93,32 @@ pub struct StrategyAllocation {
     pub enabled: bool,
 }
 
+/// What the executor does with a trade that would exceed its wallet's strategy allocation
+#[allow(dead_code)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum AllocationEnforcement {
+    /// Allocations only influence wallet selection
+    Off,
+    /// Reject the trade
+    Reject,
+    /// Shrink the trade to the remaining allocation, rejecting it when none is left
+    #[default]
+    Downsize,
+}
+
+impl FromStr for AllocationEnforcement {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.trim().to_lowercase().as_str() {
+            "off" => Ok(AllocationEnforcement::Off),
+            "reject" => Ok(AllocationEnforcement::Reject),
+            "downsize" => Ok(AllocationEnforcement::Downsize),
+            other => Err(anyhow!("Unknown allocation enforcement: {}", other)),
+        }
+    }
+}
+
 /// Risk limits specific to each wallet
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WalletRiskLimits {
@@ -98,13 +156,192 @@ pub struct WalletMetrics {
     pub updated_at: DateTime<Utc>,
 }
 
+/// On-chain SOL balance lookup used when a wallet has no metrics yet
+pub trait WalletBalanceSource: Send + Sync {
+    fn sol_balance<'a>(&'a self, pubkey: &'a Pubkey) -> BoxFuture<'a, Result<f64>>;
+}
+
+impl WalletBalanceSource for RpcClient {
+    fn sol_balance<'a>(&'a self, pubkey: &'a Pubkey) -> BoxFuture<'a, Result<f64>> {
+        Box::pin(async move {
+            let lamports = self.get_balance(pubkey).await.context("getBalance failed")?;
+            Ok(lamports as f64 / LAMPORTS_PER_SOL as f64)
+        })
+    }
+}
+
+/// Moves all SOL out of a wallet, used to evacuate a wallet whose key is suspected compromised
+pub trait FundSweeper: Send + Sync {
+    fn sweep<'a>(
+        &'a self,
+        signer: Arc<dyn TransactionSigner>,
+        destination: &'a Pubkey,
+    ) -> BoxFuture<'a, Result<Signature>>;
+}
+
+/// Fee of the single-signature sweep transfer
+const SWEEP_FEE_LAMPORTS: u64 = 5_000;
+
+impl FundSweeper for RpcClient {
+    fn sweep<'a>(
+        &'a self,
+        signer: Arc<dyn TransactionSigner>,
+        destination: &'a Pubkey,
+    ) -> BoxFuture<'a, Result<Signature>> {
+        Box::pin(async move {
+            let source = signer.pubkey();
+            let lamports = self.get_balance(&source).await.context("getBalance failed")?;
+            if lamports <= SWEEP_FEE_LAMPORTS {
+                return Err(anyhow!("Nothing to sweep from {} ({} lamports)", source, lamports));
+            }
+
+            let blockhash = self.get_latest_blockhash().await.context("getLatestBlockhash failed")?;
+            let instruction = system_instruction::transfer(&source, destination, lamports - SWEEP_FEE_LAMPORTS);
+            let mut transaction =
+                Transaction::new_unsigned(Message::new_with_blockhash(&[instruction], Some(&source), &blockhash));
+            sign_transaction(signer.as_ref(), &mut transaction).await?;
+            self.send_and_confirm_transaction(&transaction)
+                .await
+                .context("Sweep transfer failed")
+        })
+    }
+}
+
+/// When a wallet's balance is treated as evidence of a compromised key
+#[derive(Debug, Clone)]
+pub struct CompromiseContainmentConfig {
+    /// SOL missing beyond what our own trades explain before the wallet is contained
+    pub unexplained_delta_threshold: f64,
+    /// Where a contained wallet's remaining SOL is swept; no sweep when unset
+    pub safe_wallet: Option<Pubkey>,
+}
+
+struct CompromiseContainment {
+    config: CompromiseContainmentConfig,
+    sweeper: Arc<dyn FundSweeper>,
+}
+
+/// SOL a confirmed fill moved in or out of the wallet: the SOL side of the swap, less fees
+pub fn fill_sol_delta(action: &TradeAction, token_pair: &TokenPair, quantity: f64, price: f64, fees: f64) -> f64 {
+    let sol_mint = Pubkey::from_str(SOL_MINT).ok();
+    let notional = quantity * price;
+    let traded = match action {
+        TradeAction::Buy if Some(token_pair.quote_mint) == sol_mint => -notional,
+        TradeAction::Sell if Some(token_pair.quote_mint) == sol_mint => notional,
+        TradeAction::Buy if Some(token_pair.base_mint) == sol_mint => quantity,
+        TradeAction::Sell if Some(token_pair.base_mint) == sol_mint => -quantity,
+        _ => 0.0,
+    };
+    traded - fees
+}
+
+/// Periodically reads each active wallet's on-chain SOL balance and hands it to
+/// `WalletManager::detect_anomaly`, which contains wallets that lost more than our trades explain
+pub struct WalletBalanceProbe {
+    wallet_manager: Arc<WalletManager>,
+    source: Arc<dyn WalletBalanceSource>,
+    interval: Duration,
+}
+
+impl WalletBalanceProbe {
+    pub fn new(wallet_manager: Arc<WalletManager>, source: Arc<dyn WalletBalanceSource>, interval: Duration) -> Self {
+        Self {
+            wallet_manager,
+            source,
+            interval,
+        }
+    }
+
+    /// Probe every interval; the first probe sets each wallet's baseline
+    pub async fn run(self) {
+        info!("🔎 Wallet balance probe starting (interval: {:?})", self.interval);
+        let mut interval = tokio::time::interval(self.interval);
+        loop {
+            interval.tick().await;
+            self.probe_once().await;
+        }
+    }
+
+    /// Compare every active wallet's balance once, returning the anomalies found
+    pub async fn probe_once(&self) -> Vec<WalletAnomaly> {
+        let wallets = self.wallet_manager.get_active_wallets().await.unwrap_or_default();
+        let mut anomalies = Vec::new();
+        for wallet in wallets {
+            let balance = match Pubkey::from_str(&wallet.public_key) {
+                Ok(pubkey) => self.source.sol_balance(&pubkey).await,
+                Err(e) => Err(anyhow!("Invalid public key for {}: {}", wallet.wallet_id, e)),
+            };
+            let anomaly = match balance {
+                Ok(balance) => self.wallet_manager.detect_anomaly(&wallet.wallet_id, balance).await,
+                Err(e) => Err(e),
+            };
+            match anomaly {
+                Ok(Some(anomaly)) => anomalies.push(anomaly),
+                Ok(None) => {}
+                Err(e) => warn!("🔎 Balance probe of {} failed: {}", wallet.wallet_id, e),
+            }
+        }
+        anomalies
+    }
+}
+
+/// A wallet whose on-chain balance dropped further than our trade log explains
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletAnomaly {
+    pub wallet_id: String,
+    pub expected_balance: f64,
+    pub onchain_balance: f64,
+    pub unexplained_delta: f64,
+    /// Signature of the sweep to the safe wallet, when one was configured and succeeded
+    pub sweep_signature: Option<String>,
+}
+
+/// How the unallocated share of each wallet is treated
+#[derive(Debug, Clone)]
+pub struct CashBufferConfig {
+    /// Keep the unallocated share idle: selection and sizing never deploy it
+    pub enforced: bool,
+    /// Warn when a wallet leaves more than this percentage unallocated
+    pub warning_percentage: f64,
+}
+
+impl Default for CashBufferConfig {
+    fn default() -> Self {
+        Self {
+            enforced: false,
+            warning_percentage: 50.0,
+        }
+    }
+}
+
+/// Bounded on-chain balance lookup, used for just-in-time fetching of wallets selected
+/// before their first metrics refresh and for reconciliation after failed transfers
+#[derive(Clone)]
+struct JitBalanceFetch {
+    source: Arc<dyn WalletBalanceSource>,
+    timeout: Duration,
+}
+
 /// Multi-wallet manager for THE OVERMIND PROTOCOL
 pub struct WalletManager {
     wallets: Arc<RwLock<HashMap<String, WalletConfig>>>,
     wallet_metrics: Arc<RwLock<HashMap<String, WalletMetrics>>>,
     active_positions: Arc<RwLock<HashMap<String, Vec<Position>>>>,
     strategy_wallet_mapping: Arc<RwLock<HashMap<StrategyType, Vec<String>>>>,
-    default_wallet_id: Option<String>,
+    /// Wallet used when selection fails; reassigned when it stops being Active
+    default_wallet_id: Arc<RwLock<Option<String>>>,
+    /// Move the default to the best active wallet once it is suspended or stopped
+    default_wallet_recovery: bool,
+    jit_balance_fetch: Option<JitBalanceFetch>,
+    balance_reconciliation: Option<JitBalanceFetch>,
+    persistence_sender: Option<mpsc::UnboundedSender<PersistenceMessage>>,
+    selection_audit: Option<SharedWalletSelectionAudit>,
+    /// SOL balance each wallet should hold given our own trades, keyed by wallet ID
+    expected_balances: Arc<RwLock<HashMap<String, f64>>>,
+    compromise_containment: Option<CompromiseContainment>,
+    cash_buffer: CashBufferConfig,
+    /// Metrics older than this are refreshed or distrusted during selection
+    max_metrics_age: Option<chrono::Duration>,
 }
 
 /// Position tracking per wallet
@@ -113,16 +350,90 @@ pub struct Position {
     pub position_id: String,
     pub wallet_id: String,
     pub symbol: String,
+    #[serde(default)]
+    pub token_pair: Option<TokenPair>,
     pub strategy_type: StrategyType,
     pub action: TradeAction,
     pub quantity: f64,
     pub entry_price: f64,
     pub current_price: f64,
     pub unrealized_pnl: f64,
+    /// Realized by the quantity sold so far in the symbol's quote currency, net of its share
+    /// of the exit fees
+    #[serde(default)]
+    pub realized_pnl: f64,
+    /// Only changed through `transition`, so every state change is a legal one
+    state: PositionState,
     pub opened_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+/// What a sell matched against a wallet's open positions
+#[derive(Debug, Clone, Default)]
+pub struct PositionRelease {
+    pub quantity: f64,
+    /// Entry cost of the matched quantity
+    pub cost_basis: f64,
+    /// Positions the sell fully closed, with the P&L they realized over their lifetime
+    pub closed: Vec<Position>,
+}
+
+#[allow(dead_code)]
+impl Position {
+    /// A buy position in the Opening state, entered at `entry_price`
+    pub fn opening(
+        position_id: String,
+        wallet_id: String,
+        symbol: String,
+        strategy_type: StrategyType,
+        quantity: f64,
+        entry_price: f64,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            position_id,
+            wallet_id,
+            symbol,
+            token_pair: None,
+            strategy_type,
+            action: TradeAction::Buy,
+            quantity,
+            entry_price,
+            current_price: entry_price,
+            unrealized_pnl: 0.0,
+            realized_pnl: 0.0,
+            state: PositionState::Opening,
+            opened_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn state(&self) -> PositionState {
+        self.state
+    }
+
+    /// Move the position to `next`, rejecting illegal lifecycle transitions
+    pub fn transition(&mut self, next: PositionState) -> Result<()> {
+        if !self.state.can_transition_to(next) {
+            return Err(anyhow!(
+                "Illegal position transition for {}: {:?} -> {:?}",
+                self.position_id,
+                self.state,
+                next
+            ));
+        }
+        self.state = next;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// Move an Open position through Closing to Closed
+    pub fn close(&mut self) -> Result<()> {
+        self.transition(PositionState::Closing)?;
+        self.transition(PositionState::Closed)
+    }
+}
+
 /// Wallet selection criteria for trade execution
 #[derive(Debug, Clone)]
 pub struct WalletSelectionCriteria {
@@ -143,6 +454,79 @@ pub struct WalletSelection {
     pub selection_reason: String,
 }
 
+/// Components of a candidate wallet's selection score
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct WalletScoreBreakdown {
+    pub base_score: f64,
+    pub allocation_score: f64,
+    pub balance_score: f64,
+    pub performance_score: f64,
+    pub risk_score: f64,
+    pub total: f64,
+}
+
+/// How one candidate fared in a selection: scored, or excluded with a reason
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidateEvaluation {
+    pub wallet_id: String,
+    pub score: Option<WalletScoreBreakdown>,
+    pub excluded_reason: Option<String>,
+    /// Age of the metrics the candidate was judged on, when it had any
+    #[serde(default)]
+    pub metrics_age_ms: Option<i64>,
+    /// Metrics were older than the maximum age and could not be refreshed,
+    /// so the wallet was scored as holding no SOL
+    #[serde(default)]
+    pub metrics_distrusted: bool,
+}
+
+/// Full record of one `select_wallet` decision
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalletSelectionTrace {
+    pub timestamp: DateTime<Utc>,
+    pub strategy_type: StrategyType,
+    pub required_balance: f64,
+    pub candidates: Vec<CandidateEvaluation>,
+    pub selected_wallet_id: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Bounded ring buffer of recent wallet selection decisions
+#[derive(Debug)]
+#[allow(dead_code)]
+pub struct WalletSelectionAudit {
+    capacity: usize,
+    traces: VecDeque<WalletSelectionTrace>,
+}
+
+pub type SharedWalletSelectionAudit = Arc<Mutex<WalletSelectionAudit>>;
+
+#[allow(dead_code)]
+impl WalletSelectionAudit {
+    pub fn new(capacity: usize) -> Self {
+        Self { capacity, traces: VecDeque::with_capacity(capacity) }
+    }
+
+    pub fn shared(capacity: usize) -> SharedWalletSelectionAudit {
+        Arc::new(Mutex::new(Self::new(capacity)))
+    }
+
+    pub fn record(&mut self, trace: WalletSelectionTrace) {
+        if self.capacity == 0 {
+            return;
+        }
+        while self.traces.len() >= self.capacity {
+            self.traces.pop_front();
+        }
+        self.traces.push_back(trace);
+    }
+
+    /// Recorded decisions, newest first
+    pub fn traces(&self) -> Vec<WalletSelectionTrace> {
+        self.traces.iter().rev().cloned().collect()
+    }
+}
+
 impl WalletManager {
     /// Create new wallet manager
     pub fn new() -> Self {
@@ -151,24 +535,298 @@ impl WalletManager {
             wallet_metrics: Arc::new(RwLock::new(HashMap::new())),
             active_positions: Arc::new(RwLock::new(HashMap::new())),
             strategy_wallet_mapping: Arc::new(RwLock::new(HashMap::new())),
-            default_wallet_id: None,
+            default_wallet_id: Arc::new(RwLock::new(None)),
+            default_wallet_recovery: true,
+            jit_balance_fetch: None,
+            balance_reconciliation: None,
+            persistence_sender: None,
+            selection_audit: None,
+            expected_balances: Arc::new(RwLock::new(HashMap::new())),
+            compromise_containment: None,
+            cash_buffer: CashBufferConfig::default(),
+            max_metrics_age: None,
         }
     }
 
+    /// Refresh candidates' balances just in time when their metrics are older than
+    /// `max_age`, and score them as empty when that is not possible
+    pub fn with_max_metrics_age(mut self, max_age: Duration) -> Self {
+        self.max_metrics_age = chrono::Duration::from_std(max_age).ok();
+        self
+    }
+
+    /// Whether each wallet's unallocated share is reserved as idle SOL
+    pub fn with_cash_buffer(mut self, cash_buffer: CashBufferConfig) -> Self {
+        self.cash_buffer = cash_buffer;
+        self
+    }
+
+    /// Contain wallets whose balance drops without our trades explaining it: flip them to
+    /// Emergency and, with a safe wallet configured, sweep what is left through `sweeper`
+    pub fn with_compromise_containment(
+        mut self,
+        config: CompromiseContainmentConfig,
+        sweeper: Arc<dyn FundSweeper>,
+    ) -> Self {
+        self.compromise_containment = Some(CompromiseContainment { config, sweeper });
+        self
+    }
+
+    /// Record every selection decision with per-candidate scores and exclusion reasons
+    pub fn with_selection_audit(mut self, selection_audit: SharedWalletSelectionAudit) -> Self {
+        self.selection_audit = Some(selection_audit);
+        self
+    }
+
+    /// Whether a default wallet that stops being Active is replaced automatically
+    pub fn with_default_wallet_recovery(mut self, enabled: bool) -> Self {
+        self.default_wallet_recovery = enabled;
+        self
+    }
+
+    /// Persist wallet additions, status changes and position replacements
+    pub fn with_persistence(mut self, persistence_sender: mpsc::UnboundedSender<PersistenceMessage>) -> Self {
+        self.persistence_sender = Some(persistence_sender);
+        self
+    }
+
+    fn record_state_change(&self, wallet_id: &str, event: String) {
+        if let Some(sender) = &self.persistence_sender {
+            let _ = sender.send(PersistenceMessage::WalletStateChange(WalletStateChange::new(wallet_id, event)));
+        }
+    }
+
+    /// Fetch the balance of candidate wallets that have no metrics during selection
+    /// instead of scoring them as empty. Each fetch is bounded by `timeout`.
+    pub fn with_jit_balance_fetch(mut self, source: Arc<dyn WalletBalanceSource>, timeout: Duration) -> Self {
+        self.jit_balance_fetch = Some(JitBalanceFetch { source, timeout });
+        self
+    }
+
+    /// Re-read affected wallets' on-chain balances whenever a balance-affecting operation
+    /// fails, instead of leaving metrics at pre-transfer estimates
+    pub fn with_balance_reconciliation(mut self, source: Arc<dyn WalletBalanceSource>, timeout: Duration) -> Self {
+        self.balance_reconciliation = Some(JitBalanceFetch { source, timeout });
+        self
+    }
+
+    /// Run a balance-affecting operation (rebalance, top-up or any other transfer) that touches
+    /// `wallet_ids`. A failed operation may still have moved funds, so on any error the affected
+    /// wallets are reconciled against chain before the error is returned. Failed operations are
+    /// not retried here; resubmitting a partially applied transfer could move funds twice.
+    pub async fn run_balance_operation<T, F>(&self, operation: &str, wallet_ids: &[String], op: F) -> Result<T>
+    where
+        F: Future<Output = Result<T>>,
+    {
+        match op.await {
+            Ok(output) => Ok(output),
+            Err(e) => {
+                warn!("💸 {} failed for {:?}: {} - reconciling balances with chain", operation, wallet_ids, e);
+                let unreconciled = self.reconcile_balances(wallet_ids).await;
+                if !unreconciled.is_empty() {
+                    warn!("⚠️ Metrics of {:?} may be stale after failed {}", unreconciled, operation);
+                }
+                Err(e.context(format!("{} failed", operation)))
+            }
+        }
+    }
+
+    /// Overwrite the SOL balance in the metrics of `wallet_ids` with on-chain ground truth.
+    /// Returns the wallets that could not be reconciled.
+    pub async fn reconcile_balances(&self, wallet_ids: &[String]) -> Vec<String> {
+        let Some(lookup) = self.balance_reconciliation.as_ref().or(self.jit_balance_fetch.as_ref()) else {
+            warn!("No balance source configured for reconciliation");
+            return wallet_ids.to_vec();
+        };
+
+        let mut unreconciled = Vec::new();
+        for wallet_id in wallet_ids {
+            let sol_balance = match self.get_wallet(wallet_id).await {
+                Ok(wallet_config) => Self::lookup_sol_balance(lookup, &wallet_config).await,
+                Err(e) => Err(e),
+            };
+            let sol_balance = match sol_balance {
+                Ok(balance) => balance,
+                Err(e) => {
+                    warn!("Balance reconciliation for {} failed: {}", wallet_id, e);
+                    unreconciled.push(wallet_id.clone());
+                    continue;
+                }
+            };
+
+            let previous = {
+                let mut metrics = self.wallet_metrics.write().await;
+                match metrics.get_mut(wallet_id) {
+                    Some(metrics) => {
+                        let previous = metrics.sol_balance;
+                        metrics.sol_balance = sol_balance;
+                        metrics.updated_at = Utc::now();
+                        Some(previous)
+                    }
+                    None => {
+                        metrics.insert(wallet_id.clone(), Self::balance_only_metrics(wallet_id, sol_balance));
+                        None
+                    }
+                }
+            };
+            info!("🔄 Reconciled {} balance: {} SOL (was {:?})", wallet_id, sol_balance, previous);
+            self.record_state_change(wallet_id, format!("balance reconciled to {} SOL", sol_balance));
+        }
+        unreconciled
+    }
+
+    /// On-chain SOL balance of a wallet, bounded by the lookup timeout
+    async fn lookup_sol_balance(lookup: &JitBalanceFetch, wallet_config: &WalletConfig) -> Result<f64> {
+        let pubkey = Pubkey::from_str(&wallet_config.public_key)
+            .with_context(|| format!("Invalid public key for {}", wallet_config.wallet_id))?;
+        tokio::time::timeout(lookup.timeout, lookup.source.sol_balance(&pubkey))
+            .await
+            .map_err(|_| anyhow!("timed out after {:?}", lookup.timeout))?
+    }
+
+    /// Metrics snapshot carrying only a freshly read SOL balance
+    fn balance_only_metrics(wallet_id: &str, sol_balance: f64) -> WalletMetrics {
+        WalletMetrics {
+            wallet_id: wallet_id.to_string(),
+            sol_balance,
+            token_balances: HashMap::new(),
+            // Not valued yet; risk capacity falls back to the SOL balance
+            total_value_usd: 0.0,
+            daily_pnl: 0.0,
+            total_pnl: 0.0,
+            trade_count_today: 0,
+            last_trade_time: None,
+            risk_utilization: 0.0,
+            performance_score: 0.0,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Apply the SOL balance change of one of our own trades (fees included) to the
+    /// wallet's expected balance
+    pub async fn record_own_balance_change(&self, wallet_id: &str, sol_delta: f64) {
+        let mut expected_balances = self.expected_balances.write().await;
+        // Without a baseline yet the change is already part of the next on-chain reading
+        if let Some(expected) = expected_balances.get_mut(wallet_id) {
+            *expected += sol_delta;
+        }
+    }
+
+    /// Compare a wallet's on-chain SOL balance with what our trades leave it holding. A drop
+    /// beyond the containment threshold flips the wallet to Emergency, which takes it out of
+    /// routing, and sweeps its remaining funds when a safe wallet is configured. Otherwise the
+    /// reading becomes the new baseline, so fee and rent drift never accumulates.
+    pub async fn detect_anomaly(&self, wallet_id: &str, onchain_balance: f64) -> Result<Option<WalletAnomaly>> {
+        let Some(containment) = &self.compromise_containment else {
+            return Ok(None);
+        };
+        self.get_wallet(wallet_id).await?;
+
+        let expected_balance = {
+            let mut expected_balances = self.expected_balances.write().await;
+            let baseline = match expected_balances.get(wallet_id) {
+                Some(expected) => Some(*expected),
+                None => self.wallet_metrics.read().await.get(wallet_id).map(|m| m.sol_balance),
+            };
+            let Some(expected_balance) = baseline else {
+                expected_balances.insert(wallet_id.to_string(), onchain_balance);
+                return Ok(None);
+            };
+            // Only missing funds are suspicious; deposits are just a higher baseline
+            if expected_balance - onchain_balance <= containment.config.unexplained_delta_threshold {
+                expected_balances.insert(wallet_id.to_string(), onchain_balance);
+                return Ok(None);
+            }
+            expected_balance
+        };
+
+        let unexplained_delta = expected_balance - onchain_balance;
+        error!(
+            "🚨 Wallet {} lost {:.4} SOL our trades do not explain (expected {:.4}, on-chain {:.4}); suspected key compromise",
+            wallet_id, unexplained_delta, expected_balance, onchain_balance
+        );
+        self.contain_wallet(wallet_id, unexplained_delta).await;
+
+        let sweep_signature = match containment.config.safe_wallet {
+            Some(safe_wallet) => match self.sweep_wallet(containment, wallet_id, &safe_wallet).await {
+                Ok(signature) => Some(signature.to_string()),
+                Err(e) => {
+                    error!("🚨 Sweeping {} to safe wallet {} failed: {}", wallet_id, safe_wallet, e);
+                    None
+                }
+            },
+            None => None,
+        };
+
+        Ok(Some(WalletAnomaly {
+            wallet_id: wallet_id.to_string(),
+            expected_balance,
+            onchain_balance,
+            unexplained_delta,
+            sweep_signature,
+        }))
+    }
+
+    /// Flip a wallet to Emergency so selection, fallback and the default stop routing to it
+    async fn contain_wallet(&self, wallet_id: &str, unexplained_delta: f64) {
+        let mut wallets = self.wallets.write().await;
+        if let Some(wallet_config) = wallets.get_mut(wallet_id) {
+            let previous = wallet_config.status.clone();
+            wallet_config.status = WalletStatus::Emergency;
+            self.record_state_change(
+                wallet_id,
+                format!(
+                    "status {:?} -> Emergency: suspected compromise, {:.4} SOL unexplained",
+                    previous, unexplained_delta
+                ),
+            );
+        }
+        self.refresh_default_wallet(&wallets).await;
+    }
+
+    async fn sweep_wallet(
+        &self,
+        containment: &CompromiseContainment,
+        wallet_id: &str,
+        safe_wallet: &Pubkey,
+    ) -> Result<Signature> {
+        let signer = self.get_wallet_signer(wallet_id).await?;
+        let signature = containment.sweeper.sweep(signer, safe_wallet).await?;
+        warn!("🧹 Swept {} to safe wallet {}: {}", wallet_id, safe_wallet, signature);
+        self.record_state_change(wallet_id, format!("swept to safe wallet {}: {}", safe_wallet, signature));
+        self.expected_balances.write().await.insert(wallet_id.to_string(), 0.0);
+        Ok(signature)
+    }
+
     /// Initialize wallet manager with configuration
     pub async fn initialize(&mut self, wallet_configs: Vec<WalletConfig>) -> Result<()> {
         info!("🏦 Initializing THE OVERMIND PROTOCOL Multi-Wallet Manager");
         
         let mut wallets = self.wallets.write().await;
         let mut strategy_mapping = self.strategy_wallet_mapping.write().await;
+        let mut default_wallet_id = self.default_wallet_id.write().await;
+
+        // Reject the whole load on a duplicate ID instead of letting the last entry win
+        let mut seen = HashSet::new();
+        for config in &wallet_configs {
+            if !seen.insert(config.wallet_id.as_str()) {
+                return Err(anyhow!("Duplicate wallet ID '{}' in wallet configuration", config.wallet_id));
+            }
+            if let Some(existing) = wallets.get(&config.wallet_id) {
+                return Err(anyhow!(
+                    "Duplicate wallet ID '{}': already loaded as '{}'",
+                    config.wallet_id, existing.name
+                ));
+            }
+        }
         
         for config in wallet_configs {
             // Validate wallet configuration
             self.validate_wallet_config(&config)?;
             
             // Set first active wallet as default
-            if self.default_wallet_id.is_none() && config.status == WalletStatus::Active {
-                self.default_wallet_id = Some(config.wallet_id.clone());
+            if default_wallet_id.is_none() && config.status == WalletStatus::Active {
+                *default_wallet_id = Some(config.wallet_id.clone());
                 info!("🎯 Set default wallet: {} ({})", config.name, config.wallet_id);
             }
             
@@ -212,22 +870,157 @@ impl WalletManager {
         }
         
         info!("➕ Added new wallet: {} ({})", config.name, config.wallet_id);
+        self.record_state_change(&config.wallet_id, format!("added with status {:?}", config.status));
         wallets.insert(config.wallet_id.clone(), config);
         
         Ok(())
     }
 
-    /// Select optimal wallet for trade execution
-    pub async fn select_wallet(&self, criteria: WalletSelectionCriteria) -> Result<WalletSelection> {
+    /// Make `wallet_id` the default wallet; it must exist and be Active
+    pub async fn set_default_wallet(&self, wallet_id: &str) -> Result<()> {
+        let wallets = self.wallets.read().await;
+        let wallet_config = wallets.get(wallet_id)
+            .ok_or_else(|| anyhow!("Wallet not found: {}", wallet_id))?;
+        if wallet_config.status != WalletStatus::Active {
+            return Err(anyhow!("Wallet {} is {:?}, not Active", wallet_id, wallet_config.status));
+        }
+
+        *self.default_wallet_id.write().await = Some(wallet_id.to_string());
+        info!("🎯 Set default wallet: {} ({})", wallet_config.name, wallet_id);
+        Ok(())
+    }
+
+    /// The default wallet for fallback routing. With recovery enabled, a default that is
+    /// no longer Active is first replaced by the highest-scoring active wallet; when none
+    /// is left the default is cleared and this fails.
+    pub async fn default_wallet_id(&self) -> Result<String> {
         let wallets = self.wallets.read().await;
+        let mut default_wallet_id = self.default_wallet_id.write().await;
+        if !self.default_wallet_recovery {
+            return default_wallet_id.clone().ok_or_else(|| anyhow!("No default wallet configured"));
+        }
+        self.recover_default_wallet(&wallets, &mut default_wallet_id).await
+    }
+
+    async fn recover_default_wallet(
+        &self,
+        wallets: &HashMap<String, WalletConfig>,
+        default_wallet_id: &mut Option<String>,
+    ) -> Result<String> {
+        if let Some(wallet_id) = default_wallet_id.as_ref() {
+            if wallets.get(wallet_id).is_some_and(|w| w.status == WalletStatus::Active) {
+                return Ok(wallet_id.clone());
+            }
+        }
+
         let metrics = self.wallet_metrics.read().await;
-        let strategy_mapping = self.strategy_wallet_mapping.read().await;
-        
-        // Get candidate wallets for this strategy
-        let candidate_wallet_ids = strategy_mapping
-            .get(&criteria.strategy_type)
-            .cloned()
-            .unwrap_or_default();
+        let replacement = wallets
+            .values()
+            .filter(|w| w.status == WalletStatus::Active)
+            .map(|w| (self.base_wallet_score(w, metrics.get(&w.wallet_id), 0.0).total, w))
+            // Ties go to the lowest wallet ID so the choice does not depend on map order
+            .max_by(|(a, a_wallet), (b, b_wallet)| {
+                a.total_cmp(b).then_with(|| b_wallet.wallet_id.cmp(&a_wallet.wallet_id))
+            });
+
+        let previous = default_wallet_id.take();
+        let previous_label = previous.as_deref().unwrap_or("none");
+        match replacement {
+            Some((score, wallet_config)) => {
+                warn!(
+                    "🎯 Default wallet {} is not active; reassigned to {} ({}, score {:.2})",
+                    previous_label, wallet_config.wallet_id, wallet_config.name, score
+                );
+                self.record_state_change(
+                    &wallet_config.wallet_id,
+                    format!("assigned as default wallet, replacing {}", previous_label),
+                );
+                *default_wallet_id = Some(wallet_config.wallet_id.clone());
+                Ok(wallet_config.wallet_id.clone())
+            }
+            None => {
+                if let Some(previous) = &previous {
+                    error!("🚨 No active wallet left; cleared default wallet {}", previous);
+                    self.record_state_change(previous, "cleared as default wallet".to_string());
+                }
+                Err(anyhow!("No active wallet available as default"))
+            }
+        }
+    }
+
+    /// Re-check the default after a status change so it never points at a dead wallet
+    async fn refresh_default_wallet(&self, wallets: &HashMap<String, WalletConfig>) {
+        if !self.default_wallet_recovery {
+            return;
+        }
+        let mut default_wallet_id = self.default_wallet_id.write().await;
+        let _ = self.recover_default_wallet(wallets, &mut default_wallet_id).await;
+    }
+
+    /// Select optimal wallet for trade execution
+    pub async fn select_wallet(&self, criteria: WalletSelectionCriteria) -> Result<WalletSelection> {
+        self.select_wallet_with_trace(criteria).await.0
+    }
+
+    /// Select a wallet and also return the full record of the decision
+    pub async fn select_wallet_with_trace(
+        &self,
+        criteria: WalletSelectionCriteria,
+    ) -> (Result<WalletSelection>, WalletSelectionTrace) {
+        let mut candidates = Vec::new();
+        let result = self.select_wallet_traced(&criteria, &mut candidates).await;
+
+        let trace = WalletSelectionTrace {
+            timestamp: Utc::now(),
+            strategy_type: criteria.strategy_type.clone(),
+            required_balance: criteria.required_balance,
+            candidates,
+            selected_wallet_id: result.as_ref().ok().map(|s| s.wallet_id.clone()),
+            error: result.as_ref().err().map(|e| e.to_string()),
+        };
+        if let Some(audit) = &self.selection_audit {
+            if let Ok(mut audit) = audit.lock() {
+                audit.record(trace.clone());
+            }
+        }
+
+        (result, trace)
+    }
+
+    async fn select_wallet_traced(
+        &self,
+        criteria: &WalletSelectionCriteria,
+        candidates: &mut Vec<CandidateEvaluation>,
+    ) -> Result<WalletSelection> {
+        let excluded = |wallet_id: &str, reason: String| CandidateEvaluation {
+            wallet_id: wallet_id.to_string(),
+            score: None,
+            excluded_reason: Some(reason),
+            metrics_age_ms: None,
+            metrics_distrusted: false,
+        };
+
+        // Snapshot the candidates so no lock is held across JIT balance fetches
+        let (wallets, metrics, candidate_wallet_ids) = {
+            let wallets = self.wallets.read().await;
+            let metrics = self.wallet_metrics.read().await;
+            let strategy_mapping = self.strategy_wallet_mapping.read().await;
+
+            // Get candidate wallets for this strategy
+            let candidate_wallet_ids = strategy_mapping
+                .get(&criteria.strategy_type)
+                .cloned()
+                .unwrap_or_default();
+            let candidate_wallets: HashMap<String, WalletConfig> = candidate_wallet_ids
+                .iter()
+                .filter_map(|id| wallets.get(id).map(|w| (id.clone(), w.clone())))
+                .collect();
+            let candidate_metrics: HashMap<String, WalletMetrics> = candidate_wallet_ids
+                .iter()
+                .filter_map(|id| metrics.get(id).map(|m| (id.clone(), m.clone())))
+                .collect();
+            (candidate_wallets, candidate_metrics, candidate_wallet_ids)
+        };
         
         if candidate_wallet_ids.is_empty() {
             return Err(anyhow!("No wallets configured for strategy: {:?}", criteria.strategy_type));
@@ -235,9 +1028,11 @@ impl WalletManager {
         
         let mut best_wallet: Option<WalletSelection> = None;
         let mut best_score = 0.0;
+        let mut fetched_metrics = Vec::new();
         
         for wallet_id in candidate_wallet_ids {
             if criteria.exclude_wallets.contains(&wallet_id) {
+                candidates.push(excluded(&wallet_id, "excluded by criteria".to_string()));
                 continue;
             }
             
@@ -246,29 +1041,100 @@ impl WalletManager {
             
             // Skip inactive wallets
             if wallet_config.status != WalletStatus::Active {
+                candidates.push(excluded(&wallet_id, format!("status {:?}", wallet_config.status)));
                 continue;
             }
             
             // Check wallet type preference
             if let Some(preferred_type) = &criteria.preferred_wallet_type {
                 if &wallet_config.wallet_type != preferred_type {
+                    candidates.push(excluded(
+                        &wallet_id,
+                        format!("type {:?} is not preferred {:?}", wallet_config.wallet_type, preferred_type),
+                    ));
                     continue;
                 }
             }
             
-            let wallet_metrics = metrics.get(&wallet_id);
+            let jit_metrics;
+            let mut wallet_metrics = match metrics.get(&wallet_id) {
+                Some(wallet_metrics) => Some(wallet_metrics),
+                None => {
+                    jit_metrics = self.fetch_jit_metrics(wallet_config).await;
+                    if let Some(fetched) = &jit_metrics {
+                        fetched_metrics.push(fetched.clone());
+                    }
+                    jit_metrics.as_ref()
+                }
+            };
+
+            let refreshed_metrics;
+            let mut metrics_distrusted = false;
+            if let Some(stale) = wallet_metrics.filter(|m| self.metrics_too_old(m)) {
+                refreshed_metrics = match self.fetch_jit_balance(wallet_config).await {
+                    Some(sol_balance) => {
+                        let refreshed = WalletMetrics {
+                            sol_balance,
+                            updated_at: Utc::now(),
+                            ..stale.clone()
+                        };
+                        fetched_metrics.push(refreshed.clone());
+                        refreshed
+                    }
+                    None => {
+                        warn!(
+                            "⏳ Metrics for {} are from {}; scoring it as holding no SOL",
+                            wallet_id, stale.updated_at
+                        );
+                        metrics_distrusted = true;
+                        WalletMetrics {
+                            sol_balance: 0.0,
+                            ..stale.clone()
+                        }
+                    }
+                };
+                wallet_metrics = Some(&refreshed_metrics);
+            }
+            let metrics_age_ms = wallet_metrics.map(|m| (Utc::now() - m.updated_at).num_milliseconds());
+
+            let open_notional = Self::open_notional(&*self.active_positions.read().await, &wallet_id);
+            let deployable_balance = wallet_metrics.map(|m| self.deployable_sol(wallet_config, m, open_notional));
+            if self.cash_buffer.enforced {
+                if let Some(deployable) = deployable_balance.filter(|d| *d < criteria.required_balance) {
+                    candidates.push(CandidateEvaluation {
+                        metrics_age_ms,
+                        metrics_distrusted,
+                        ..excluded(
+                            &wallet_id,
+                            format!(
+                                "{:.4} SOL deployable outside the {:.2}% cash buffer, {:.4} required",
+                                deployable,
+                                wallet_config.unallocated_percentage(),
+                                criteria.required_balance
+                            ),
+                        )
+                    });
+                    continue;
+                }
+            }
             
             // Calculate selection score
-            let score = self.calculate_wallet_score(
+            let breakdown = self.calculate_wallet_score(
                 wallet_config,
                 wallet_metrics,
-                &criteria,
+                criteria,
             ).await?;
+            let score = breakdown.total;
+            candidates.push(CandidateEvaluation {
+                wallet_id: wallet_id.clone(),
+                score: Some(breakdown),
+                excluded_reason: None,
+                metrics_age_ms,
+                metrics_distrusted,
+            });
             
             if score > best_score {
-                let available_balance = wallet_metrics
-                    .map(|m| m.sol_balance)
-                    .unwrap_or(0.0);
+                let available_balance = deployable_balance.unwrap_or(0.0);
                 
                 let risk_capacity = self.calculate_risk_capacity(wallet_config, wallet_metrics);
                 
@@ -278,14 +1144,60 @@ impl WalletManager {
                     wallet_config: wallet_config.clone(),
                     available_balance,
                     risk_capacity,
-                    selection_reason: format!("Best score: {:.2}", score),
+                    selection_reason: if metrics_distrusted {
+                        format!("Best score: {:.2} (stale metrics, balance assumed empty)", score)
+                    } else {
+                        format!("Best score: {:.2}", score)
+                    },
                 });
             }
         }
         
+        // Keep JIT balances until the next metrics refresh replaces them
+        if !fetched_metrics.is_empty() {
+            let mut wallet_metrics = self.wallet_metrics.write().await;
+            for fetched in fetched_metrics {
+                match wallet_metrics.entry(fetched.wallet_id.clone()) {
+                    std::collections::hash_map::Entry::Occupied(mut entry) => {
+                        if entry.get().updated_at < fetched.updated_at {
+                            entry.insert(fetched);
+                        }
+                    }
+                    std::collections::hash_map::Entry::Vacant(entry) => {
+                        entry.insert(fetched);
+                    }
+                }
+            }
+        }
+
         best_wallet.ok_or_else(|| anyhow!("No suitable wallet found for criteria"))
     }
 
+    /// Balance-only metrics snapshot for a wallet that has never been refreshed
+    async fn fetch_jit_metrics(&self, wallet_config: &WalletConfig) -> Option<WalletMetrics> {
+        let sol_balance = self.fetch_jit_balance(wallet_config).await?;
+        Some(Self::balance_only_metrics(&wallet_config.wallet_id, sol_balance))
+    }
+
+    async fn fetch_jit_balance(&self, wallet_config: &WalletConfig) -> Option<f64> {
+        let jit = self.jit_balance_fetch.as_ref()?;
+        match Self::lookup_sol_balance(jit, wallet_config).await {
+            Ok(balance) => {
+                info!("💡 JIT balance for {}: {} SOL", wallet_config.wallet_id, balance);
+                Some(balance)
+            }
+            Err(e) => {
+                warn!("JIT balance fetch for {} failed: {}", wallet_config.wallet_id, e);
+                None
+            }
+        }
+    }
+
+    fn metrics_too_old(&self, metrics: &WalletMetrics) -> bool {
+        self.max_metrics_age
+            .is_some_and(|max_age| Utc::now() - metrics.updated_at > max_age)
+    }
+
     /// Get wallet by ID
     pub async fn get_wallet(&self, wallet_id: &str) -> Result<WalletConfig> {
         let wallets = self.wallets.read().await;
@@ -319,12 +1231,193 @@ impl WalletManager {
             .collect())
     }
 
-    /// Get wallet keypair for transaction signing
+    /// Get all tracked positions across wallets
+    pub async fn get_all_positions(&self) -> Vec<Position> {
+        let positions = self.active_positions.read().await;
+        positions.values().flatten().cloned().collect()
+    }
+
+    /// Replace the tracked positions of a wallet (e.g. after startup reconciliation)
+    pub async fn replace_positions(&self, wallet_id: &str, wallet_positions: Vec<Position>) {
+        let mut positions = self.active_positions.write().await;
+        self.record_state_change(wallet_id, format!("positions replaced ({} open)", wallet_positions.len()));
+        if wallet_positions.is_empty() {
+            positions.remove(wallet_id);
+        } else {
+            positions.insert(wallet_id.to_string(), wallet_positions);
+        }
+    }
+
+    /// Notional (quantity x entry price) of the wallet's positions opened by `strategy` and not closed
+    pub async fn strategy_open_notional(&self, wallet_id: &str, strategy: &StrategyType) -> f64 {
+        let positions = self.active_positions.read().await;
+        positions
+            .get(wallet_id)
+            .into_iter()
+            .flatten()
+            .filter(|p| &p.strategy_type == strategy && p.state() != PositionState::Closed)
+            .map(|p| p.quantity * p.entry_price)
+            .sum()
+    }
+
+    /// Notional `strategy` may still open on the wallet: its allocation percentage of the
+    /// wallet's SOL balance, capped at the allocation's max position size, minus what is
+    /// already open. None when the wallet has no allocation entry for the strategy.
+    pub async fn remaining_strategy_allocation(&self, wallet_id: &str, strategy: &StrategyType) -> Result<Option<f64>> {
+        let wallet = self.get_wallet(wallet_id).await?;
+        let Some(allocation) = wallet.strategy_allocation.iter().find(|a| &a.strategy_type == strategy) else {
+            return Ok(None);
+        };
+        if !allocation.enabled {
+            return Ok(Some(0.0));
+        }
+
+        let limit = match self.wallet_metrics.read().await.get(wallet_id) {
+            Some(metrics) => (metrics.sol_balance * allocation.allocation_percentage / 100.0)
+                .min(allocation.max_position_size),
+            None => allocation.max_position_size,
+        };
+        let open = self.strategy_open_notional(wallet_id, strategy).await;
+        Ok(Some((limit - open).max(0.0)))
+    }
+
+    /// SOL the wallet may still deploy. With the cash buffer enforced, the unallocated share
+    /// of the wallet's capital (free SOL plus open notional) stays idle; otherwise this is the
+    /// whole free balance.
+    pub async fn deployable_balance(&self, wallet_id: &str) -> Result<f64> {
+        let wallet = self.get_wallet(wallet_id).await?;
+        let metrics = self.get_wallet_metrics(wallet_id).await?;
+        let open_notional = Self::open_notional(&*self.active_positions.read().await, wallet_id);
+        Ok(self.deployable_sol(&wallet, &metrics, open_notional))
+    }
+
+    fn deployable_sol(&self, config: &WalletConfig, metrics: &WalletMetrics, open_notional: f64) -> f64 {
+        if !self.cash_buffer.enforced {
+            return metrics.sol_balance;
+        }
+        let reserve = (metrics.sol_balance + open_notional) * config.unallocated_percentage() / 100.0;
+        (metrics.sol_balance - reserve).max(0.0)
+    }
+
+    /// Notional of all positions that are not closed, across every wallet; `to_sol` converts
+    /// a position's quote-currency notional given its symbol
+    pub async fn total_open_notional(&self, to_sol: impl Fn(&str, f64) -> f64) -> f64 {
+        let positions = self.active_positions.read().await;
+        positions
+            .values()
+            .flatten()
+            .filter(|p| p.state() != PositionState::Closed)
+            .map(|p| to_sol(&p.symbol, p.quantity * p.entry_price))
+            .sum()
+    }
+
+    /// Notional of all the wallet's positions that are not closed
+    fn open_notional(positions: &HashMap<String, Vec<Position>>, wallet_id: &str) -> f64 {
+        positions
+            .get(wallet_id)
+            .into_iter()
+            .flatten()
+            .filter(|p| p.state() != PositionState::Closed)
+            .map(|p| p.quantity * p.entry_price)
+            .sum()
+    }
+
+    /// Track a position opened by an executed trade
+    pub async fn record_open_position(&self, position: Position) {
+        let mut positions = self.active_positions.write().await;
+        positions.entry(position.wallet_id.clone()).or_default().push(position);
+    }
+
+    /// Reduce the wallet's open positions in `symbol` for `strategy` by a sell of `quantity`
+    /// at `exit_price`, oldest first, dropping positions that are fully closed. Each position
+    /// realizes its share of the sell, net of its share of `fees`, all in the quote currency.
+    pub async fn release_position_quantity(
+        &self,
+        wallet_id: &str,
+        strategy: &StrategyType,
+        symbol: &str,
+        quantity: f64,
+        exit_price: f64,
+        fees: f64,
+    ) -> PositionRelease {
+        let mut positions = self.active_positions.write().await;
+        let Some(wallet_positions) = positions.get_mut(wallet_id) else {
+            return PositionRelease::default();
+        };
+
+        let mut remaining = quantity;
+        let mut cost_basis = 0.0;
+        let mut closed = Vec::new();
+        for position in wallet_positions
+            .iter_mut()
+            .filter(|p| &p.strategy_type == strategy && p.symbol == symbol && p.state() != PositionState::Closed)
+        {
+            if remaining <= 0.0 {
+                break;
+            }
+            let released = position.quantity.min(remaining);
+            position.quantity -= released;
+            position.updated_at = Utc::now();
+            remaining -= released;
+            cost_basis += released * position.entry_price;
+            position.realized_pnl +=
+                released * (exit_price - position.entry_price) - fees * released / quantity;
+            if position.quantity <= 0.0 {
+                match position.close() {
+                    Ok(()) => closed.push(position.clone()),
+                    Err(e) => warn!("{}", e),
+                }
+            }
+        }
+        wallet_positions.retain(|p| p.quantity > 0.0);
+        if wallet_positions.is_empty() {
+            positions.remove(wallet_id);
+        }
+        PositionRelease {
+            quantity: quantity - remaining,
+            cost_basis,
+            closed,
+        }
+    }
+
+    /// Get wallet keypair for transaction signing (local signers only)
     pub async fn get_wallet_keypair(&self, wallet_id: &str) -> Result<Keypair> {
         let wallet = self.get_wallet(wallet_id).await?;
+        if !matches!(wallet.signer, SignerConfig::Local) {
+            return Err(anyhow!("Wallet {} uses a remote signer and has no local key", wallet_id));
+        }
         self.parse_private_key(&wallet.private_key)
     }
 
+    /// Get the signer for a wallet's transactions
+    pub async fn get_wallet_signer(&self, wallet_id: &str) -> Result<Arc<dyn TransactionSigner>> {
+        let wallet = self.get_wallet(wallet_id).await?;
+
+        match &wallet.signer {
+            SignerConfig::Local => {
+                let keypair = self.parse_private_key(&wallet.private_key)?;
+                Ok(Arc::new(LocalKeypairSigner::new(keypair)))
+            }
+            SignerConfig::Remote { endpoint, key_id, auth_token_env } => {
+                let pubkey = Pubkey::from_str(&wallet.public_key)
+                    .context("Invalid public key for remote signer")?;
+                let auth_token = match auth_token_env {
+                    Some(var) => Some(
+                        std::env::var(var)
+                            .with_context(|| format!("Environment variable {} not found", var))?,
+                    ),
+                    None => None,
+                };
+                Ok(Arc::new(RemoteSigner::new(
+                    endpoint.clone(),
+                    key_id.clone(),
+                    pubkey,
+                    auth_token,
+                )?))
+            }
+        }
+    }
+
     /// Validate wallet configuration
     fn validate_wallet_config(&self, config: &WalletConfig) -> Result<()> {
         // Validate wallet ID
@@ -332,9 +1425,23 @@ impl WalletManager {
             return Err(anyhow!("Wallet ID cannot be empty"));
         }
         
-        // Validate private key format
-        self.parse_private_key(&config.private_key)
-            .context("Invalid private key format")?;
+        // Validate signing setup
+        match &config.signer {
+            SignerConfig::Local => {
+                self.parse_private_key(&config.private_key)
+                    .context("Invalid private key format")?;
+            }
+            SignerConfig::Remote { endpoint, .. } => {
+                if endpoint.is_empty() {
+                    return Err(anyhow!("Remote signer endpoint cannot be empty"));
+                }
+                if !config.private_key.is_empty() {
+                    return Err(anyhow!("Wallets with a remote signer must not store a private key"));
+                }
+                Pubkey::from_str(&config.public_key)
+                    .context("Invalid public key for remote signer")?;
+            }
+        }
         
         // Validate strategy allocations
         let total_allocation: f64 = config.strategy_allocation
@@ -346,6 +1453,13 @@ impl WalletManager {
         if total_allocation > 100.0 {
             return Err(anyhow!("Total strategy allocation exceeds 100%: {:.2}%", total_allocation));
         }
+        let unallocated = config.unallocated_percentage();
+        if self.cash_buffer.enforced && unallocated > self.cash_buffer.warning_percentage {
+            warn!(
+                "💤 Wallet {} allocates only {:.2}% to strategies; {:.2}% will be held idle as cash buffer",
+                config.wallet_id, total_allocation, unallocated
+            );
+        }
         
         // Validate risk limits
         if config.risk_limits.max_exposure_percentage > 100.0 {
@@ -387,49 +1501,72 @@ impl WalletManager {
         wallet_config: &WalletConfig,
         wallet_metrics: Option<&WalletMetrics>,
         criteria: &WalletSelectionCriteria,
-    ) -> Result<f64> {
-        let mut score = 0.0;
-        
-        // Base score from wallet type
-        score += match wallet_config.wallet_type {
-            WalletType::Primary => 10.0,
-            WalletType::HFT => 9.0,
-            WalletType::Secondary => 8.0,
-            WalletType::Arbitrage => 7.0,
-            WalletType::Conservative => 6.0,
-            WalletType::MEVProtection => 5.0,
-            WalletType::Experimental => 4.0,
-            WalletType::Emergency => 1.0,
-        };
-        
+    ) -> Result<WalletScoreBreakdown> {
+        let mut score = self.base_wallet_score(wallet_config, wallet_metrics, criteria.required_balance);
+
         // Strategy allocation score
         for allocation in &wallet_config.strategy_allocation {
             if allocation.strategy_type == criteria.strategy_type && allocation.enabled {
-                score += allocation.allocation_percentage / 10.0; // Max 10 points
+                score.allocation_score = allocation.allocation_percentage / 10.0; // Max 10 points
+                score.total += score.allocation_score;
                 break;
             }
         }
+        Ok(score)
+    }
+
+    /// Score components that do not depend on the strategy being routed
+    fn base_wallet_score(
+        &self,
+        wallet_config: &WalletConfig,
+        wallet_metrics: Option<&WalletMetrics>,
+        required_balance: f64,
+    ) -> WalletScoreBreakdown {
+        let mut score = WalletScoreBreakdown {
+            // Base score from wallet type
+            base_score: match wallet_config.wallet_type {
+                WalletType::Primary => 10.0,
+                WalletType::HFT => 9.0,
+                WalletType::Secondary => 8.0,
+                WalletType::Arbitrage => 7.0,
+                WalletType::Conservative => 6.0,
+                WalletType::MEVProtection => 5.0,
+                WalletType::Experimental => 4.0,
+                WalletType::Emergency => 1.0,
+            },
+            ..WalletScoreBreakdown::default()
+        };
         
         // Balance and capacity score
         if let Some(metrics) = wallet_metrics {
-            if metrics.sol_balance >= criteria.required_balance {
-                score += 5.0;
+            if metrics.sol_balance >= required_balance {
+                score.balance_score = 5.0;
             }
             
             // Performance score
-            score += metrics.performance_score.min(5.0);
+            score.performance_score = metrics.performance_score.min(5.0);
             
             // Risk utilization (lower is better)
-            score += (100.0 - metrics.risk_utilization) / 20.0; // Max 5 points
+            score.risk_score = (100.0 - metrics.risk_utilization) / 20.0; // Max 5 points
         }
         
-        Ok(score)
+        score.total = score.base_score
+            + score.balance_score
+            + score.performance_score
+            + score.risk_score;
+        score
     }
 
     /// Calculate risk capacity for a wallet
     fn calculate_risk_capacity(&self, config: &WalletConfig, metrics: Option<&WalletMetrics>) -> f64 {
         if let Some(metrics) = metrics {
-            let max_risk = config.risk_limits.max_exposure_percentage / 100.0 * metrics.total_value_usd;
+            // Balance-only snapshots (JIT fetches) carry no USD valuation yet
+            let wallet_value = if metrics.total_value_usd > 0.0 {
+                metrics.total_value_usd
+            } else {
+                metrics.sol_balance
+            };
+            let max_risk = config.risk_limits.max_exposure_percentage / 100.0 * wallet_value;
             let current_risk = metrics.risk_utilization / 100.0 * max_risk;
             max_risk - current_risk
         } else {
@@ -462,6 +1599,39 @@ impl WalletConfigBuilder {
                 description: String::new(),
                 private_key,
                 public_key,
+                signer: SignerConfig::Local,
+                rpc_url: None,
+                wallet_type: WalletType::Primary,
+                strategy_allocation: Vec::new(),
+                risk_limits: WalletRiskLimits::default(),
+                status: WalletStatus::Active,
+                created_at: Utc::now(),
+                last_used: None,
+            },
+        })
+    }
+
+    /// Wallet whose key lives in an external signer; only the public key is kept
+    pub fn new_remote(
+        wallet_id: String,
+        name: String,
+        public_key: String,
+        signer: SignerConfig,
+    ) -> Result<Self> {
+        Pubkey::from_str(&public_key).context("Invalid public key for remote signer")?;
+        if matches!(signer, SignerConfig::Local) {
+            return Err(anyhow!("new_remote requires a remote signer configuration"));
+        }
+
+        Ok(Self {
+            config: WalletConfig {
+                wallet_id,
+                name,
+                description: String::new(),
+                private_key: String::new(),
+                public_key,
+                signer,
+                rpc_url: None,
                 wallet_type: WalletType::Primary,
                 strategy_allocation: Vec::new(),
                 risk_limits: WalletRiskLimits::default(),
@@ -497,6 +1667,11 @@ impl WalletConfigBuilder {
         self
     }
 
+    pub fn rpc_url(mut self, rpc_url: String) -> Self {
+        self.config.rpc_url = Some(rpc_url);
+        self
+    }
+
     pub fn risk_limits(mut self, risk_limits: WalletRiskLimits) -> Self {
         self.config.risk_limits = risk_limits;
         self
@@ -666,8 +1841,10 @@ impl WalletManager {
             if wallet_config.status == WalletStatus::Active {
                 wallet_config.status = WalletStatus::Emergency;
                 warn!("🚨 Wallet {} suspended in emergency mode", wallet_id);
+                self.record_state_change(wallet_id, "status Active -> Emergency".to_string());
             }
         }
+        self.refresh_default_wallet(&wallets).await;
 
         Ok(())
     }
@@ -680,12 +1857,15 @@ impl WalletManager {
             if wallet_config.status == WalletStatus::Emergency {
                 wallet_config.status = WalletStatus::Active;
                 info!("✅ Wallet {} reactivated from emergency mode", wallet_id);
+                self.record_state_change(wallet_id, "status Emergency -> Active".to_string());
             } else {
                 return Err(anyhow!("Wallet {} is not in emergency mode", wallet_id));
             }
         } else {
             return Err(anyhow!("Wallet {} not found", wallet_id));
         }
+        // A default cleared by an emergency stop comes back with the first reactivated wallet
+        self.refresh_default_wallet(&wallets).await;
 
         Ok(())
     }
@@ -716,3 +1896,205 @@ impl WalletManager {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Records sweeps instead of sending them
+    #[derive(Default)]
+    struct RecordingSweeper {
+        swept: Mutex<Vec<(Pubkey, Pubkey)>>,
+    }
+
+    impl FundSweeper for RecordingSweeper {
+        fn sweep<'a>(
+            &'a self,
+            signer: Arc<dyn TransactionSigner>,
+            destination: &'a Pubkey,
+        ) -> BoxFuture<'a, Result<Signature>> {
+            Box::pin(async move {
+                self.swept.lock().unwrap().push((signer.pubkey(), *destination));
+                Ok(Signature::default())
+            })
+        }
+    }
+
+    async fn contained_manager(safe_wallet: Option<Pubkey>, sweeper: Arc<RecordingSweeper>) -> WalletManager {
+        let mut manager = WalletManager::new().with_compromise_containment(
+            CompromiseContainmentConfig { unexplained_delta_threshold: 0.5, safe_wallet },
+            sweeper,
+        );
+        let wallet = WalletConfigBuilder::new(
+            "hot".to_string(),
+            "Hot".to_string(),
+            Keypair::new().to_base58_string(),
+        )
+        .unwrap()
+        .build();
+        manager.initialize(vec![wallet]).await.unwrap();
+        manager
+    }
+
+    #[tokio::test]
+    async fn test_balance_drops_explained_by_our_trades_are_not_anomalies() {
+        let manager = contained_manager(None, Arc::new(RecordingSweeper::default())).await;
+
+        // First reading only sets the baseline
+        assert!(manager.detect_anomaly("hot", 10.0).await.unwrap().is_none());
+        manager.record_own_balance_change("hot", -3.0).await;
+        assert!(manager.detect_anomaly("hot", 6.8).await.unwrap().is_none());
+        // Deposits are never suspicious
+        assert!(manager.detect_anomaly("hot", 20.0).await.unwrap().is_none());
+        assert_eq!(manager.get_wallet("hot").await.unwrap().status, WalletStatus::Active);
+    }
+
+    #[tokio::test]
+    async fn test_balance_probe_contains_wallet_after_unexplained_drop() {
+        let manager = Arc::new(contained_manager(None, Arc::new(RecordingSweeper::default())).await);
+        let first = WalletBalanceProbe::new(manager.clone(), Arc::new(FixedBalance(10.0)), Duration::from_secs(30));
+        assert!(first.probe_once().await.is_empty());
+
+        // A confirmed 3 SOL buy explains most of the drop
+        let sol_pair = TokenPair {
+            base_mint: Pubkey::new_unique(),
+            quote_mint: Pubkey::from_str(SOL_MINT).unwrap(),
+        };
+        manager
+            .record_own_balance_change("hot", fill_sol_delta(&TradeAction::Buy, &sol_pair, 30.0, 0.1, 0.01))
+            .await;
+        let explained = WalletBalanceProbe::new(manager.clone(), Arc::new(FixedBalance(6.9)), Duration::from_secs(30));
+        assert!(explained.probe_once().await.is_empty());
+
+        let drained = WalletBalanceProbe::new(manager.clone(), Arc::new(FixedBalance(1.0)), Duration::from_secs(30));
+        let anomalies = drained.probe_once().await;
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(manager.get_wallet("hot").await.unwrap().status, WalletStatus::Emergency);
+        // Contained wallets are no longer probed
+        assert!(drained.probe_once().await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_unexplained_drop_contains_and_sweeps_wallet() {
+        let safe_wallet = Pubkey::new_unique();
+        let sweeper = Arc::new(RecordingSweeper::default());
+        let manager = contained_manager(Some(safe_wallet), sweeper.clone()).await;
+
+        manager.detect_anomaly("hot", 10.0).await.unwrap();
+        let anomaly = manager.detect_anomaly("hot", 8.0).await.unwrap().unwrap();
+
+        assert_eq!(anomaly.unexplained_delta, 2.0);
+        assert!(anomaly.sweep_signature.is_some());
+        let wallet = manager.get_wallet("hot").await.unwrap();
+        assert_eq!(wallet.status, WalletStatus::Emergency);
+        assert!(manager.default_wallet_id().await.is_err());
+        assert_eq!(
+            *sweeper.swept.lock().unwrap(),
+            vec![(Pubkey::from_str(&wallet.public_key).unwrap(), safe_wallet)]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_unallocated_share_is_kept_as_cash_buffer() {
+        let mut manager = WalletManager::new().with_cash_buffer(CashBufferConfig {
+            enforced: true,
+            ..CashBufferConfig::default()
+        });
+        let wallet = WalletConfigBuilder::new(
+            "sniper".to_string(),
+            "Sniper".to_string(),
+            Keypair::new().to_base58_string(),
+        )
+        .unwrap()
+        .add_strategy_allocation(StrategyType::TokenSniping, 80.0, 100.0)
+        .build();
+        assert_eq!(wallet.unallocated_percentage(), 20.0);
+        manager.initialize(vec![wallet]).await.unwrap();
+        manager
+            .update_wallet_metrics(WalletManager::balance_only_metrics("sniper", 5.0))
+            .await
+            .unwrap();
+
+        // 5 SOL free plus 5 SOL open: 2 SOL of the 10 SOL capital stay idle
+        let mut position = Position::opening(
+            "p1".to_string(),
+            "sniper".to_string(),
+            "BONK/SOL".to_string(),
+            StrategyType::TokenSniping,
+            5.0,
+            1.0,
+        );
+        position.transition(PositionState::Open).unwrap();
+        manager.record_open_position(position).await;
+        assert_eq!(manager.deployable_balance("sniper").await.unwrap(), 3.0);
+
+        let criteria = |required_balance| WalletSelectionCriteria {
+            strategy_type: StrategyType::TokenSniping,
+            required_balance,
+            risk_tolerance: 1.0,
+            preferred_wallet_type: None,
+            exclude_wallets: Vec::new(),
+        };
+        let selection = manager.select_wallet(criteria(2.0)).await.unwrap();
+        assert_eq!(selection.available_balance, 3.0);
+        assert!(manager.select_wallet(criteria(4.0)).await.is_err());
+    }
+
+    struct FixedBalance(f64);
+
+    impl WalletBalanceSource for FixedBalance {
+        fn sol_balance<'a>(&'a self, _pubkey: &'a Pubkey) -> BoxFuture<'a, Result<f64>> {
+            Box::pin(async move { Ok(self.0) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stale_metrics_are_refreshed_or_distrusted() {
+        let audit = WalletSelectionAudit::shared(10);
+        let stale_manager = || async {
+            let mut manager = WalletManager::new()
+                .with_max_metrics_age(Duration::from_secs(60))
+                .with_selection_audit(audit.clone());
+            let wallet = WalletConfigBuilder::new(
+                "hot".to_string(),
+                "Hot".to_string(),
+                Keypair::new().to_base58_string(),
+            )
+            .unwrap()
+            .add_strategy_allocation(StrategyType::TokenSniping, 100.0, 100.0)
+            .build();
+            manager.initialize(vec![wallet]).await.unwrap();
+            manager
+                .update_wallet_metrics(WalletMetrics {
+                    updated_at: Utc::now() - chrono::Duration::hours(1),
+                    ..WalletManager::balance_only_metrics("hot", 10.0)
+                })
+                .await
+                .unwrap();
+            manager
+        };
+        let criteria = WalletSelectionCriteria {
+            strategy_type: StrategyType::TokenSniping,
+            required_balance: 1.0,
+            risk_tolerance: 1.0,
+            preferred_wallet_type: None,
+            exclude_wallets: Vec::new(),
+        };
+
+        // Nothing to refresh from: the hour-old 10 SOL is not trusted
+        let selection = stale_manager().await.select_wallet(criteria.clone()).await.unwrap();
+        assert_eq!(selection.available_balance, 0.0);
+        let candidate = &audit.lock().unwrap().traces()[0].candidates[0];
+        assert!(candidate.metrics_distrusted);
+        assert!(candidate.metrics_age_ms.unwrap() >= 3_600_000);
+
+        // A JIT source replaces the stale balance before scoring
+        let manager = stale_manager()
+            .await
+            .with_jit_balance_fetch(Arc::new(FixedBalance(4.0)), Duration::from_secs(1));
+        let selection = manager.select_wallet(criteria).await.unwrap();
+        assert_eq!(selection.available_balance, 4.0);
+        assert!(!audit.lock().unwrap().traces()[0].candidates[0].metrics_distrusted);
+        assert_eq!(manager.get_wallet_metrics("hot").await.unwrap().sol_balance, 4.0);
+    }
+}
@@ -0,0 +1,165 @@
+// Wallet Submission Module
+// Per-wallet submission lanes: submissions from one wallet are serialized (or capped at a
+// configured concurrency) to keep nonce and ordering guarantees, while different wallets
+// submit in parallel
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+/// Submissions one wallet may have in flight at once; 1 keeps them strictly ordered
+pub const DEFAULT_PER_WALLET_CONCURRENCY: usize = 1;
+
+/// Submissions waiting for or holding a wallet's lane
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct WalletQueueDepth {
+    pub current: usize,
+    pub peak: usize,
+}
+
+#[derive(Debug)]
+struct WalletLane {
+    permits: Arc<Semaphore>,
+    depth: AtomicUsize,
+    peak: AtomicUsize,
+}
+
+/// Held for the duration of one submission; releases the wallet's lane when dropped
+#[derive(Debug)]
+pub struct WalletSubmissionPermit {
+    lane: Arc<WalletLane>,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl Drop for WalletSubmissionPermit {
+    fn drop(&mut self) {
+        self.lane.depth.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+#[derive(Debug)]
+pub struct WalletSubmissionLanes {
+    per_wallet_concurrency: usize,
+    lanes: Mutex<HashMap<String, Arc<WalletLane>>>,
+}
+
+impl Default for WalletSubmissionLanes {
+    fn default() -> Self {
+        Self::new(DEFAULT_PER_WALLET_CONCURRENCY)
+    }
+}
+
+#[allow(dead_code)]
+impl WalletSubmissionLanes {
+    pub fn new(per_wallet_concurrency: usize) -> Self {
+        Self {
+            per_wallet_concurrency: per_wallet_concurrency.max(1),
+            lanes: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn per_wallet_concurrency(&self) -> usize {
+        self.per_wallet_concurrency
+    }
+
+    fn lane(&self, wallet_id: &str) -> Arc<WalletLane> {
+        let mut lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        lanes
+            .entry(wallet_id.to_string())
+            .or_insert_with(|| {
+                Arc::new(WalletLane {
+                    permits: Arc::new(Semaphore::new(self.per_wallet_concurrency)),
+                    depth: AtomicUsize::new(0),
+                    peak: AtomicUsize::new(0),
+                })
+            })
+            .clone()
+    }
+
+    /// Wait for a slot in `wallet_id`'s lane. The submission counts towards the wallet's
+    /// queue depth from the moment it starts waiting until the permit is dropped.
+    pub async fn acquire(&self, wallet_id: &str) -> WalletSubmissionPermit {
+        let lane = self.lane(wallet_id);
+        let depth = lane.depth.fetch_add(1, Ordering::Relaxed) + 1;
+        lane.peak.fetch_max(depth, Ordering::Relaxed);
+        let permit = lane
+            .permits
+            .clone()
+            .acquire_owned()
+            .await
+            .expect("wallet submission semaphore is never closed");
+        WalletSubmissionPermit {
+            lane,
+            _permit: permit,
+        }
+    }
+
+    pub fn queue_depth(&self, wallet_id: &str) -> WalletQueueDepth {
+        let lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        lanes
+            .get(wallet_id)
+            .map(|lane| Self::depth_of(lane))
+            .unwrap_or_default()
+    }
+
+    /// Queue depth of every wallet that has submitted
+    pub fn queue_depths(&self) -> HashMap<String, WalletQueueDepth> {
+        let lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+        lanes
+            .iter()
+            .map(|(wallet_id, lane)| (wallet_id.clone(), Self::depth_of(lane)))
+            .collect()
+    }
+
+    fn depth_of(lane: &WalletLane) -> WalletQueueDepth {
+        WalletQueueDepth {
+            current: lane.depth.load(Ordering::Relaxed),
+            peak: lane.peak.load(Ordering::Relaxed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[tokio::test]
+    async fn test_wallet_lanes_serialize_per_wallet_only() {
+        let lanes = Arc::new(WalletSubmissionLanes::default());
+        let first = lanes.acquire("wallet-a").await;
+
+        // Another wallet is not blocked by wallet-a's submission
+        let other = tokio::time::timeout(Duration::from_millis(50), lanes.acquire("wallet-b"))
+            .await
+            .expect("other wallets submit in parallel");
+
+        // A second wallet-a submission waits for the first
+        let waiting = {
+            let lanes = lanes.clone();
+            tokio::spawn(async move {
+                let _permit = lanes.acquire("wallet-a").await;
+            })
+        };
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        assert!(!waiting.is_finished());
+        assert_eq!(
+            lanes.queue_depth("wallet-a"),
+            WalletQueueDepth {
+                current: 2,
+                peak: 2
+            }
+        );
+
+        drop(first);
+        waiting.await.unwrap();
+        drop(other);
+        let depths = lanes.queue_depths();
+        assert_eq!(depths["wallet-a"].current, 0);
+        assert_eq!(depths["wallet-a"].peak, 2);
+        assert_eq!(depths["wallet-b"].peak, 1);
+        assert_eq!(lanes.queue_depth("unknown"), WalletQueueDepth::default());
+    }
+}
@@ -1,11 +1,52 @@
 // Monitoring and health check endpoints for SNIPERCOR
 // Provides observability for HFT system performance
 
-use axum::{extract::State, http::StatusCode, response::Json, routing::get, Router};
+use crate::modules::ai_feedback::{AIFeedbackReporter, EstimateAccuracy};
+use crate::modules::ai_funnel::SharedAIDecisionFunnel;
+use crate::modules::ewma::DEFAULT_EWMA_HALF_LIFE_SAMPLES;
+use crate::modules::hft_engine::{HFTMetrics, SharedHFTMetrics};
+use crate::modules::allocation::{
+    RebalancerConfig, SharedStrategyAllocations, StrategyAllocation, StrategyAllocations,
+};
+use crate::modules::clock_skew::ClockSkewStatus;
+use crate::modules::fee_budget::{FeeBudgetConfig, FeeBudgetStatus, FeeBudgets, SharedFeeBudgets};
+use crate::modules::pending_replacement::{PendingReplacements, SharedPendingReplacements};
+use crate::modules::persistence::AIDecisionOutcome;
+use crate::modules::shadow_divergence::{
+    ShadowDivergence, ShadowDivergenceConfig, ShadowDivergenceStatus, SharedShadowDivergence,
+};
+use crate::modules::simulation_guard::{
+    SharedSimulationGuard, SimulationGuard, SimulationGuardConfig, SimulationGuardStatus,
+};
+use crate::modules::strategy::StrategyType;
+use crate::modules::strategy_cooldown::{
+    parse_strategy_type, ExecutionCooldownStatus, ExecutionCooldowns, LosingStreakConfig,
+    SharedExecutionCooldowns, SharedStrategyCooldowns, StrategyCooldownStatus, StrategyCooldowns,
+};
+use crate::modules::pipeline_capture::{PipelineTrace, SharedPipelineCapture};
+use crate::modules::portfolio_exposure::{PortfolioExposure, PortfolioExposureCap};
+use crate::modules::strategy_sandbox::{
+    SharedStrategySandbox, StrategyPanicStatus, StrategySandbox, StrategySandboxConfig,
+};
+use crate::modules::wallet_manager::{
+    SharedWalletSelectionAudit, WalletSelectionAudit, WalletSelectionTrace,
+};
+use anyhow::{anyhow, Context, Result};
+use axum::{
+    extract::{Path, Request, State},
+    http::{header::AUTHORIZATION, HeaderMap, StatusCode},
+    middleware::{self, Next},
+    response::{Json, Response},
+    routing::{get, post},
+    Router,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
-use std::time::Instant;
-use tracing::{info, warn};
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HealthStatus {
@@ -14,6 +55,22 @@ pub struct HealthStatus {
     pub uptime_seconds: u64,
     pub version: String,
     pub components: ComponentHealth,
+    pub dependencies: BTreeMap<String, DependencyStatus>,
+    pub clock_skew: Option<ClockSkewStatus>,
+    /// Strategies the engine runs that are not disabled; None until the engine starts
+    pub active_strategy_count: Option<usize>,
+    pub issues: Vec<String>,
+}
+
+/// Operator summary served on `/status`, led by the SOL deployed in open positions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusDashboard {
+    /// Deployed total and remaining headroom; None without a portfolio exposure cap
+    pub portfolio_exposure: Option<PortfolioExposure>,
+    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub uptime_seconds: u64,
+    pub active_strategy_count: Option<usize>,
+    pub trading_metrics: TradingMetrics,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +90,15 @@ pub struct ServiceStatus {
     pub error_count: u64,
 }
 
+/// Result of the latest background probe of an external dependency
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyStatus {
+    pub reachable: bool,
+    pub last_checked: chrono::DateTime<chrono::Utc>,
+    pub latency_ms: u64,
+    pub error: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Metrics {
     pub trading_metrics: TradingMetrics,
@@ -45,6 +111,8 @@ pub struct TradingMetrics {
     pub total_signals: u64,
     pub approved_signals: u64,
     pub executed_trades: u64,
+    /// Strategy decisions to not trade, which are recorded but never executed
+    pub hold_decisions: u64,
     pub total_volume: f64,
     pub total_pnl: f64,
     pub success_rate: f64,
@@ -56,6 +124,123 @@ pub struct PerformanceMetrics {
     pub avg_execution_latency_ms: f64,
     pub max_latency_ms: f64,
     pub throughput_per_second: f64,
+    /// Market tick ingestion to bundle/transaction submission
+    pub tick_to_trade_latency_ms: LatencyHistogram,
+    /// Mean and percentiles of `tick_to_trade_latency_ms`, filled in when metrics are served
+    #[serde(default)]
+    pub tick_to_trade_summary: LatencySummary,
+    /// Market ticks dropped by the strategy engine for exceeding the max age
+    pub stale_ticks_skipped: u64,
+}
+
+/// Default tick-to-trade bucket upper bounds in milliseconds
+pub const DEFAULT_LATENCY_BUCKETS_MS: [f64; 11] = [
+    1.0, 2.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0,
+];
+
+/// Mean and bucket-bound percentiles of a latency histogram
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LatencySummary {
+    pub mean_ms: f64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+}
+
+/// Fixed-bucket latency histogram (last bucket counts values above every bound)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LatencyHistogram {
+    pub bucket_bounds_ms: Vec<f64>,
+    pub bucket_counts: Vec<u64>,
+    pub count: u64,
+    pub sum_ms: f64,
+    pub max_ms: f64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::with_buckets(DEFAULT_LATENCY_BUCKETS_MS.to_vec())
+    }
+}
+
+impl LatencyHistogram {
+    pub fn with_buckets(mut bucket_bounds_ms: Vec<f64>) -> Self {
+        bucket_bounds_ms.sort_by(|a, b| a.total_cmp(b));
+        let bucket_counts = vec![0; bucket_bounds_ms.len() + 1];
+
+        Self {
+            bucket_bounds_ms,
+            bucket_counts,
+            count: 0,
+            sum_ms: 0.0,
+            max_ms: 0.0,
+        }
+    }
+
+    pub fn record(&mut self, latency_ms: f64) {
+        let bucket = self
+            .bucket_bounds_ms
+            .iter()
+            .position(|bound| latency_ms <= *bound)
+            .unwrap_or(self.bucket_bounds_ms.len());
+
+        self.bucket_counts[bucket] += 1;
+        self.count += 1;
+        self.sum_ms += latency_ms;
+        self.max_ms = self.max_ms.max(latency_ms);
+    }
+
+    pub fn mean_ms(&self) -> f64 {
+        if self.count == 0 {
+            0.0
+        } else {
+            self.sum_ms / self.count as f64
+        }
+    }
+
+    /// Upper bound of the bucket containing the given quantile (0.0..=1.0)
+    pub fn percentile_ms(&self, quantile: f64) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+
+        let target = (quantile.clamp(0.0, 1.0) * self.count as f64)
+            .ceil()
+            .max(1.0) as u64;
+        let mut seen = 0;
+        for (i, count) in self.bucket_counts.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return self.bucket_bounds_ms.get(i).copied().unwrap_or(self.max_ms);
+            }
+        }
+        self.max_ms
+    }
+
+    pub fn summary(&self) -> LatencySummary {
+        LatencySummary {
+            mean_ms: self.mean_ms(),
+            p50_ms: self.percentile_ms(0.5),
+            p90_ms: self.percentile_ms(0.9),
+            p99_ms: self.percentile_ms(0.99),
+        }
+    }
+
+    fn to_prometheus(&self, name: &str, help: &str) -> String {
+        let mut out = format!("# HELP {} {}\n# TYPE {} histogram\n", name, help, name);
+        let mut cumulative = 0;
+        for (bound, count) in self.bucket_bounds_ms.iter().zip(&self.bucket_counts) {
+            cumulative += count;
+            out.push_str(&format!(
+                "{}_bucket{{le=\"{}\"}} {}\n",
+                name, bound, cumulative
+            ));
+        }
+        out.push_str(&format!("{}_bucket{{le=\"+Inf\"}} {}\n", name, self.count));
+        out.push_str(&format!("{}_sum {}\n", name, self.sum_ms));
+        out.push_str(&format!("{}_count {}\n", name, self.count));
+        out
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -79,6 +264,48 @@ pub struct MonitoringState {
     pub start_time: Instant,
     pub health: Arc<Mutex<ComponentHealth>>,
     pub metrics: Arc<Mutex<Metrics>>,
+    pub dependencies: Arc<Mutex<BTreeMap<String, DependencyStatus>>>,
+    /// Latest local-vs-cluster clock comparison; readiness fails while out of tolerance
+    pub clock_skew: Arc<Mutex<Option<ClockSkewStatus>>>,
+    pub strategy_cooldowns: SharedStrategyCooldowns,
+    pub execution_cooldowns: SharedExecutionCooldowns,
+    /// Fees each strategy paid today against its daily fee budget
+    pub fee_budgets: SharedFeeBudgets,
+    pub strategy_allocations: SharedStrategyAllocations,
+    /// Per-strategy panic counts; panicking strategies are disabled until re-enabled
+    pub strategy_sandbox: SharedStrategySandbox,
+    /// Halts live trading on repeated pre-flight simulation failures
+    pub simulation_guard: SharedSimulationGuard,
+    /// Shadow-vs-live decision disagreement, judged before promoting a shadow config
+    pub shadow_divergence: SharedShadowDivergence,
+    /// Pending live bundles that can be replaced with a fee-bumped rebuild
+    pub pending_replacements: SharedPendingReplacements,
+    /// Sniping buys rejected by the honeypot guard
+    pub honeypots_blocked: Arc<AtomicU64>,
+    /// Ticks dropped as provider replays after a market data reconnect
+    pub market_replays_suppressed: Arc<AtomicU64>,
+    /// Developer-tracker money flows evicted by the count cap
+    pub money_flows_evicted: Arc<AtomicU64>,
+    /// Jito bundles submitted and not yet confirmed or dropped
+    pub jito_bundles_in_flight: Arc<AtomicU64>,
+    /// HFT engine metrics, recorded by the engine inside the executor task
+    pub hft_metrics: SharedHFTMetrics,
+    /// Recent wallet selection decisions with per-candidate scoring
+    pub wallet_selection_audit: SharedWalletSelectionAudit,
+    /// Strategies the strategy engine runs, reported when it starts
+    pub engine_strategies: Arc<Mutex<Option<Vec<StrategyType>>>>,
+    /// Readiness fails while no strategy is active
+    pub require_active_strategies: bool,
+    /// Reports realized P&L of AI decisions to TensorZero when AI feedback is enabled
+    pub ai_feedback: Option<Arc<AIFeedbackReporter>>,
+    /// AI decisions reaching each stage from receipt to confirmation
+    pub ai_funnel: Option<SharedAIDecisionFunnel>,
+    /// Global cap on SOL deployed in open positions, headlined on `/status`
+    pub portfolio_exposure: Option<Arc<PortfolioExposureCap>>,
+    /// Forensic traces of targeted signals, served on `/debug/trace/:signal_id`
+    pub pipeline_capture: Option<SharedPipelineCapture>,
+    /// Bearer token the mutating routes require; they answer 403 while unset
+    pub admin_token: Option<Arc<str>>,
 }
 
 #[allow(dead_code)]
@@ -130,6 +357,7 @@ impl MonitoringState {
                 trading_metrics: TradingMetrics {
                     total_signals: 0,
                     approved_signals: 0,
+                    hold_decisions: 0,
                     executed_trades: 0,
                     total_volume: 0.0,
                     total_pnl: 0.0,
@@ -140,6 +368,9 @@ impl MonitoringState {
                     avg_execution_latency_ms: 0.0,
                     max_latency_ms: 0.0,
                     throughput_per_second: 0.0,
+                    tick_to_trade_latency_ms: LatencyHistogram::default(),
+                    tick_to_trade_summary: LatencySummary::default(),
+                    stale_ticks_skipped: 0,
                 },
                 system_metrics: SystemMetrics {
                     memory_usage_mb: 0.0,
@@ -153,6 +384,210 @@ impl MonitoringState {
                     },
                 },
             })),
+            dependencies: Arc::new(Mutex::new(BTreeMap::new())),
+            clock_skew: Arc::new(Mutex::new(None)),
+            strategy_cooldowns: StrategyCooldowns::shared(LosingStreakConfig::default()),
+            execution_cooldowns: ExecutionCooldowns::shared(0),
+            fee_budgets: FeeBudgets::shared(FeeBudgetConfig::default()),
+            strategy_allocations: StrategyAllocations::shared(RebalancerConfig::default()),
+            strategy_sandbox: StrategySandbox::shared(StrategySandboxConfig::default()),
+            simulation_guard: SimulationGuard::shared(SimulationGuardConfig::default()),
+            shadow_divergence: ShadowDivergence::shared(ShadowDivergenceConfig::default()),
+            pending_replacements: PendingReplacements::shared(),
+            honeypots_blocked: Arc::new(AtomicU64::new(0)),
+            market_replays_suppressed: Arc::new(AtomicU64::new(0)),
+            money_flows_evicted: Arc::new(AtomicU64::new(0)),
+            jito_bundles_in_flight: Arc::new(AtomicU64::new(0)),
+            hft_metrics: HFTMetrics::shared(DEFAULT_EWMA_HALF_LIFE_SAMPLES),
+            wallet_selection_audit: WalletSelectionAudit::shared(0),
+            engine_strategies: Arc::new(Mutex::new(None)),
+            require_active_strategies: false,
+            ai_feedback: None,
+            ai_funnel: None,
+            portfolio_exposure: None,
+            pipeline_capture: None,
+            admin_token: None,
+        }
+    }
+
+    /// Accept realized trade outcomes on `POST /ai/outcomes` and forward them as AI feedback
+    pub fn with_ai_feedback(mut self, reporter: Arc<AIFeedbackReporter>) -> Self {
+        self.ai_feedback = Some(reporter);
+        self
+    }
+
+    /// Export the AI decision acceptance funnel on `/metrics`
+    pub fn with_ai_funnel(mut self, ai_funnel: SharedAIDecisionFunnel) -> Self {
+        self.ai_funnel = Some(ai_funnel);
+        self
+    }
+
+    /// Headline the deployed total and headroom under the portfolio exposure cap on `/status`
+    pub fn with_portfolio_exposure_cap(mut self, exposure_cap: Arc<PortfolioExposureCap>) -> Self {
+        self.portfolio_exposure = Some(exposure_cap);
+        self
+    }
+
+    /// Serve captured signal→execution traces through the HTTP API
+    pub fn with_pipeline_capture(mut self, pipeline_capture: SharedPipelineCapture) -> Self {
+        self.pipeline_capture = Some(pipeline_capture);
+        self
+    }
+
+    /// Enable the mutating routes for requests bearing `token`
+    pub fn with_admin_token(mut self, token: &str) -> Self {
+        self.admin_token = Some(Arc::from(token));
+        self
+    }
+
+    /// Expose the risk manager's cool-down state through the HTTP API
+    pub fn with_strategy_cooldowns(mut self, strategy_cooldowns: SharedStrategyCooldowns) -> Self {
+        self.strategy_cooldowns = strategy_cooldowns;
+        self
+    }
+
+    /// Expose active post-execution cool-downs through the HTTP API
+    pub fn with_execution_cooldowns(
+        mut self,
+        execution_cooldowns: SharedExecutionCooldowns,
+    ) -> Self {
+        self.execution_cooldowns = execution_cooldowns;
+        self
+    }
+
+    /// Expose and allow overriding the rebalancer's strategy allocations
+    pub fn with_strategy_allocations(
+        mut self,
+        strategy_allocations: SharedStrategyAllocations,
+    ) -> Self {
+        self.strategy_allocations = strategy_allocations;
+        self
+    }
+
+    /// Expose strategy panic counts and re-enable sandboxed strategies through the HTTP API
+    pub fn with_strategy_sandbox(mut self, strategy_sandbox: SharedStrategySandbox) -> Self {
+        self.strategy_sandbox = strategy_sandbox;
+        self
+    }
+
+    /// Expose the live trading halt and allow lifting it through the HTTP API
+    pub fn with_simulation_guard(mut self, simulation_guard: SharedSimulationGuard) -> Self {
+        self.simulation_guard = simulation_guard;
+        self
+    }
+
+    /// Expose the remaining per-strategy fee budgets through the HTTP API
+    pub fn with_fee_budgets(mut self, fee_budgets: SharedFeeBudgets) -> Self {
+        self.fee_budgets = fee_budgets;
+        self
+    }
+
+    /// Expose the shadow-vs-live divergence comparator through the HTTP API
+    pub fn with_shadow_divergence(mut self, shadow_divergence: SharedShadowDivergence) -> Self {
+        self.shadow_divergence = shadow_divergence;
+        self
+    }
+
+    /// Allow replacing stuck pending bundles through the HTTP API
+    pub fn with_pending_replacements(
+        mut self,
+        pending_replacements: SharedPendingReplacements,
+    ) -> Self {
+        self.pending_replacements = pending_replacements;
+        self
+    }
+
+    /// Fail readiness while the strategy engine has no active strategy
+    pub fn with_required_active_strategies(mut self, required: bool) -> Self {
+        self.require_active_strategies = required;
+        self
+    }
+
+    /// Export the honeypot guard's blocked-buy counter
+    pub fn with_honeypot_counter(mut self, honeypots_blocked: Arc<AtomicU64>) -> Self {
+        self.honeypots_blocked = honeypots_blocked;
+        self
+    }
+
+    /// Export the data ingestor's suppressed-replay counter
+    pub fn with_replay_counter(mut self, market_replays_suppressed: Arc<AtomicU64>) -> Self {
+        self.market_replays_suppressed = market_replays_suppressed;
+        self
+    }
+
+    /// Export the developer tracker's money-flow eviction counter
+    pub fn with_money_flow_eviction_counter(mut self, money_flows_evicted: Arc<AtomicU64>) -> Self {
+        self.money_flows_evicted = money_flows_evicted;
+        self
+    }
+
+    /// Expose the wallet manager's selection decisions through the HTTP API
+    pub fn with_wallet_selection_audit(
+        mut self,
+        wallet_selection_audit: SharedWalletSelectionAudit,
+    ) -> Self {
+        self.wallet_selection_audit = wallet_selection_audit;
+        self
+    }
+
+    /// Use custom tick-to-trade histogram buckets (milliseconds)
+    pub fn with_latency_buckets(self, bucket_bounds_ms: Vec<f64>) -> Self {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.performance_metrics.tick_to_trade_latency_ms =
+                LatencyHistogram::with_buckets(bucket_bounds_ms);
+        }
+        self
+    }
+
+    pub fn record_tick_to_trade(&self, latency_ms: f64) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics
+                .performance_metrics
+                .tick_to_trade_latency_ms
+                .record(latency_ms);
+        }
+    }
+
+    pub fn set_engine_strategies(&self, strategies: Vec<StrategyType>) {
+        if let Ok(mut engine_strategies) = self.engine_strategies.lock() {
+            *engine_strategies = Some(strategies);
+        }
+    }
+
+    /// Engine strategies not disabled by the panic sandbox; None until the engine starts
+    pub fn active_strategy_count(&self) -> Option<usize> {
+        let engine_strategies = self.engine_strategies.lock().ok()?;
+        let strategies = engine_strategies.as_ref()?;
+        let sandbox = self.strategy_sandbox.lock().ok()?;
+        Some(
+            strategies
+                .iter()
+                .filter(|strategy| !sandbox.is_disabled(strategy))
+                .count(),
+        )
+    }
+
+    pub fn record_hold_decision(&self) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.trading_metrics.hold_decisions += 1;
+        }
+    }
+
+    pub fn record_stale_tick_skipped(&self) {
+        if let Ok(mut metrics) = self.metrics.lock() {
+            metrics.performance_metrics.stale_ticks_skipped += 1;
+        }
+    }
+
+    pub fn update_dependency_status(&self, name: &str, status: DependencyStatus) {
+        if let Ok(mut dependencies) = self.dependencies.lock() {
+            dependencies.insert(name.to_string(), status);
+        }
+    }
+
+    pub fn update_clock_skew(&self, status: ClockSkewStatus) {
+        if let Ok(mut clock_skew) = self.clock_skew.lock() {
+            *clock_skew = Some(status);
         }
     }
 
@@ -215,8 +650,21 @@ pub async fn health_check(
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .clone();
 
+    let dependencies = state
+        .dependencies
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .clone();
+
+    let clock_skew = state
+        .clock_skew
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .clone();
+
     // Determine overall status
-    let overall_status = if is_system_healthy(&health) {
+    let issues = collect_health_issues(&health, &dependencies, clock_skew.as_ref());
+    let overall_status = if issues.is_empty() {
         "healthy"
     } else {
         "unhealthy"
@@ -228,6 +676,10 @@ pub async fn health_check(
         uptime_seconds: uptime,
         version: env!("CARGO_PKG_VERSION").to_string(),
         components: health,
+        dependencies,
+        clock_skew,
+        active_strategy_count: state.active_strategy_count(),
+        issues,
     };
 
     info!("Health check requested - Status: {}", overall_status);
@@ -244,7 +696,17 @@ pub async fn readiness_check(
         .lock()
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
-    if is_system_ready(&health) {
+    let clock_skew_ok = state
+        .clock_skew
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .as_ref()
+        .is_none_or(|skew| skew.within_tolerance);
+
+    let strategies_ok =
+        !state.require_active_strategies || state.active_strategy_count() != Some(0);
+
+    if is_system_ready(&health) && clock_skew_ok && strategies_ok {
         Ok(StatusCode::OK)
     } else {
         Err(StatusCode::SERVICE_UNAVAILABLE)
@@ -261,11 +723,13 @@ pub async fn liveness_check() -> StatusCode {
 pub async fn metrics_endpoint(
     State(state): State<MonitoringState>,
 ) -> Result<Json<Metrics>, StatusCode> {
-    let metrics = state
+    let mut metrics = state
         .metrics
         .lock()
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
         .clone();
+    metrics.performance_metrics.tick_to_trade_summary =
+        metrics.performance_metrics.tick_to_trade_latency_ms.summary();
 
     Ok(Json(metrics))
 }
@@ -279,8 +743,101 @@ pub async fn prometheus_metrics(
         .lock()
         .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
 
+    let tick_to_trade = metrics
+        .performance_metrics
+        .tick_to_trade_latency_ms
+        .to_prometheus(
+            "sniper_tick_to_trade_latency_ms",
+            "Latency from market tick ingestion to trade submission in milliseconds",
+        );
+
+    let clock_skew_ms = state
+        .clock_skew
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .as_ref()
+        .map_or(0, |skew| skew.skew_ms);
+
+    let mut strategy_panics = String::from(
+        "# HELP sniper_strategy_panics Panics caught per strategy by the sandbox\n\
+         # TYPE sniper_strategy_panics counter\n",
+    );
+    for status in state
+        .strategy_sandbox
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .all_status()
+    {
+        strategy_panics.push_str(&format!(
+            "sniper_strategy_panics{{strategy=\"{:?}\"}} {}\n",
+            status.strategy_type, status.panic_count
+        ));
+    }
+
+    let live_trading_halted = state
+        .simulation_guard
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .is_halted();
+
+    let hft = state
+        .hft_metrics
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .clone();
+
+    let ai_funnel = state
+        .ai_funnel
+        .as_ref()
+        .map(|funnel| format!("{}\n", funnel.to_prometheus()))
+        .unwrap_or_default();
+
     let prometheus_format = format!(
-        "# HELP sniper_total_signals Total number of trading signals generated\n\
+        "{}\n\
+         {}\n\
+         {}\
+         # HELP sniper_clock_skew_ms Local clock minus Solana cluster block time in milliseconds\n\
+         # TYPE sniper_clock_skew_ms gauge\n\
+         sniper_clock_skew_ms {}\n\
+         \n\
+         # HELP sniper_stale_ticks_skipped Market ticks skipped for exceeding the max tick age\n\
+         # TYPE sniper_stale_ticks_skipped counter\n\
+         sniper_stale_ticks_skipped {}\n\
+         \n\
+         # HELP sniper_honeypots_blocked Sniping buys rejected because the token appeared unsellable\n\
+         # TYPE sniper_honeypots_blocked counter\n\
+         sniper_honeypots_blocked {}\n\
+         \n\
+         # HELP sniper_market_replays_suppressed Duplicate ticks dropped after a market data reconnect\n\
+         # TYPE sniper_market_replays_suppressed counter\n\
+         sniper_market_replays_suppressed {}\n\
+         \n\
+         # HELP sniper_money_flows_evicted Developer-tracker money flows dropped by the history cap\n\
+         # TYPE sniper_money_flows_evicted counter\n\
+         sniper_money_flows_evicted {}\n\
+         \n\
+         # HELP sniper_jito_bundles_in_flight Jito bundles submitted and awaiting confirmation\n\
+         # TYPE sniper_jito_bundles_in_flight gauge\n\
+         sniper_jito_bundles_in_flight {}\n\
+         \n\
+         # HELP sniper_hft_executions HFT engine executions by outcome\n\
+         # TYPE sniper_hft_executions counter\n\
+         sniper_hft_executions{{outcome=\"success\"}} {}\n\
+         sniper_hft_executions{{outcome=\"failure\"}} {}\n\
+         \n\
+         # HELP sniper_hft_bundles_submitted Jito bundles submitted by the HFT engine\n\
+         # TYPE sniper_hft_bundles_submitted counter\n\
+         sniper_hft_bundles_submitted {}\n\
+         \n\
+         # HELP sniper_hft_avg_latency_ms Recent average HFT execution latency in milliseconds\n\
+         # TYPE sniper_hft_avg_latency_ms gauge\n\
+         sniper_hft_avg_latency_ms {}\n\
+         \n\
+         # HELP sniper_live_trading_halted 1 while live trading is halted by pre-flight simulation failures\n\
+         # TYPE sniper_live_trading_halted gauge\n\
+         sniper_live_trading_halted {}\n\
+         \n\
+         # HELP sniper_total_signals Total number of trading signals generated\n\
          # TYPE sniper_total_signals counter\n\
          sniper_total_signals {}\n\
          \n\
@@ -288,6 +845,10 @@ pub async fn prometheus_metrics(
          # TYPE sniper_executed_trades counter\n\
          sniper_executed_trades {}\n\
          \n\
+         # HELP sniper_hold_decisions Strategy decisions to hold instead of trading\n\
+         # TYPE sniper_hold_decisions counter\n\
+         sniper_hold_decisions {}\n\
+         \n\
          # HELP sniper_avg_latency_ms Average signal processing latency in milliseconds\n\
          # TYPE sniper_avg_latency_ms gauge\n\
          sniper_avg_latency_ms {}\n\
@@ -299,8 +860,23 @@ pub async fn prometheus_metrics(
          # HELP sniper_success_rate Trading success rate\n\
          # TYPE sniper_success_rate gauge\n\
          sniper_success_rate {}\n",
+        tick_to_trade,
+        strategy_panics,
+        ai_funnel,
+        clock_skew_ms,
+        metrics.performance_metrics.stale_ticks_skipped,
+        state.honeypots_blocked.load(Ordering::Relaxed),
+        state.market_replays_suppressed.load(Ordering::Relaxed),
+        state.money_flows_evicted.load(Ordering::Relaxed),
+        state.jito_bundles_in_flight.load(Ordering::Relaxed),
+        hft.successful_executions,
+        hft.failed_executions,
+        hft.bundles_submitted,
+        hft.avg_latency_ms,
+        u8::from(live_trading_halted),
         metrics.trading_metrics.total_signals,
         metrics.trading_metrics.executed_trades,
+        metrics.trading_metrics.hold_decisions,
         metrics.performance_metrics.avg_signal_latency_ms,
         metrics.trading_metrics.total_pnl,
         metrics.trading_metrics.success_rate
@@ -309,29 +885,55 @@ pub async fn prometheus_metrics(
     Ok(prometheus_format)
 }
 
-fn is_system_healthy(health: &ComponentHealth) -> bool {
+/// Explain every reason the system is currently unhealthy (empty when healthy)
+fn collect_health_issues(
+    health: &ComponentHealth,
+    dependencies: &BTreeMap<String, DependencyStatus>,
+    clock_skew: Option<&ClockSkewStatus>,
+) -> Vec<String> {
     let now = chrono::Utc::now();
     let max_age = chrono::Duration::seconds(30); // 30 seconds max age for heartbeat
+    let mut issues = Vec::new();
 
     let components = [
-        &health.data_ingestor,
-        &health.strategy_engine,
-        &health.risk_manager,
-        &health.executor,
-        &health.persistence,
+        ("data_ingestor", &health.data_ingestor),
+        ("strategy_engine", &health.strategy_engine),
+        ("risk_manager", &health.risk_manager),
+        ("executor", &health.executor),
+        ("persistence", &health.persistence),
     ];
 
-    for component in components {
+    for (name, component) in components {
         // Check if component is running and heartbeat is recent
-        if component.status != "running"
-            || (now - component.last_heartbeat) > max_age
-            || component.error_count > 10
-        {
-            return false;
+        if component.status != "running" {
+            issues.push(format!("{} status is {}", name, component.status));
+        }
+        if (now - component.last_heartbeat) > max_age {
+            issues.push(format!("{} heartbeat is stale", name));
+        }
+        if component.error_count > 10 {
+            issues.push(format!("{} has {} errors", name, component.error_count));
         }
     }
 
-    true
+    for (name, dependency) in dependencies {
+        if !dependency.reachable {
+            issues.push(format!(
+                "{} unreachable: {}",
+                name,
+                dependency.error.as_deref().unwrap_or("unknown error")
+            ));
+        }
+    }
+
+    if let Some(skew) = clock_skew.filter(|skew| !skew.within_tolerance) {
+        issues.push(format!(
+            "clock skew {}ms exceeds {}ms",
+            skew.skew_ms, skew.max_skew_ms
+        ));
+    }
+
+    issues
 }
 
 fn is_system_ready(health: &ComponentHealth) -> bool {
@@ -352,12 +954,642 @@ fn is_system_ready(health: &ComponentHealth) -> bool {
     true
 }
 
+/// Which external dependencies to probe and how often
+#[derive(Debug, Clone)]
+pub struct DependencyProbeConfig {
+    pub solana_rpc_url: Option<String>,
+    pub jito_endpoint: Option<String>,
+    pub tensorzero_gateway_url: Option<String>,
+    pub dragonfly_url: Option<String>,
+    pub database_url: Option<String>,
+    pub probe_interval: Duration,
+    pub probe_timeout: Duration,
+}
+
+impl Default for DependencyProbeConfig {
+    fn default() -> Self {
+        Self {
+            solana_rpc_url: None,
+            jito_endpoint: None,
+            tensorzero_gateway_url: None,
+            dragonfly_url: None,
+            database_url: None,
+            probe_interval: Duration::from_secs(15),
+            probe_timeout: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Background task that periodically probes external dependencies and
+/// records their reachability and latency in the monitoring state
+pub struct DependencyProber {
+    config: DependencyProbeConfig,
+    client: reqwest::Client,
+    state: MonitoringState,
+}
+
+impl DependencyProber {
+    pub fn new(config: DependencyProbeConfig, state: MonitoringState) -> Result<Self> {
+        let client = reqwest::Client::builder()
+            .timeout(config.probe_timeout)
+            .build()
+            .context("Failed to create probe HTTP client")?;
+
+        Ok(Self {
+            config,
+            client,
+            state,
+        })
+    }
+
+    pub async fn run(self) {
+        info!(
+            "🩺 Dependency prober starting (interval: {:?})",
+            self.config.probe_interval
+        );
+        let mut interval = tokio::time::interval(self.config.probe_interval);
+
+        loop {
+            interval.tick().await;
+            self.probe_all().await;
+        }
+    }
+
+    async fn probe_all(&self) {
+        if let Some(url) = &self.config.solana_rpc_url {
+            let status = self.timed(self.probe_solana_rpc(url)).await;
+            self.record("solana_rpc", status);
+        }
+        if let Some(url) = &self.config.jito_endpoint {
+            let status = self.timed(self.probe_jito(url)).await;
+            self.record("jito", status);
+        }
+        if let Some(url) = &self.config.tensorzero_gateway_url {
+            let status = self
+                .timed(self.probe_http_get(format!("{}/health", url)))
+                .await;
+            self.record("tensorzero", status);
+        }
+        if let Some(url) = &self.config.dragonfly_url {
+            let status = self.timed(self.probe_dragonfly(url)).await;
+            self.record("dragonflydb", status);
+        }
+        if let Some(url) = &self.config.database_url {
+            let status = self.timed(self.probe_tcp(url)).await;
+            self.record("database", status);
+        }
+    }
+
+    fn record(&self, name: &str, status: DependencyStatus) {
+        if status.reachable {
+            debug!("🩺 {} reachable in {}ms", name, status.latency_ms);
+        } else {
+            warn!(
+                "🩺 {} unreachable: {}",
+                name,
+                status.error.as_deref().unwrap_or("unknown")
+            );
+        }
+        self.state.update_dependency_status(name, status);
+    }
+
+    async fn timed<F>(&self, probe: F) -> DependencyStatus
+    where
+        F: Future<Output = Result<()>>,
+    {
+        let start = Instant::now();
+        let outcome = match tokio::time::timeout(self.config.probe_timeout, probe).await {
+            Ok(result) => result,
+            Err(_) => Err(anyhow!(
+                "probe timed out after {:?}",
+                self.config.probe_timeout
+            )),
+        };
+
+        DependencyStatus {
+            reachable: outcome.is_ok(),
+            last_checked: chrono::Utc::now(),
+            latency_ms: start.elapsed().as_millis() as u64,
+            error: outcome.err().map(|e| e.to_string()),
+        }
+    }
+
+    async fn probe_solana_rpc(&self, url: &str) -> Result<()> {
+        let response = self
+            .client
+            .post(url)
+            .json(&serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "getHealth"}))
+            .send()
+            .await?;
+
+        let body: serde_json::Value = response.json().await?;
+        if let Some(error) = body.get("error") {
+            return Err(anyhow!("RPC reports unhealthy: {}", error));
+        }
+        Ok(())
+    }
+
+    async fn probe_jito(&self, endpoint: &str) -> Result<()> {
+        let response = self
+            .client
+            .post(format!("{}/api/v1/bundles", endpoint))
+            .json(&serde_json::json!({"jsonrpc": "2.0", "id": 1, "method": "getTipAccounts", "params": []}))
+            .send()
+            .await?;
+
+        if !response.status().is_success() {
+            return Err(anyhow!("HTTP {}", response.status()));
+        }
+        Ok(())
+    }
+
+    async fn probe_http_get(&self, url: String) -> Result<()> {
+        let response = self.client.get(&url).send().await?;
+        if !response.status().is_success() {
+            return Err(anyhow!("HTTP {}", response.status()));
+        }
+        Ok(())
+    }
+
+    async fn probe_dragonfly(&self, url: &str) -> Result<()> {
+        let client = redis::Client::open(url)?;
+        let mut conn = client.get_multiplexed_async_connection().await?;
+        let _: String = redis::cmd("PING").query_async(&mut conn).await?;
+        Ok(())
+    }
+
+    async fn probe_tcp(&self, url: &str) -> Result<()> {
+        let parsed = reqwest::Url::parse(url).context("Invalid database URL")?;
+        let host = parsed.host_str().context("Database URL has no host")?;
+        let port = parsed.port().unwrap_or(5432);
+        tokio::net::TcpStream::connect((host, port)).await?;
+        Ok(())
+    }
+}
+
+async fn strategy_cooldowns(
+    State(state): State<MonitoringState>,
+) -> Result<Json<Vec<StrategyCooldownStatus>>, StatusCode> {
+    let cooldowns = state
+        .strategy_cooldowns
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(cooldowns.all_status()))
+}
+
+async fn execution_cooldowns(
+    State(state): State<MonitoringState>,
+) -> Result<Json<Vec<ExecutionCooldownStatus>>, StatusCode> {
+    let cooldowns = state
+        .execution_cooldowns
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(cooldowns.active()))
+}
+
+async fn strategy_allocations(
+    State(state): State<MonitoringState>,
+) -> Result<Json<Vec<StrategyAllocation>>, StatusCode> {
+    let allocations = state
+        .strategy_allocations
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(allocations.allocations()))
+}
+
+/// Body of `POST /strategies/:strategy/allocation`; a null multiplier clears the override
+#[derive(Debug, Deserialize)]
+pub struct AllocationOverrideRequest {
+    pub multiplier: Option<f64>,
+}
+
+async fn override_strategy_allocation(
+    State(state): State<MonitoringState>,
+    Path(strategy): Path<String>,
+    Json(request): Json<AllocationOverrideRequest>,
+) -> Result<Json<Vec<StrategyAllocation>>, StatusCode> {
+    let strategy = parse_strategy_type(&strategy).map_err(|_| StatusCode::NOT_FOUND)?;
+    let mut allocations = state
+        .strategy_allocations
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    match request.multiplier {
+        Some(multiplier) if !multiplier.is_finite() || multiplier < 0.0 => {
+            return Err(StatusCode::BAD_REQUEST);
+        }
+        Some(multiplier) => allocations.set_override(&strategy, multiplier),
+        None => allocations.clear_override(&strategy),
+    }
+    Ok(Json(allocations.allocations()))
+}
+
+async fn enable_strategy(
+    State(state): State<MonitoringState>,
+    Path(strategy): Path<String>,
+) -> Result<Json<StrategyCooldownStatus>, StatusCode> {
+    let strategy = parse_strategy_type(&strategy).map_err(|_| StatusCode::NOT_FOUND)?;
+    let mut cooldowns = state
+        .strategy_cooldowns
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    cooldowns.reset(&strategy);
+    if let Ok(mut sandbox) = state.strategy_sandbox.lock() {
+        sandbox.enable(&strategy);
+    }
+    Ok(Json(cooldowns.status(&strategy)))
+}
+
+async fn simulation_guard_status(
+    State(state): State<MonitoringState>,
+) -> Result<Json<SimulationGuardStatus>, StatusCode> {
+    let guard = state
+        .simulation_guard
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(guard.status()))
+}
+
+async fn enable_live_trading(
+    State(state): State<MonitoringState>,
+) -> Result<Json<SimulationGuardStatus>, StatusCode> {
+    let mut guard = state
+        .simulation_guard
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    guard.reenable();
+    Ok(Json(guard.status()))
+}
+
+async fn fee_budgets(
+    State(state): State<MonitoringState>,
+) -> Result<Json<Vec<FeeBudgetStatus>>, StatusCode> {
+    let mut budgets = state
+        .fee_budgets
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(budgets.status()))
+}
+
+async fn shadow_divergence_status(
+    State(state): State<MonitoringState>,
+) -> Result<Json<ShadowDivergenceStatus>, StatusCode> {
+    let divergence = state
+        .shadow_divergence
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(divergence.status()))
+}
+
+async fn pending_trades(State(state): State<MonitoringState>) -> Json<Vec<String>> {
+    Json(state.pending_replacements.pending_signals())
+}
+
+async fn replace_pending_trade(
+    State(state): State<MonitoringState>,
+    Path(signal_id): Path<String>,
+) -> StatusCode {
+    match state.pending_replacements.replace_pending(&signal_id) {
+        Ok(()) => StatusCode::ACCEPTED,
+        Err(e) => {
+            warn!("Replacement of {} not requested: {}", signal_id, e);
+            StatusCode::NOT_FOUND
+        }
+    }
+}
+
+async fn strategy_panics(
+    State(state): State<MonitoringState>,
+) -> Result<Json<Vec<StrategyPanicStatus>>, StatusCode> {
+    let sandbox = state
+        .strategy_sandbox
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(sandbox.all_status()))
+}
+
+async fn wallet_selection_trace(
+    State(state): State<MonitoringState>,
+) -> Result<Json<Vec<WalletSelectionTrace>>, StatusCode> {
+    let audit = state
+        .wallet_selection_audit
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+
+    Ok(Json(audit.traces()))
+}
+
+/// Body of `POST /ai/outcomes`
+#[derive(Debug, Deserialize)]
+pub struct AIOutcomeRequest {
+    pub signal_id: String,
+    pub realized_pnl: f64,
+}
+
+async fn report_ai_outcome(
+    State(state): State<MonitoringState>,
+    Json(request): Json<AIOutcomeRequest>,
+) -> Result<Json<AIDecisionOutcome>, StatusCode> {
+    let reporter = state.ai_feedback.ok_or(StatusCode::NOT_FOUND)?;
+    if !request.realized_pnl.is_finite() {
+        return Err(StatusCode::BAD_REQUEST);
+    }
+
+    reporter
+        .report_outcome(&request.signal_id, request.realized_pnl)
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn status_dashboard(
+    State(state): State<MonitoringState>,
+) -> Result<Json<StatusDashboard>, StatusCode> {
+    let portfolio_exposure = match &state.portfolio_exposure {
+        Some(exposure_cap) => Some(exposure_cap.exposure().await),
+        None => None,
+    };
+    let trading_metrics = state
+        .metrics
+        .lock()
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .trading_metrics
+        .clone();
+
+    Ok(Json(StatusDashboard {
+        portfolio_exposure,
+        timestamp: chrono::Utc::now(),
+        uptime_seconds: state.start_time.elapsed().as_secs(),
+        active_strategy_count: state.active_strategy_count(),
+        trading_metrics,
+    }))
+}
+
+/// Every captured artifact of one targeted signal, from market tick to final result
+async fn pipeline_trace(
+    State(state): State<MonitoringState>,
+    Path(signal_id): Path<String>,
+) -> Result<Json<PipelineTrace>, StatusCode> {
+    state
+        .pipeline_capture
+        .as_ref()
+        .and_then(|capture| capture.trace(&signal_id))
+        .map(Json)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+/// Rolling accuracy of the AI's estimated profit against realized P&L, per strategy
+async fn ai_accuracy_report(
+    State(state): State<MonitoringState>,
+) -> Result<Json<Vec<EstimateAccuracy>>, StatusCode> {
+    let reporter = state.ai_feedback.ok_or(StatusCode::NOT_FOUND)?;
+    Ok(Json(reporter.accuracy_report()))
+}
+
+/// Whether a request may use the mutating routes: it must carry the configured admin
+/// token as a bearer token, and nothing may while no token is configured
+fn check_admin_token(admin_token: Option<&str>, headers: &HeaderMap) -> Result<(), StatusCode> {
+    let admin_token = admin_token.ok_or(StatusCode::FORBIDDEN)?;
+    let presented = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(StatusCode::UNAUTHORIZED)?;
+
+    // Compare every byte so the response time does not reveal a matching prefix
+    let matches = presented.len() == admin_token.len()
+        && presented
+            .bytes()
+            .zip(admin_token.bytes())
+            .fold(0u8, |diff, (a, b)| diff | (a ^ b))
+            == 0;
+    if matches {
+        Ok(())
+    } else {
+        Err(StatusCode::UNAUTHORIZED)
+    }
+}
+
+async fn require_admin_token(
+    State(state): State<MonitoringState>,
+    request: Request,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if let Err(status) = check_admin_token(state.admin_token.as_deref(), request.headers()) {
+        warn!("🔒 Rejected {} {}: {}", request.method(), request.uri().path(), status);
+        return Err(status);
+    }
+    Ok(next.run(request).await)
+}
+
 pub fn create_monitoring_router(state: MonitoringState) -> Router {
+    // Routes that change trading state require the admin token
+    let admin = Router::new()
+        .route(
+            "/strategies/:strategy/allocation",
+            post(override_strategy_allocation),
+        )
+        .route("/strategies/:strategy/enable", post(enable_strategy))
+        .route("/trading/live/enable", post(enable_live_trading))
+        .route(
+            "/trading/pending/:signal_id/replace",
+            post(replace_pending_trade),
+        )
+        .route("/ai/outcomes", post(report_ai_outcome))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_admin_token,
+        ));
+
     Router::new()
+        .route("/status", get(status_dashboard))
         .route("/health", get(health_check))
         .route("/ready", get(readiness_check))
         .route("/live", get(liveness_check))
         .route("/metrics", get(metrics_endpoint))
         .route("/metrics/prometheus", get(prometheus_metrics))
+        .route("/strategies/cooldowns", get(strategy_cooldowns))
+        .route("/strategies/execution-cooldowns", get(execution_cooldowns))
+        .route("/strategies/allocations", get(strategy_allocations))
+        .route("/strategies/panics", get(strategy_panics))
+        .route(
+            "/strategies/shadow-divergence",
+            get(shadow_divergence_status),
+        )
+        .route("/strategies/fee-budgets", get(fee_budgets))
+        .route("/admin/wallet-selection-trace", get(wallet_selection_trace))
+        .route("/trading/simulation-guard", get(simulation_guard_status))
+        .route("/trading/pending", get(pending_trades))
+        .route("/reports/ai-accuracy", get(ai_accuracy_report))
+        .route("/debug/trace/:signal_id", get(pipeline_trace))
+        .merge(admin)
         .with_state(state)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_latency_histogram() {
+        let mut histogram = LatencyHistogram::with_buckets(vec![10.0, 1.0, 5.0]);
+        for latency in [0.5, 3.0, 4.0, 8.0, 40.0] {
+            histogram.record(latency);
+        }
+
+        assert_eq!(histogram.bucket_bounds_ms, vec![1.0, 5.0, 10.0]);
+        assert_eq!(histogram.bucket_counts, vec![1, 2, 1, 1]);
+        assert_eq!(histogram.count, 5);
+        assert_eq!(histogram.percentile_ms(0.5), 5.0);
+        assert_eq!(histogram.percentile_ms(1.0), 40.0);
+        assert!((histogram.mean_ms() - 11.1).abs() < 1e-9);
+        let summary = histogram.summary();
+        assert_eq!((summary.p50_ms, summary.p90_ms, summary.p99_ms), (5.0, 40.0, 40.0));
+
+        let exported = histogram.to_prometheus("latency", "test");
+        assert!(exported.contains("latency_bucket{le=\"5\"} 3"));
+        assert!(exported.contains("latency_bucket{le=\"+Inf\"} 5"));
+    }
+
+    #[test]
+    fn test_admin_routes_require_the_configured_token() {
+        let headers = |value: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert(AUTHORIZATION, value.parse().unwrap());
+            headers
+        };
+
+        // Disabled entirely until a token is configured
+        assert_eq!(
+            check_admin_token(None, &headers("Bearer anything")),
+            Err(StatusCode::FORBIDDEN)
+        );
+        assert_eq!(
+            check_admin_token(Some("s3cret"), &HeaderMap::new()),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+        assert_eq!(
+            check_admin_token(Some("s3cret"), &headers("Bearer s3cre")),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+        assert_eq!(
+            check_admin_token(Some("s3cret"), &headers("s3cret")),
+            Err(StatusCode::UNAUTHORIZED)
+        );
+        assert_eq!(check_admin_token(Some("s3cret"), &headers("Bearer s3cret")), Ok(()));
+    }
+
+    #[tokio::test]
+    async fn test_replace_pending_route_requires_admin_token() {
+        let router = create_monitoring_router(MonitoringState::new().with_admin_token("s3cret"));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/trading/pending/sig-1/replace", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        let client = reqwest::Client::new();
+        let unauthenticated = client.post(&url).send().await.unwrap();
+        assert_eq!(unauthenticated.status().as_u16(), StatusCode::UNAUTHORIZED.as_u16());
+
+        // Authorized, but nothing is pending under that signal
+        let authorized = client.post(&url).bearer_auth("s3cret").send().await.unwrap();
+        assert_eq!(authorized.status().as_u16(), StatusCode::NOT_FOUND.as_u16());
+    }
+
+    #[test]
+    fn test_health_issues_name_failed_dependency() {
+        let state = MonitoringState::new();
+        for component in [
+            "data_ingestor",
+            "strategy_engine",
+            "risk_manager",
+            "executor",
+            "persistence",
+        ] {
+            state.update_component_health(component, "running", 0, 0);
+        }
+        let health = state.health.lock().unwrap().clone();
+
+        let mut dependencies = BTreeMap::new();
+        dependencies.insert(
+            "jito".to_string(),
+            DependencyStatus {
+                reachable: true,
+                last_checked: chrono::Utc::now(),
+                latency_ms: 12,
+                error: None,
+            },
+        );
+        assert!(collect_health_issues(&health, &dependencies, None).is_empty());
+
+        dependencies.insert(
+            "solana_rpc".to_string(),
+            DependencyStatus {
+                reachable: false,
+                last_checked: chrono::Utc::now(),
+                latency_ms: 2000,
+                error: Some("probe timed out".to_string()),
+            },
+        );
+        let issues = collect_health_issues(&health, &dependencies, None);
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].starts_with("solana_rpc unreachable"));
+
+        let skew = ClockSkewStatus {
+            skew_ms: -7_500,
+            max_skew_ms: 5_000,
+            within_tolerance: false,
+            cluster_time: chrono::Utc::now(),
+            last_checked: chrono::Utc::now(),
+            source: "solana_block_time".to_string(),
+        };
+        let issues = collect_health_issues(&health, &BTreeMap::new(), Some(&skew));
+        assert_eq!(
+            issues,
+            vec!["clock skew -7500ms exceeds 5000ms".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_readiness_requires_an_active_strategy() {
+        let state = MonitoringState::new().with_required_active_strategies(true);
+        for component in [
+            "data_ingestor",
+            "strategy_engine",
+            "risk_manager",
+            "executor",
+            "persistence",
+        ] {
+            state.update_component_health(component, "running", 0, 0);
+        }
+        assert_eq!(state.active_strategy_count(), None);
+
+        state.set_engine_strategies(vec![StrategyType::TokenSniping]);
+        assert_eq!(state.active_strategy_count(), Some(1));
+        assert_eq!(
+            readiness_check(State(state.clone())).await,
+            Ok(StatusCode::OK)
+        );
+
+        // The only strategy is disabled after panicking
+        state
+            .strategy_sandbox
+            .lock()
+            .unwrap()
+            .record_panic(&StrategyType::TokenSniping, "bad tick".to_string());
+        assert_eq!(state.active_strategy_count(), Some(0));
+        assert_eq!(
+            readiness_check(State(state.clone())).await,
+            Err(StatusCode::SERVICE_UNAVAILABLE)
+        );
+    }
+}